@@ -0,0 +1,68 @@
+//! Lexer throughput on a synthetic document of about 6 MB, made of rows of markup with text,
+//! attributes, character references and comments, and a script every 20 rows. The document is
+//! the same on every run. Run with `cargo bench --bench lexer`.
+
+use std::time::Instant;
+
+use toy_browser::html::lexer::{Lexer, LexerState};
+use toy_browser::html::tokens::Token;
+
+const WORDS: [&str; 16] = [
+    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit", "sed", "do",
+    "eiusmod", "tempor", "incididunt", "ut", "labore", "et",
+];
+
+/// Builds the document, picking words with a fixed linear congruential generator.
+fn document(size: usize) -> String {
+    let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+    let mut word = || {
+        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        WORDS[(seed >> 60) as usize]
+    };
+    let mut document = String::from("<!DOCTYPE html><html><head><title>Bench &amp; stuff</title>\
+        <style>body { color: red; } p > a { x: y }</style></head><body>\n");
+    let mut row = 0;
+    while document.len() < size {
+        document += &format!("<div class=\"row item-{row}\" id=\"n{row}\" data-x='{}'><p>", word());
+        for _ in 0..20 {
+            document += word();
+            document += " ";
+        }
+        document += &format!("&amp; {} &copy; 2024</p><a href=\"/path/to?q={row}&amp;r=2\">link</a>\
+            <!-- comment {row} --><img src=a.png alt=\"\"><br/></div>\n", word());
+        if row % 20 == 19 {
+            document += "<script>var a = 1 < 2 && x; // <b>\n</script>\n";
+        }
+        row += 1;
+    }
+    document + "</body></html>"
+}
+
+/// Runs the lexer over the whole input, switching to script data after `<script>` the way
+/// the tree builder would. Returns the number of tokens.
+fn lex(input: &str) -> usize {
+    let mut lexer = Lexer::new(input.to_string());
+    let mut count = 0;
+    while let Some(token) = lexer.next_token() {
+        count += 1;
+        match token {
+            Token::Tag(tag) if !tag.end_tag && tag.name == "script" => lexer.set_state(LexerState::ScriptDataState),
+            Token::EndOfFile() => break,
+            _ => {}
+        }
+    }
+    count
+}
+
+fn main() {
+    let input = document(6_000_000);
+    let mut best = f64::MAX;
+    let mut count = 0;
+    for _ in 0..5 {
+        let start = Instant::now();
+        count = lex(&input);
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    println!("{} bytes, {} tokens, best of 5: {:.3}s, {:.1} MB/s",
+        input.len(), count, best, input.len() as f64 / best / 1e6);
+}
@@ -0,0 +1,905 @@
+//! Interned element and attribute names. Every HTML, SVG and MathML name the parser knows
+//! about has a constant here, so that matching on a tag or attribute name is an integer
+//! comparison. Names that aren't known are kept in shared strings instead.
+//! The constants and tables are generated, do not edit them by hand.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// An element or attribute name. Two atoms are equal exactly when their names are.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Atom(Repr);
+
+/// A name in the static table is its index there. Any other name is kept in a shared string,
+/// which is freed along with the last element or attribute using it.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Repr {
+    Static(u32),
+    Dynamic(Arc<str>),
+}
+
+impl Atom {
+    /// The atom for `name`, which is case-sensitive.
+    pub fn new(name: &str) -> Atom {
+        match SORTED.binary_search_by(|&index| NAMES[index as usize].cmp(name)) {
+            Ok(found) => Atom(Repr::Static(SORTED[found] as u32)),
+            Err(_) => Atom(Repr::Dynamic(name.into()))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Static(index) => NAMES[*index as usize],
+            Repr::Dynamic(name) => name
+        }
+    }
+
+    /// Whether this is one of the names known ahead of time.
+    pub fn is_static(&self) -> bool {
+        matches!(self.0, Repr::Static(_))
+    }
+}
+
+impl Default for Atom {
+    fn default() -> Atom {
+        EMPTY
+    }
+}
+
+impl From<&str> for Atom {
+    fn from(name: &str) -> Atom {
+        Atom::new(name)
+    }
+}
+
+impl PartialEq<str> for Atom {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Atom {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+pub const EMPTY: Atom = Atom(Repr::Static(0));
+pub const A: Atom = Atom(Repr::Static(1));
+pub const ABBR: Atom = Atom(Repr::Static(2));
+pub const ACRONYM: Atom = Atom(Repr::Static(3));
+pub const ADDRESS: Atom = Atom(Repr::Static(4));
+pub const APPLET: Atom = Atom(Repr::Static(5));
+pub const AREA: Atom = Atom(Repr::Static(6));
+pub const ARTICLE: Atom = Atom(Repr::Static(7));
+pub const ASIDE: Atom = Atom(Repr::Static(8));
+pub const AUDIO: Atom = Atom(Repr::Static(9));
+pub const B: Atom = Atom(Repr::Static(10));
+pub const BASE: Atom = Atom(Repr::Static(11));
+pub const BASEFONT: Atom = Atom(Repr::Static(12));
+pub const BDI: Atom = Atom(Repr::Static(13));
+pub const BDO: Atom = Atom(Repr::Static(14));
+pub const BGSOUND: Atom = Atom(Repr::Static(15));
+pub const BIG: Atom = Atom(Repr::Static(16));
+pub const BLINK: Atom = Atom(Repr::Static(17));
+pub const BLOCKQUOTE: Atom = Atom(Repr::Static(18));
+pub const BODY: Atom = Atom(Repr::Static(19));
+pub const BR: Atom = Atom(Repr::Static(20));
+pub const BUTTON: Atom = Atom(Repr::Static(21));
+pub const CANVAS: Atom = Atom(Repr::Static(22));
+pub const CAPTION: Atom = Atom(Repr::Static(23));
+pub const CENTER: Atom = Atom(Repr::Static(24));
+pub const CITE: Atom = Atom(Repr::Static(25));
+pub const CODE: Atom = Atom(Repr::Static(26));
+pub const COL: Atom = Atom(Repr::Static(27));
+pub const COLGROUP: Atom = Atom(Repr::Static(28));
+pub const DATA: Atom = Atom(Repr::Static(29));
+pub const DATALIST: Atom = Atom(Repr::Static(30));
+pub const DD: Atom = Atom(Repr::Static(31));
+pub const DEL: Atom = Atom(Repr::Static(32));
+pub const DETAILS: Atom = Atom(Repr::Static(33));
+pub const DFN: Atom = Atom(Repr::Static(34));
+pub const DIALOG: Atom = Atom(Repr::Static(35));
+pub const DIR: Atom = Atom(Repr::Static(36));
+pub const DIV: Atom = Atom(Repr::Static(37));
+pub const DL: Atom = Atom(Repr::Static(38));
+pub const DT: Atom = Atom(Repr::Static(39));
+pub const EM: Atom = Atom(Repr::Static(40));
+pub const EMBED: Atom = Atom(Repr::Static(41));
+pub const FIELDSET: Atom = Atom(Repr::Static(42));
+pub const FIGCAPTION: Atom = Atom(Repr::Static(43));
+pub const FIGURE: Atom = Atom(Repr::Static(44));
+pub const FONT: Atom = Atom(Repr::Static(45));
+pub const FOOTER: Atom = Atom(Repr::Static(46));
+pub const FORM: Atom = Atom(Repr::Static(47));
+pub const FRAME: Atom = Atom(Repr::Static(48));
+pub const FRAMESET: Atom = Atom(Repr::Static(49));
+pub const H1: Atom = Atom(Repr::Static(50));
+pub const H2: Atom = Atom(Repr::Static(51));
+pub const H3: Atom = Atom(Repr::Static(52));
+pub const H4: Atom = Atom(Repr::Static(53));
+pub const H5: Atom = Atom(Repr::Static(54));
+pub const H6: Atom = Atom(Repr::Static(55));
+pub const HEAD: Atom = Atom(Repr::Static(56));
+pub const HEADER: Atom = Atom(Repr::Static(57));
+pub const HGROUP: Atom = Atom(Repr::Static(58));
+pub const HR: Atom = Atom(Repr::Static(59));
+pub const HTML: Atom = Atom(Repr::Static(60));
+pub const I: Atom = Atom(Repr::Static(61));
+pub const IFRAME: Atom = Atom(Repr::Static(62));
+pub const IMAGE: Atom = Atom(Repr::Static(63));
+pub const IMG: Atom = Atom(Repr::Static(64));
+pub const INPUT: Atom = Atom(Repr::Static(65));
+pub const INS: Atom = Atom(Repr::Static(66));
+pub const ISINDEX: Atom = Atom(Repr::Static(67));
+pub const KBD: Atom = Atom(Repr::Static(68));
+pub const KEYGEN: Atom = Atom(Repr::Static(69));
+pub const LABEL: Atom = Atom(Repr::Static(70));
+pub const LEGEND: Atom = Atom(Repr::Static(71));
+pub const LI: Atom = Atom(Repr::Static(72));
+pub const LINK: Atom = Atom(Repr::Static(73));
+pub const LISTING: Atom = Atom(Repr::Static(74));
+pub const MAIN: Atom = Atom(Repr::Static(75));
+pub const MAP: Atom = Atom(Repr::Static(76));
+pub const MARK: Atom = Atom(Repr::Static(77));
+pub const MARQUEE: Atom = Atom(Repr::Static(78));
+pub const MENU: Atom = Atom(Repr::Static(79));
+pub const MENUITEM: Atom = Atom(Repr::Static(80));
+pub const META: Atom = Atom(Repr::Static(81));
+pub const METER: Atom = Atom(Repr::Static(82));
+pub const NAV: Atom = Atom(Repr::Static(83));
+pub const NOBR: Atom = Atom(Repr::Static(84));
+pub const NOEMBED: Atom = Atom(Repr::Static(85));
+pub const NOFRAMES: Atom = Atom(Repr::Static(86));
+pub const NOSCRIPT: Atom = Atom(Repr::Static(87));
+pub const OBJECT: Atom = Atom(Repr::Static(88));
+pub const OL: Atom = Atom(Repr::Static(89));
+pub const OPTGROUP: Atom = Atom(Repr::Static(90));
+pub const OPTION: Atom = Atom(Repr::Static(91));
+pub const OUTPUT: Atom = Atom(Repr::Static(92));
+pub const P: Atom = Atom(Repr::Static(93));
+pub const PARAM: Atom = Atom(Repr::Static(94));
+pub const PICTURE: Atom = Atom(Repr::Static(95));
+pub const PLAINTEXT: Atom = Atom(Repr::Static(96));
+pub const PRE: Atom = Atom(Repr::Static(97));
+pub const PROGRESS: Atom = Atom(Repr::Static(98));
+pub const Q: Atom = Atom(Repr::Static(99));
+pub const RB: Atom = Atom(Repr::Static(100));
+pub const RP: Atom = Atom(Repr::Static(101));
+pub const RT: Atom = Atom(Repr::Static(102));
+pub const RTC: Atom = Atom(Repr::Static(103));
+pub const RUBY: Atom = Atom(Repr::Static(104));
+pub const S: Atom = Atom(Repr::Static(105));
+pub const SAMP: Atom = Atom(Repr::Static(106));
+pub const SCRIPT: Atom = Atom(Repr::Static(107));
+pub const SEARCH: Atom = Atom(Repr::Static(108));
+pub const SECTION: Atom = Atom(Repr::Static(109));
+pub const SELECT: Atom = Atom(Repr::Static(110));
+pub const SLOT: Atom = Atom(Repr::Static(111));
+pub const SMALL: Atom = Atom(Repr::Static(112));
+pub const SOURCE: Atom = Atom(Repr::Static(113));
+pub const SPAN: Atom = Atom(Repr::Static(114));
+pub const STRIKE: Atom = Atom(Repr::Static(115));
+pub const STRONG: Atom = Atom(Repr::Static(116));
+pub const STYLE: Atom = Atom(Repr::Static(117));
+pub const SUB: Atom = Atom(Repr::Static(118));
+pub const SUMMARY: Atom = Atom(Repr::Static(119));
+pub const SUP: Atom = Atom(Repr::Static(120));
+pub const TABLE: Atom = Atom(Repr::Static(121));
+pub const TBODY: Atom = Atom(Repr::Static(122));
+pub const TD: Atom = Atom(Repr::Static(123));
+pub const TEMPLATE: Atom = Atom(Repr::Static(124));
+pub const TEXTAREA: Atom = Atom(Repr::Static(125));
+pub const TFOOT: Atom = Atom(Repr::Static(126));
+pub const TH: Atom = Atom(Repr::Static(127));
+pub const THEAD: Atom = Atom(Repr::Static(128));
+pub const TIME: Atom = Atom(Repr::Static(129));
+pub const TITLE: Atom = Atom(Repr::Static(130));
+pub const TR: Atom = Atom(Repr::Static(131));
+pub const TRACK: Atom = Atom(Repr::Static(132));
+pub const TT: Atom = Atom(Repr::Static(133));
+pub const U: Atom = Atom(Repr::Static(134));
+pub const UL: Atom = Atom(Repr::Static(135));
+pub const VAR: Atom = Atom(Repr::Static(136));
+pub const VIDEO: Atom = Atom(Repr::Static(137));
+pub const WBR: Atom = Atom(Repr::Static(138));
+pub const XMP: Atom = Atom(Repr::Static(139));
+pub const SVG: Atom = Atom(Repr::Static(140));
+pub const G: Atom = Atom(Repr::Static(141));
+pub const PATH: Atom = Atom(Repr::Static(142));
+pub const RECT: Atom = Atom(Repr::Static(143));
+pub const CIRCLE: Atom = Atom(Repr::Static(144));
+pub const ELLIPSE: Atom = Atom(Repr::Static(145));
+pub const LINE: Atom = Atom(Repr::Static(146));
+pub const POLYLINE: Atom = Atom(Repr::Static(147));
+pub const POLYGON: Atom = Atom(Repr::Static(148));
+pub const TEXT: Atom = Atom(Repr::Static(149));
+pub const TSPAN: Atom = Atom(Repr::Static(150));
+pub const DEFS: Atom = Atom(Repr::Static(151));
+pub const USE: Atom = Atom(Repr::Static(152));
+pub const SYMBOL: Atom = Atom(Repr::Static(153));
+pub const MARKER: Atom = Atom(Repr::Static(154));
+pub const MASK: Atom = Atom(Repr::Static(155));
+pub const PATTERN: Atom = Atom(Repr::Static(156));
+pub const STOP: Atom = Atom(Repr::Static(157));
+pub const FILTER: Atom = Atom(Repr::Static(158));
+pub const DESC: Atom = Atom(Repr::Static(159));
+pub const ANIMATE: Atom = Atom(Repr::Static(160));
+pub const SET: Atom = Atom(Repr::Static(161));
+pub const MPATH: Atom = Atom(Repr::Static(162));
+pub const VIEW: Atom = Atom(Repr::Static(163));
+pub const SWITCH: Atom = Atom(Repr::Static(164));
+pub const METADATA: Atom = Atom(Repr::Static(165));
+pub const GLYPH: Atom = Atom(Repr::Static(166));
+pub const HKERN: Atom = Atom(Repr::Static(167));
+pub const VKERN: Atom = Atom(Repr::Static(168));
+pub const MISSING_GLYPH: Atom = Atom(Repr::Static(169));
+pub const FONT_FACE: Atom = Atom(Repr::Static(170));
+pub const FONT_FACE_SRC: Atom = Atom(Repr::Static(171));
+pub const FONT_FACE_URI: Atom = Atom(Repr::Static(172));
+pub const FONT_FACE_FORMAT: Atom = Atom(Repr::Static(173));
+pub const FONT_FACE_NAME: Atom = Atom(Repr::Static(174));
+pub const CURSOR: Atom = Atom(Repr::Static(175));
+pub const TREF: Atom = Atom(Repr::Static(176));
+pub const MATH: Atom = Atom(Repr::Static(177));
+pub const MI: Atom = Atom(Repr::Static(178));
+pub const MO: Atom = Atom(Repr::Static(179));
+pub const MN: Atom = Atom(Repr::Static(180));
+pub const MS: Atom = Atom(Repr::Static(181));
+pub const MTEXT: Atom = Atom(Repr::Static(182));
+pub const ANNOTATION_XML: Atom = Atom(Repr::Static(183));
+pub const ANNOTATION: Atom = Atom(Repr::Static(184));
+pub const SEMANTICS: Atom = Atom(Repr::Static(185));
+pub const MROW: Atom = Atom(Repr::Static(186));
+pub const MFRAC: Atom = Atom(Repr::Static(187));
+pub const MSQRT: Atom = Atom(Repr::Static(188));
+pub const MROOT: Atom = Atom(Repr::Static(189));
+pub const MSTYLE: Atom = Atom(Repr::Static(190));
+pub const MERROR: Atom = Atom(Repr::Static(191));
+pub const MPADDED: Atom = Atom(Repr::Static(192));
+pub const MPHANTOM: Atom = Atom(Repr::Static(193));
+pub const MFENCED: Atom = Atom(Repr::Static(194));
+pub const MENCLOSE: Atom = Atom(Repr::Static(195));
+pub const MSUB: Atom = Atom(Repr::Static(196));
+pub const MSUP: Atom = Atom(Repr::Static(197));
+pub const MSUBSUP: Atom = Atom(Repr::Static(198));
+pub const MUNDER: Atom = Atom(Repr::Static(199));
+pub const MOVER: Atom = Atom(Repr::Static(200));
+pub const MUNDEROVER: Atom = Atom(Repr::Static(201));
+pub const MMULTISCRIPTS: Atom = Atom(Repr::Static(202));
+pub const MPRESCRIPTS: Atom = Atom(Repr::Static(203));
+pub const NONE: Atom = Atom(Repr::Static(204));
+pub const MTABLE: Atom = Atom(Repr::Static(205));
+pub const MTR: Atom = Atom(Repr::Static(206));
+pub const MTD: Atom = Atom(Repr::Static(207));
+pub const MLABELEDTR: Atom = Atom(Repr::Static(208));
+pub const MALIGNGROUP: Atom = Atom(Repr::Static(209));
+pub const MALIGNMARK: Atom = Atom(Repr::Static(210));
+pub const MSPACE: Atom = Atom(Repr::Static(211));
+pub const MACTION: Atom = Atom(Repr::Static(212));
+pub const MGLYPH: Atom = Atom(Repr::Static(213));
+pub const ACCEPT: Atom = Atom(Repr::Static(214));
+pub const ACCEPT_CHARSET: Atom = Atom(Repr::Static(215));
+pub const ACCESSKEY: Atom = Atom(Repr::Static(216));
+pub const ACTION: Atom = Atom(Repr::Static(217));
+pub const ALIGN: Atom = Atom(Repr::Static(218));
+pub const ALINK: Atom = Atom(Repr::Static(219));
+pub const ALLOW: Atom = Atom(Repr::Static(220));
+pub const ALLOWFULLSCREEN: Atom = Atom(Repr::Static(221));
+pub const ALT: Atom = Atom(Repr::Static(222));
+pub const ARCHIVE: Atom = Atom(Repr::Static(223));
+pub const ASYNC: Atom = Atom(Repr::Static(224));
+pub const AUTOCAPITALIZE: Atom = Atom(Repr::Static(225));
+pub const AUTOCOMPLETE: Atom = Atom(Repr::Static(226));
+pub const AUTOFOCUS: Atom = Atom(Repr::Static(227));
+pub const AUTOPLAY: Atom = Atom(Repr::Static(228));
+pub const AXIS: Atom = Atom(Repr::Static(229));
+pub const BACKGROUND: Atom = Atom(Repr::Static(230));
+pub const BGCOLOR: Atom = Atom(Repr::Static(231));
+pub const BORDER: Atom = Atom(Repr::Static(232));
+pub const CELLPADDING: Atom = Atom(Repr::Static(233));
+pub const CELLSPACING: Atom = Atom(Repr::Static(234));
+pub const CHAR: Atom = Atom(Repr::Static(235));
+pub const CHAROFF: Atom = Atom(Repr::Static(236));
+pub const CHARSET: Atom = Atom(Repr::Static(237));
+pub const CHECKED: Atom = Atom(Repr::Static(238));
+pub const CLASS: Atom = Atom(Repr::Static(239));
+pub const CLASSID: Atom = Atom(Repr::Static(240));
+pub const CLEAR: Atom = Atom(Repr::Static(241));
+pub const CODEBASE: Atom = Atom(Repr::Static(242));
+pub const CODETYPE: Atom = Atom(Repr::Static(243));
+pub const COLOR: Atom = Atom(Repr::Static(244));
+pub const COLS: Atom = Atom(Repr::Static(245));
+pub const COLSPAN: Atom = Atom(Repr::Static(246));
+pub const COMPACT: Atom = Atom(Repr::Static(247));
+pub const CONTENT: Atom = Atom(Repr::Static(248));
+pub const CONTENTEDITABLE: Atom = Atom(Repr::Static(249));
+pub const CONTROLS: Atom = Atom(Repr::Static(250));
+pub const COORDS: Atom = Atom(Repr::Static(251));
+pub const CROSSORIGIN: Atom = Atom(Repr::Static(252));
+pub const DATETIME: Atom = Atom(Repr::Static(253));
+pub const DECLARE: Atom = Atom(Repr::Static(254));
+pub const DECODING: Atom = Atom(Repr::Static(255));
+pub const DEFAULT: Atom = Atom(Repr::Static(256));
+pub const DEFER: Atom = Atom(Repr::Static(257));
+pub const DIRNAME: Atom = Atom(Repr::Static(258));
+pub const DISABLED: Atom = Atom(Repr::Static(259));
+pub const DOWNLOAD: Atom = Atom(Repr::Static(260));
+pub const DRAGGABLE: Atom = Atom(Repr::Static(261));
+pub const ENCTYPE: Atom = Atom(Repr::Static(262));
+pub const ENTERKEYHINT: Atom = Atom(Repr::Static(263));
+pub const FACE: Atom = Atom(Repr::Static(264));
+pub const FOR: Atom = Atom(Repr::Static(265));
+pub const FORMACTION: Atom = Atom(Repr::Static(266));
+pub const FORMENCTYPE: Atom = Atom(Repr::Static(267));
+pub const FORMMETHOD: Atom = Atom(Repr::Static(268));
+pub const FORMNOVALIDATE: Atom = Atom(Repr::Static(269));
+pub const FORMTARGET: Atom = Atom(Repr::Static(270));
+pub const FRAMEBORDER: Atom = Atom(Repr::Static(271));
+pub const HEADERS: Atom = Atom(Repr::Static(272));
+pub const HEIGHT: Atom = Atom(Repr::Static(273));
+pub const HIDDEN: Atom = Atom(Repr::Static(274));
+pub const HIGH: Atom = Atom(Repr::Static(275));
+pub const HREF: Atom = Atom(Repr::Static(276));
+pub const HREFLANG: Atom = Atom(Repr::Static(277));
+pub const HSPACE: Atom = Atom(Repr::Static(278));
+pub const HTTP_EQUIV: Atom = Atom(Repr::Static(279));
+pub const ID: Atom = Atom(Repr::Static(280));
+pub const INERT: Atom = Atom(Repr::Static(281));
+pub const INPUTMODE: Atom = Atom(Repr::Static(282));
+pub const INTEGRITY: Atom = Atom(Repr::Static(283));
+pub const IS: Atom = Atom(Repr::Static(284));
+pub const ISMAP: Atom = Atom(Repr::Static(285));
+pub const ITEMID: Atom = Atom(Repr::Static(286));
+pub const ITEMPROP: Atom = Atom(Repr::Static(287));
+pub const ITEMREF: Atom = Atom(Repr::Static(288));
+pub const ITEMSCOPE: Atom = Atom(Repr::Static(289));
+pub const ITEMTYPE: Atom = Atom(Repr::Static(290));
+pub const KIND: Atom = Atom(Repr::Static(291));
+pub const LANG: Atom = Atom(Repr::Static(292));
+pub const LANGUAGE: Atom = Atom(Repr::Static(293));
+pub const LIST: Atom = Atom(Repr::Static(294));
+pub const LOADING: Atom = Atom(Repr::Static(295));
+pub const LONGDESC: Atom = Atom(Repr::Static(296));
+pub const LOOP: Atom = Atom(Repr::Static(297));
+pub const LOW: Atom = Atom(Repr::Static(298));
+pub const MARGINHEIGHT: Atom = Atom(Repr::Static(299));
+pub const MARGINWIDTH: Atom = Atom(Repr::Static(300));
+pub const MAX: Atom = Atom(Repr::Static(301));
+pub const MAXLENGTH: Atom = Atom(Repr::Static(302));
+pub const MEDIA: Atom = Atom(Repr::Static(303));
+pub const METHOD: Atom = Atom(Repr::Static(304));
+pub const MIN: Atom = Atom(Repr::Static(305));
+pub const MINLENGTH: Atom = Atom(Repr::Static(306));
+pub const MULTIPLE: Atom = Atom(Repr::Static(307));
+pub const MUTED: Atom = Atom(Repr::Static(308));
+pub const NAME: Atom = Atom(Repr::Static(309));
+pub const NOHREF: Atom = Atom(Repr::Static(310));
+pub const NONCE: Atom = Atom(Repr::Static(311));
+pub const NORESIZE: Atom = Atom(Repr::Static(312));
+pub const NOSHADE: Atom = Atom(Repr::Static(313));
+pub const NOVALIDATE: Atom = Atom(Repr::Static(314));
+pub const NOWRAP: Atom = Atom(Repr::Static(315));
+pub const OPEN: Atom = Atom(Repr::Static(316));
+pub const OPTIMUM: Atom = Atom(Repr::Static(317));
+pub const PING: Atom = Atom(Repr::Static(318));
+pub const PLACEHOLDER: Atom = Atom(Repr::Static(319));
+pub const PLAYSINLINE: Atom = Atom(Repr::Static(320));
+pub const POPOVER: Atom = Atom(Repr::Static(321));
+pub const POSTER: Atom = Atom(Repr::Static(322));
+pub const PRELOAD: Atom = Atom(Repr::Static(323));
+pub const PROFILE: Atom = Atom(Repr::Static(324));
+pub const PROMPT: Atom = Atom(Repr::Static(325));
+pub const READONLY: Atom = Atom(Repr::Static(326));
+pub const REFERRERPOLICY: Atom = Atom(Repr::Static(327));
+pub const REL: Atom = Atom(Repr::Static(328));
+pub const REQUIRED: Atom = Atom(Repr::Static(329));
+pub const REV: Atom = Atom(Repr::Static(330));
+pub const REVERSED: Atom = Atom(Repr::Static(331));
+pub const ROLE: Atom = Atom(Repr::Static(332));
+pub const ROWS: Atom = Atom(Repr::Static(333));
+pub const ROWSPAN: Atom = Atom(Repr::Static(334));
+pub const RULES: Atom = Atom(Repr::Static(335));
+pub const SANDBOX: Atom = Atom(Repr::Static(336));
+pub const SCHEME: Atom = Atom(Repr::Static(337));
+pub const SCOPE: Atom = Atom(Repr::Static(338));
+pub const SCROLLING: Atom = Atom(Repr::Static(339));
+pub const SELECTED: Atom = Atom(Repr::Static(340));
+pub const SHAPE: Atom = Atom(Repr::Static(341));
+pub const SIZE: Atom = Atom(Repr::Static(342));
+pub const SIZES: Atom = Atom(Repr::Static(343));
+pub const SPELLCHECK: Atom = Atom(Repr::Static(344));
+pub const SRC: Atom = Atom(Repr::Static(345));
+pub const SRCDOC: Atom = Atom(Repr::Static(346));
+pub const SRCLANG: Atom = Atom(Repr::Static(347));
+pub const SRCSET: Atom = Atom(Repr::Static(348));
+pub const STANDBY: Atom = Atom(Repr::Static(349));
+pub const START: Atom = Atom(Repr::Static(350));
+pub const STEP: Atom = Atom(Repr::Static(351));
+pub const TABINDEX: Atom = Atom(Repr::Static(352));
+pub const TARGET: Atom = Atom(Repr::Static(353));
+pub const TRANSLATE: Atom = Atom(Repr::Static(354));
+pub const TYPE: Atom = Atom(Repr::Static(355));
+pub const USEMAP: Atom = Atom(Repr::Static(356));
+pub const VALIGN: Atom = Atom(Repr::Static(357));
+pub const VALUE: Atom = Atom(Repr::Static(358));
+pub const VALUETYPE: Atom = Atom(Repr::Static(359));
+pub const VERSION: Atom = Atom(Repr::Static(360));
+pub const VLINK: Atom = Atom(Repr::Static(361));
+pub const VSPACE: Atom = Atom(Repr::Static(362));
+pub const WIDTH: Atom = Atom(Repr::Static(363));
+pub const WRAP: Atom = Atom(Repr::Static(364));
+pub const D: Atom = Atom(Repr::Static(365));
+pub const X: Atom = Atom(Repr::Static(366));
+pub const Y: Atom = Atom(Repr::Static(367));
+pub const X1: Atom = Atom(Repr::Static(368));
+pub const X2: Atom = Atom(Repr::Static(369));
+pub const Y1: Atom = Atom(Repr::Static(370));
+pub const Y2: Atom = Atom(Repr::Static(371));
+pub const CX: Atom = Atom(Repr::Static(372));
+pub const CY: Atom = Atom(Repr::Static(373));
+pub const R: Atom = Atom(Repr::Static(374));
+pub const RX: Atom = Atom(Repr::Static(375));
+pub const RY: Atom = Atom(Repr::Static(376));
+pub const FX: Atom = Atom(Repr::Static(377));
+pub const FY: Atom = Atom(Repr::Static(378));
+pub const FR: Atom = Atom(Repr::Static(379));
+pub const DX: Atom = Atom(Repr::Static(380));
+pub const DY: Atom = Atom(Repr::Static(381));
+pub const FILL: Atom = Atom(Repr::Static(382));
+pub const FILL_OPACITY: Atom = Atom(Repr::Static(383));
+pub const FILL_RULE: Atom = Atom(Repr::Static(384));
+pub const STROKE: Atom = Atom(Repr::Static(385));
+pub const STROKE_WIDTH: Atom = Atom(Repr::Static(386));
+pub const STROKE_OPACITY: Atom = Atom(Repr::Static(387));
+pub const STROKE_LINECAP: Atom = Atom(Repr::Static(388));
+pub const STROKE_LINEJOIN: Atom = Atom(Repr::Static(389));
+pub const STROKE_DASHARRAY: Atom = Atom(Repr::Static(390));
+pub const STROKE_DASHOFFSET: Atom = Atom(Repr::Static(391));
+pub const STROKE_MITERLIMIT: Atom = Atom(Repr::Static(392));
+pub const OPACITY: Atom = Atom(Repr::Static(393));
+pub const TRANSFORM: Atom = Atom(Repr::Static(394));
+pub const POINTS: Atom = Atom(Repr::Static(395));
+pub const CLIP_PATH: Atom = Atom(Repr::Static(396));
+pub const CLIP_RULE: Atom = Atom(Repr::Static(397));
+pub const FONT_FAMILY: Atom = Atom(Repr::Static(398));
+pub const FONT_SIZE: Atom = Atom(Repr::Static(399));
+pub const FONT_WEIGHT: Atom = Atom(Repr::Static(400));
+pub const FONT_STYLE: Atom = Atom(Repr::Static(401));
+pub const TEXT_ANCHOR: Atom = Atom(Repr::Static(402));
+pub const OFFSET: Atom = Atom(Repr::Static(403));
+pub const STOP_COLOR: Atom = Atom(Repr::Static(404));
+pub const STOP_OPACITY: Atom = Atom(Repr::Static(405));
+pub const IN: Atom = Atom(Repr::Static(406));
+pub const IN2: Atom = Atom(Repr::Static(407));
+pub const RESULT: Atom = Atom(Repr::Static(408));
+pub const MODE: Atom = Atom(Repr::Static(409));
+pub const OPERATOR: Atom = Atom(Repr::Static(410));
+pub const VALUES: Atom = Atom(Repr::Static(411));
+pub const DUR: Atom = Atom(Repr::Static(412));
+pub const BEGIN: Atom = Atom(Repr::Static(413));
+pub const END: Atom = Atom(Repr::Static(414));
+pub const FROM: Atom = Atom(Repr::Static(415));
+pub const TO: Atom = Atom(Repr::Static(416));
+pub const BY: Atom = Atom(Repr::Static(417));
+pub const VISIBILITY: Atom = Atom(Repr::Static(418));
+pub const DISPLAY: Atom = Atom(Repr::Static(419));
+pub const XMLNS: Atom = Atom(Repr::Static(420));
+pub const MATHVARIANT: Atom = Atom(Repr::Static(421));
+pub const MATHCOLOR: Atom = Atom(Repr::Static(422));
+pub const MATHBACKGROUND: Atom = Atom(Repr::Static(423));
+pub const MATHSIZE: Atom = Atom(Repr::Static(424));
+pub const DISPLAYSTYLE: Atom = Atom(Repr::Static(425));
+pub const SCRIPTLEVEL: Atom = Atom(Repr::Static(426));
+pub const STRETCHY: Atom = Atom(Repr::Static(427));
+pub const FENCE: Atom = Atom(Repr::Static(428));
+pub const SEPARATOR: Atom = Atom(Repr::Static(429));
+pub const SEPARATORS: Atom = Atom(Repr::Static(430));
+pub const LSPACE: Atom = Atom(Repr::Static(431));
+pub const RSPACE: Atom = Atom(Repr::Static(432));
+pub const ACCENT: Atom = Atom(Repr::Static(433));
+pub const ACCENTUNDER: Atom = Atom(Repr::Static(434));
+pub const LINETHICKNESS: Atom = Atom(Repr::Static(435));
+pub const COLUMNALIGN: Atom = Atom(Repr::Static(436));
+pub const ROWALIGN: Atom = Atom(Repr::Static(437));
+pub const ENCODING: Atom = Atom(Repr::Static(438));
+pub const XLINK_ACTUATE: Atom = Atom(Repr::Static(439));
+pub const XLINK_ARCROLE: Atom = Atom(Repr::Static(440));
+pub const XLINK_HREF: Atom = Atom(Repr::Static(441));
+pub const XLINK_ROLE: Atom = Atom(Repr::Static(442));
+pub const XLINK_SHOW: Atom = Atom(Repr::Static(443));
+pub const XLINK_TITLE: Atom = Atom(Repr::Static(444));
+pub const XLINK_TYPE: Atom = Atom(Repr::Static(445));
+pub const XML_LANG: Atom = Atom(Repr::Static(446));
+pub const XML_SPACE: Atom = Atom(Repr::Static(447));
+pub const XMLNS_XLINK: Atom = Atom(Repr::Static(448));
+pub const ALTGLYPH: Atom = Atom(Repr::Static(449));
+pub const ALT_GLYPH: Atom = Atom(Repr::Static(450));
+pub const ALTGLYPHDEF: Atom = Atom(Repr::Static(451));
+pub const ALT_GLYPH_DEF: Atom = Atom(Repr::Static(452));
+pub const ALTGLYPHITEM: Atom = Atom(Repr::Static(453));
+pub const ALT_GLYPH_ITEM: Atom = Atom(Repr::Static(454));
+pub const ANIMATECOLOR: Atom = Atom(Repr::Static(455));
+pub const ANIMATE_COLOR: Atom = Atom(Repr::Static(456));
+pub const ANIMATEMOTION: Atom = Atom(Repr::Static(457));
+pub const ANIMATE_MOTION: Atom = Atom(Repr::Static(458));
+pub const ANIMATETRANSFORM: Atom = Atom(Repr::Static(459));
+pub const ANIMATE_TRANSFORM: Atom = Atom(Repr::Static(460));
+pub const CLIPPATH: Atom = Atom(Repr::Static(461));
+pub const CLIPPATH_ADJUSTED: Atom = Atom(Repr::Static(462));
+pub const FEBLEND: Atom = Atom(Repr::Static(463));
+pub const FE_BLEND: Atom = Atom(Repr::Static(464));
+pub const FECOLORMATRIX: Atom = Atom(Repr::Static(465));
+pub const FE_COLOR_MATRIX: Atom = Atom(Repr::Static(466));
+pub const FECOMPONENTTRANSFER: Atom = Atom(Repr::Static(467));
+pub const FE_COMPONENT_TRANSFER: Atom = Atom(Repr::Static(468));
+pub const FECOMPOSITE: Atom = Atom(Repr::Static(469));
+pub const FE_COMPOSITE: Atom = Atom(Repr::Static(470));
+pub const FECONVOLVEMATRIX: Atom = Atom(Repr::Static(471));
+pub const FE_CONVOLVE_MATRIX: Atom = Atom(Repr::Static(472));
+pub const FEDIFFUSELIGHTING: Atom = Atom(Repr::Static(473));
+pub const FE_DIFFUSE_LIGHTING: Atom = Atom(Repr::Static(474));
+pub const FEDISPLACEMENTMAP: Atom = Atom(Repr::Static(475));
+pub const FE_DISPLACEMENT_MAP: Atom = Atom(Repr::Static(476));
+pub const FEDISTANTLIGHT: Atom = Atom(Repr::Static(477));
+pub const FE_DISTANT_LIGHT: Atom = Atom(Repr::Static(478));
+pub const FEDROPSHADOW: Atom = Atom(Repr::Static(479));
+pub const FE_DROP_SHADOW: Atom = Atom(Repr::Static(480));
+pub const FEFLOOD: Atom = Atom(Repr::Static(481));
+pub const FE_FLOOD: Atom = Atom(Repr::Static(482));
+pub const FEFUNCA: Atom = Atom(Repr::Static(483));
+pub const FE_FUNC_A: Atom = Atom(Repr::Static(484));
+pub const FEFUNCB: Atom = Atom(Repr::Static(485));
+pub const FE_FUNC_B: Atom = Atom(Repr::Static(486));
+pub const FEFUNCG: Atom = Atom(Repr::Static(487));
+pub const FE_FUNC_G: Atom = Atom(Repr::Static(488));
+pub const FEFUNCR: Atom = Atom(Repr::Static(489));
+pub const FE_FUNC_R: Atom = Atom(Repr::Static(490));
+pub const FEGAUSSIANBLUR: Atom = Atom(Repr::Static(491));
+pub const FE_GAUSSIAN_BLUR: Atom = Atom(Repr::Static(492));
+pub const FEIMAGE: Atom = Atom(Repr::Static(493));
+pub const FE_IMAGE: Atom = Atom(Repr::Static(494));
+pub const FEMERGE: Atom = Atom(Repr::Static(495));
+pub const FE_MERGE: Atom = Atom(Repr::Static(496));
+pub const FEMERGENODE: Atom = Atom(Repr::Static(497));
+pub const FE_MERGE_NODE: Atom = Atom(Repr::Static(498));
+pub const FEMORPHOLOGY: Atom = Atom(Repr::Static(499));
+pub const FE_MORPHOLOGY: Atom = Atom(Repr::Static(500));
+pub const FEOFFSET: Atom = Atom(Repr::Static(501));
+pub const FE_OFFSET: Atom = Atom(Repr::Static(502));
+pub const FEPOINTLIGHT: Atom = Atom(Repr::Static(503));
+pub const FE_POINT_LIGHT: Atom = Atom(Repr::Static(504));
+pub const FESPECULARLIGHTING: Atom = Atom(Repr::Static(505));
+pub const FE_SPECULAR_LIGHTING: Atom = Atom(Repr::Static(506));
+pub const FESPOTLIGHT: Atom = Atom(Repr::Static(507));
+pub const FE_SPOT_LIGHT: Atom = Atom(Repr::Static(508));
+pub const FETILE: Atom = Atom(Repr::Static(509));
+pub const FE_TILE: Atom = Atom(Repr::Static(510));
+pub const FETURBULENCE: Atom = Atom(Repr::Static(511));
+pub const FE_TURBULENCE: Atom = Atom(Repr::Static(512));
+pub const FOREIGNOBJECT: Atom = Atom(Repr::Static(513));
+pub const FOREIGN_OBJECT: Atom = Atom(Repr::Static(514));
+pub const GLYPHREF: Atom = Atom(Repr::Static(515));
+pub const GLYPH_REF: Atom = Atom(Repr::Static(516));
+pub const LINEARGRADIENT: Atom = Atom(Repr::Static(517));
+pub const LINEAR_GRADIENT: Atom = Atom(Repr::Static(518));
+pub const RADIALGRADIENT: Atom = Atom(Repr::Static(519));
+pub const RADIAL_GRADIENT: Atom = Atom(Repr::Static(520));
+pub const TEXTPATH: Atom = Atom(Repr::Static(521));
+pub const TEXT_PATH: Atom = Atom(Repr::Static(522));
+pub const ATTRIBUTENAME: Atom = Atom(Repr::Static(523));
+pub const ATTRIBUTE_NAME: Atom = Atom(Repr::Static(524));
+pub const ATTRIBUTETYPE: Atom = Atom(Repr::Static(525));
+pub const ATTRIBUTE_TYPE: Atom = Atom(Repr::Static(526));
+pub const BASEFREQUENCY: Atom = Atom(Repr::Static(527));
+pub const BASE_FREQUENCY: Atom = Atom(Repr::Static(528));
+pub const BASEPROFILE: Atom = Atom(Repr::Static(529));
+pub const BASE_PROFILE: Atom = Atom(Repr::Static(530));
+pub const CALCMODE: Atom = Atom(Repr::Static(531));
+pub const CALC_MODE: Atom = Atom(Repr::Static(532));
+pub const CLIPPATHUNITS: Atom = Atom(Repr::Static(533));
+pub const CLIP_PATH_UNITS: Atom = Atom(Repr::Static(534));
+pub const DIFFUSECONSTANT: Atom = Atom(Repr::Static(535));
+pub const DIFFUSE_CONSTANT: Atom = Atom(Repr::Static(536));
+pub const EDGEMODE: Atom = Atom(Repr::Static(537));
+pub const EDGE_MODE: Atom = Atom(Repr::Static(538));
+pub const FILTERUNITS: Atom = Atom(Repr::Static(539));
+pub const FILTER_UNITS: Atom = Atom(Repr::Static(540));
+pub const GRADIENTTRANSFORM: Atom = Atom(Repr::Static(541));
+pub const GRADIENT_TRANSFORM: Atom = Atom(Repr::Static(542));
+pub const GRADIENTUNITS: Atom = Atom(Repr::Static(543));
+pub const GRADIENT_UNITS: Atom = Atom(Repr::Static(544));
+pub const KERNELMATRIX: Atom = Atom(Repr::Static(545));
+pub const KERNEL_MATRIX: Atom = Atom(Repr::Static(546));
+pub const KERNELUNITLENGTH: Atom = Atom(Repr::Static(547));
+pub const KERNEL_UNIT_LENGTH: Atom = Atom(Repr::Static(548));
+pub const KEYPOINTS: Atom = Atom(Repr::Static(549));
+pub const KEY_POINTS: Atom = Atom(Repr::Static(550));
+pub const KEYSPLINES: Atom = Atom(Repr::Static(551));
+pub const KEY_SPLINES: Atom = Atom(Repr::Static(552));
+pub const KEYTIMES: Atom = Atom(Repr::Static(553));
+pub const KEY_TIMES: Atom = Atom(Repr::Static(554));
+pub const LENGTHADJUST: Atom = Atom(Repr::Static(555));
+pub const LENGTH_ADJUST: Atom = Atom(Repr::Static(556));
+pub const LIMITINGCONEANGLE: Atom = Atom(Repr::Static(557));
+pub const LIMITING_CONE_ANGLE: Atom = Atom(Repr::Static(558));
+pub const MARKERHEIGHT: Atom = Atom(Repr::Static(559));
+pub const MARKER_HEIGHT: Atom = Atom(Repr::Static(560));
+pub const MARKERUNITS: Atom = Atom(Repr::Static(561));
+pub const MARKER_UNITS: Atom = Atom(Repr::Static(562));
+pub const MARKERWIDTH: Atom = Atom(Repr::Static(563));
+pub const MARKER_WIDTH: Atom = Atom(Repr::Static(564));
+pub const MASKCONTENTUNITS: Atom = Atom(Repr::Static(565));
+pub const MASK_CONTENT_UNITS: Atom = Atom(Repr::Static(566));
+pub const MASKUNITS: Atom = Atom(Repr::Static(567));
+pub const MASK_UNITS: Atom = Atom(Repr::Static(568));
+pub const NUMOCTAVES: Atom = Atom(Repr::Static(569));
+pub const NUM_OCTAVES: Atom = Atom(Repr::Static(570));
+pub const PATHLENGTH: Atom = Atom(Repr::Static(571));
+pub const PATH_LENGTH: Atom = Atom(Repr::Static(572));
+pub const PATTERNCONTENTUNITS: Atom = Atom(Repr::Static(573));
+pub const PATTERN_CONTENT_UNITS: Atom = Atom(Repr::Static(574));
+pub const PATTERNTRANSFORM: Atom = Atom(Repr::Static(575));
+pub const PATTERN_TRANSFORM: Atom = Atom(Repr::Static(576));
+pub const PATTERNUNITS: Atom = Atom(Repr::Static(577));
+pub const PATTERN_UNITS: Atom = Atom(Repr::Static(578));
+pub const POINTSATX: Atom = Atom(Repr::Static(579));
+pub const POINTS_AT_X: Atom = Atom(Repr::Static(580));
+pub const POINTSATY: Atom = Atom(Repr::Static(581));
+pub const POINTS_AT_Y: Atom = Atom(Repr::Static(582));
+pub const POINTSATZ: Atom = Atom(Repr::Static(583));
+pub const POINTS_AT_Z: Atom = Atom(Repr::Static(584));
+pub const PRESERVEALPHA: Atom = Atom(Repr::Static(585));
+pub const PRESERVE_ALPHA: Atom = Atom(Repr::Static(586));
+pub const PRESERVEASPECTRATIO: Atom = Atom(Repr::Static(587));
+pub const PRESERVE_ASPECT_RATIO: Atom = Atom(Repr::Static(588));
+pub const PRIMITIVEUNITS: Atom = Atom(Repr::Static(589));
+pub const PRIMITIVE_UNITS: Atom = Atom(Repr::Static(590));
+pub const REFX: Atom = Atom(Repr::Static(591));
+pub const REF_X: Atom = Atom(Repr::Static(592));
+pub const REFY: Atom = Atom(Repr::Static(593));
+pub const REF_Y: Atom = Atom(Repr::Static(594));
+pub const REPEATCOUNT: Atom = Atom(Repr::Static(595));
+pub const REPEAT_COUNT: Atom = Atom(Repr::Static(596));
+pub const REPEATDUR: Atom = Atom(Repr::Static(597));
+pub const REPEAT_DUR: Atom = Atom(Repr::Static(598));
+pub const REQUIREDEXTENSIONS: Atom = Atom(Repr::Static(599));
+pub const REQUIRED_EXTENSIONS: Atom = Atom(Repr::Static(600));
+pub const REQUIREDFEATURES: Atom = Atom(Repr::Static(601));
+pub const REQUIRED_FEATURES: Atom = Atom(Repr::Static(602));
+pub const SPECULARCONSTANT: Atom = Atom(Repr::Static(603));
+pub const SPECULAR_CONSTANT: Atom = Atom(Repr::Static(604));
+pub const SPECULAREXPONENT: Atom = Atom(Repr::Static(605));
+pub const SPECULAR_EXPONENT: Atom = Atom(Repr::Static(606));
+pub const SPREADMETHOD: Atom = Atom(Repr::Static(607));
+pub const SPREAD_METHOD: Atom = Atom(Repr::Static(608));
+pub const STARTOFFSET: Atom = Atom(Repr::Static(609));
+pub const START_OFFSET: Atom = Atom(Repr::Static(610));
+pub const STDDEVIATION: Atom = Atom(Repr::Static(611));
+pub const STD_DEVIATION: Atom = Atom(Repr::Static(612));
+pub const STITCHTILES: Atom = Atom(Repr::Static(613));
+pub const STITCH_TILES: Atom = Atom(Repr::Static(614));
+pub const SURFACESCALE: Atom = Atom(Repr::Static(615));
+pub const SURFACE_SCALE: Atom = Atom(Repr::Static(616));
+pub const SYSTEMLANGUAGE: Atom = Atom(Repr::Static(617));
+pub const SYSTEM_LANGUAGE: Atom = Atom(Repr::Static(618));
+pub const TABLEVALUES: Atom = Atom(Repr::Static(619));
+pub const TABLE_VALUES: Atom = Atom(Repr::Static(620));
+pub const TARGETX: Atom = Atom(Repr::Static(621));
+pub const TARGET_X: Atom = Atom(Repr::Static(622));
+pub const TARGETY: Atom = Atom(Repr::Static(623));
+pub const TARGET_Y: Atom = Atom(Repr::Static(624));
+pub const TEXTLENGTH: Atom = Atom(Repr::Static(625));
+pub const TEXT_LENGTH: Atom = Atom(Repr::Static(626));
+pub const VIEWBOX: Atom = Atom(Repr::Static(627));
+pub const VIEW_BOX: Atom = Atom(Repr::Static(628));
+pub const VIEWTARGET: Atom = Atom(Repr::Static(629));
+pub const VIEW_TARGET: Atom = Atom(Repr::Static(630));
+pub const XCHANNELSELECTOR: Atom = Atom(Repr::Static(631));
+pub const X_CHANNEL_SELECTOR: Atom = Atom(Repr::Static(632));
+pub const YCHANNELSELECTOR: Atom = Atom(Repr::Static(633));
+pub const Y_CHANNEL_SELECTOR: Atom = Atom(Repr::Static(634));
+pub const ZOOMANDPAN: Atom = Atom(Repr::Static(635));
+pub const ZOOM_AND_PAN: Atom = Atom(Repr::Static(636));
+pub const DEFINITIONURL: Atom = Atom(Repr::Static(637));
+pub const DEFINITION_URL: Atom = Atom(Repr::Static(638));
+pub const ARIA_ACTIVEDESCENDANT: Atom = Atom(Repr::Static(639));
+pub const ARIA_ATOMIC: Atom = Atom(Repr::Static(640));
+pub const ARIA_AUTOCOMPLETE: Atom = Atom(Repr::Static(641));
+pub const ARIA_BUSY: Atom = Atom(Repr::Static(642));
+pub const ARIA_CHECKED: Atom = Atom(Repr::Static(643));
+pub const ARIA_CONTROLS: Atom = Atom(Repr::Static(644));
+pub const ARIA_CURRENT: Atom = Atom(Repr::Static(645));
+pub const ARIA_DESCRIBEDBY: Atom = Atom(Repr::Static(646));
+pub const ARIA_DETAILS: Atom = Atom(Repr::Static(647));
+pub const ARIA_DISABLED: Atom = Atom(Repr::Static(648));
+pub const ARIA_EXPANDED: Atom = Atom(Repr::Static(649));
+pub const ARIA_HASPOPUP: Atom = Atom(Repr::Static(650));
+pub const ARIA_HIDDEN: Atom = Atom(Repr::Static(651));
+pub const ARIA_INVALID: Atom = Atom(Repr::Static(652));
+pub const ARIA_LABEL: Atom = Atom(Repr::Static(653));
+pub const ARIA_LABELLEDBY: Atom = Atom(Repr::Static(654));
+pub const ARIA_LEVEL: Atom = Atom(Repr::Static(655));
+pub const ARIA_LIVE: Atom = Atom(Repr::Static(656));
+pub const ARIA_MODAL: Atom = Atom(Repr::Static(657));
+pub const ARIA_MULTISELECTABLE: Atom = Atom(Repr::Static(658));
+pub const ARIA_ORIENTATION: Atom = Atom(Repr::Static(659));
+pub const ARIA_OWNS: Atom = Atom(Repr::Static(660));
+pub const ARIA_PLACEHOLDER: Atom = Atom(Repr::Static(661));
+pub const ARIA_PRESSED: Atom = Atom(Repr::Static(662));
+pub const ARIA_READONLY: Atom = Atom(Repr::Static(663));
+pub const ARIA_REQUIRED: Atom = Atom(Repr::Static(664));
+pub const ARIA_ROLEDESCRIPTION: Atom = Atom(Repr::Static(665));
+pub const ARIA_SELECTED: Atom = Atom(Repr::Static(666));
+pub const ARIA_SORT: Atom = Atom(Repr::Static(667));
+pub const ARIA_VALUEMAX: Atom = Atom(Repr::Static(668));
+pub const ARIA_VALUEMIN: Atom = Atom(Repr::Static(669));
+pub const ARIA_VALUENOW: Atom = Atom(Repr::Static(670));
+pub const ARIA_VALUETEXT: Atom = Atom(Repr::Static(671));
+pub const AS: Atom = Atom(Repr::Static(672));
+pub const BLOCKING: Atom = Atom(Repr::Static(673));
+pub const EXPORTPARTS: Atom = Atom(Repr::Static(674));
+pub const FETCHPRIORITY: Atom = Atom(Repr::Static(675));
+pub const NOMODULE: Atom = Atom(Repr::Static(676));
+pub const ONABORT: Atom = Atom(Repr::Static(677));
+pub const ONBLUR: Atom = Atom(Repr::Static(678));
+pub const ONCHANGE: Atom = Atom(Repr::Static(679));
+pub const ONCLICK: Atom = Atom(Repr::Static(680));
+pub const ONCONTEXTMENU: Atom = Atom(Repr::Static(681));
+pub const ONDBLCLICK: Atom = Atom(Repr::Static(682));
+pub const ONERROR: Atom = Atom(Repr::Static(683));
+pub const ONFOCUS: Atom = Atom(Repr::Static(684));
+pub const ONINPUT: Atom = Atom(Repr::Static(685));
+pub const ONKEYDOWN: Atom = Atom(Repr::Static(686));
+pub const ONKEYPRESS: Atom = Atom(Repr::Static(687));
+pub const ONKEYUP: Atom = Atom(Repr::Static(688));
+pub const ONLOAD: Atom = Atom(Repr::Static(689));
+pub const ONMOUSEDOWN: Atom = Atom(Repr::Static(690));
+pub const ONMOUSEENTER: Atom = Atom(Repr::Static(691));
+pub const ONMOUSELEAVE: Atom = Atom(Repr::Static(692));
+pub const ONMOUSEMOVE: Atom = Atom(Repr::Static(693));
+pub const ONMOUSEOUT: Atom = Atom(Repr::Static(694));
+pub const ONMOUSEOVER: Atom = Atom(Repr::Static(695));
+pub const ONMOUSEUP: Atom = Atom(Repr::Static(696));
+pub const ONRESET: Atom = Atom(Repr::Static(697));
+pub const ONRESIZE: Atom = Atom(Repr::Static(698));
+pub const ONSCROLL: Atom = Atom(Repr::Static(699));
+pub const ONSELECT: Atom = Atom(Repr::Static(700));
+pub const ONSUBMIT: Atom = Atom(Repr::Static(701));
+pub const ONUNLOAD: Atom = Atom(Repr::Static(702));
+pub const PART: Atom = Atom(Repr::Static(703));
+pub const SHADOWROOTCLONABLE: Atom = Atom(Repr::Static(704));
+pub const SHADOWROOTDELEGATESFOCUS: Atom = Atom(Repr::Static(705));
+pub const SHADOWROOTMODE: Atom = Atom(Repr::Static(706));
+
+/// The static names, indexed by atom.
+static NAMES: [&str; 707] = [
+    "", "a", "abbr", "acronym", "address", "applet", "area", "article", "aside", "audio", "b", "base", "basefont",
+    "bdi", "bdo", "bgsound", "big", "blink", "blockquote", "body", "br", "button", "canvas", "caption", "center",
+    "cite", "code", "col", "colgroup", "data", "datalist", "dd", "del", "details", "dfn", "dialog", "dir", "div",
+    "dl", "dt", "em", "embed", "fieldset", "figcaption", "figure", "font", "footer", "form", "frame", "frameset",
+    "h1", "h2", "h3", "h4", "h5", "h6", "head", "header", "hgroup", "hr", "html", "i", "iframe", "image", "img",
+    "input", "ins", "isindex", "kbd", "keygen", "label", "legend", "li", "link", "listing", "main", "map", "mark",
+    "marquee", "menu", "menuitem", "meta", "meter", "nav", "nobr", "noembed", "noframes", "noscript", "object",
+    "ol", "optgroup", "option", "output", "p", "param", "picture", "plaintext", "pre", "progress", "q", "rb", "rp",
+    "rt", "rtc", "ruby", "s", "samp", "script", "search", "section", "select", "slot", "small", "source", "span",
+    "strike", "strong", "style", "sub", "summary", "sup", "table", "tbody", "td", "template", "textarea", "tfoot",
+    "th", "thead", "time", "title", "tr", "track", "tt", "u", "ul", "var", "video", "wbr", "xmp", "svg", "g",
+    "path", "rect", "circle", "ellipse", "line", "polyline", "polygon", "text", "tspan", "defs", "use", "symbol",
+    "marker", "mask", "pattern", "stop", "filter", "desc", "animate", "set", "mpath", "view", "switch", "metadata",
+    "glyph", "hkern", "vkern", "missing-glyph", "font-face", "font-face-src", "font-face-uri", "font-face-format",
+    "font-face-name", "cursor", "tref", "math", "mi", "mo", "mn", "ms", "mtext", "annotation-xml", "annotation",
+    "semantics", "mrow", "mfrac", "msqrt", "mroot", "mstyle", "merror", "mpadded", "mphantom", "mfenced",
+    "menclose", "msub", "msup", "msubsup", "munder", "mover", "munderover", "mmultiscripts", "mprescripts", "none",
+    "mtable", "mtr", "mtd", "mlabeledtr", "maligngroup", "malignmark", "mspace", "maction", "mglyph", "accept",
+    "accept-charset", "accesskey", "action", "align", "alink", "allow", "allowfullscreen", "alt", "archive",
+    "async", "autocapitalize", "autocomplete", "autofocus", "autoplay", "axis", "background", "bgcolor", "border",
+    "cellpadding", "cellspacing", "char", "charoff", "charset", "checked", "class", "classid", "clear", "codebase",
+    "codetype", "color", "cols", "colspan", "compact", "content", "contenteditable", "controls", "coords",
+    "crossorigin", "datetime", "declare", "decoding", "default", "defer", "dirname", "disabled", "download",
+    "draggable", "enctype", "enterkeyhint", "face", "for", "formaction", "formenctype", "formmethod",
+    "formnovalidate", "formtarget", "frameborder", "headers", "height", "hidden", "high", "href", "hreflang",
+    "hspace", "http-equiv", "id", "inert", "inputmode", "integrity", "is", "ismap", "itemid", "itemprop", "itemref",
+    "itemscope", "itemtype", "kind", "lang", "language", "list", "loading", "longdesc", "loop", "low",
+    "marginheight", "marginwidth", "max", "maxlength", "media", "method", "min", "minlength", "multiple", "muted",
+    "name", "nohref", "nonce", "noresize", "noshade", "novalidate", "nowrap", "open", "optimum", "ping",
+    "placeholder", "playsinline", "popover", "poster", "preload", "profile", "prompt", "readonly", "referrerpolicy",
+    "rel", "required", "rev", "reversed", "role", "rows", "rowspan", "rules", "sandbox", "scheme", "scope",
+    "scrolling", "selected", "shape", "size", "sizes", "spellcheck", "src", "srcdoc", "srclang", "srcset",
+    "standby", "start", "step", "tabindex", "target", "translate", "type", "usemap", "valign", "value", "valuetype",
+    "version", "vlink", "vspace", "width", "wrap", "d", "x", "y", "x1", "x2", "y1", "y2", "cx", "cy", "r", "rx",
+    "ry", "fx", "fy", "fr", "dx", "dy", "fill", "fill-opacity", "fill-rule", "stroke", "stroke-width",
+    "stroke-opacity", "stroke-linecap", "stroke-linejoin", "stroke-dasharray", "stroke-dashoffset",
+    "stroke-miterlimit", "opacity", "transform", "points", "clip-path", "clip-rule", "font-family", "font-size",
+    "font-weight", "font-style", "text-anchor", "offset", "stop-color", "stop-opacity", "in", "in2", "result",
+    "mode", "operator", "values", "dur", "begin", "end", "from", "to", "by", "visibility", "display", "xmlns",
+    "mathvariant", "mathcolor", "mathbackground", "mathsize", "displaystyle", "scriptlevel", "stretchy", "fence",
+    "separator", "separators", "lspace", "rspace", "accent", "accentunder", "linethickness", "columnalign",
+    "rowalign", "encoding", "xlink:actuate", "xlink:arcrole", "xlink:href", "xlink:role", "xlink:show",
+    "xlink:title", "xlink:type", "xml:lang", "xml:space", "xmlns:xlink", "altglyph", "altGlyph", "altglyphdef",
+    "altGlyphDef", "altglyphitem", "altGlyphItem", "animatecolor", "animateColor", "animatemotion", "animateMotion",
+    "animatetransform", "animateTransform", "clippath", "clipPath", "feblend", "feBlend", "fecolormatrix",
+    "feColorMatrix", "fecomponenttransfer", "feComponentTransfer", "fecomposite", "feComposite", "feconvolvematrix",
+    "feConvolveMatrix", "fediffuselighting", "feDiffuseLighting", "fedisplacementmap", "feDisplacementMap",
+    "fedistantlight", "feDistantLight", "fedropshadow", "feDropShadow", "feflood", "feFlood", "fefunca", "feFuncA",
+    "fefuncb", "feFuncB", "fefuncg", "feFuncG", "fefuncr", "feFuncR", "fegaussianblur", "feGaussianBlur", "feimage",
+    "feImage", "femerge", "feMerge", "femergenode", "feMergeNode", "femorphology", "feMorphology", "feoffset",
+    "feOffset", "fepointlight", "fePointLight", "fespecularlighting", "feSpecularLighting", "fespotlight",
+    "feSpotLight", "fetile", "feTile", "feturbulence", "feTurbulence", "foreignobject", "foreignObject", "glyphref",
+    "glyphRef", "lineargradient", "linearGradient", "radialgradient", "radialGradient", "textpath", "textPath",
+    "attributename", "attributeName", "attributetype", "attributeType", "basefrequency", "baseFrequency",
+    "baseprofile", "baseProfile", "calcmode", "calcMode", "clippathunits", "clipPathUnits", "diffuseconstant",
+    "diffuseConstant", "edgemode", "edgeMode", "filterunits", "filterUnits", "gradienttransform",
+    "gradientTransform", "gradientunits", "gradientUnits", "kernelmatrix", "kernelMatrix", "kernelunitlength",
+    "kernelUnitLength", "keypoints", "keyPoints", "keysplines", "keySplines", "keytimes", "keyTimes",
+    "lengthadjust", "lengthAdjust", "limitingconeangle", "limitingConeAngle", "markerheight", "markerHeight",
+    "markerunits", "markerUnits", "markerwidth", "markerWidth", "maskcontentunits", "maskContentUnits", "maskunits",
+    "maskUnits", "numoctaves", "numOctaves", "pathlength", "pathLength", "patterncontentunits",
+    "patternContentUnits", "patterntransform", "patternTransform", "patternunits", "patternUnits", "pointsatx",
+    "pointsAtX", "pointsaty", "pointsAtY", "pointsatz", "pointsAtZ", "preservealpha", "preserveAlpha",
+    "preserveaspectratio", "preserveAspectRatio", "primitiveunits", "primitiveUnits", "refx", "refX", "refy",
+    "refY", "repeatcount", "repeatCount", "repeatdur", "repeatDur", "requiredextensions", "requiredExtensions",
+    "requiredfeatures", "requiredFeatures", "specularconstant", "specularConstant", "specularexponent",
+    "specularExponent", "spreadmethod", "spreadMethod", "startoffset", "startOffset", "stddeviation",
+    "stdDeviation", "stitchtiles", "stitchTiles", "surfacescale", "surfaceScale", "systemlanguage",
+    "systemLanguage", "tablevalues", "tableValues", "targetx", "targetX", "targety", "targetY", "textlength",
+    "textLength", "viewbox", "viewBox", "viewtarget", "viewTarget", "xchannelselector", "xChannelSelector",
+    "ychannelselector", "yChannelSelector", "zoomandpan", "zoomAndPan", "definitionurl", "definitionURL",
+    "aria-activedescendant", "aria-atomic", "aria-autocomplete", "aria-busy", "aria-checked", "aria-controls",
+    "aria-current", "aria-describedby", "aria-details", "aria-disabled", "aria-expanded", "aria-haspopup",
+    "aria-hidden", "aria-invalid", "aria-label", "aria-labelledby", "aria-level", "aria-live", "aria-modal",
+    "aria-multiselectable", "aria-orientation", "aria-owns", "aria-placeholder", "aria-pressed", "aria-readonly",
+    "aria-required", "aria-roledescription", "aria-selected", "aria-sort", "aria-valuemax", "aria-valuemin",
+    "aria-valuenow", "aria-valuetext", "as", "blocking", "exportparts", "fetchpriority", "nomodule", "onabort",
+    "onblur", "onchange", "onclick", "oncontextmenu", "ondblclick", "onerror", "onfocus", "oninput", "onkeydown",
+    "onkeypress", "onkeyup", "onload", "onmousedown", "onmouseenter", "onmouseleave", "onmousemove", "onmouseout",
+    "onmouseover", "onmouseup", "onreset", "onresize", "onscroll", "onselect", "onsubmit", "onunload", "part",
+    "shadowrootclonable", "shadowrootdelegatesfocus", "shadowrootmode",
+];
+
+/// Indices into `NAMES`, sorted by name.
+static SORTED: [u16; 707] = [
+    0, 1, 2, 433, 434, 214, 215, 216, 3, 217, 4, 218, 219, 220, 221, 222, 450, 452, 454, 449, 451, 453, 160, 456,
+    458, 460, 455, 457, 459, 184, 183, 5, 223, 6, 639, 640, 641, 642, 643, 644, 645, 646, 647, 648, 649, 650, 651,
+    652, 653, 654, 655, 656, 657, 658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668, 669, 670, 671, 7, 672, 8,
+    224, 524, 526, 523, 525, 9, 225, 226, 227, 228, 229, 10, 230, 11, 528, 530, 12, 527, 529, 13, 14, 413, 231, 15,
+    16, 17, 673, 18, 19, 232, 20, 21, 417, 532, 531, 22, 23, 233, 234, 24, 235, 236, 237, 238, 144, 25, 239, 240,
+    241, 396, 397, 462, 534, 461, 533, 26, 242, 243, 27, 28, 244, 245, 246, 436, 247, 248, 249, 250, 251, 252, 175,
+    372, 373, 365, 29, 30, 253, 31, 254, 255, 256, 257, 638, 637, 151, 32, 159, 33, 34, 35, 536, 535, 36, 258, 259,
+    419, 425, 37, 38, 260, 261, 39, 412, 380, 381, 538, 537, 145, 40, 41, 438, 262, 414, 263, 674, 264, 464, 466,
+    468, 470, 472, 474, 476, 478, 480, 482, 484, 486, 488, 490, 492, 494, 496, 498, 500, 502, 504, 506, 508, 510,
+    512, 463, 465, 467, 469, 471, 473, 475, 477, 479, 481, 483, 485, 487, 489, 491, 493, 495, 497, 499, 428, 501,
+    503, 505, 507, 675, 509, 511, 42, 43, 44, 382, 383, 384, 158, 540, 539, 45, 170, 173, 174, 171, 172, 398, 399,
+    401, 400, 46, 265, 514, 513, 47, 266, 267, 268, 269, 270, 379, 48, 271, 49, 415, 377, 378, 141, 166, 516, 515,
+    542, 544, 541, 543, 50, 51, 52, 53, 54, 55, 56, 57, 272, 273, 58, 274, 275, 167, 59, 276, 277, 278, 60, 279, 61,
+    280, 62, 63, 64, 406, 407, 281, 65, 282, 66, 283, 284, 67, 285, 286, 287, 288, 289, 290, 68, 546, 548, 545, 547,
+    550, 552, 554, 69, 549, 551, 553, 291, 70, 292, 293, 71, 556, 555, 72, 558, 557, 146, 518, 517, 435, 73, 294,
+    74, 295, 296, 297, 298, 431, 212, 75, 209, 210, 76, 299, 300, 77, 154, 560, 562, 564, 559, 561, 563, 78, 155,
+    566, 568, 565, 567, 177, 423, 422, 424, 421, 301, 302, 303, 195, 79, 80, 191, 81, 165, 82, 304, 194, 187, 213,
+    178, 305, 306, 169, 208, 202, 180, 179, 409, 200, 192, 162, 193, 203, 189, 186, 181, 211, 188, 190, 196, 198,
+    197, 205, 207, 182, 206, 307, 199, 201, 308, 309, 83, 84, 85, 86, 310, 676, 311, 204, 312, 87, 313, 314, 315,
+    570, 569, 88, 403, 89, 677, 678, 679, 680, 681, 682, 683, 684, 685, 686, 687, 688, 689, 690, 691, 692, 693, 694,
+    695, 696, 697, 698, 699, 700, 701, 702, 393, 316, 410, 90, 317, 91, 92, 93, 94, 703, 142, 572, 571, 156, 574,
+    576, 578, 573, 575, 577, 95, 318, 319, 96, 320, 395, 580, 582, 584, 579, 581, 583, 148, 147, 321, 322, 97, 323,
+    586, 588, 585, 587, 590, 589, 324, 98, 325, 99, 374, 520, 519, 100, 326, 143, 592, 594, 327, 591, 593, 328, 596,
+    598, 595, 597, 329, 600, 602, 599, 601, 408, 330, 331, 332, 437, 333, 334, 101, 432, 102, 103, 104, 335, 375,
+    376, 105, 106, 336, 337, 338, 107, 426, 339, 108, 109, 110, 340, 185, 429, 430, 161, 704, 705, 706, 341, 342,
+    343, 111, 112, 113, 114, 604, 606, 603, 605, 344, 608, 607, 345, 346, 347, 348, 349, 350, 610, 609, 612, 611,
+    351, 614, 613, 157, 404, 405, 427, 115, 385, 390, 391, 388, 389, 392, 387, 386, 116, 117, 118, 119, 120, 616,
+    615, 140, 164, 153, 618, 617, 352, 121, 620, 619, 353, 622, 624, 621, 623, 122, 123, 124, 149, 402, 626, 522,
+    125, 625, 521, 126, 127, 128, 129, 130, 416, 131, 132, 394, 354, 176, 150, 133, 355, 134, 135, 152, 356, 357,
+    358, 411, 359, 136, 360, 137, 163, 628, 630, 627, 629, 418, 168, 361, 362, 138, 363, 364, 366, 368, 369, 632,
+    631, 439, 440, 441, 442, 443, 444, 445, 446, 447, 420, 448, 139, 367, 370, 371, 634, 633, 636, 635,
+];
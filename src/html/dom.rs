@@ -0,0 +1,394 @@
+//! The document tree. Nodes live in an arena and refer to each other by `NodeId`, each one
+//! linked to its parent, its first and last child, its siblings and the document it belongs
+//! to, so the tree can be walked in any direction without searching.
+
+use std::iter;
+use std::ops::{Index, IndexMut};
+
+use crate::html::errors::DomException;
+use crate::html::node::{Document, DocumentFragment, Element, NodeType};
+use crate::html::quirks::QuirksMode;
+
+/// A handle to a node, only meaningful for the `Dom` that created it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+pub struct Node {
+    pub node_type: NodeType,
+    parent: Option<NodeId>,
+    first_child: Option<NodeId>,
+    last_child: Option<NodeId>,
+    previous_sibling: Option<NodeId>,
+    next_sibling: Option<NodeId>,
+    /// The document the node belongs to, which is the node itself for documents.
+    document: NodeId,
+}
+
+impl Node {
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    pub fn first_child(&self) -> Option<NodeId> {
+        self.first_child
+    }
+
+    pub fn last_child(&self) -> Option<NodeId> {
+        self.last_child
+    }
+
+    pub fn previous_sibling(&self) -> Option<NodeId> {
+        self.previous_sibling
+    }
+
+    pub fn next_sibling(&self) -> Option<NodeId> {
+        self.next_sibling
+    }
+
+    pub fn has_children(&self) -> bool {
+        self.first_child.is_some()
+    }
+}
+
+/// The nodes of one or more documents. Nodes are never freed, one that's removed from the
+/// tree stays in the arena without a parent.
+pub struct Dom {
+    nodes: Vec<Node>,
+}
+
+impl Dom {
+    /// An arena holding an empty document.
+    pub fn new() -> Dom {
+        let mut dom = Dom { nodes: Vec::new() };
+        dom.create_document();
+        dom
+    }
+
+    /// The document the arena was created with.
+    pub fn document(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn create_document(&mut self) -> NodeId {
+        let document = NodeId(self.nodes.len());
+        self.create_node(document, NodeType::Document(Document { quirks_mode: QuirksMode::NoQuirks }))
+    }
+
+    /// Creates a node belonging to `document`, without a parent.
+    pub fn create_node(&mut self, document: NodeId, node_type: NodeType) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            node_type,
+            parent: None,
+            first_child: None,
+            last_child: None,
+            previous_sibling: None,
+            next_sibling: None,
+            document,
+        });
+        id
+    }
+
+    /// The document the node belongs to, or `None` if it's a document itself.
+    pub fn owner_document(&self, node: NodeId) -> Option<NodeId> {
+        Some(self[node].document).filter(|&document| document != node)
+    }
+
+    pub fn children(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        iter::successors(self[node].first_child, move |&child| self[child].next_sibling)
+    }
+
+    /// The node's parent, its parent's parent, and so on up to the root.
+    pub fn ancestors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        iter::successors(self[node].parent, move |&ancestor| self[ancestor].parent)
+    }
+
+    /// The nodes inside `root` in tree order, not counting the contents of templates.
+    pub fn descendants(&self, root: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        iter::successors(self[root].first_child, move |&node| self.following(node, root))
+    }
+
+    /// The node after `node` in tree order, staying inside `root`.
+    fn following(&self, node: NodeId, root: NodeId) -> Option<NodeId> {
+        if let Some(child) = self[node].first_child {
+            return Some(child);
+        }
+        let mut current = node;
+        while current != root {
+            if let Some(next) = self[current].next_sibling {
+                return Some(next);
+            }
+            current = self[current].parent?;
+        }
+        None
+    }
+
+    /// Inserts a node that has no parent into `parent`, in front of `before` or at the end.
+    /// Nothing is checked, the tree builder knows the result is a valid tree.
+    pub(crate) fn insert(&mut self, parent: NodeId, child: NodeId, before: Option<NodeId>) {
+        let previous = match before {
+            Some(before) => self[before].previous_sibling,
+            None => self[parent].last_child
+        };
+        self[child].parent = Some(parent);
+        self[child].previous_sibling = previous;
+        self[child].next_sibling = before;
+        match previous {
+            Some(previous) => self[previous].next_sibling = Some(child),
+            None => self[parent].first_child = Some(child)
+        }
+        match before {
+            Some(before) => self[before].previous_sibling = Some(child),
+            None => self[parent].last_child = Some(child)
+        }
+    }
+
+    pub(crate) fn append(&mut self, parent: NodeId, child: NodeId) {
+        self.insert(parent, child, None);
+    }
+
+    /// Takes a node out of its parent, it keeps its own children.
+    pub(crate) fn detach(&mut self, node: NodeId) {
+        let Some(parent) = self[node].parent.take() else {
+            return;
+        };
+        let previous = self[node].previous_sibling.take();
+        let next = self[node].next_sibling.take();
+        match previous {
+            Some(previous) => self[previous].next_sibling = next,
+            None => self[parent].first_child = next
+        }
+        match next {
+            Some(next) => self[next].previous_sibling = previous,
+            None => self[parent].last_child = previous
+        }
+    }
+
+    /// Inserts `node` as the last child of `parent`, taking it out of where it was before.
+    /// A document fragment is replaced by its children.
+    pub fn append_child(&mut self, parent: NodeId, node: NodeId) -> Result<NodeId, DomException> {
+        self.insert_before(parent, node, None)
+    }
+
+    /// Inserts `node` into `parent` in front of `child`, or at the end if there's none.
+    pub fn insert_before(&mut self, parent: NodeId, node: NodeId, child: Option<NodeId>) -> Result<NodeId, DomException> {
+        self.check_insertion(parent, node, child, None)?;
+        let before = if child == Some(node) { self[node].next_sibling } else { child };
+        self.insert_node(parent, node, before);
+        Ok(node)
+    }
+
+    /// Puts `node` in the place of `child`, which is returned without a parent.
+    pub fn replace_child(&mut self, parent: NodeId, node: NodeId, child: NodeId) -> Result<NodeId, DomException> {
+        self.check_insertion(parent, node, Some(child), Some(child))?;
+        let mut before = self[child].next_sibling;
+        if before == Some(node) {
+            before = self[node].next_sibling;
+        }
+        self.detach(child);
+        self.insert_node(parent, node, before);
+        Ok(child)
+    }
+
+    pub fn remove_child(&mut self, parent: NodeId, child: NodeId) -> Result<NodeId, DomException> {
+        if self[child].parent != Some(parent) {
+            return Err(DomException::NotFoundError);
+        }
+        self.detach(child);
+        Ok(child)
+    }
+
+    /// Moves a node and everything inside it into `document`, taking it out of its parent.
+    pub fn adopt(&mut self, node: NodeId, document: NodeId) -> Result<NodeId, DomException> {
+        if matches!(self[node].node_type, NodeType::Document(_)) {
+            return Err(DomException::NotSupportedError);
+        }
+        if !matches!(self[document].node_type, NodeType::Document(_)) {
+            return Err(DomException::HierarchyRequestError);
+        }
+        self.adopt_node(node, document);
+        Ok(node)
+    }
+
+    /// A copy of the node without a parent, belonging to the same document, with copies of
+    /// everything inside it if `deep`. A copied document is a new document holding copies of
+    /// its children.
+    pub fn clone_node(&mut self, node: NodeId, deep: bool) -> NodeId {
+        let document = self[node].document;
+        self.clone_into(node, document, deep)
+    }
+
+    fn clone_into(&mut self, node: NodeId, document: NodeId, deep: bool) -> NodeId {
+        let node_type = self[node].node_type.clone();
+        let copy = match node_type {
+            NodeType::Document(_) => {
+                let copy = self.create_node(document, node_type);
+                self[copy].document = copy;
+                copy
+            }
+            // A template copy gets contents of its own, copied along with its children
+            NodeType::Element(Element { template_contents: Some(contents), .. }) => {
+                let copy_contents = self.create_node(document, NodeType::DocumentFragment(DocumentFragment {}));
+                if deep {
+                    self.clone_children(contents, copy_contents);
+                }
+                let mut node_type = node_type;
+                if let NodeType::Element(element) = &mut node_type {
+                    element.template_contents = Some(copy_contents);
+                }
+                self.create_node(document, node_type)
+            }
+            node_type => self.create_node(document, node_type)
+        };
+        if deep {
+            self.clone_children(node, copy);
+        }
+        copy
+    }
+
+    fn clone_children(&mut self, from: NodeId, to: NodeId) {
+        let document = self[to].document;
+        let children: Vec<NodeId> = self.children(from).collect();
+        for child in children {
+            let copy = self.clone_into(child, document, true);
+            self.append(to, copy);
+        }
+    }
+
+    /// Removes the empty text nodes inside `node` and merges adjacent ones.
+    pub fn normalize(&mut self, node: NodeId) {
+        let descendants: Vec<NodeId> = self.descendants(node).collect();
+        for text in descendants {
+            // Already merged into a text node in front of it
+            if self[text].parent.is_none() {
+                continue;
+            }
+            let mut data = match &self[text].node_type {
+                NodeType::Text(data) => data.clone(),
+                _ => continue
+            };
+            if data.is_empty() {
+                self.detach(text);
+                continue;
+            }
+            while let Some(next) = self[text].next_sibling {
+                match &self[next].node_type {
+                    NodeType::Text(next_data) => data.push_str(next_data),
+                    _ => break
+                }
+                self.detach(next);
+            }
+            self[text].node_type = NodeType::Text(data);
+        }
+    }
+
+    /// The checks the DOM Standard runs before inserting `node` into `parent` in front of
+    /// `child`, or in place of `replaced`. A document may only have one doctype followed by
+    /// one element, and no text or CDATA sections.
+    fn check_insertion(&self, parent: NodeId, node: NodeId, child: Option<NodeId>, replaced: Option<NodeId>) -> Result<(), DomException> {
+        if !matches!(self[parent].node_type, NodeType::Document(_) | NodeType::DocumentFragment(_) | NodeType::Element(_)) {
+            return Err(DomException::HierarchyRequestError);
+        }
+        if node == parent || self.ancestors(parent).any(|ancestor| ancestor == node) {
+            return Err(DomException::HierarchyRequestError);
+        }
+        if child.is_some_and(|child| self[child].parent != Some(parent)) {
+            return Err(DomException::NotFoundError);
+        }
+        let parent_is_document = matches!(self[parent].node_type, NodeType::Document(_));
+        match self[node].node_type {
+            NodeType::Document(_) => return Err(DomException::HierarchyRequestError),
+            NodeType::Text(_) | NodeType::CDataSection(_) if parent_is_document => return Err(DomException::HierarchyRequestError),
+            NodeType::DocumentType(_) if !parent_is_document => return Err(DomException::HierarchyRequestError),
+            _ => {}
+        }
+        if !parent_is_document {
+            return Ok(());
+        }
+
+        let is_element = |node: NodeId| matches!(self[node].node_type, NodeType::Element(_));
+        let is_doctype = |node: NodeId| matches!(self[node].node_type, NodeType::DocumentType(_));
+        // The children that stay, the one being replaced doesn't count
+        let other_children = || self.children(parent).filter(move |&other| Some(other) != replaced);
+        let doctype_after_child = || {
+            child.is_some_and(|child| iter::successors(self[child].next_sibling, |&next| self[next].next_sibling).any(is_doctype))
+        };
+        let element_count = match self[node].node_type {
+            NodeType::DocumentFragment(_) => {
+                if self.children(node).any(|child| matches!(self[child].node_type, NodeType::Text(_) | NodeType::CDataSection(_))) {
+                    return Err(DomException::HierarchyRequestError);
+                }
+                self.children(node).filter(|&child| is_element(child)).count()
+            }
+            NodeType::Element(_) => 1,
+            NodeType::DocumentType(_) => {
+                let element_before_child = match child {
+                    Some(child) => iter::successors(self[child].previous_sibling, |&previous| self[previous].previous_sibling)
+                        .any(is_element),
+                    None => self.children(parent).any(is_element)
+                };
+                if other_children().any(is_doctype) || element_before_child {
+                    return Err(DomException::HierarchyRequestError);
+                }
+                0
+            }
+            _ => 0
+        };
+        if element_count > 1 {
+            return Err(DomException::HierarchyRequestError);
+        }
+        if element_count == 1 && (other_children().any(is_element)
+            || (replaced.is_none() && child.is_some_and(is_doctype)) || doctype_after_child()) {
+            return Err(DomException::HierarchyRequestError);
+        }
+        Ok(())
+    }
+
+    /// Inserts a node that passed the checks, or the children of a document fragment.
+    fn insert_node(&mut self, parent: NodeId, node: NodeId, before: Option<NodeId>) {
+        let document = self[parent].document;
+        let nodes: Vec<NodeId> = match self[node].node_type {
+            NodeType::DocumentFragment(_) => self.children(node).collect(),
+            _ => vec![node]
+        };
+        for node in nodes {
+            self.adopt_node(node, document);
+            self.insert(parent, node, before);
+        }
+    }
+
+    fn adopt_node(&mut self, node: NodeId, document: NodeId) {
+        self.detach(node);
+        if self[node].document == document {
+            return;
+        }
+        let mut pending = vec![node];
+        while let Some(node) = pending.pop() {
+            self[node].document = document;
+            if let NodeType::Element(Element { template_contents: Some(contents), .. }) = self[node].node_type {
+                pending.push(contents);
+            }
+            pending.extend(self.children(node));
+        }
+    }
+}
+
+impl Default for Dom {
+    fn default() -> Dom {
+        Dom::new()
+    }
+}
+
+impl Index<NodeId> for Dom {
+    type Output = Node;
+
+    fn index(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+}
+
+impl IndexMut<NodeId> for Dom {
+    fn index_mut(&mut self, id: NodeId) -> &mut Node {
+        &mut self.nodes[id.0]
+    }
+}
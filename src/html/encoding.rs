@@ -0,0 +1,765 @@
+//! The encodings of the WHATWG Encoding Standard and their decoders, which turn the bytes a
+//! document arrives in into the characters the lexer reads. Decoders never fail, anything
+//! that isn't valid in the encoding becomes U+FFFD.
+
+use crate::html::encoding_indexes as index;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Ibm866,
+    Iso8859_2,
+    Iso8859_3,
+    Iso8859_4,
+    Iso8859_5,
+    Iso8859_6,
+    Iso8859_7,
+    Iso8859_8,
+    Iso8859_8I,
+    Iso8859_10,
+    Iso8859_13,
+    Iso8859_14,
+    Iso8859_15,
+    Iso8859_16,
+    Koi8R,
+    Koi8U,
+    Macintosh,
+    Windows874,
+    Windows1250,
+    Windows1251,
+    Windows1252,
+    Windows1253,
+    Windows1254,
+    Windows1255,
+    Windows1256,
+    Windows1257,
+    Windows1258,
+    XMacCyrillic,
+    Gbk,
+    Gb18030,
+    Big5,
+    EucJp,
+    Iso2022Jp,
+    ShiftJis,
+    EucKr,
+    Replacement,
+    Utf16Be,
+    Utf16Le,
+    XUserDefined,
+}
+
+impl Encoding {
+    /// The encoding a label like `latin1` or `" Shift_JIS "` stands for, ignoring ASCII case
+    /// and surrounding whitespace.
+    pub fn for_label(label: &str) -> Option<Encoding> {
+        let label = label.trim_matches(|char| matches!(char, '\t' | '\n' | '\u{000C}' | '\r' | ' '));
+        let label = label.to_ascii_lowercase();
+        index::LABELS.binary_search_by(|(candidate, _)| candidate.cmp(&label.as_str()))
+            .ok()
+            .map(|found| index::LABELS[found].1)
+    }
+
+    /// The canonical name of the encoding.
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Ibm866 => "IBM866",
+            Encoding::Iso8859_2 => "ISO-8859-2",
+            Encoding::Iso8859_3 => "ISO-8859-3",
+            Encoding::Iso8859_4 => "ISO-8859-4",
+            Encoding::Iso8859_5 => "ISO-8859-5",
+            Encoding::Iso8859_6 => "ISO-8859-6",
+            Encoding::Iso8859_7 => "ISO-8859-7",
+            Encoding::Iso8859_8 => "ISO-8859-8",
+            Encoding::Iso8859_8I => "ISO-8859-8-I",
+            Encoding::Iso8859_10 => "ISO-8859-10",
+            Encoding::Iso8859_13 => "ISO-8859-13",
+            Encoding::Iso8859_14 => "ISO-8859-14",
+            Encoding::Iso8859_15 => "ISO-8859-15",
+            Encoding::Iso8859_16 => "ISO-8859-16",
+            Encoding::Koi8R => "KOI8-R",
+            Encoding::Koi8U => "KOI8-U",
+            Encoding::Macintosh => "macintosh",
+            Encoding::Windows874 => "windows-874",
+            Encoding::Windows1250 => "windows-1250",
+            Encoding::Windows1251 => "windows-1251",
+            Encoding::Windows1252 => "windows-1252",
+            Encoding::Windows1253 => "windows-1253",
+            Encoding::Windows1254 => "windows-1254",
+            Encoding::Windows1255 => "windows-1255",
+            Encoding::Windows1256 => "windows-1256",
+            Encoding::Windows1257 => "windows-1257",
+            Encoding::Windows1258 => "windows-1258",
+            Encoding::XMacCyrillic => "x-mac-cyrillic",
+            Encoding::Gbk => "GBK",
+            Encoding::Gb18030 => "gb18030",
+            Encoding::Big5 => "Big5",
+            Encoding::EucJp => "EUC-JP",
+            Encoding::Iso2022Jp => "ISO-2022-JP",
+            Encoding::ShiftJis => "Shift_JIS",
+            Encoding::EucKr => "EUC-KR",
+            Encoding::Replacement => "replacement",
+            Encoding::Utf16Be => "UTF-16BE",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::XUserDefined => "x-user-defined"
+        }
+    }
+
+    /// The table of a single-byte encoding, for bytes 0x80 to 0xFF.
+    fn single_byte_index(self) -> Option<&'static [u16; 128]> {
+        Some(match self {
+            Encoding::Ibm866 => &index::IBM866,
+            Encoding::Iso8859_2 => &index::ISO_8859_2,
+            Encoding::Iso8859_3 => &index::ISO_8859_3,
+            Encoding::Iso8859_4 => &index::ISO_8859_4,
+            Encoding::Iso8859_5 => &index::ISO_8859_5,
+            Encoding::Iso8859_6 => &index::ISO_8859_6,
+            Encoding::Iso8859_7 => &index::ISO_8859_7,
+            // The two only differ in how the text is meant to be laid out
+            Encoding::Iso8859_8 | Encoding::Iso8859_8I => &index::ISO_8859_8,
+            Encoding::Iso8859_10 => &index::ISO_8859_10,
+            Encoding::Iso8859_13 => &index::ISO_8859_13,
+            Encoding::Iso8859_14 => &index::ISO_8859_14,
+            Encoding::Iso8859_15 => &index::ISO_8859_15,
+            Encoding::Iso8859_16 => &index::ISO_8859_16,
+            Encoding::Koi8R => &index::KOI8_R,
+            Encoding::Koi8U => &index::KOI8_U,
+            Encoding::Macintosh => &index::MACINTOSH,
+            Encoding::Windows874 => &index::WINDOWS_874,
+            Encoding::Windows1250 => &index::WINDOWS_1250,
+            Encoding::Windows1251 => &index::WINDOWS_1251,
+            Encoding::Windows1252 => &index::WINDOWS_1252,
+            Encoding::Windows1253 => &index::WINDOWS_1253,
+            Encoding::Windows1254 => &index::WINDOWS_1254,
+            Encoding::Windows1255 => &index::WINDOWS_1255,
+            Encoding::Windows1256 => &index::WINDOWS_1256,
+            Encoding::Windows1257 => &index::WINDOWS_1257,
+            Encoding::Windows1258 => &index::WINDOWS_1258,
+            Encoding::XMacCyrillic => &index::X_MAC_CYRILLIC,
+            _ => return None
+        })
+    }
+
+    pub fn new_decoder(self) -> Decoder {
+        let state = match self {
+            Encoding::Utf8 => State::Utf8 { incomplete: Vec::new() },
+            Encoding::Gbk | Encoding::Gb18030 => State::Gb18030 { first: 0, second: 0, third: 0 },
+            Encoding::Big5 => State::Big5 { lead: 0 },
+            Encoding::EucJp => State::EucJp { lead: 0, jis0212: false },
+            Encoding::Iso2022Jp => State::Iso2022Jp {
+                state: Iso2022JpState::Ascii,
+                output_state: Iso2022JpState::Ascii,
+                lead: 0,
+                output: false,
+            },
+            Encoding::ShiftJis => State::ShiftJis { lead: 0 },
+            Encoding::EucKr => State::EucKr { lead: 0 },
+            Encoding::Replacement => State::Replacement { error_returned: false },
+            Encoding::Utf16Be => State::Utf16 { big_endian: true, lead_byte: None, lead_surrogate: None },
+            Encoding::Utf16Le => State::Utf16 { big_endian: false, lead_byte: None, lead_surrogate: None },
+            Encoding::XUserDefined => State::XUserDefined,
+            single_byte => match single_byte.single_byte_index() {
+                Some(index) => State::SingleByte(index),
+                None => unreachable!("every other encoding is single-byte")
+            },
+        };
+        Decoder { encoding: self, state }
+    }
+}
+
+/// Decodes a byte stream that can be split into chunks anywhere, a sequence cut in two by
+/// the end of a chunk is completed by the next one.
+pub struct Decoder {
+    encoding: Encoding,
+    state: State,
+}
+
+/// What the decoder of each encoding remembers between bytes.
+enum State {
+    Utf8 { incomplete: Vec<u8> },
+    SingleByte(&'static [u16; 128]),
+    Gb18030 { first: u8, second: u8, third: u8 },
+    Big5 { lead: u8 },
+    EucJp { lead: u8, jis0212: bool },
+    Iso2022Jp { state: Iso2022JpState, output_state: Iso2022JpState, lead: u8, output: bool },
+    ShiftJis { lead: u8 },
+    EucKr { lead: u8 },
+    Replacement { error_returned: bool },
+    Utf16 { big_endian: bool, lead_byte: Option<u8>, lead_surrogate: Option<u16> },
+    XUserDefined,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Iso2022JpState {
+    Ascii,
+    Roman,
+    Katakana,
+    LeadByte,
+    TrailByte,
+    EscapeStart,
+    Escape,
+}
+
+/// The outcome of handing a decoder one byte, or the end of the input.
+enum Step {
+    /// More bytes are needed before there's anything to output.
+    Continue,
+    Emit(u32),
+    Error,
+    Finished,
+}
+
+impl Decoder {
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Decodes the next chunk of bytes, `last` tells it that no more will come so that an
+    /// unfinished sequence at the end is an error.
+    pub fn decode(&mut self, bytes: &[u8], last: bool) -> String {
+        let mut output = String::with_capacity(bytes.len());
+        if let State::Utf8 { incomplete } = &mut self.state {
+            decode_utf8(incomplete, bytes, last, &mut output);
+            return output;
+        }
+
+        // Bytes the decoder gave back to be read again, the next one to read is at the end
+        let mut restored = Vec::new();
+        let mut bytes = bytes.iter().copied();
+        loop {
+            let byte = match restored.pop().or_else(|| bytes.next()) {
+                Some(byte) => Some(byte),
+                None if last => None,
+                None => break
+            };
+            match self.handle(byte, &mut restored, &mut output) {
+                Step::Continue => {}
+                Step::Emit(code_point) => output.push(char::from_u32(code_point).unwrap_or('\u{FFFD}')),
+                Step::Error => output.push('\u{FFFD}'),
+                Step::Finished => break
+            }
+        }
+        output
+    }
+
+    /// Hands the decoder the next byte, or `None` at the end of the input.
+    fn handle(&mut self, byte: Option<u8>, restored: &mut Vec<u8>, output: &mut String) -> Step {
+        match &mut self.state {
+            State::Utf8 { .. } => unreachable!("UTF-8 is decoded a chunk at a time"),
+            State::SingleByte(index) => match byte {
+                None => Step::Finished,
+                Some(byte @ 0x00..=0x7F) => Step::Emit(byte as u32),
+                Some(byte) => match index[byte as usize - 0x80] {
+                    0 => Step::Error,
+                    code_point => Step::Emit(code_point as u32)
+                },
+            },
+            State::XUserDefined => match byte {
+                None => Step::Finished,
+                Some(byte @ 0x00..=0x7F) => Step::Emit(byte as u32),
+                Some(byte) => Step::Emit(0xF780 + byte as u32 - 0x80)
+            },
+            State::Replacement { error_returned } => {
+                if byte.is_none() || *error_returned {
+                    return Step::Finished;
+                }
+                *error_returned = true;
+                Step::Error
+            }
+            State::Gb18030 { first, second, third } => gb18030(byte, first, second, third, restored),
+            State::Big5 { lead } => big5(byte, lead, restored, output),
+            State::EucJp { lead, jis0212 } => euc_jp(byte, lead, jis0212, restored),
+            State::Iso2022Jp { state, output_state, lead, output } => {
+                iso_2022_jp(byte, state, output_state, lead, output, restored)
+            }
+            State::ShiftJis { lead } => shift_jis(byte, lead, restored),
+            State::EucKr { lead } => euc_kr(byte, lead, restored),
+            State::Utf16 { big_endian, lead_byte, lead_surrogate } => {
+                utf_16(byte, *big_endian, lead_byte, lead_surrogate, restored)
+            }
+        }
+    }
+}
+
+/// Puts bytes back in front of the input, to be read again in order.
+fn restore(restored: &mut Vec<u8>, bytes: &[u8]) {
+    restored.extend(bytes.iter().rev());
+}
+
+/// Looks up a pointer in a multi-byte index, which are trimmed after their last code point.
+fn lookup<T: Copy + Into<u32>>(index: &[T], pointer: usize) -> Option<u32> {
+    index.get(pointer).map(|&code_point| code_point.into()).filter(|&code_point| code_point != 0)
+}
+
+fn decode_utf8(incomplete: &mut Vec<u8>, chunk: &[u8], last: bool, output: &mut String) {
+    let mut bytes = std::mem::take(incomplete);
+    bytes.extend_from_slice(chunk);
+    let mut rest = &bytes[..];
+    loop {
+        match std::str::from_utf8(rest) {
+            Ok(valid) => {
+                output.push_str(valid);
+                break;
+            }
+            Err(error) => {
+                let (valid, after) = rest.split_at(error.valid_up_to());
+                output.push_str(std::str::from_utf8(valid).unwrap_or_default());
+                match error.error_len() {
+                    Some(invalid) => {
+                        output.push('\u{FFFD}');
+                        rest = &after[invalid..];
+                    }
+                    None if last => {
+                        output.push('\u{FFFD}');
+                        break;
+                    }
+                    None => {
+                        *incomplete = after.to_vec();
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn gb18030(byte: Option<u8>, first: &mut u8, second: &mut u8, third: &mut u8, restored: &mut Vec<u8>) -> Step {
+    let Some(byte) = byte else {
+        if (*first, *second, *third) == (0, 0, 0) {
+            return Step::Finished;
+        }
+        (*first, *second, *third) = (0, 0, 0);
+        return Step::Error;
+    };
+
+    if *third != 0 {
+        if !byte.is_ascii_digit() {
+            restore(restored, &[*second, *third, byte]);
+            (*first, *second, *third) = (0, 0, 0);
+            return Step::Error;
+        }
+        let pointer = (*first as u32 - 0x81) * (10 * 126 * 10)
+            + (*second as u32 - 0x30) * (10 * 126)
+            + (*third as u32 - 0x81) * 10
+            + byte as u32 - 0x30;
+        (*first, *second, *third) = (0, 0, 0);
+        return match gb18030_ranges_code_point(pointer) {
+            Some(code_point) => Step::Emit(code_point),
+            None => Step::Error
+        };
+    }
+
+    if *second != 0 {
+        if let 0x81..=0xFE = byte {
+            *third = byte;
+            return Step::Continue;
+        }
+        restore(restored, &[*second, byte]);
+        (*first, *second) = (0, 0);
+        return Step::Error;
+    }
+
+    if *first != 0 {
+        if byte.is_ascii_digit() {
+            *second = byte;
+            return Step::Continue;
+        }
+        let lead = std::mem::take(first) as usize;
+        let offset = if byte < 0x7F { 0x40 } else { 0x41 };
+        let code_point = match byte {
+            0x40..=0x7E | 0x80..=0xFE => lookup(&index::GB18030, (lead - 0x81) * 190 + byte as usize - offset),
+            _ => None
+        };
+        if let Some(code_point) = code_point {
+            return Step::Emit(code_point);
+        }
+        if byte.is_ascii() {
+            restore(restored, &[byte]);
+        }
+        return Step::Error;
+    }
+
+    match byte {
+        0x00..=0x7F => Step::Emit(byte as u32),
+        0x80 => Step::Emit(0x20AC),
+        0x81..=0xFE => {
+            *first = byte;
+            Step::Continue
+        }
+        _ => Step::Error
+    }
+}
+
+/// The code point of a four-byte gb18030 sequence, most of which are spread over ranges of
+/// consecutive code points.
+fn gb18030_ranges_code_point(pointer: u32) -> Option<u32> {
+    if (pointer > 39419 && pointer < 189000) || pointer > 1237575 {
+        return None;
+    }
+    if pointer == 7457 {
+        return Some(0xE7C7);
+    }
+    if pointer >= 189000 {
+        return Some(0x10000 + pointer - 189000);
+    }
+    let ranges = &index::GB18030_RANGES;
+    let range = ranges.partition_point(|&(start, _)| start <= pointer).checked_sub(1)?;
+    let (start, code_point) = ranges[range];
+    Some(code_point + pointer - start)
+}
+
+fn big5(byte: Option<u8>, lead: &mut u8, restored: &mut Vec<u8>, output: &mut String) -> Step {
+    let Some(byte) = byte else {
+        if *lead == 0 {
+            return Step::Finished;
+        }
+        *lead = 0;
+        return Step::Error;
+    };
+
+    if *lead != 0 {
+        let lead = std::mem::take(lead) as usize;
+        let offset = if byte < 0x7F { 0x40 } else { 0x62 };
+        let pointer = match byte {
+            0x40..=0x7E | 0xA1..=0xFE => Some((lead - 0x81) * 157 + byte as usize - offset),
+            _ => None
+        };
+        // These four decode to a letter followed by a combining mark
+        let pair = match pointer {
+            Some(1133) => Some("\u{00CA}\u{0304}"),
+            Some(1135) => Some("\u{00CA}\u{030C}"),
+            Some(1164) => Some("\u{00EA}\u{0304}"),
+            Some(1166) => Some("\u{00EA}\u{030C}"),
+            _ => None
+        };
+        if let Some(pair) = pair {
+            output.push_str(pair);
+            return Step::Continue;
+        }
+        if let Some(code_point) = pointer.and_then(|pointer| lookup(&index::BIG5, pointer)) {
+            return Step::Emit(code_point);
+        }
+        if byte.is_ascii() {
+            restore(restored, &[byte]);
+        }
+        return Step::Error;
+    }
+
+    match byte {
+        0x00..=0x7F => Step::Emit(byte as u32),
+        0x81..=0xFE => {
+            *lead = byte;
+            Step::Continue
+        }
+        _ => Step::Error
+    }
+}
+
+fn euc_jp(byte: Option<u8>, lead: &mut u8, jis0212: &mut bool, restored: &mut Vec<u8>) -> Step {
+    let Some(byte) = byte else {
+        if *lead == 0 {
+            return Step::Finished;
+        }
+        *lead = 0;
+        return Step::Error;
+    };
+
+    if *lead == 0x8E && matches!(byte, 0xA1..=0xDF) {
+        *lead = 0;
+        return Step::Emit(0xFF61 - 0xA1 + byte as u32);
+    }
+    if *lead == 0x8F && matches!(byte, 0xA1..=0xFE) {
+        *jis0212 = true;
+        *lead = byte;
+        return Step::Continue;
+    }
+    if *lead != 0 {
+        let lead = std::mem::take(lead);
+        let code_point = match (lead, byte) {
+            (0xA1..=0xFE, 0xA1..=0xFE) => {
+                let pointer = (lead as usize - 0xA1) * 94 + byte as usize - 0xA1;
+                if *jis0212 {
+                    lookup(&index::JIS0212, pointer)
+                } else {
+                    lookup(&index::JIS0208, pointer)
+                }
+            }
+            _ => None
+        };
+        *jis0212 = false;
+        if let Some(code_point) = code_point {
+            return Step::Emit(code_point);
+        }
+        if byte.is_ascii() {
+            restore(restored, &[byte]);
+        }
+        return Step::Error;
+    }
+
+    match byte {
+        0x00..=0x7F => Step::Emit(byte as u32),
+        0x8E | 0x8F | 0xA1..=0xFE => {
+            *lead = byte;
+            Step::Continue
+        }
+        _ => Step::Error
+    }
+}
+
+fn iso_2022_jp(
+    byte: Option<u8>,
+    state: &mut Iso2022JpState,
+    output_state: &mut Iso2022JpState,
+    lead: &mut u8,
+    output: &mut bool,
+    restored: &mut Vec<u8>,
+) -> Step {
+    match *state {
+        Iso2022JpState::Ascii => match byte {
+            Some(0x1B) => {
+                *state = Iso2022JpState::EscapeStart;
+                Step::Continue
+            }
+            Some(byte @ 0x00..=0x7F) if byte != 0x0E && byte != 0x0F => {
+                *output = false;
+                Step::Emit(byte as u32)
+            }
+            None => Step::Finished,
+            Some(_) => {
+                *output = false;
+                Step::Error
+            }
+        },
+        Iso2022JpState::Roman => match byte {
+            Some(0x1B) => {
+                *state = Iso2022JpState::EscapeStart;
+                Step::Continue
+            }
+            Some(0x5C) => {
+                *output = false;
+                Step::Emit(0x00A5)
+            }
+            Some(0x7E) => {
+                *output = false;
+                Step::Emit(0x203E)
+            }
+            Some(byte @ 0x00..=0x7F) if byte != 0x0E && byte != 0x0F => {
+                *output = false;
+                Step::Emit(byte as u32)
+            }
+            None => Step::Finished,
+            Some(_) => {
+                *output = false;
+                Step::Error
+            }
+        },
+        Iso2022JpState::Katakana => match byte {
+            Some(0x1B) => {
+                *state = Iso2022JpState::EscapeStart;
+                Step::Continue
+            }
+            Some(byte @ 0x21..=0x5F) => {
+                *output = false;
+                Step::Emit(0xFF61 - 0x21 + byte as u32)
+            }
+            None => Step::Finished,
+            Some(_) => {
+                *output = false;
+                Step::Error
+            }
+        },
+        Iso2022JpState::LeadByte => match byte {
+            Some(0x1B) => {
+                *state = Iso2022JpState::EscapeStart;
+                Step::Continue
+            }
+            Some(byte @ 0x21..=0x7E) => {
+                *output = false;
+                *lead = byte;
+                *state = Iso2022JpState::TrailByte;
+                Step::Continue
+            }
+            None => Step::Finished,
+            Some(_) => {
+                *output = false;
+                Step::Error
+            }
+        },
+        Iso2022JpState::TrailByte => match byte {
+            Some(0x1B) => {
+                *state = Iso2022JpState::EscapeStart;
+                Step::Error
+            }
+            Some(byte @ 0x21..=0x7E) => {
+                *state = Iso2022JpState::LeadByte;
+                let pointer = (*lead as usize - 0x21) * 94 + byte as usize - 0x21;
+                match lookup(&index::JIS0208, pointer) {
+                    Some(code_point) => Step::Emit(code_point),
+                    None => Step::Error
+                }
+            }
+            // The end of the input is read again in the lead byte state
+            _ => {
+                *state = Iso2022JpState::LeadByte;
+                Step::Error
+            }
+        },
+        Iso2022JpState::EscapeStart => {
+            if let Some(byte @ (0x24 | 0x28)) = byte {
+                *lead = byte;
+                *state = Iso2022JpState::Escape;
+                return Step::Continue;
+            }
+            if let Some(byte) = byte {
+                restore(restored, &[byte]);
+            }
+            *output = false;
+            *state = *output_state;
+            Step::Error
+        }
+        Iso2022JpState::Escape => {
+            let escape_lead = std::mem::take(lead);
+            let next = match (escape_lead, byte) {
+                (0x28, Some(0x42)) => Some(Iso2022JpState::Ascii),
+                (0x28, Some(0x4A)) => Some(Iso2022JpState::Roman),
+                (0x28, Some(0x49)) => Some(Iso2022JpState::Katakana),
+                (0x24, Some(0x40 | 0x42)) => Some(Iso2022JpState::LeadByte),
+                _ => None
+            };
+            if let Some(next) = next {
+                *state = next;
+                *output_state = next;
+                // Two escape sequences in a row with nothing between them are an error
+                let error = std::mem::replace(output, true);
+                return if error { Step::Error } else { Step::Continue };
+            }
+            match byte {
+                Some(byte) => restore(restored, &[escape_lead, byte]),
+                None => restore(restored, &[escape_lead])
+            }
+            *output = false;
+            *state = *output_state;
+            Step::Error
+        }
+    }
+}
+
+fn shift_jis(byte: Option<u8>, lead: &mut u8, restored: &mut Vec<u8>) -> Step {
+    let Some(byte) = byte else {
+        if *lead == 0 {
+            return Step::Finished;
+        }
+        *lead = 0;
+        return Step::Error;
+    };
+
+    if *lead != 0 {
+        let lead = std::mem::take(lead) as usize;
+        let offset = if byte < 0x7F { 0x40 } else { 0x41 };
+        let lead_offset = if lead < 0xA0 { 0x81 } else { 0xC1 };
+        if let 0x40..=0x7E | 0x80..=0xFC = byte {
+            let pointer = (lead - lead_offset) * 188 + byte as usize - offset;
+            // The end-user-defined characters map to the Private Use Area
+            if (8836..=10715).contains(&pointer) {
+                return Step::Emit(0xE000 - 8836 + pointer as u32);
+            }
+            if let Some(code_point) = lookup(&index::JIS0208, pointer) {
+                return Step::Emit(code_point);
+            }
+        }
+        if byte.is_ascii() {
+            restore(restored, &[byte]);
+        }
+        return Step::Error;
+    }
+
+    match byte {
+        0x00..=0x80 => Step::Emit(byte as u32),
+        0xA1..=0xDF => Step::Emit(0xFF61 - 0xA1 + byte as u32),
+        0x81..=0x9F | 0xE0..=0xFC => {
+            *lead = byte;
+            Step::Continue
+        }
+        _ => Step::Error
+    }
+}
+
+fn euc_kr(byte: Option<u8>, lead: &mut u8, restored: &mut Vec<u8>) -> Step {
+    let Some(byte) = byte else {
+        if *lead == 0 {
+            return Step::Finished;
+        }
+        *lead = 0;
+        return Step::Error;
+    };
+
+    if *lead != 0 {
+        let lead = std::mem::take(lead) as usize;
+        if let 0x41..=0xFE = byte {
+            if let Some(code_point) = lookup(&index::EUC_KR, (lead - 0x81) * 190 + byte as usize - 0x41) {
+                return Step::Emit(code_point);
+            }
+        }
+        if byte.is_ascii() {
+            restore(restored, &[byte]);
+        }
+        return Step::Error;
+    }
+
+    match byte {
+        0x00..=0x7F => Step::Emit(byte as u32),
+        0x81..=0xFE => {
+            *lead = byte;
+            Step::Continue
+        }
+        _ => Step::Error
+    }
+}
+
+fn utf_16(
+    byte: Option<u8>,
+    big_endian: bool,
+    lead_byte: &mut Option<u8>,
+    lead_surrogate: &mut Option<u16>,
+    restored: &mut Vec<u8>,
+) -> Step {
+    let Some(byte) = byte else {
+        if lead_byte.is_none() && lead_surrogate.is_none() {
+            return Step::Finished;
+        }
+        (*lead_byte, *lead_surrogate) = (None, None);
+        return Step::Error;
+    };
+
+    let Some(lead) = lead_byte.take() else {
+        *lead_byte = Some(byte);
+        return Step::Continue;
+    };
+    let code_unit = if big_endian {
+        u16::from_be_bytes([lead, byte])
+    } else {
+        u16::from_le_bytes([lead, byte])
+    };
+
+    if let Some(surrogate) = lead_surrogate.take() {
+        if let 0xDC00..=0xDFFF = code_unit {
+            return Step::Emit(0x10000 + ((surrogate as u32 - 0xD800) << 10) + (code_unit as u32 - 0xDC00));
+        }
+        // Not a trail surrogate, so it's read again on its own
+        if big_endian {
+            restore(restored, &code_unit.to_be_bytes());
+        } else {
+            restore(restored, &code_unit.to_le_bytes());
+        }
+        return Step::Error;
+    }
+
+    match code_unit {
+        0xD800..=0xDBFF => {
+            *lead_surrogate = Some(code_unit);
+            Step::Continue
+        }
+        0xDC00..=0xDFFF => Step::Error,
+        _ => Step::Emit(code_unit as u32)
+    }
+}
@@ -0,0 +1,2701 @@
+//! The WHATWG named character reference table (https://html.spec.whatwg.org/entities.json),
+//! compiled into a prefix trie so the tokenizer can find the longest match one character at a time.
+//! Generated from the upstream table, do not edit by hand.
+
+/// The length of the longest entity name, including its semicolon.
+pub const LONGEST_NAME: usize = 32;
+
+/// A trie node, its children are stored contiguously and sorted by character.
+pub struct EntityNode {
+    pub char: u8,
+    pub children_start: u16,
+    pub children_len: u8,
+    /// Index into `VALUES`, zero if no entity ends at this node.
+    pub value: u16,
+}
+
+/// Walks the trie over `input` and returns the number of characters in the longest entity
+/// name it starts with, along with the replacement text for that entity.
+pub fn longest_match(input: impl Iterator<Item = char>) -> Option<(usize, &'static str)> {
+    let mut node = &NODES[0];
+    let mut longest = None;
+    for (length, char) in input.enumerate() {
+        if !char.is_ascii() {
+            break;
+        }
+        let start = node.children_start as usize;
+        let children = &NODES[start..start + node.children_len as usize];
+        node = match children.binary_search_by_key(&(char as u8), |child| child.char) {
+            Ok(found) => &children[found],
+            Err(_) => break
+        };
+        if node.value != 0 {
+            longest = Some((length + 1, VALUES[node.value as usize]));
+        }
+    }
+    longest
+}
+
+const fn node(char: u8, children_start: u16, children_len: u8, value: u16) -> EntityNode {
+    EntityNode { char, children_start, children_len, value }
+}
+
+pub static NODES: [EntityNode; 9854] = [
+    node(0, 1, 52, 0), node(65, 53, 16, 0), node(66, 69, 8, 0), node(67, 77, 14, 0),
+    node(68, 91, 11, 0), node(69, 102, 16, 0), node(70, 118, 5, 0), node(71, 123, 12, 0),
+    node(72, 135, 8, 0), node(73, 143, 14, 0), node(74, 157, 5, 0), node(75, 162, 7, 0),
+    node(76, 169, 11, 0), node(77, 180, 8, 0), node(78, 188, 9, 0), node(79, 197, 14, 0),
+    node(80, 211, 9, 0), node(81, 220, 4, 0), node(82, 224, 12, 0), node(83, 236, 13, 0),
+    node(84, 249, 11, 0), node(85, 260, 14, 0), node(86, 274, 9, 0), node(87, 283, 5, 0),
+    node(88, 288, 4, 0), node(89, 292, 9, 0), node(90, 301, 8, 0), node(97, 309, 16, 0),
+    node(98, 325, 16, 0), node(99, 341, 15, 0), node(100, 356, 19, 0), node(101, 375, 18, 0),
+    node(102, 393, 12, 0), node(103, 405, 17, 0), node(104, 422, 10, 0), node(105, 432, 15, 0),
+    node(106, 447, 6, 0), node(107, 453, 8, 0), node(108, 461, 23, 0), node(109, 484, 14, 0),
+    node(110, 498, 24, 0), node(111, 522, 18, 0), node(112, 540, 12, 0), node(113, 552, 6, 0),
+    node(114, 558, 21, 0), node(115, 579, 19, 0), node(116, 598, 13, 0), node(117, 611, 18, 0),
+    node(118, 629, 15, 0), node(119, 644, 7, 0), node(120, 651, 14, 0), node(121, 665, 8, 0),
+    node(122, 673, 10, 0), node(69, 683, 1, 0), node(77, 684, 1, 0), node(97, 685, 1, 0),
+    node(98, 686, 1, 0), node(99, 687, 2, 0), node(102, 689, 1, 0), node(103, 690, 1, 0),
+    node(108, 691, 1, 0), node(109, 692, 1, 0), node(110, 693, 1, 0), node(111, 694, 2, 0),
+    node(112, 696, 1, 0), node(114, 697, 1, 0), node(115, 698, 2, 0), node(116, 700, 1, 0),
+    node(117, 701, 1, 0), node(97, 702, 2, 0), node(99, 704, 1, 0), node(101, 705, 3, 0),
+    node(102, 708, 1, 0), node(111, 709, 1, 0), node(114, 710, 1, 0), node(115, 711, 1, 0),
+    node(117, 712, 1, 0), node(72, 713, 1, 0), node(79, 714, 1, 0), node(97, 715, 3, 0),
+    node(99, 718, 4, 0), node(100, 722, 1, 0), node(101, 723, 2, 0), node(102, 725, 1, 0),
+    node(104, 726, 1, 0), node(105, 727, 1, 0), node(108, 728, 1, 0), node(111, 729, 4, 0),
+    node(114, 733, 1, 0), node(115, 734, 1, 0), node(117, 735, 1, 0), node(68, 736, 2, 0),
+    node(74, 738, 1, 0), node(83, 739, 1, 0), node(90, 740, 1, 0), node(97, 741, 3, 0),
+    node(99, 744, 2, 0), node(101, 746, 1, 0), node(102, 747, 1, 0), node(105, 748, 2, 0),
+    node(111, 750, 4, 0), node(115, 754, 2, 0), node(78, 756, 1, 0), node(84, 757, 1, 0),
+    node(97, 758, 1, 0), node(99, 759, 3, 0), node(100, 762, 1, 0), node(102, 763, 1, 0),
+    node(103, 764, 1, 0), node(108, 765, 1, 0), node(109, 766, 2, 0), node(111, 768, 2, 0),
+    node(112, 770, 1, 0), node(113, 771, 1, 0), node(115, 772, 2, 0), node(116, 774, 1, 0),
+    node(117, 775, 1, 0), node(120, 776, 2, 0), node(99, 778, 1, 0), node(102, 779, 1, 0),
+    node(105, 780, 1, 0), node(111, 781, 3, 0), node(115, 784, 1, 0), node(74, 785, 1, 0),
+    node(84, 786, 1, 1), node(97, 787, 1, 0), node(98, 788, 1, 0), node(99, 789, 3, 0),
+    node(100, 792, 1, 0), node(102, 793, 1, 0), node(103, 794, 1, 0), node(111, 795, 1, 0),
+    node(114, 796, 1, 0), node(115, 797, 1, 0), node(116, 798, 1, 0), node(65, 799, 1, 0),
+    node(97, 800, 2, 0), node(99, 802, 1, 0), node(102, 803, 1, 0), node(105, 804, 1, 0),
+    node(111, 805, 2, 0), node(115, 807, 2, 0), node(117, 809, 1, 0), node(69, 810, 1, 0),
+    node(74, 811, 1, 0), node(79, 812, 1, 0), node(97, 813, 1, 0), node(99, 814, 2, 0),
+    node(100, 816, 1, 0), node(102, 817, 1, 0), node(103, 818, 1, 0), node(109, 819, 3, 0),
+    node(110, 822, 2, 0), node(111, 824, 3, 0), node(115, 827, 1, 0), node(116, 828, 1, 0),
+    node(117, 829, 2, 0), node(99, 831, 2, 0), node(102, 833, 1, 0), node(111, 834, 1, 0),
+    node(115, 835, 2, 0), node(117, 837, 1, 0), node(72, 838, 1, 0), node(74, 839, 1, 0),
+    node(97, 840, 1, 0), node(99, 841, 2, 0), node(102, 843, 1, 0), node(111, 844, 1, 0),
+    node(115, 845, 1, 0), node(74, 846, 1, 0), node(84, 847, 1, 2), node(97, 848, 5, 0),
+    node(99, 853, 3, 0), node(101, 856, 2, 0), node(102, 858, 1, 0), node(108, 859, 2, 0),
+    node(109, 861, 1, 0), node(111, 862, 3, 0), node(115, 865, 3, 0), node(116, 868, 1, 0),
+    node(97, 869, 1, 0), node(99, 870, 1, 0), node(101, 871, 2, 0), node(102, 873, 1, 0),
+    node(105, 874, 1, 0), node(111, 875, 1, 0), node(115, 876, 1, 0), node(117, 877, 1, 0),
+    node(74, 878, 1, 0), node(97, 879, 1, 0), node(99, 880, 3, 0), node(101, 883, 3, 0),
+    node(102, 886, 1, 0), node(111, 887, 4, 0), node(115, 891, 1, 0), node(116, 892, 1, 0),
+    node(117, 893, 1, 0), node(69, 894, 1, 0), node(97, 895, 1, 0), node(99, 896, 2, 0),
+    node(100, 898, 1, 0), node(102, 899, 1, 0), node(103, 900, 1, 0), node(109, 901, 3, 0),
+    node(111, 904, 1, 0), node(112, 905, 1, 0), node(114, 906, 1, 0), node(115, 907, 2, 0),
+    node(116, 909, 1, 0), node(117, 910, 1, 0), node(118, 911, 1, 0), node(97, 912, 1, 0),
+    node(99, 913, 1, 0), node(102, 914, 1, 0), node(104, 915, 1, 0), node(105, 916, 1, 0),
+    node(108, 917, 1, 0), node(111, 918, 2, 0), node(114, 920, 4, 0), node(115, 924, 2, 0),
+    node(85, 926, 1, 0), node(102, 927, 1, 0), node(111, 928, 1, 0), node(115, 929, 1, 0),
+    node(66, 930, 1, 0), node(69, 931, 1, 0), node(97, 932, 3, 0), node(99, 935, 3, 0),
+    node(101, 938, 2, 0), node(102, 940, 1, 0), node(104, 941, 1, 0), node(105, 942, 1, 0),
+    node(111, 943, 2, 0), node(114, 945, 1, 0), node(115, 946, 2, 0), node(117, 948, 1, 0),
+    node(72, 949, 2, 0), node(79, 951, 1, 0), node(97, 952, 1, 0), node(99, 953, 5, 0),
+    node(102, 958, 1, 0), node(104, 959, 1, 0), node(105, 960, 1, 0), node(109, 961, 1, 0),
+    node(111, 962, 1, 0), node(113, 963, 2, 0), node(115, 965, 1, 0), node(116, 966, 1, 0),
+    node(117, 967, 4, 0), node(72, 971, 1, 0), node(82, 972, 1, 0), node(83, 973, 2, 0),
+    node(97, 975, 2, 0), node(99, 977, 3, 0), node(102, 980, 1, 0), node(104, 981, 2, 0),
+    node(105, 983, 1, 0), node(111, 984, 1, 0), node(114, 985, 1, 0), node(115, 986, 2, 0),
+    node(97, 988, 2, 0), node(98, 990, 1, 0), node(99, 991, 2, 0), node(100, 993, 1, 0),
+    node(102, 994, 1, 0), node(103, 995, 1, 0), node(109, 996, 1, 0), node(110, 997, 2, 0),
+    node(111, 999, 2, 0), node(112, 1001, 8, 0), node(114, 1009, 1, 0), node(115, 1010, 1, 0),
+    node(116, 1011, 1, 0), node(117, 1012, 1, 0), node(68, 1013, 1, 0), node(98, 1014, 1, 0),
+    node(99, 1015, 1, 0), node(100, 1016, 1, 0), node(101, 1017, 2, 0), node(102, 1019, 1, 0),
+    node(111, 1020, 1, 0), node(115, 1021, 1, 0), node(118, 1022, 1, 0), node(99, 1023, 1, 0),
+    node(101, 1024, 1, 0), node(102, 1025, 1, 0), node(111, 1026, 1, 0), node(115, 1027, 1, 0),
+    node(102, 1028, 1, 0), node(105, 1029, 1, 0), node(111, 1030, 1, 0), node(115, 1031, 1, 0),
+    node(65, 1032, 1, 0), node(73, 1033, 1, 0), node(85, 1034, 1, 0), node(97, 1035, 1, 0),
+    node(99, 1036, 2, 0), node(102, 1038, 1, 0), node(111, 1039, 1, 0), node(115, 1040, 1, 0),
+    node(117, 1041, 1, 0), node(72, 1042, 1, 0), node(97, 1043, 1, 0), node(99, 1044, 2, 0),
+    node(100, 1046, 1, 0), node(101, 1047, 2, 0), node(102, 1049, 1, 0), node(111, 1050, 1, 0),
+    node(115, 1051, 1, 0), node(97, 1052, 1, 0), node(98, 1053, 1, 0), node(99, 1054, 6, 0),
+    node(101, 1060, 1, 0), node(102, 1061, 2, 0), node(103, 1063, 1, 0), node(108, 1064, 2, 0),
+    node(109, 1066, 2, 0), node(110, 1068, 2, 0), node(111, 1070, 2, 0), node(112, 1072, 7, 0),
+    node(114, 1079, 1, 0), node(115, 1080, 3, 0), node(116, 1083, 1, 0), node(117, 1084, 1, 0),
+    node(119, 1085, 2, 0), node(78, 1087, 1, 0), node(97, 1088, 2, 0), node(98, 1090, 1, 0),
+    node(99, 1091, 2, 0), node(100, 1093, 1, 0), node(101, 1094, 5, 0), node(102, 1099, 1, 0),
+    node(105, 1100, 1, 0), node(107, 1101, 1, 0), node(108, 1102, 3, 0), node(110, 1105, 2, 0),
+    node(111, 1107, 4, 0), node(112, 1111, 1, 0), node(114, 1112, 2, 0), node(115, 1114, 4, 0),
+    node(117, 1118, 2, 0), node(97, 1120, 3, 0), node(99, 1123, 4, 0), node(100, 1127, 1, 0),
+    node(101, 1128, 3, 0), node(102, 1131, 1, 0), node(104, 1132, 3, 0), node(105, 1135, 1, 0),
+    node(108, 1136, 1, 0), node(111, 1137, 4, 0), node(114, 1141, 2, 0), node(115, 1143, 2, 0),
+    node(116, 1145, 1, 0), node(117, 1146, 7, 0), node(119, 1153, 2, 0), node(121, 1155, 1, 0),
+    node(65, 1156, 1, 0), node(72, 1157, 1, 0), node(97, 1158, 4, 0), node(98, 1162, 2, 0),
+    node(99, 1164, 2, 0), node(100, 1166, 3, 0), node(101, 1169, 3, 0), node(102, 1172, 2, 0),
+    node(104, 1174, 1, 0), node(105, 1175, 5, 0), node(106, 1180, 1, 0), node(108, 1181, 1, 0),
+    node(111, 1182, 5, 0), node(114, 1187, 2, 0), node(115, 1189, 3, 0), node(116, 1192, 2, 0),
+    node(117, 1194, 2, 0), node(119, 1196, 1, 0), node(122, 1197, 2, 0), node(68, 1199, 2, 0),
+    node(97, 1201, 2, 0), node(99, 1203, 4, 0), node(100, 1207, 1, 0), node(101, 1208, 1, 0),
+    node(102, 1209, 2, 0), node(103, 1211, 3, 0), node(108, 1214, 4, 0), node(109, 1218, 3, 0),
+    node(110, 1221, 2, 0), node(111, 1223, 2, 0), node(112, 1225, 3, 0), node(113, 1228, 4, 0),
+    node(114, 1232, 2, 0), node(115, 1234, 3, 0), node(116, 1237, 2, 0), node(117, 1239, 2, 0),
+    node(120, 1241, 3, 0), node(97, 1244, 1, 0), node(99, 1245, 1, 0), node(101, 1246, 1, 0),
+    node(102, 1247, 3, 0), node(105, 1250, 1, 0), node(106, 1251, 1, 0), node(108, 1252, 3, 0),
+    node(110, 1255, 1, 0), node(111, 1256, 2, 0), node(112, 1258, 1, 0), node(114, 1259, 2, 0),
+    node(115, 1261, 1, 0), node(69, 1262, 2, 0), node(97, 1264, 3, 0), node(98, 1267, 1, 0),
+    node(99, 1268, 2, 0), node(100, 1270, 1, 0), node(101, 1271, 4, 0), node(102, 1275, 1, 0),
+    node(103, 1276, 2, 0), node(105, 1278, 1, 0), node(106, 1279, 1, 0), node(108, 1280, 4, 0),
+    node(110, 1284, 4, 0), node(111, 1288, 1, 0), node(114, 1289, 1, 0), node(115, 1290, 2, 0),
+    node(116, 1292, 6, 1), node(118, 1298, 2, 0), node(65, 1300, 1, 0), node(97, 1301, 4, 0),
+    node(98, 1305, 1, 0), node(99, 1306, 1, 0), node(101, 1307, 3, 0), node(102, 1310, 1, 0),
+    node(107, 1311, 1, 0), node(111, 1312, 5, 0), node(115, 1317, 3, 0), node(121, 1320, 2, 0),
+    node(97, 1322, 1, 0), node(99, 1323, 3, 0), node(101, 1326, 2, 0), node(102, 1328, 2, 0),
+    node(103, 1330, 1, 0), node(105, 1331, 4, 0), node(106, 1335, 1, 0), node(109, 1336, 3, 0),
+    node(110, 1339, 5, 0), node(111, 1344, 4, 0), node(112, 1348, 1, 0), node(113, 1349, 1, 0),
+    node(115, 1350, 2, 0), node(116, 1352, 2, 0), node(117, 1354, 2, 0), node(99, 1356, 2, 0),
+    node(102, 1358, 1, 0), node(109, 1359, 1, 0), node(111, 1360, 1, 0), node(115, 1361, 2, 0),
+    node(117, 1363, 1, 0), node(97, 1364, 1, 0), node(99, 1365, 2, 0), node(102, 1367, 1, 0),
+    node(103, 1368, 1, 0), node(104, 1369, 1, 0), node(106, 1370, 1, 0), node(111, 1371, 1, 0),
+    node(115, 1372, 1, 0), node(65, 1373, 3, 0), node(66, 1376, 1, 0), node(69, 1377, 2, 0),
+    node(72, 1379, 1, 0), node(97, 1380, 9, 0), node(98, 1389, 3, 0), node(99, 1392, 4, 0),
+    node(100, 1396, 4, 0), node(101, 1400, 5, 0), node(102, 1405, 3, 0), node(103, 1408, 2, 0),
+    node(104, 1410, 2, 0), node(106, 1412, 1, 0), node(108, 1413, 5, 0), node(109, 1418, 2, 0),
+    node(110, 1420, 4, 0), node(111, 1424, 8, 0), node(112, 1432, 1, 0), node(114, 1433, 5, 0),
+    node(115, 1438, 6, 0), node(116, 1444, 8, 2), node(117, 1452, 1, 0), node(118, 1453, 2, 0),
+    node(68, 1455, 1, 0), node(97, 1456, 4, 0), node(99, 1460, 2, 0), node(100, 1462, 1, 0),
+    node(101, 1463, 1, 0), node(102, 1464, 1, 0), node(104, 1465, 1, 0), node(105, 1466, 3, 0),
+    node(108, 1469, 2, 0), node(110, 1471, 1, 0), node(111, 1472, 2, 0), node(112, 1474, 1, 0),
+    node(115, 1475, 2, 0), node(117, 1477, 3, 0), node(71, 1480, 2, 0), node(76, 1482, 3, 0),
+    node(82, 1485, 1, 0), node(86, 1486, 2, 0), node(97, 1488, 5, 0), node(98, 1493, 2, 0),
+    node(99, 1495, 5, 0), node(100, 1500, 1, 0), node(101, 1501, 7, 0), node(102, 1508, 1, 0),
+    node(103, 1509, 4, 0), node(104, 1513, 3, 0), node(105, 1516, 3, 0), node(106, 1519, 1, 0),
+    node(108, 1520, 7, 0), node(109, 1527, 1, 0), node(111, 1528, 2, 0), node(112, 1530, 3, 0),
+    node(114, 1533, 4, 0), node(115, 1537, 7, 0), node(116, 1544, 4, 0), node(117, 1548, 2, 0),
+    node(118, 1550, 9, 0), node(119, 1559, 3, 0), node(83, 1562, 1, 0), node(97, 1563, 2, 0),
+    node(99, 1565, 2, 0), node(100, 1567, 5, 0), node(101, 1572, 1, 0), node(102, 1573, 2, 0),
+    node(103, 1575, 3, 0), node(104, 1578, 2, 0), node(105, 1580, 1, 0), node(108, 1581, 4, 0),
+    node(109, 1585, 3, 0), node(111, 1588, 1, 0), node(112, 1589, 3, 0), node(114, 1592, 7, 0),
+    node(115, 1599, 3, 0), node(116, 1602, 1, 0), node(117, 1603, 1, 0), node(118, 1604, 1, 0),
+    node(97, 1605, 1, 0), node(99, 1606, 1, 0), node(101, 1607, 1, 0), node(102, 1608, 1, 0),
+    node(104, 1609, 3, 0), node(105, 1612, 3, 0), node(108, 1615, 2, 0), node(109, 1617, 1, 0),
+    node(111, 1618, 3, 0), node(114, 1621, 10, 0), node(115, 1631, 2, 0), node(117, 1633, 1, 0),
+    node(102, 1634, 1, 0), node(105, 1635, 1, 0), node(111, 1636, 1, 0), node(112, 1637, 1, 0),
+    node(115, 1638, 1, 0), node(117, 1639, 3, 0), node(65, 1642, 3, 0), node(66, 1645, 1, 0),
+    node(72, 1646, 1, 0), node(97, 1647, 7, 0), node(98, 1654, 3, 0), node(99, 1657, 4, 0),
+    node(100, 1661, 4, 0), node(101, 1665, 3, 0), node(102, 1668, 3, 0), node(104, 1671, 2, 0),
+    node(105, 1673, 3, 0), node(108, 1676, 3, 0), node(109, 1679, 1, 0), node(110, 1680, 1, 0),
+    node(111, 1681, 4, 0), node(112, 1685, 2, 0), node(114, 1687, 1, 0), node(115, 1688, 4, 0),
+    node(116, 1692, 3, 0), node(117, 1695, 1, 0), node(120, 1696, 1, 0), node(97, 1697, 1, 0),
+    node(98, 1698, 1, 0), node(99, 1699, 10, 0), node(100, 1709, 1, 0), node(101, 1710, 7, 0),
+    node(102, 1717, 1, 0), node(104, 1718, 4, 0), node(105, 1722, 2, 0), node(108, 1724, 1, 0),
+    node(109, 1725, 4, 0), node(111, 1729, 3, 0), node(112, 1732, 1, 0), node(113, 1733, 3, 0),
+    node(114, 1736, 1, 0), node(115, 1737, 4, 0), node(116, 1741, 2, 0), node(117, 1743, 5, 0),
+    node(119, 1748, 3, 0), node(122, 1751, 1, 0), node(97, 1752, 2, 0), node(98, 1754, 1, 0),
+    node(99, 1755, 3, 0), node(100, 1758, 1, 0), node(101, 1759, 1, 0), node(102, 1760, 1, 0),
+    node(104, 1761, 4, 0), node(105, 1765, 3, 0), node(111, 1768, 3, 0), node(112, 1771, 1, 0),
+    node(114, 1772, 3, 0), node(115, 1775, 3, 0), node(119, 1778, 2, 0), node(65, 1780, 1, 0),
+    node(72, 1781, 1, 0), node(97, 1782, 2, 0), node(98, 1784, 1, 0), node(99, 1785, 2, 0),
+    node(100, 1787, 3, 0), node(102, 1790, 2, 0), node(103, 1792, 1, 0), node(104, 1793, 2, 0),
+    node(108, 1795, 2, 0), node(109, 1797, 2, 0), node(111, 1799, 2, 0), node(112, 1801, 6, 0),
+    node(114, 1807, 3, 0), node(115, 1810, 1, 0), node(116, 1811, 3, 0), node(117, 1814, 2, 0),
+    node(119, 1816, 1, 0), node(65, 1817, 1, 0), node(66, 1818, 1, 0), node(68, 1819, 1, 0),
+    node(97, 1820, 2, 0), node(99, 1822, 1, 0), node(100, 1823, 1, 0), node(101, 1824, 3, 0),
+    node(102, 1827, 1, 0), node(108, 1828, 1, 0), node(110, 1829, 1, 0), node(111, 1830, 1, 0),
+    node(112, 1831, 1, 0), node(114, 1832, 1, 0), node(115, 1833, 2, 0), node(122, 1835, 1, 0),
+    node(99, 1836, 1, 0), node(101, 1837, 2, 0), node(102, 1839, 1, 0), node(111, 1840, 1, 0),
+    node(112, 1841, 1, 0), node(114, 1842, 2, 0), node(115, 1844, 1, 0), node(99, 1845, 3, 0),
+    node(100, 1848, 1, 0), node(102, 1849, 1, 0), node(104, 1850, 2, 0), node(105, 1852, 1, 0),
+    node(108, 1853, 2, 0), node(109, 1855, 1, 0), node(110, 1856, 1, 0), node(111, 1857, 3, 0),
+    node(114, 1860, 2, 0), node(115, 1862, 2, 0), node(117, 1864, 2, 0), node(118, 1866, 1, 0),
+    node(119, 1867, 1, 0), node(97, 1868, 1, 0), node(99, 1869, 2, 0), node(101, 1871, 1, 0),
+    node(102, 1872, 1, 0), node(105, 1873, 1, 0), node(111, 1874, 1, 0), node(115, 1875, 1, 0),
+    node(117, 1876, 2, 0), node(97, 1878, 1, 0), node(99, 1879, 2, 0), node(100, 1881, 1, 0),
+    node(101, 1882, 2, 0), node(102, 1884, 1, 0), node(104, 1885, 1, 0), node(105, 1886, 1, 0),
+    node(111, 1887, 1, 0), node(115, 1888, 1, 0), node(119, 1889, 2, 0), node(108, 1891, 1, 0),
+    node(80, 1892, 1, 3), node(99, 1893, 1, 0), node(114, 1894, 1, 0), node(105, 1895, 1, 0),
+    node(121, 1896, 1, 0), node(114, 1897, 1, 0), node(114, 1898, 1, 0), node(112, 1899, 1, 0),
+    node(97, 1900, 1, 0), node(100, 1901, 1, 0), node(103, 1902, 1, 0), node(112, 1903, 1, 0),
+    node(112, 1904, 1, 0), node(105, 1905, 1, 0), node(99, 1906, 1, 0), node(115, 1907, 1, 0),
+    node(105, 1908, 1, 0), node(109, 1909, 1, 0), node(99, 1910, 1, 0), node(114, 1911, 2, 0),
+    node(121, 1913, 1, 0), node(99, 1914, 1, 0), node(114, 1915, 1, 0), node(116, 1916, 1, 0),
+    node(114, 1917, 1, 0), node(112, 1918, 1, 0), node(101, 1919, 1, 0), node(99, 1920, 1, 0),
+    node(109, 1921, 1, 0), node(99, 1922, 1, 0), node(80, 1923, 1, 0), node(99, 1924, 1, 0),
+    node(112, 1925, 2, 0), node(121, 1927, 1, 0), node(97, 1928, 1, 0), node(101, 1929, 1, 0),
+    node(105, 1930, 1, 0), node(111, 1931, 1, 0), node(111, 1932, 1, 0), node(100, 1933, 1, 0),
+    node(110, 1934, 1, 0), node(114, 1935, 1, 0), node(105, 1936, 1, 0), node(114, 1937, 1, 0),
+    node(111, 1938, 2, 0), node(108, 1940, 1, 0), node(110, 1941, 3, 0), node(112, 1944, 2, 0),
+    node(117, 1946, 1, 0), node(111, 1947, 1, 0), node(99, 1948, 1, 0), node(112, 1949, 2, 0),
+    node(59, 0, 0, 4), node(111, 1951, 1, 0), node(99, 1952, 1, 0), node(99, 1953, 1, 0),
+    node(99, 1954, 1, 0), node(103, 1955, 1, 0), node(114, 1956, 1, 0), node(115, 1957, 1, 0),
+    node(97, 1958, 1, 0), node(121, 1959, 1, 0), node(108, 1960, 2, 0), node(114, 1962, 1, 0),
+    node(97, 1963, 2, 0), node(102, 1965, 1, 0), node(112, 1966, 1, 0), node(116, 1967, 3, 0),
+    node(117, 1970, 1, 0), node(119, 1971, 1, 0), node(99, 1972, 1, 0), node(116, 1973, 1, 0),
+    node(71, 1974, 1, 0), node(72, 1975, 1, 5), node(99, 1976, 1, 0), node(97, 1977, 1, 0),
+    node(105, 1978, 1, 0), node(121, 1979, 1, 0), node(111, 1980, 1, 0), node(114, 1981, 1, 0),
+    node(114, 1982, 1, 0), node(101, 1983, 1, 0), node(97, 1984, 1, 0), node(112, 1985, 1, 0),
+    node(103, 1986, 1, 0), node(112, 1987, 1, 0), node(115, 1988, 1, 0), node(117, 1989, 2, 0),
+    node(99, 1991, 1, 0), node(105, 1992, 1, 0), node(97, 1993, 1, 0), node(109, 1994, 1, 0),
+    node(105, 1995, 1, 0), node(112, 1996, 1, 0), node(121, 1997, 1, 0), node(114, 1998, 1, 0),
+    node(108, 1999, 1, 0), node(112, 2000, 1, 0), node(114, 2001, 1, 0), node(117, 2002, 1, 0),
+    node(99, 2003, 1, 0), node(99, 2004, 1, 0), node(59, 0, 0, 1), node(109, 2005, 1, 0),
+    node(114, 2006, 1, 0), node(101, 2007, 1, 0), node(105, 2008, 1, 0), node(121, 2009, 1, 0),
+    node(111, 2010, 1, 0), node(114, 2011, 1, 0), node(59, 0, 0, 6), node(112, 2012, 1, 0),
+    node(101, 2013, 1, 0), node(99, 2014, 1, 0), node(59, 0, 0, 7), node(82, 2015, 1, 0),
+    node(99, 2016, 1, 0), node(116, 2017, 1, 0), node(105, 2018, 1, 0), node(114, 2019, 1, 0),
+    node(108, 2020, 1, 0), node(112, 2021, 1, 0), node(114, 2022, 1, 0), node(99, 2023, 1, 0),
+    node(116, 2024, 1, 0), node(109, 2025, 1, 0), node(99, 2026, 1, 0), node(108, 2027, 1, 0),
+    node(99, 2028, 1, 0), node(99, 2029, 1, 0), node(105, 2030, 1, 0), node(121, 2031, 1, 0),
+    node(111, 2032, 1, 0), node(114, 2033, 1, 0), node(114, 2034, 1, 0), node(59, 0, 0, 8),
+    node(97, 2035, 2, 0), node(112, 2037, 1, 0), node(116, 2038, 2, 0), node(118, 2040, 1, 0),
+    node(103, 2041, 1, 0), node(112, 2042, 1, 0), node(116, 2043, 1, 0), node(99, 2044, 1, 0),
+    node(105, 2045, 1, 0), node(107, 2046, 1, 0), node(109, 2047, 1, 0), node(105, 2048, 1, 0),
+    node(121, 2049, 1, 0), node(114, 2050, 1, 0), node(112, 2051, 1, 0), node(99, 2052, 1, 0),
+    node(101, 2053, 1, 0), node(107, 2054, 1, 0), node(99, 2055, 1, 0), node(99, 2056, 1, 0),
+    node(112, 2057, 1, 0), node(101, 2058, 1, 0), node(121, 2059, 1, 0), node(114, 2060, 1, 0),
+    node(112, 2061, 1, 0), node(99, 2062, 1, 0), node(99, 2063, 1, 0), node(59, 0, 0, 2),
+    node(99, 2064, 1, 0), node(109, 2065, 1, 0), node(110, 2066, 1, 0), node(112, 2067, 1, 0),
+    node(114, 2068, 1, 0), node(97, 2069, 1, 0), node(101, 2070, 1, 0), node(121, 2071, 1, 0),
+    node(102, 2072, 1, 0), node(115, 2073, 1, 0), node(114, 2074, 1, 0), node(59, 0, 0, 9),
+    node(101, 2075, 1, 0), node(105, 2076, 1, 0), node(110, 2077, 1, 0), node(112, 2078, 1, 0),
+    node(119, 2079, 1, 0), node(99, 2080, 1, 0), node(104, 2081, 1, 0), node(116, 2082, 1, 0),
+    node(59, 0, 0, 10), node(112, 2083, 1, 0), node(121, 2084, 1, 0), node(100, 2085, 1, 0),
+    node(108, 2086, 1, 0), node(114, 2087, 1, 0), node(110, 2088, 1, 0), node(112, 2089, 1, 0),
+    node(99, 2090, 1, 0), node(59, 0, 0, 11), node(99, 2091, 1, 0), node(99, 2092, 1, 0),
+    node(97, 2093, 1, 0), node(101, 2094, 1, 0), node(121, 2095, 1, 0), node(103, 2096, 1, 0),
+    node(115, 2097, 1, 0), node(119, 2098, 1, 0), node(114, 2099, 1, 0), node(66, 2100, 1, 0),
+    node(110, 2101, 1, 0), node(112, 2102, 1, 0), node(116, 2103, 13, 0), node(99, 2116, 1, 0),
+    node(105, 2117, 1, 0), node(59, 0, 0, 12), node(108, 2118, 1, 0), node(99, 2119, 1, 0),
+    node(105, 2120, 1, 0), node(121, 2121, 1, 0), node(98, 2122, 1, 0), node(114, 2123, 1, 0),
+    node(114, 2124, 1, 0), node(97, 2125, 1, 0), node(101, 2126, 1, 0), node(105, 2127, 1, 0),
+    node(112, 2128, 1, 0), node(101, 2129, 1, 0), node(59, 0, 0, 13), node(99, 2130, 1, 0),
+    node(108, 2131, 1, 0), node(105, 2132, 2, 0), node(109, 2134, 1, 0), node(101, 2135, 1, 0),
+    node(114, 2136, 1, 0), node(121, 2137, 1, 0), node(114, 2138, 1, 0), node(105, 2139, 1, 0),
+    node(59, 0, 0, 14), node(117, 2140, 1, 0), node(105, 2141, 1, 0), node(112, 2142, 1, 0),
+    node(59, 0, 0, 15), node(101, 2143, 1, 0), node(105, 2144, 1, 0), node(111, 2145, 2, 0),
+    node(99, 2147, 1, 0), node(105, 2148, 1, 0), node(79, 2149, 1, 0), node(114, 2150, 1, 0),
+    node(112, 2151, 1, 0), node(99, 2152, 1, 0), node(97, 2153, 1, 0), node(71, 2154, 1, 16),
+    node(99, 2155, 1, 0), node(110, 2156, 1, 0), node(114, 2157, 1, 0), node(97, 2158, 1, 0),
+    node(101, 2159, 1, 0), node(121, 2160, 1, 0), node(59, 0, 0, 17), node(118, 2161, 1, 0),
+    node(114, 2162, 1, 0), node(111, 2163, 1, 0), node(103, 2164, 1, 0), node(112, 2165, 1, 0),
+    node(117, 2166, 1, 0), node(105, 2167, 1, 0), node(99, 2168, 1, 0), node(104, 2169, 1, 0),
+    node(108, 2170, 1, 0), node(67, 2171, 1, 0), node(99, 2172, 1, 0), node(70, 2173, 1, 0),
+    node(99, 2174, 1, 0), node(59, 0, 0, 18), node(97, 2175, 1, 0), node(101, 2176, 1, 0),
+    node(105, 2177, 1, 0), node(121, 2178, 1, 0), node(114, 2179, 1, 0), node(111, 2180, 1, 0),
+    node(103, 2181, 1, 0), node(97, 2182, 1, 0), node(112, 2183, 1, 0), node(114, 2184, 1, 0),
+    node(117, 2185, 1, 0), node(99, 2186, 1, 0), node(97, 2187, 1, 0), node(98, 2188, 2, 0),
+    node(99, 2190, 2, 0), node(109, 2192, 1, 0), node(112, 2193, 3, 0), node(79, 2196, 1, 0),
+    node(65, 2197, 1, 0), node(72, 2198, 1, 0), node(99, 2199, 1, 0), node(98, 2200, 1, 0),
+    node(117, 2201, 1, 0), node(97, 2202, 1, 0), node(101, 2203, 1, 0), node(121, 2204, 1, 0),
+    node(114, 2205, 1, 0), node(101, 2206, 2, 0), node(105, 2208, 2, 0), node(108, 2210, 1, 0),
+    node(112, 2211, 1, 0), node(105, 2212, 1, 0), node(99, 2213, 1, 0), node(116, 2214, 1, 0),
+    node(99, 2215, 1, 0), node(114, 2216, 1, 0), node(114, 2217, 2, 0), node(105, 2219, 1, 0),
+    node(121, 2220, 1, 0), node(98, 2221, 1, 0), node(114, 2222, 1, 0), node(114, 2223, 1, 0),
+    node(97, 2224, 1, 0), node(100, 2225, 1, 0), node(105, 2226, 1, 0), node(103, 2227, 1, 0),
+    node(112, 2228, 1, 0), node(65, 2229, 1, 0), node(68, 2230, 1, 0), node(69, 2231, 1, 0),
+    node(84, 2232, 1, 0), node(97, 2233, 1, 0), node(100, 2234, 1, 0), node(112, 2235, 1, 0),
+    node(115, 2236, 1, 0), node(105, 2237, 1, 0), node(99, 2238, 1, 0), node(105, 2239, 1, 0),
+    node(109, 2240, 1, 0), node(97, 2241, 1, 0), node(97, 2242, 1, 0), node(121, 2243, 1, 0),
+    node(97, 2244, 1, 0), node(101, 2245, 1, 0), node(114, 2246, 3, 0), node(114, 2249, 1, 0),
+    node(112, 2250, 1, 0), node(99, 2251, 1, 0), node(100, 2252, 1, 0), node(105, 2253, 1, 0),
+    node(100, 2254, 1, 0), node(114, 2255, 1, 0), node(112, 2256, 1, 0), node(99, 2257, 1, 0),
+    node(114, 2258, 1, 0), node(59, 0, 0, 19), node(112, 2259, 1, 0), node(99, 2260, 1, 0),
+    node(99, 2261, 1, 0), node(99, 2262, 1, 0), node(99, 2263, 1, 0), node(99, 2264, 1, 0),
+    node(105, 2265, 1, 0), node(121, 2266, 1, 0), node(114, 2267, 1, 0), node(112, 2268, 1, 0),
+    node(99, 2269, 1, 0), node(109, 2270, 1, 0), node(99, 2271, 1, 0), node(99, 2272, 1, 0),
+    node(97, 2273, 1, 0), node(121, 2274, 1, 0), node(111, 2275, 1, 0), node(114, 2276, 1, 0),
+    node(116, 2277, 1, 0), node(114, 2278, 1, 0), node(112, 2279, 1, 0), node(99, 2280, 1, 0),
+    node(99, 2281, 1, 0), node(114, 2282, 1, 0), node(59, 0, 0, 20), node(69, 2283, 1, 0),
+    node(100, 2284, 1, 0), node(105, 2285, 1, 0), node(117, 2286, 1, 0), node(121, 2287, 1, 0),
+    node(108, 2288, 1, 0), node(59, 0, 0, 21), node(114, 2289, 1, 0), node(114, 2290, 1, 0),
+    node(101, 2291, 2, 0), node(112, 2293, 1, 0), node(97, 2294, 2, 0), node(112, 2296, 1, 3),
+    node(100, 2297, 5, 0), node(103, 2302, 7, 0), node(103, 2309, 1, 0), node(112, 2310, 1, 0),
+    node(59, 0, 0, 22), node(69, 2311, 1, 0), node(97, 2312, 1, 0), node(101, 2313, 1, 0),
+    node(105, 2314, 1, 0), node(111, 2315, 1, 0), node(112, 2316, 1, 0), node(105, 2317, 1, 0),
+    node(99, 2318, 1, 0), node(116, 2319, 1, 0), node(121, 2320, 1, 0), node(105, 2321, 1, 0),
+    node(109, 2322, 1, 0), node(99, 2323, 1, 0), node(105, 2324, 1, 0), node(111, 2325, 1, 0),
+    node(99, 2326, 1, 0), node(114, 2327, 2, 0), node(114, 2329, 1, 0), node(111, 2330, 1, 0),
+    node(121, 2331, 1, 0), node(113, 2332, 1, 0), node(99, 2333, 1, 0), node(109, 2334, 1, 0),
+    node(112, 2335, 1, 0), node(114, 2336, 1, 0), node(116, 2337, 3, 0), node(114, 2340, 1, 0),
+    node(103, 2341, 7, 0), node(97, 2348, 1, 0), node(97, 2349, 2, 0), node(107, 2351, 2, 0),
+    node(111, 2353, 1, 0), node(101, 2354, 2, 0), node(111, 2356, 1, 0), node(112, 2357, 1, 0),
+    node(116, 2358, 2, 0), node(119, 2360, 1, 0), node(120, 2361, 12, 0), node(114, 2373, 1, 0),
+    node(101, 2374, 1, 0), node(118, 2375, 1, 0), node(99, 2376, 1, 0), node(101, 2377, 1, 0),
+    node(105, 2378, 1, 0), node(111, 2379, 1, 0), node(108, 2380, 1, 0), node(109, 2381, 1, 0),
+    node(99, 2382, 1, 0), node(112, 2383, 6, 0), node(114, 2389, 2, 0), node(97, 2391, 2, 0),
+    node(101, 2393, 1, 0), node(105, 2394, 1, 0), node(117, 2395, 1, 0), node(111, 2396, 1, 0),
+    node(100, 2397, 1, 0), node(109, 2398, 1, 0), node(110, 2399, 1, 0), node(114, 2400, 1, 0),
+    node(99, 2401, 1, 0), node(101, 2402, 1, 0), node(105, 2403, 1, 0), node(114, 2404, 7, 0),
+    node(117, 2411, 1, 0), node(108, 2412, 1, 0), node(109, 2413, 2, 0), node(110, 2415, 2, 0),
+    node(112, 2417, 3, 0), node(97, 2420, 1, 0), node(111, 2421, 1, 0), node(99, 2422, 1, 0),
+    node(117, 2423, 2, 0), node(100, 2425, 1, 0), node(100, 2426, 1, 0), node(101, 2427, 2, 0),
+    node(108, 2429, 1, 0), node(112, 2430, 6, 0), node(114, 2436, 4, 0), node(118, 2440, 1, 0),
+    node(119, 2441, 1, 0), node(99, 2442, 1, 0), node(105, 2443, 1, 0), node(108, 2444, 1, 0),
+    node(114, 2445, 1, 0), node(97, 2446, 1, 0), node(103, 2447, 1, 0), node(108, 2448, 1, 0),
+    node(114, 2449, 1, 0), node(115, 2450, 1, 0), node(107, 2451, 1, 0), node(108, 2452, 1, 0),
+    node(97, 2453, 1, 0), node(121, 2454, 1, 0), node(59, 0, 0, 23), node(97, 2455, 2, 0),
+    node(111, 2457, 1, 0), node(103, 2458, 1, 24), node(108, 2459, 1, 0), node(109, 2460, 1, 0),
+    node(105, 2461, 1, 0), node(114, 2462, 1, 0), node(97, 2463, 1, 0), node(97, 2464, 1, 0),
+    node(101, 2465, 1, 0), node(103, 2466, 1, 0), node(115, 2467, 1, 0), node(118, 2468, 3, 0),
+    node(99, 2471, 1, 0), node(99, 2472, 2, 0), node(108, 2474, 1, 0), node(112, 2475, 1, 0),
+    node(116, 2476, 5, 0), node(117, 2481, 1, 0), node(119, 2482, 1, 0), node(98, 2483, 1, 0),
+    node(99, 2484, 2, 0), node(99, 2486, 2, 0), node(111, 2488, 1, 0), node(116, 2489, 1, 0),
+    node(100, 2490, 1, 0), node(114, 2491, 1, 0), node(97, 2492, 1, 0), node(104, 2493, 1, 0),
+    node(97, 2494, 1, 0), node(99, 2495, 1, 0), node(105, 2496, 1, 0), node(68, 2497, 1, 0),
+    node(111, 2498, 1, 0), node(99, 2499, 1, 0), node(115, 2500, 1, 0), node(97, 2501, 1, 0),
+    node(105, 2502, 1, 0), node(111, 2503, 1, 0), node(121, 2504, 1, 0), node(111, 2505, 1, 0),
+    node(59, 0, 0, 25), node(68, 2506, 1, 0), node(114, 2507, 1, 0), node(59, 0, 0, 26),
+    node(114, 2508, 1, 0), node(115, 2509, 2, 0), node(59, 0, 0, 27), node(105, 2511, 1, 0),
+    node(108, 2512, 1, 0), node(115, 2513, 2, 0), node(97, 2515, 1, 0), node(112, 2516, 1, 0),
+    node(115, 2517, 1, 0), node(103, 2518, 1, 0), node(115, 2519, 1, 0), node(103, 2520, 1, 0),
+    node(112, 2521, 1, 0), node(97, 2522, 1, 0), node(108, 2523, 1, 0), node(115, 2524, 1, 0),
+    node(99, 2525, 2, 0), node(115, 2527, 2, 0), node(117, 2529, 3, 0), node(118, 2532, 1, 0),
+    node(68, 2533, 1, 0), node(97, 2534, 1, 0), node(99, 2535, 1, 0), node(100, 2536, 1, 0),
+    node(105, 2537, 1, 0), node(97, 2538, 1, 0), node(104, 2539, 1, 28), node(109, 2540, 1, 0),
+    node(114, 2541, 1, 0), node(99, 2542, 1, 0), node(105, 2543, 1, 0), node(112, 2544, 2, 0),
+    node(108, 2546, 1, 0), node(121, 2547, 1, 0), node(109, 2548, 1, 0), node(105, 2549, 1, 0),
+    node(108, 2550, 2, 0), node(114, 2552, 1, 0), node(108, 2553, 1, 0), node(108, 2554, 1, 0),
+    node(97, 2555, 1, 0), node(108, 2556, 1, 0), node(116, 2557, 1, 0), node(111, 2558, 1, 0),
+    node(112, 2559, 1, 0), node(114, 2560, 2, 0), node(97, 2562, 1, 0), node(97, 2563, 2, 0),
+    node(111, 2565, 1, 0), node(99, 2566, 1, 0), node(59, 0, 0, 29), node(108, 2567, 1, 0),
+    node(99, 2568, 1, 0), node(109, 2569, 1, 0), node(112, 2570, 1, 0), node(114, 2571, 1, 0),
+    node(105, 2572, 1, 0), node(121, 2573, 1, 0), node(111, 2574, 1, 0), node(59, 0, 0, 30),
+    node(108, 2575, 1, 0), node(113, 2576, 3, 0), node(115, 2579, 4, 0), node(114, 2583, 1, 0),
+    node(59, 0, 0, 7), node(103, 2584, 1, 0), node(109, 2585, 1, 0), node(99, 2586, 1, 0),
+    node(59, 0, 0, 31), node(69, 2587, 1, 0), node(97, 2588, 1, 0), node(106, 2589, 1, 0),
+    node(69, 2590, 1, 0), node(97, 2591, 1, 0), node(101, 2592, 2, 0), node(115, 2594, 1, 0),
+    node(112, 2595, 1, 0), node(97, 2596, 1, 0), node(99, 2597, 1, 0), node(105, 2598, 1, 0),
+    node(59, 0, 0, 1), node(99, 2599, 2, 0), node(100, 2601, 1, 0), node(108, 2602, 1, 0),
+    node(113, 2603, 1, 0), node(114, 2604, 5, 0), node(101, 2609, 1, 0), node(110, 2610, 1, 0),
+    node(114, 2611, 1, 0), node(105, 2612, 1, 0), node(108, 2613, 1, 0), node(109, 2614, 1, 0),
+    node(114, 2615, 2, 0), node(97, 2617, 1, 0), node(105, 2618, 1, 0), node(97, 2619, 1, 0),
+    node(108, 2620, 1, 0), node(114, 2621, 1, 0), node(114, 2622, 1, 0), node(115, 2623, 2, 0),
+    node(97, 2625, 1, 0), node(109, 2626, 1, 0), node(111, 2627, 1, 0), node(112, 2628, 1, 0),
+    node(114, 2629, 1, 0), node(99, 2630, 1, 0), node(108, 2631, 1, 0), node(116, 2632, 1, 0),
+    node(98, 2633, 1, 0), node(112, 2634, 1, 0), node(99, 2635, 1, 0), node(59, 0, 0, 32),
+    node(105, 2636, 1, 0), node(121, 2637, 1, 0), node(99, 2638, 1, 0), node(120, 2639, 1, 0),
+    node(102, 2640, 1, 0), node(114, 2641, 1, 0), node(114, 2642, 1, 0), node(59, 0, 0, 33),
+    node(105, 2643, 2, 0), node(110, 2645, 1, 0), node(111, 2646, 1, 0), node(108, 2647, 1, 0),
+    node(97, 2648, 3, 0), node(111, 2651, 1, 0), node(112, 2652, 1, 0), node(59, 0, 0, 34),
+    node(99, 2653, 1, 0), node(102, 2654, 1, 0), node(111, 2655, 1, 0), node(116, 2656, 5, 0),
+    node(99, 2661, 1, 0), node(103, 2662, 1, 0), node(112, 2663, 1, 0), node(116, 2664, 1, 0),
+    node(114, 2665, 1, 0), node(117, 2666, 1, 0), node(99, 2667, 1, 0), node(105, 2668, 1, 0),
+    node(59, 0, 0, 35), node(105, 2669, 1, 0), node(107, 2670, 1, 0), node(109, 2671, 1, 0),
+    node(105, 2672, 1, 0), node(121, 2673, 1, 0), node(114, 2674, 1, 0), node(97, 2675, 1, 0),
+    node(112, 2676, 1, 0), node(99, 2677, 1, 0), node(101, 2678, 1, 0), node(107, 2679, 1, 0),
+    node(112, 2680, 1, 0), node(101, 2681, 1, 0), node(121, 2682, 1, 0), node(114, 2683, 1, 0),
+    node(114, 2684, 1, 0), node(99, 2685, 1, 0), node(99, 2686, 1, 0), node(112, 2687, 1, 0),
+    node(99, 2688, 1, 0), node(97, 2689, 1, 0), node(114, 2690, 1, 0), node(116, 2691, 1, 0),
+    node(97, 2692, 1, 0), node(59, 0, 0, 36), node(103, 2693, 1, 0), node(97, 2694, 1, 0),
+    node(99, 2695, 1, 0), node(101, 2696, 1, 0), node(103, 2697, 1, 0), node(109, 2698, 1, 0),
+    node(110, 2699, 1, 0), node(112, 2700, 1, 0), node(113, 2701, 1, 0), node(114, 2702, 1, 0),
+    node(116, 2703, 3, 0), node(97, 2706, 1, 0), node(98, 2707, 1, 0), node(114, 2708, 2, 0),
+    node(97, 2710, 1, 0), node(101, 2711, 2, 0), node(117, 2713, 1, 0), node(121, 2714, 1, 0),
+    node(99, 2715, 1, 0), node(113, 2716, 1, 0), node(114, 2717, 2, 0), node(115, 2719, 1, 0),
+    node(59, 0, 0, 37), node(102, 2720, 1, 0), node(103, 2721, 1, 0), node(113, 2722, 3, 0),
+    node(115, 2725, 5, 0), node(105, 2730, 1, 0), node(108, 2731, 1, 0), node(114, 2732, 1, 0),
+    node(59, 0, 0, 38), node(69, 2733, 1, 0), node(97, 2734, 1, 0), node(98, 2735, 1, 0),
+    node(99, 2736, 1, 0), node(59, 0, 0, 10), node(97, 2737, 1, 0), node(99, 2738, 1, 0),
+    node(104, 2739, 1, 0), node(116, 2740, 1, 0), node(105, 2741, 1, 0), node(111, 2742, 1, 0),
+    node(69, 2743, 1, 0), node(97, 2744, 1, 0), node(101, 2745, 2, 0), node(115, 2747, 1, 0),
+    node(97, 2748, 2, 0), node(98, 2750, 1, 0), node(110, 2751, 1, 0), node(111, 2752, 1, 0),
+    node(112, 2753, 3, 0), node(116, 2756, 1, 0), node(119, 2757, 2, 0), node(122, 2759, 3, 0),
+    node(97, 2762, 1, 0), node(97, 2763, 1, 0), node(99, 2764, 1, 0), node(104, 2765, 1, 0),
+    node(109, 2766, 1, 0), node(116, 2767, 1, 0), node(97, 2768, 1, 0), node(99, 2769, 1, 0),
+    node(104, 2770, 1, 0), node(105, 2771, 1, 0), node(113, 2772, 2, 0), node(116, 2774, 1, 0),
+    node(59, 0, 0, 2), node(99, 2775, 2, 0), node(100, 2777, 1, 0), node(104, 2778, 1, 0),
+    node(105, 2779, 1, 0), node(108, 2780, 1, 0), node(113, 2781, 1, 0), node(114, 2782, 2, 0),
+    node(114, 2784, 2, 0), node(101, 2786, 1, 0), node(110, 2787, 1, 0), node(68, 2788, 1, 0),
+    node(99, 2789, 1, 0), node(108, 2790, 2, 0), node(112, 2792, 2, 0), node(114, 2794, 1, 0),
+    node(111, 2795, 1, 0), node(121, 2796, 1, 0), node(97, 2797, 1, 0), node(97, 2798, 1, 0),
+    node(114, 2799, 1, 0), node(111, 2800, 1, 0), node(99, 2801, 1, 0), node(100, 2802, 4, 0),
+    node(110, 2806, 1, 0), node(99, 2807, 1, 0), node(100, 2808, 1, 0), node(112, 2809, 1, 0),
+    node(100, 2810, 1, 0), node(112, 2811, 1, 0), node(59, 0, 0, 39), node(99, 2812, 1, 0),
+    node(116, 2813, 1, 0), node(59, 0, 0, 40), node(108, 2814, 1, 0), node(109, 2815, 1, 0),
+    node(103, 2816, 1, 0), node(116, 2817, 2, 0), node(101, 2819, 1, 0), node(108, 2820, 1, 0),
+    node(116, 2821, 2, 0), node(105, 2823, 1, 0), node(68, 2824, 1, 0), node(100, 2825, 1, 0),
+    node(98, 2826, 1, 0), node(99, 2827, 1, 0), node(110, 2828, 1, 0), node(112, 2829, 5, 0),
+    node(116, 2834, 1, 0), node(115, 2835, 1, 0), node(117, 2836, 1, 0), node(97, 2837, 2, 0),
+    node(101, 2839, 1, 0), node(111, 2840, 1, 0), node(117, 2841, 1, 0), node(121, 2842, 1, 0),
+    node(97, 2843, 1, 0), node(59, 0, 0, 41), node(65, 2844, 1, 0), node(97, 2845, 1, 0),
+    node(100, 2846, 1, 0), node(113, 2847, 1, 0), node(115, 2848, 2, 0), node(120, 2850, 1, 0),
+    node(114, 2851, 1, 0), node(69, 2852, 1, 0), node(101, 2853, 3, 0), node(115, 2856, 1, 0),
+    node(116, 2857, 2, 0), node(65, 2859, 1, 0), node(97, 2860, 1, 0), node(112, 2861, 1, 0),
+    node(59, 0, 0, 42), node(115, 2862, 2, 0), node(118, 2864, 1, 0), node(99, 2865, 1, 0),
+    node(65, 2866, 1, 0), node(69, 2867, 1, 0), node(97, 2868, 1, 0), node(100, 2869, 1, 0),
+    node(101, 2870, 4, 0), node(115, 2874, 1, 0), node(116, 2875, 2, 0), node(105, 2877, 1, 0),
+    node(112, 2878, 1, 0), node(116, 2879, 3, 43), node(97, 2882, 1, 0), node(111, 2883, 1, 0),
+    node(114, 2884, 3, 0), node(65, 2887, 1, 0), node(97, 2888, 1, 0), node(105, 2889, 1, 0),
+    node(116, 2890, 1, 0), node(99, 2891, 4, 0), node(104, 2895, 1, 0), node(105, 2896, 1, 0),
+    node(109, 2897, 1, 0), node(112, 2898, 1, 0), node(113, 2899, 1, 0), node(117, 2900, 3, 0),
+    node(103, 2903, 1, 0), node(105, 2904, 1, 0), node(108, 2905, 1, 0), node(114, 2906, 1, 0),
+    node(59, 0, 0, 44), node(109, 2907, 3, 0), node(68, 2910, 1, 0), node(72, 2911, 1, 0),
+    node(97, 2912, 1, 0), node(100, 2913, 1, 0), node(103, 2914, 2, 0), node(105, 2916, 1, 0),
+    node(108, 2917, 3, 0), node(114, 2920, 2, 0), node(115, 2922, 1, 0), node(65, 2923, 1, 0),
+    node(97, 2924, 1, 0), node(110, 2925, 1, 0), node(59, 0, 0, 45), node(99, 2926, 1, 0),
+    node(115, 2927, 1, 0), node(105, 2928, 1, 0), node(121, 2929, 1, 0), node(97, 2930, 1, 0),
+    node(98, 2931, 1, 0), node(105, 2932, 1, 0), node(111, 2933, 1, 0), node(115, 2934, 1, 0),
+    node(108, 2935, 1, 0), node(99, 2936, 1, 0), node(114, 2937, 1, 0), node(111, 2938, 1, 0),
+    node(114, 2939, 1, 0), node(116, 2940, 1, 0), node(98, 2941, 1, 0), node(109, 2942, 1, 0),
+    node(110, 2943, 1, 0), node(97, 2944, 1, 0), node(99, 2945, 2, 0), node(105, 2947, 1, 0),
+    node(116, 2948, 1, 0), node(97, 2949, 1, 0), node(101, 2950, 1, 0), node(105, 2951, 3, 0),
+    node(112, 2954, 1, 0), node(97, 2955, 1, 0), node(101, 2956, 1, 0), node(108, 2957, 1, 0),
+    node(59, 0, 0, 46), node(97, 2958, 1, 0), node(100, 2959, 4, 0), node(105, 2963, 1, 0),
+    node(111, 2964, 1, 0), node(115, 2965, 1, 0), node(118, 2966, 1, 0), node(99, 2967, 1, 0),
+    node(108, 2968, 1, 0), node(111, 2969, 1, 0), node(105, 2970, 2, 0), node(109, 2972, 1, 0),
+    node(98, 2973, 1, 0), node(114, 2974, 4, 0), node(121, 2978, 1, 0), node(114, 2979, 5, 0),
+    node(114, 2984, 1, 0), node(105, 2985, 2, 0), node(109, 2987, 1, 0), node(111, 2988, 1, 0),
+    node(59, 0, 0, 47), node(116, 2989, 1, 0), node(118, 2990, 1, 0), node(97, 2991, 1, 0),
+    node(117, 2992, 1, 0), node(59, 0, 0, 48), node(105, 2993, 1, 0), node(112, 2994, 1, 0),
+    node(117, 2995, 1, 0), node(59, 0, 0, 49), node(69, 2996, 1, 0), node(97, 2997, 1, 0),
+    node(99, 2998, 1, 0), node(101, 2999, 2, 0), node(105, 3001, 1, 0), node(110, 3002, 3, 0),
+    node(111, 3005, 3, 0), node(115, 3008, 1, 0), node(117, 3009, 1, 0), node(99, 3010, 1, 0),
+    node(105, 3011, 1, 0), node(110, 3012, 1, 0), node(114, 3013, 1, 0), node(110, 3014, 1, 0),
+    node(112, 3015, 1, 0), node(114, 3016, 1, 0), node(99, 3017, 1, 0), node(97, 3018, 1, 0),
+    node(101, 3019, 1, 0), node(111, 3020, 1, 0), node(97, 3021, 1, 0), node(114, 3022, 1, 0),
+    node(116, 3023, 1, 0), node(97, 3024, 1, 0), node(97, 3025, 1, 0), node(99, 3026, 2, 0),
+    node(100, 3028, 1, 0), node(101, 3029, 1, 0), node(110, 3030, 1, 0), node(113, 3031, 1, 0),
+    node(114, 3032, 1, 0), node(116, 3033, 2, 0), node(97, 3035, 1, 0), node(98, 3036, 1, 0),
+    node(114, 3037, 2, 0), node(97, 3039, 1, 0), node(101, 3040, 2, 0), node(117, 3042, 1, 0),
+    node(121, 3043, 1, 0), node(99, 3044, 1, 0), node(108, 3045, 1, 0), node(113, 3046, 1, 0),
+    node(115, 3047, 1, 0), node(97, 3048, 1, 0), node(99, 3049, 1, 0), node(103, 3050, 1, 16),
+    node(105, 3051, 1, 0), node(108, 3052, 1, 0), node(114, 3053, 1, 0), node(97, 3054, 1, 0),
+    node(111, 3055, 2, 0), node(103, 3057, 1, 0), node(110, 3058, 1, 0), node(115, 3059, 1, 0),
+    node(97, 3060, 1, 0), node(104, 3061, 1, 0), node(109, 3062, 1, 0), node(111, 3063, 1, 0),
+    node(109, 3064, 1, 0), node(97, 3065, 2, 0), node(98, 3067, 1, 0), node(112, 3068, 3, 0),
+    node(116, 3071, 1, 0), node(97, 3072, 1, 0), node(112, 3073, 1, 0), node(97, 3074, 1, 0),
+    node(97, 3075, 1, 0), node(99, 3076, 1, 0), node(104, 3077, 1, 0), node(113, 3078, 2, 0),
+    node(104, 3080, 1, 0), node(105, 3081, 1, 0), node(114, 3082, 1, 0), node(108, 3083, 1, 0),
+    node(59, 0, 0, 50), node(99, 3084, 1, 0), node(113, 3085, 1, 0), node(59, 0, 0, 51),
+    node(69, 3086, 1, 0), node(97, 3087, 2, 0), node(99, 3089, 1, 0), node(101, 3090, 2, 0),
+    node(105, 3092, 1, 0), node(110, 3093, 3, 0), node(112, 3096, 1, 0), node(115, 3097, 1, 0),
+    node(121, 3098, 1, 0), node(111, 3099, 1, 0), node(65, 3100, 1, 0), node(97, 3101, 1, 0),
+    node(99, 3102, 1, 0), node(109, 3103, 1, 0), node(115, 3104, 1, 0), node(116, 3105, 1, 0),
+    node(120, 3106, 1, 0), node(114, 3107, 2, 0), node(97, 3109, 1, 0), node(99, 3110, 2, 0),
+    node(111, 3112, 1, 0), node(121, 3113, 1, 52), node(103, 3114, 1, 0), node(109, 3115, 8, 0),
+    node(97, 3123, 1, 0), node(97, 3124, 2, 0), node(101, 3126, 1, 0), node(105, 3127, 2, 0),
+    node(116, 3129, 2, 0), node(102, 3131, 1, 0), node(108, 3132, 2, 0), node(112, 3134, 1, 0),
+    node(97, 3135, 2, 0), node(99, 3137, 2, 0), node(115, 3139, 1, 0), node(117, 3140, 3, 0),
+    node(97, 3143, 1, 0), node(99, 3144, 1, 0), node(101, 3145, 1, 0), node(109, 3146, 1, 0),
+    node(116, 3147, 1, 0), node(97, 3148, 1, 0), node(114, 3149, 2, 0), node(98, 3151, 9, 0),
+    node(99, 3160, 1, 0), node(109, 3161, 1, 0), node(110, 3162, 1, 0), node(112, 3163, 13, 0),
+    node(65, 3176, 1, 0), node(97, 3177, 1, 0), node(110, 3178, 1, 0), node(108, 3179, 1, 0),
+    node(114, 3180, 1, 0), node(117, 3181, 1, 0), node(114, 3182, 1, 0), node(97, 3183, 1, 0),
+    node(101, 3184, 1, 0), node(121, 3185, 1, 0), node(111, 3186, 1, 0), node(108, 3187, 1, 0),
+    node(114, 3188, 1, 0), node(101, 3189, 2, 0), node(105, 3191, 2, 0), node(107, 3193, 2, 0),
+    node(111, 3195, 1, 0), node(108, 3196, 1, 0), node(109, 3197, 1, 0), node(110, 3198, 1, 0),
+    node(101, 3199, 1, 0), node(112, 3200, 4, 0), node(115, 3204, 1, 0), node(114, 3205, 1, 0),
+    node(97, 3206, 1, 0), node(105, 3207, 7, 0), node(112, 3214, 1, 0), node(99, 3215, 2, 0),
+    node(104, 3217, 1, 0), node(116, 3218, 1, 0), node(105, 3219, 1, 0), node(111, 3220, 1, 0),
+    node(114, 3221, 1, 0), node(97, 3222, 1, 0), node(99, 3223, 1, 0), node(114, 3224, 1, 0),
+    node(114, 3225, 2, 0), node(105, 3227, 1, 0), node(121, 3228, 1, 0), node(97, 3229, 1, 0),
+    node(98, 3230, 1, 0), node(104, 3231, 1, 0), node(105, 3232, 1, 0), node(114, 3233, 1, 0),
+    node(114, 3234, 1, 0), node(97, 3235, 1, 0), node(98, 3236, 1, 0), node(99, 3237, 2, 0),
+    node(116, 3239, 1, 0), node(97, 3240, 1, 0), node(108, 3241, 1, 53), node(103, 3242, 1, 0),
+    node(112, 3243, 1, 0), node(97, 3244, 1, 0), node(100, 3245, 1, 0), node(104, 3246, 1, 0),
+    node(108, 3247, 1, 0), node(115, 3248, 1, 0), node(117, 3249, 1, 0), node(99, 3250, 2, 0),
+    node(105, 3252, 1, 0), node(116, 3253, 1, 0), node(99, 3254, 1, 0), node(100, 3255, 1, 0),
+    node(105, 3256, 1, 0), node(114, 3257, 1, 0), node(97, 3258, 1, 0), node(109, 3259, 1, 0),
+    node(97, 3260, 1, 0), node(114, 3261, 1, 0), node(97, 3262, 1, 0), node(97, 3263, 1, 0),
+    node(110, 3264, 1, 0), node(114, 3265, 7, 0), node(121, 3272, 1, 0), node(97, 3273, 1, 0),
+    node(101, 3274, 3, 0), node(108, 3277, 1, 0), node(114, 3278, 2, 0), node(114, 3280, 1, 0),
+    node(116, 3281, 1, 0), node(115, 3282, 1, 0), node(112, 3283, 1, 0), node(114, 3284, 1, 0),
+    node(116, 3285, 1, 0), node(99, 3286, 1, 0), node(117, 3287, 2, 0), node(105, 3289, 1, 0),
+    node(105, 3290, 1, 0), node(100, 3291, 2, 0), node(105, 3293, 1, 0), node(114, 3294, 1, 0),
+    node(112, 3295, 1, 0), node(59, 0, 0, 54), node(59, 0, 0, 55), node(101, 3296, 1, 0),
+    node(99, 3297, 1, 0), node(97, 3298, 1, 0), node(105, 3299, 1, 0), node(117, 3300, 1, 0),
+    node(116, 3301, 1, 0), node(114, 3302, 1, 0), node(65, 3303, 1, 0), node(97, 3304, 1, 0),
+    node(59, 0, 0, 56), node(65, 3305, 1, 0), node(97, 3306, 1, 0), node(97, 3307, 1, 0),
+    node(105, 3308, 1, 0), node(100, 3309, 1, 0), node(112, 3310, 2, 0), node(116, 3312, 1, 0),
+    node(65, 3313, 1, 0), node(97, 3314, 1, 0), node(99, 3315, 1, 0), node(113, 3316, 1, 0),
+    node(112, 3317, 1, 0), node(116, 3318, 1, 0), node(101, 3319, 1, 0), node(101, 3320, 1, 0),
+    node(99, 3321, 2, 0), node(105, 3323, 1, 0), node(121, 3324, 1, 0), node(110, 3325, 1, 57),
+    node(114, 3326, 1, 0), node(99, 3327, 1, 0), node(112, 3328, 1, 0), node(99, 3329, 1, 0),
+    node(99, 3330, 1, 0), node(109, 3331, 1, 0), node(99, 3332, 1, 0), node(97, 3333, 1, 0),
+    node(121, 3334, 1, 0), node(111, 3335, 1, 0), node(101, 3336, 1, 0), node(116, 3337, 1, 0),
+    node(114, 3338, 1, 0), node(99, 3339, 1, 0), node(103, 3340, 1, 0), node(112, 3341, 1, 0),
+    node(99, 3342, 1, 0), node(106, 3343, 1, 0), node(110, 3344, 1, 0), node(105, 3345, 1, 0),
+    node(59, 0, 0, 3), node(117, 3346, 1, 0), node(101, 3347, 1, 0), node(114, 3348, 1, 0),
+    node(59, 0, 0, 58), node(59, 0, 0, 59), node(97, 3349, 1, 0), node(104, 3350, 1, 0),
+    node(99, 3351, 1, 0), node(59, 0, 0, 60), node(111, 3352, 1, 0), node(102, 3353, 1, 0),
+    node(108, 3354, 1, 0), node(110, 3355, 1, 0), node(114, 3356, 1, 0), node(105, 3357, 1, 0),
+    node(108, 3358, 1, 0), node(108, 3359, 1, 61), node(107, 3360, 1, 0), node(118, 3361, 1, 0),
+    node(119, 3362, 1, 0), node(59, 0, 0, 62), node(97, 3363, 1, 0), node(110, 3364, 1, 0),
+    node(97, 3365, 1, 0), node(59, 0, 0, 63), node(102, 3366, 1, 0), node(118, 3367, 1, 0),
+    node(114, 3368, 1, 0), node(112, 3369, 1, 0), node(121, 3370, 1, 0), node(89, 3371, 1, 64),
+    node(117, 3372, 1, 0), node(59, 0, 0, 65), node(105, 3373, 1, 0), node(108, 3374, 1, 0),
+    node(114, 3375, 1, 0), node(100, 3376, 1, 0), node(114, 3377, 1, 0), node(110, 3378, 1, 0),
+    node(116, 3379, 1, 0), node(105, 3380, 1, 0), node(116, 3381, 1, 0), node(59, 0, 0, 66),
+    node(59, 0, 0, 67), node(99, 3382, 1, 0), node(99, 3383, 1, 0), node(115, 3384, 1, 0),
+    node(111, 3385, 1, 0), node(103, 3386, 1, 0), node(105, 3387, 1, 0), node(116, 3388, 1, 0),
+    node(102, 3389, 1, 0), node(114, 3390, 1, 0), node(110, 3391, 1, 0), node(115, 3392, 1, 0),
+    node(114, 3393, 1, 0), node(59, 0, 0, 68), node(67, 3394, 1, 0), node(116, 3395, 1, 0),
+    node(121, 3396, 1, 0), node(121, 3397, 1, 0), node(121, 3398, 1, 0), node(103, 3399, 1, 0),
+    node(114, 3400, 1, 0), node(104, 3401, 1, 0), node(114, 3402, 1, 0), node(59, 0, 0, 69),
+    node(59, 0, 0, 70), node(116, 3403, 1, 0), node(59, 0, 0, 71), node(99, 3404, 1, 0),
+    node(109, 3405, 1, 0), node(102, 3406, 1, 0), node(102, 3407, 1, 0), node(59, 0, 0, 53),
+    node(68, 3408, 1, 0), node(69, 3409, 1, 0), node(98, 3410, 1, 0), node(110, 3411, 6, 0),
+    node(114, 3417, 1, 0), node(114, 3418, 1, 0), node(59, 0, 0, 72), node(59, 0, 0, 5),
+    node(117, 3419, 1, 0), node(114, 3420, 1, 0), node(114, 3421, 1, 0), node(59, 0, 0, 73),
+    node(116, 3422, 1, 0), node(59, 0, 0, 74), node(97, 3423, 1, 0), node(109, 3424, 1, 0),
+    node(99, 3425, 1, 0), node(116, 3426, 1, 0), node(111, 3427, 1, 0), node(102, 3428, 1, 0),
+    node(105, 3429, 1, 0), node(97, 3430, 1, 0), node(105, 3431, 1, 0), node(114, 3432, 1, 0),
+    node(109, 3433, 1, 0), node(59, 0, 0, 75), node(108, 3434, 1, 76), node(115, 3435, 1, 0),
+    node(111, 3436, 1, 0), node(59, 0, 0, 77), node(59, 0, 0, 78), node(108, 3437, 1, 0),
+    node(102, 3438, 1, 0), node(65, 3439, 1, 0), node(114, 3440, 1, 0), node(114, 3441, 1, 0),
+    node(121, 3442, 1, 0), node(109, 3443, 1, 0), node(101, 3444, 1, 0), node(100, 3445, 1, 0),
+    node(114, 3446, 1, 0), node(59, 0, 0, 79), node(116, 3447, 1, 0), node(59, 0, 0, 80),
+    node(102, 3448, 1, 0), node(97, 3449, 1, 0), node(114, 3450, 1, 0), node(68, 3451, 1, 0),
+    node(101, 3452, 1, 0), node(59, 0, 0, 81), node(114, 3453, 1, 0), node(59, 0, 0, 82),
+    node(98, 3454, 1, 0), node(102, 3455, 1, 0), node(105, 3456, 1, 0), node(114, 3457, 1, 0),
+    node(114, 3458, 1, 0), node(112, 3459, 2, 0), node(121, 3461, 1, 0), node(105, 3462, 1, 0),
+    node(121, 3463, 1, 0), node(117, 3464, 1, 0), node(114, 3465, 1, 0), node(59, 0, 0, 83),
+    node(116, 3466, 1, 0), node(59, 0, 0, 8), node(97, 3467, 1, 0), node(99, 3468, 1, 0),
+    node(103, 3469, 1, 0), node(108, 3470, 1, 0), node(59, 0, 0, 84), node(101, 3471, 2, 0),
+    node(105, 3473, 1, 0), node(111, 3474, 1, 0), node(102, 3475, 1, 0), node(97, 3476, 1, 0),
+    node(114, 3477, 1, 0), node(108, 3478, 1, 0), node(99, 3479, 1, 0), node(108, 3480, 1, 85),
+    node(114, 3481, 1, 0), node(59, 0, 0, 86), node(59, 0, 0, 87), node(102, 3482, 1, 0),
+    node(114, 3483, 1, 0), node(114, 3484, 1, 0), node(99, 3485, 1, 0), node(121, 3486, 1, 0),
+    node(121, 3487, 1, 0), node(112, 3488, 1, 0), node(100, 3489, 1, 0), node(59, 0, 0, 88),
+    node(59, 0, 0, 89), node(102, 3490, 1, 0), node(114, 3491, 1, 0), node(121, 3492, 1, 0),
+    node(117, 3493, 1, 0), node(98, 3494, 1, 0), node(103, 3495, 1, 0), node(108, 3496, 1, 0),
+    node(114, 3497, 1, 0), node(114, 3498, 1, 0), node(100, 3499, 1, 0), node(59, 0, 0, 90),
+    node(116, 3500, 10, 0), node(115, 3510, 6, 0), node(59, 0, 0, 91), node(102, 3516, 1, 0),
+    node(100, 3517, 1, 0), node(103, 3518, 4, 0), node(102, 3522, 1, 0), node(101, 3523, 1, 0),
+    node(114, 3524, 1, 0), node(59, 0, 0, 92), node(114, 3525, 1, 0), node(59, 0, 0, 93),
+    node(59, 0, 0, 94), node(105, 3526, 1, 0), node(108, 3527, 1, 0), node(59, 0, 0, 95),
+    node(117, 3528, 1, 0), node(102, 3529, 1, 0), node(114, 3530, 1, 0), node(121, 3531, 1, 0),
+    node(117, 3532, 1, 0), node(114, 3533, 1, 0), node(100, 3534, 1, 0), node(59, 0, 0, 96),
+    node(97, 3535, 1, 0), node(116, 3536, 1, 0), node(76, 3537, 1, 0), node(59, 0, 0, 97),
+    node(114, 3538, 1, 0), node(66, 3539, 1, 0), node(102, 3540, 1, 0), node(59, 0, 0, 98),
+    node(67, 3541, 2, 0), node(68, 3543, 1, 0), node(69, 3544, 3, 0), node(71, 3547, 1, 0),
+    node(72, 3548, 1, 0), node(76, 3549, 1, 0), node(78, 3550, 1, 0), node(80, 3551, 1, 0),
+    node(82, 3552, 2, 0), node(83, 3554, 2, 0), node(84, 3556, 1, 0), node(86, 3557, 1, 0),
+    node(114, 3558, 1, 0), node(108, 3559, 1, 0), node(105, 3560, 1, 0), node(117, 3561, 1, 0),
+    node(114, 3562, 1, 0), node(59, 0, 0, 99), node(108, 3563, 1, 0), node(59, 0, 0, 100),
+    node(97, 3564, 1, 0), node(99, 3565, 1, 0), node(103, 3566, 1, 0), node(99, 3567, 1, 0),
+    node(102, 3568, 1, 0), node(110, 3569, 1, 0), node(114, 3570, 1, 0), node(97, 3571, 1, 0),
+    node(108, 3572, 1, 0), node(109, 3573, 1, 0), node(108, 3574, 1, 101), node(114, 3575, 2, 0),
+    node(116, 3577, 1, 0), node(59, 0, 0, 102), node(59, 0, 0, 103), node(59, 0, 0, 104),
+    node(115, 3578, 1, 0), node(110, 3579, 1, 0), node(102, 3580, 1, 0), node(99, 3581, 1, 0),
+    node(109, 3582, 1, 0), node(100, 3583, 1, 0), node(112, 3584, 1, 0), node(114, 3585, 1, 0),
+    node(59, 0, 0, 105), node(84, 3586, 1, 106), node(59, 0, 0, 107), node(102, 3587, 1, 0),
+    node(114, 3588, 1, 0), node(114, 3589, 1, 0), node(59, 0, 0, 16), node(117, 3590, 1, 0),
+    node(103, 3591, 1, 0), node(114, 3592, 2, 0), node(114, 3594, 1, 0), node(100, 3595, 1, 0),
+    node(59, 0, 0, 108), node(101, 3596, 1, 0), node(59, 0, 0, 17), node(59, 0, 0, 109),
+    node(104, 3597, 1, 0), node(102, 3598, 1, 0), node(110, 3599, 1, 0), node(103, 3600, 1, 0),
+    node(114, 3601, 1, 0), node(59, 0, 0, 110), node(101, 3602, 1, 0), node(72, 3603, 1, 0),
+    node(121, 3604, 1, 0), node(84, 3605, 1, 0), node(117, 3606, 1, 0), node(114, 3607, 1, 0),
+    node(100, 3608, 1, 0), node(114, 3609, 1, 0), node(59, 0, 0, 111), node(59, 0, 0, 112),
+    node(114, 3610, 1, 0), node(109, 3611, 1, 0), node(108, 3612, 1, 0), node(102, 3613, 1, 0),
+    node(116, 3614, 1, 0), node(97, 3615, 1, 0), node(114, 3616, 1, 0), node(114, 3617, 1, 0),
+    node(59, 0, 0, 113), node(115, 3618, 1, 0), node(99, 3619, 1, 0), node(104, 3620, 1, 0),
+    node(59, 0, 0, 114), node(59, 0, 0, 115), node(101, 3621, 1, 0), node(115, 3622, 1, 0),
+    node(82, 3623, 1, 0), node(68, 3624, 1, 0), node(99, 3625, 1, 0), node(121, 3626, 1, 0),
+    node(59, 0, 0, 116), node(59, 0, 0, 117), node(114, 3627, 1, 0), node(100, 3628, 1, 0),
+    node(59, 0, 0, 118), node(59, 0, 0, 119), node(114, 3629, 1, 0), node(116, 3630, 1, 0),
+    node(99, 3631, 1, 0), node(110, 3632, 1, 0), node(100, 3633, 1, 0), node(102, 3634, 1, 0),
+    node(112, 3635, 1, 0), node(114, 3636, 1, 0), node(114, 3637, 1, 0), node(117, 3638, 1, 0),
+    node(114, 3639, 2, 0), node(99, 3641, 1, 0), node(101, 3642, 1, 0), node(114, 3643, 1, 0),
+    node(59, 0, 0, 120), node(108, 3644, 1, 0), node(59, 0, 0, 121), node(97, 3645, 1, 0),
+    node(99, 3646, 1, 0), node(101, 3647, 1, 0), node(111, 3648, 1, 0), node(111, 3649, 1, 0),
+    node(102, 3650, 1, 0), node(114, 3651, 1, 0), node(111, 3652, 1, 0), node(113, 3653, 1, 0),
+    node(101, 3654, 1, 0), node(114, 3655, 1, 0), node(111, 3656, 1, 0), node(101, 3657, 1, 0),
+    node(105, 3658, 2, 0), node(110, 3660, 1, 0), node(114, 3661, 1, 0), node(108, 3662, 1, 0),
+    node(108, 3663, 1, 122), node(115, 3664, 1, 0), node(114, 3665, 1, 0), node(59, 0, 0, 123),
+    node(115, 3666, 1, 0), node(59, 0, 0, 124), node(98, 3667, 1, 0), node(116, 3668, 2, 0),
+    node(121, 3670, 1, 0), node(59, 0, 0, 125), node(102, 3671, 1, 0), node(114, 3672, 1, 0),
+    node(97, 3673, 1, 0), node(114, 3674, 1, 0), node(103, 3675, 1, 0), node(59, 0, 0, 126),
+    node(102, 3676, 1, 0), node(114, 3677, 1, 0), node(59, 0, 0, 127), node(102, 3678, 1, 0),
+    node(114, 3679, 1, 0), node(121, 3680, 1, 0), node(121, 3681, 1, 0), node(121, 3682, 1, 0),
+    node(117, 3683, 1, 0), node(114, 3684, 1, 0), node(59, 0, 0, 128), node(59, 0, 0, 129),
+    node(102, 3685, 1, 0), node(114, 3686, 1, 0), node(108, 3687, 1, 0), node(121, 3688, 1, 0),
+    node(117, 3689, 1, 0), node(114, 3690, 1, 0), node(59, 0, 0, 130), node(116, 3691, 1, 0),
+    node(111, 3692, 1, 0), node(97, 3693, 1, 0), node(59, 0, 0, 131), node(102, 3694, 1, 0),
+    node(114, 3695, 1, 0), node(117, 3696, 1, 0), node(101, 3697, 1, 0), node(59, 0, 0, 132),
+    node(59, 0, 0, 133), node(114, 3698, 1, 0), node(116, 3699, 1, 0), node(59, 0, 0, 134),
+    node(105, 3700, 1, 0), node(59, 0, 0, 135), node(97, 3701, 1, 0), node(102, 3702, 1, 0),
+    node(112, 3703, 1, 0), node(104, 3704, 1, 0), node(99, 3705, 1, 0), node(108, 3706, 1, 0),
+    node(59, 0, 0, 3), node(59, 0, 0, 136), node(97, 3707, 1, 0), node(100, 3708, 1, 0),
+    node(115, 3709, 1, 0), node(118, 3710, 1, 0), node(59, 0, 0, 137), node(101, 3711, 1, 0),
+    node(108, 3712, 1, 0), node(109, 3713, 1, 0), node(114, 3714, 1, 0), node(115, 3715, 2, 0),
+    node(122, 3717, 1, 0), node(111, 3718, 1, 0), node(102, 3719, 1, 0), node(59, 0, 0, 138),
+    node(99, 3720, 1, 0), node(59, 0, 0, 139), node(100, 3721, 1, 0), node(115, 3722, 1, 0),
+    node(114, 3723, 1, 0), node(110, 3724, 1, 0), node(114, 3725, 1, 0), node(59, 0, 0, 140),
+    node(109, 3726, 1, 0), node(108, 3727, 1, 0), node(108, 3728, 1, 141), node(111, 3729, 1, 0),
+    node(110, 3730, 1, 0), node(116, 3731, 1, 0), node(107, 3732, 4, 0), node(118, 3736, 1, 0),
+    node(119, 3737, 1, 0), node(107, 3738, 2, 0), node(110, 3740, 1, 0), node(59, 0, 0, 142),
+    node(117, 3741, 1, 0), node(97, 3742, 1, 0), node(112, 3743, 1, 0), node(115, 3744, 1, 0),
+    node(110, 3745, 1, 0), node(97, 3746, 1, 0), node(104, 3747, 1, 0), node(119, 3748, 1, 0),
+    node(59, 0, 0, 143), node(99, 3749, 3, 0), node(111, 3752, 3, 0), node(115, 3755, 2, 0),
+    node(116, 3757, 1, 0), node(117, 3758, 1, 0), node(118, 3759, 1, 0), node(119, 3760, 1, 0),
+    node(114, 3761, 1, 0), node(99, 3762, 1, 0), node(110, 3763, 1, 0), node(49, 3764, 2, 0),
+    node(51, 3766, 1, 0), node(99, 3767, 1, 0), node(59, 0, 0, 144), node(113, 3768, 1, 0),
+    node(116, 3769, 1, 0), node(102, 3770, 1, 0), node(59, 0, 0, 145), node(116, 3771, 1, 0),
+    node(116, 3772, 1, 0), node(68, 3773, 4, 0), node(72, 3777, 5, 0), node(85, 3782, 4, 0),
+    node(86, 3786, 7, 0), node(98, 3793, 1, 0), node(100, 3794, 4, 0), node(104, 3798, 5, 0),
+    node(109, 3803, 1, 0), node(112, 3804, 1, 0), node(116, 3805, 1, 0), node(117, 3806, 4, 0),
+    node(118, 3810, 7, 0), node(105, 3817, 1, 0), node(118, 3818, 1, 0), node(98, 3819, 1, 0),
+    node(114, 3820, 1, 0), node(109, 3821, 1, 0), node(109, 3822, 2, 0), node(108, 3824, 3, 0),
+    node(108, 3827, 2, 0), node(112, 3829, 3, 0), node(117, 3832, 1, 0), node(59, 0, 0, 146),
+    node(97, 3833, 1, 0), node(98, 3834, 1, 0), node(99, 3835, 2, 0), node(100, 3837, 1, 0),
+    node(115, 3838, 1, 0), node(101, 3839, 1, 0), node(111, 3840, 1, 0), node(112, 3841, 1, 0),
+    node(114, 3842, 1, 0), node(100, 3843, 1, 0), node(114, 3844, 1, 0), node(112, 3845, 1, 0),
+    node(116, 3846, 1, 0), node(105, 3847, 1, 0), node(112, 3848, 1, 0), node(116, 3849, 2, 147),
+    node(59, 0, 0, 148), node(121, 3851, 1, 0), node(99, 3852, 1, 0), node(59, 0, 0, 149),
+    node(59, 0, 0, 150), node(69, 3853, 1, 0), node(99, 3854, 3, 0), node(101, 3857, 1, 0),
+    node(102, 3858, 1, 0), node(109, 3859, 1, 0), node(115, 3860, 1, 0), node(98, 3861, 1, 0),
+    node(111, 3862, 1, 0), node(109, 3863, 1, 0), node(112, 3864, 3, 0), node(103, 3867, 2, 0),
+    node(105, 3869, 1, 0), node(102, 3870, 1, 0), node(114, 3871, 1, 0), node(121, 3872, 2, 64),
+    node(114, 3874, 1, 0), node(115, 3875, 1, 0), node(114, 3876, 1, 0), node(98, 3877, 2, 0),
+    node(112, 3879, 2, 0), node(111, 3881, 1, 0), node(97, 3882, 1, 0), node(112, 3883, 1, 0),
+    node(115, 3884, 1, 0), node(97, 3885, 1, 0), node(59, 0, 0, 151), node(98, 3886, 1, 0),
+    node(99, 3887, 2, 0), node(100, 3889, 1, 0), node(111, 3890, 1, 0), node(115, 3891, 1, 0),
+    node(97, 3892, 1, 0), node(108, 3893, 1, 0), node(114, 3894, 1, 0), node(118, 3895, 1, 0),
+    node(101, 3896, 1, 0), node(101, 3897, 1, 0), node(111, 3898, 1, 0), node(110, 3899, 1, 0),
+    node(99, 3900, 1, 0), node(114, 3901, 1, 0), node(114, 3902, 1, 0), node(103, 3903, 1, 0),
+    node(101, 3904, 1, 0), node(114, 3905, 1, 0), node(104, 3906, 2, 0), node(97, 3908, 1, 0),
+    node(97, 3909, 1, 0), node(114, 3910, 1, 0), node(59, 0, 0, 152), node(103, 3911, 1, 0),
+    node(114, 3912, 1, 0), node(116, 3913, 1, 0), node(59, 0, 0, 24), node(116, 3914, 1, 0),
+    node(112, 3915, 1, 0), node(115, 3916, 1, 0), node(59, 0, 0, 153), node(114, 3917, 2, 0),
+    node(109, 3919, 3, 0), node(59, 0, 0, 53), node(97, 3922, 1, 0), node(105, 3923, 1, 0),
+    node(59, 0, 0, 154), node(105, 3924, 1, 0), node(111, 3925, 1, 0), node(121, 3926, 1, 0),
+    node(111, 3927, 1, 0), node(114, 3928, 1, 0), node(108, 3929, 1, 0), node(102, 3930, 1, 0),
+    node(59, 0, 0, 155), node(101, 3931, 1, 0), node(109, 3932, 1, 0), node(112, 3933, 1, 0),
+    node(115, 3934, 1, 0), node(98, 3935, 1, 0), node(110, 3936, 3, 0), node(107, 3939, 1, 0),
+    node(111, 3940, 1, 0), node(114, 3941, 1, 0), node(114, 3942, 1, 0), node(121, 3943, 1, 0),
+    node(108, 3944, 1, 0), node(114, 3945, 1, 0), node(111, 3946, 1, 0), node(105, 3947, 2, 0),
+    node(114, 3949, 1, 0), node(97, 3950, 1, 0), node(110, 3951, 1, 0), node(121, 3952, 1, 0),
+    node(103, 3953, 1, 0), node(111, 3954, 1, 0), node(116, 3955, 1, 0), node(117, 3956, 1, 0),
+    node(116, 3957, 1, 0), node(114, 3958, 1, 0), node(114, 3959, 2, 0), node(108, 3961, 1, 0),
+    node(59, 0, 0, 156), node(116, 3962, 1, 0), node(111, 3963, 1, 0), node(59, 0, 0, 157),
+    node(97, 3964, 1, 0), node(59, 0, 0, 158), node(100, 3965, 1, 0), node(110, 3966, 1, 0),
+    node(59, 0, 0, 159), node(59, 0, 0, 160), node(100, 3967, 1, 0), node(99, 3968, 1, 0),
+    node(116, 3969, 1, 0), node(112, 3970, 2, 0), node(59, 0, 0, 161), node(112, 3972, 1, 0),
+    node(111, 3973, 1, 0), node(102, 3974, 1, 0), node(114, 3975, 2, 0), node(117, 3977, 1, 0),
+    node(105, 3978, 3, 0), node(105, 3981, 1, 0), node(111, 3982, 1, 0), node(105, 3983, 1, 0),
+    node(108, 3984, 1, 0), node(97, 3985, 1, 0), node(101, 3986, 1, 0), node(105, 3987, 1, 0),
+    node(112, 3988, 1, 0), node(111, 3989, 1, 0), node(114, 3990, 1, 0), node(114, 3991, 1, 0),
+    node(111, 3992, 1, 0), node(109, 3993, 1, 0), node(59, 0, 0, 162), node(59, 0, 0, 28),
+    node(108, 3994, 1, 163), node(111, 3995, 1, 0), node(108, 3996, 1, 0), node(115, 3997, 1, 0),
+    node(101, 3998, 1, 0), node(111, 3999, 1, 0), node(108, 4000, 1, 0), node(59, 0, 0, 164),
+    node(97, 4001, 1, 0), node(108, 4002, 1, 0), node(105, 4003, 1, 0), node(108, 4004, 1, 0),
+    node(59, 0, 0, 165), node(105, 4005, 1, 0), node(105, 4006, 1, 0), node(116, 4007, 1, 0),
+    node(105, 4008, 1, 0), node(110, 4009, 1, 0), node(102, 4010, 1, 0), node(102, 4011, 1, 0),
+    node(97, 4012, 1, 0), node(107, 4013, 2, 0), node(114, 4015, 1, 0), node(99, 4016, 6, 0),
+    node(115, 4022, 1, 0), node(119, 4023, 1, 0), node(114, 4024, 1, 0), node(59, 0, 0, 166),
+    node(117, 4025, 1, 0), node(109, 4026, 1, 0), node(59, 0, 0, 167), node(101, 4027, 1, 0),
+    node(114, 4028, 1, 0), node(59, 0, 0, 168), node(116, 4029, 1, 0), node(59, 0, 0, 169),
+    node(59, 0, 0, 30), node(113, 4030, 1, 0), node(115, 4031, 1, 0), node(59, 0, 0, 170),
+    node(99, 4032, 1, 0), node(100, 4033, 1, 0), node(108, 4034, 2, 0), node(59, 0, 0, 171),
+    node(59, 0, 0, 6), node(101, 4036, 1, 0), node(121, 4037, 1, 0), node(59, 0, 0, 172),
+    node(59, 0, 0, 173), node(59, 0, 0, 174), node(59, 0, 0, 175), node(112, 4038, 2, 0),
+    node(59, 0, 0, 176), node(113, 4040, 2, 0), node(105, 4042, 1, 0), node(102, 4043, 1, 0),
+    node(118, 4044, 1, 0), node(114, 4045, 1, 0), node(109, 4046, 3, 0), node(99, 4049, 1, 0),
+    node(105, 4050, 1, 0), node(111, 4051, 1, 0), node(80, 4052, 1, 0), node(117, 4053, 1, 0),
+    node(97, 4054, 2, 0), node(100, 4056, 1, 0), node(101, 4057, 1, 0), node(108, 4058, 1, 0),
+    node(115, 4059, 1, 0), node(114, 4060, 1, 0), node(69, 4061, 1, 0), node(114, 4062, 1, 0),
+    node(114, 4063, 1, 0), node(102, 4064, 1, 0), node(105, 4065, 1, 0), node(100, 4066, 1, 0),
+    node(114, 4067, 3, 0), node(114, 4070, 1, 0), node(114, 4071, 1, 0), node(114, 4072, 1, 0),
+    node(108, 4073, 1, 0), node(99, 4074, 1, 0), node(59, 0, 0, 177), node(101, 4075, 1, 0),
+    node(119, 4076, 1, 0), node(114, 4077, 1, 0), node(116, 4078, 1, 0), node(107, 4079, 2, 0),
+    node(102, 4081, 1, 0), node(98, 4082, 1, 0), node(114, 4083, 1, 0), node(97, 4084, 1, 0),
+    node(114, 4085, 1, 0), node(117, 4086, 1, 0), node(104, 4087, 1, 0), node(117, 4088, 1, 0),
+    node(114, 4089, 1, 0), node(59, 0, 0, 178), node(121, 4090, 1, 0), node(99, 4091, 1, 0),
+    node(59, 0, 0, 179), node(59, 0, 0, 180), node(97, 4092, 1, 0), node(105, 4093, 1, 0),
+    node(110, 4094, 1, 0), node(102, 4095, 1, 0), node(116, 4096, 1, 0), node(105, 4097, 1, 0),
+    node(99, 4098, 1, 0), node(103, 4099, 3, 0), node(116, 4102, 1, 0), node(102, 4103, 1, 0),
+    node(101, 4104, 1, 0), node(97, 4105, 1, 0), node(105, 4106, 1, 0), node(100, 4107, 1, 0),
+    node(59, 0, 0, 181), node(99, 4108, 1, 0), node(101, 4109, 2, 0), node(108, 4111, 1, 0),
+    node(112, 4112, 1, 0), node(121, 4113, 1, 0), node(111, 4114, 1, 0), node(102, 4115, 1, 0),
+    node(97, 4116, 1, 0), node(111, 4117, 1, 0), node(101, 4118, 1, 0), node(114, 4119, 1, 0),
+    node(110, 4120, 5, 0), node(108, 4125, 1, 0), node(99, 4126, 1, 0), node(108, 4127, 1, 182),
+    node(114, 4128, 1, 0), node(59, 0, 0, 183), node(59, 0, 0, 184), node(116, 4129, 1, 0),
+    node(102, 4130, 1, 0), node(114, 4131, 1, 0), node(114, 4132, 1, 0), node(99, 4133, 1, 0),
+    node(112, 4134, 1, 0), node(100, 4135, 1, 0), node(59, 0, 0, 185), node(59, 0, 0, 186),
+    node(101, 4136, 1, 0), node(121, 4137, 1, 0), node(121, 4138, 1, 0), node(102, 4139, 1, 0),
+    node(114, 4140, 1, 0), node(114, 4141, 1, 0), node(114, 4142, 1, 0), node(97, 4143, 1, 0),
+    node(114, 4144, 1, 0), node(59, 0, 0, 187), node(114, 4145, 1, 0), node(117, 4146, 1, 0),
+    node(109, 4147, 1, 0), node(114, 4148, 1, 0), node(98, 4149, 1, 0), node(103, 4150, 3, 0),
+    node(59, 0, 0, 188), node(117, 4153, 1, 0), node(114, 4154, 8, 0), node(59, 0, 0, 189),
+    node(97, 4162, 1, 0), node(101, 4163, 2, 0), node(114, 4165, 1, 0), node(114, 4166, 1, 0),
+    node(97, 4167, 1, 0), node(107, 4168, 2, 0), node(114, 4170, 1, 0), node(100, 4171, 1, 0),
+    node(105, 4172, 1, 0), node(98, 4173, 1, 0), node(59, 0, 0, 190), node(97, 4174, 1, 0),
+    node(117, 4175, 1, 0), node(100, 4176, 1, 0), node(117, 4177, 1, 0), node(104, 4178, 1, 0),
+    node(116, 4179, 5, 0), node(59, 0, 0, 191), node(59, 0, 0, 37), node(113, 4184, 1, 0),
+    node(115, 4185, 1, 0), node(59, 0, 0, 192), node(99, 4186, 1, 0), node(100, 4187, 1, 0),
+    node(103, 4188, 2, 0), node(115, 4190, 5, 0), node(115, 4195, 1, 0), node(111, 4196, 1, 0),
+    node(59, 0, 0, 193), node(59, 0, 0, 194), node(114, 4197, 2, 0), node(108, 4199, 1, 0),
+    node(121, 4200, 1, 0), node(114, 4201, 1, 0), node(111, 4202, 1, 0), node(97, 4203, 1, 0),
+    node(114, 4204, 1, 0), node(100, 4205, 1, 0), node(117, 4206, 1, 0), node(59, 0, 0, 195),
+    node(112, 4207, 2, 0), node(59, 0, 0, 196), node(113, 4209, 2, 0), node(105, 4211, 1, 0),
+    node(110, 4212, 1, 0), node(114, 4213, 1, 0), node(114, 4214, 1, 0), node(103, 4215, 3, 0),
+    node(112, 4218, 1, 0), node(97, 4219, 1, 0), node(102, 4220, 1, 0), node(108, 4221, 1, 0),
+    node(105, 4222, 1, 0), node(97, 4223, 1, 0), node(98, 4224, 1, 0), node(59, 0, 0, 197),
+    node(101, 4225, 1, 0), node(102, 4226, 1, 0), node(114, 4227, 2, 0), node(114, 4229, 1, 0),
+    node(111, 4230, 1, 0), node(97, 4231, 1, 0), node(59, 0, 0, 198), node(114, 4232, 1, 0),
+    node(113, 4233, 1, 0), node(114, 4234, 1, 0), node(59, 0, 0, 92), node(109, 4235, 3, 0),
+    node(98, 4238, 1, 0), node(117, 4239, 1, 0), node(114, 4240, 1, 0), node(99, 4241, 1, 0),
+    node(105, 4242, 1, 0), node(111, 4243, 1, 0), node(114, 4244, 1, 0), node(109, 4245, 1, 0),
+    node(97, 4246, 1, 0), node(117, 4247, 1, 0), node(80, 4248, 1, 0), node(105, 4249, 3, 0),
+    node(100, 4252, 1, 0), node(117, 4253, 1, 0), node(114, 4254, 1, 0), node(69, 4255, 1, 0),
+    node(111, 4256, 1, 0), node(114, 4257, 1, 199), node(101, 4258, 1, 0), node(116, 4259, 2, 0),
+    node(59, 0, 0, 200), node(115, 4261, 1, 0), node(107, 4262, 1, 0), node(109, 4263, 1, 0),
+    node(59, 0, 0, 201), node(115, 4264, 1, 0), node(115, 4265, 1, 0), node(59, 0, 0, 202),
+    node(59, 0, 0, 203), node(114, 4266, 1, 0), node(59, 0, 0, 204), node(97, 4267, 1, 0),
+    node(99, 4268, 1, 0), node(100, 4269, 1, 0), node(117, 4270, 1, 0), node(112, 4271, 1, 0),
+    node(114, 4272, 1, 0), node(108, 4273, 1, 0), node(101, 4274, 1, 0), node(102, 4275, 1, 0),
+    node(114, 4276, 1, 0), node(112, 4277, 1, 0), node(116, 4278, 1, 0), node(97, 4279, 1, 0),
+    node(59, 0, 0, 205), node(59, 0, 0, 206), node(118, 4280, 1, 0), node(102, 4281, 1, 0),
+    node(59, 0, 0, 207), node(59, 0, 0, 208), node(118, 4282, 1, 0), node(103, 4283, 1, 0),
+    node(97, 4284, 1, 0), node(97, 4285, 1, 0), node(108, 4286, 1, 0), node(117, 4287, 1, 0),
+    node(103, 4288, 1, 0), node(59, 0, 0, 209), node(69, 4289, 1, 0), node(105, 4290, 1, 0),
+    node(111, 4291, 1, 0), node(112, 4292, 1, 0), node(117, 4293, 1, 0), node(112, 4294, 1, 210),
+    node(109, 4295, 1, 0), node(112, 4296, 1, 0), node(114, 4297, 1, 0), node(100, 4298, 1, 0),
+    node(110, 4299, 1, 0), node(112, 4300, 1, 0), node(59, 0, 0, 211), node(115, 4301, 1, 0),
+    node(114, 4302, 1, 0), node(114, 4303, 2, 0), node(111, 4305, 1, 0), node(117, 4306, 1, 0),
+    node(101, 4307, 1, 0), node(105, 4308, 1, 0), node(105, 4309, 1, 0), node(59, 0, 0, 212),
+    node(59, 0, 0, 213), node(59, 0, 0, 214), node(113, 4310, 3, 0), node(115, 4313, 1, 0),
+    node(105, 4314, 1, 0), node(59, 0, 0, 215), node(114, 4315, 1, 0), node(114, 4316, 1, 0),
+    node(114, 4317, 1, 0), node(97, 4318, 1, 0), node(59, 0, 0, 216), node(100, 4319, 1, 0),
+    node(59, 0, 0, 42), node(121, 4320, 1, 0), node(114, 4321, 1, 0), node(59, 0, 0, 217),
+    node(114, 4322, 1, 0), node(114, 4323, 1, 0), node(59, 0, 0, 218), node(102, 4324, 1, 0),
+    node(113, 4325, 3, 0), node(115, 4328, 2, 0), node(105, 4330, 1, 0), node(59, 0, 0, 219),
+    node(114, 4331, 1, 0), node(100, 4332, 1, 0), node(102, 4333, 1, 0), node(59, 0, 0, 43),
+    node(105, 4334, 1, 0), node(110, 4335, 1, 0), node(114, 4336, 4, 0), node(108, 4340, 1, 0),
+    node(59, 0, 0, 220), node(99, 4341, 1, 0), node(101, 4342, 2, 0), node(114, 4344, 1, 0),
+    node(114, 4345, 1, 0), node(103, 4346, 1, 0), node(114, 4347, 1, 0), node(59, 0, 0, 221),
+    node(99, 4348, 1, 0), node(101, 4349, 1, 0), node(114, 4350, 1, 0), node(111, 4351, 1, 0),
+    node(109, 4352, 2, 0), node(105, 4354, 1, 0), node(97, 4355, 1, 0), node(115, 4356, 1, 0),
+    node(98, 4357, 4, 0), node(99, 4361, 1, 0), node(112, 4362, 4, 0), node(108, 4366, 1, 0),
+    node(108, 4367, 1, 0), node(103, 4368, 1, 0), node(105, 4369, 1, 0), node(59, 0, 0, 222),
+    node(101, 4370, 1, 0), node(115, 4371, 1, 0), node(97, 4372, 1, 0), node(97, 4373, 1, 0),
+    node(112, 4374, 1, 0), node(97, 4375, 1, 0), node(101, 4376, 1, 0), node(116, 4377, 1, 0),
+    node(110, 4378, 1, 0), node(65, 4379, 1, 0), node(101, 4380, 1, 0), node(116, 4381, 2, 0),
+    node(65, 4383, 1, 0), node(116, 4384, 1, 0), node(105, 4385, 1, 0), node(114, 4386, 1, 0),
+    node(114, 4387, 2, 0), node(101, 4389, 1, 0), node(117, 4390, 1, 0), node(116, 4391, 1, 0),
+    node(114, 4392, 2, 0), node(59, 0, 0, 223), node(115, 4394, 1, 0), node(108, 4395, 1, 0),
+    node(118, 4396, 1, 0), node(116, 4397, 1, 0), node(111, 4398, 1, 0), node(105, 4399, 1, 0),
+    node(105, 4400, 1, 0), node(59, 0, 0, 224), node(110, 4401, 1, 0), node(97, 4402, 1, 0),
+    node(59, 0, 0, 225), node(97, 4403, 1, 0), node(59, 0, 0, 226), node(116, 4404, 1, 0),
+    node(114, 4405, 1, 0), node(105, 4406, 1, 0), node(114, 4407, 1, 0), node(110, 4408, 1, 0),
+    node(59, 0, 0, 227), node(99, 4409, 1, 0), node(103, 4410, 1, 0), node(99, 4411, 1, 0),
+    node(100, 4412, 1, 0), node(110, 4413, 1, 0), node(102, 4414, 1, 0), node(114, 4415, 1, 0),
+    node(114, 4416, 1, 0), node(117, 4417, 1, 0), node(114, 4418, 1, 0), node(59, 0, 0, 228),
+    node(101, 4419, 1, 0), node(102, 4420, 1, 229), node(109, 4421, 1, 230), node(103, 4422, 1, 0),
+    node(114, 4423, 1, 0), node(108, 4424, 1, 0), node(59, 0, 0, 231), node(114, 4425, 1, 0),
+    node(97, 4426, 1, 0), node(108, 4427, 1, 0), node(108, 4428, 1, 0), node(109, 4429, 1, 0),
+    node(108, 4430, 1, 232), node(97, 4431, 1, 0), node(59, 0, 0, 233), node(97, 4432, 2, 234),
+    node(115, 4434, 2, 0), node(116, 4436, 1, 0), node(59, 0, 0, 235), node(99, 4437, 1, 0),
+    node(105, 4438, 1, 0), node(109, 4439, 1, 0), node(112, 4440, 1, 0), node(116, 4441, 1, 0),
+    node(59, 0, 0, 236), node(59, 0, 0, 237), node(118, 4442, 1, 0), node(109, 4443, 1, 0),
+    node(110, 4444, 1, 0), node(99, 4445, 1, 0), node(59, 0, 0, 238), node(110, 4446, 2, 0),
+    node(115, 4448, 9, 0), node(110, 4457, 1, 0), node(102, 4458, 1, 0), node(110, 4459, 1, 0),
+    node(59, 0, 0, 239), node(112, 4460, 1, 0), node(117, 4461, 1, 0), node(59, 0, 0, 240),
+    node(99, 4462, 6, 0), node(109, 4468, 1, 0), node(69, 4469, 1, 0), node(97, 4470, 1, 0),
+    node(115, 4471, 1, 0), node(100, 4472, 1, 0), node(102, 4473, 3, 0), node(112, 4476, 2, 0),
+    node(105, 4478, 1, 0), node(114, 4479, 1, 0), node(114, 4480, 1, 0), node(59, 0, 0, 241),
+    node(99, 4481, 1, 0), node(59, 0, 0, 242), node(116, 4482, 1, 0), node(102, 4483, 1, 0),
+    node(105, 4484, 1, 0), node(114, 4485, 1, 0), node(116, 4486, 2, 0), node(115, 4488, 1, 0),
+    node(116, 4489, 1, 106), node(114, 4490, 1, 0), node(114, 4491, 1, 0), node(97, 4492, 1, 0),
+    node(114, 4493, 1, 0), node(114, 4494, 1, 0), node(101, 4495, 1, 0), node(117, 4496, 1, 0),
+    node(105, 4497, 1, 0), node(109, 4498, 1, 0), node(103, 4499, 4, 0), node(117, 4503, 1, 0),
+    node(114, 4504, 11, 0), node(97, 4515, 1, 0), node(105, 4516, 1, 0), node(114, 4517, 1, 0),
+    node(114, 4518, 1, 0), node(97, 4519, 1, 0), node(107, 4520, 2, 0), node(114, 4522, 1, 0),
+    node(100, 4523, 1, 0), node(105, 4524, 1, 0), node(98, 4525, 1, 0), node(59, 0, 0, 243),
+    node(97, 4526, 1, 0), node(100, 4527, 1, 0), node(117, 4528, 1, 0), node(104, 4529, 1, 0),
+    node(108, 4530, 4, 0), node(116, 4534, 1, 0), node(59, 0, 0, 16), node(115, 4535, 1, 0),
+    node(111, 4536, 1, 0), node(59, 0, 0, 244), node(114, 4537, 2, 0), node(59, 0, 0, 245),
+    node(118, 4539, 1, 0), node(104, 4540, 1, 0), node(103, 4541, 1, 0), node(105, 4542, 1, 0),
+    node(114, 4543, 1, 0), node(97, 4544, 1, 0), node(59, 0, 0, 246), node(117, 4545, 1, 0),
+    node(105, 4546, 1, 0), node(110, 4547, 1, 0), node(114, 4548, 1, 0), node(114, 4549, 1, 0),
+    node(97, 4550, 1, 0), node(102, 4551, 1, 0), node(108, 4552, 1, 0), node(105, 4553, 1, 0),
+    node(114, 4554, 2, 0), node(111, 4556, 1, 0), node(114, 4557, 1, 0), node(113, 4558, 1, 0),
+    node(114, 4559, 1, 0), node(59, 0, 0, 110), node(98, 4560, 1, 0), node(117, 4561, 1, 0),
+    node(114, 4562, 1, 0), node(109, 4563, 1, 0), node(105, 4564, 4, 0), node(117, 4568, 1, 0),
+    node(117, 4569, 1, 0), node(117, 4570, 1, 0), node(59, 0, 0, 247), node(112, 4571, 1, 0),
+    node(114, 4572, 1, 0), node(117, 4573, 1, 0), node(59, 0, 0, 248), node(100, 4574, 1, 0),
+    node(114, 4575, 1, 0), node(69, 4576, 1, 0), node(97, 4577, 1, 0), node(115, 4578, 1, 0),
+    node(111, 4579, 1, 0), node(105, 4580, 1, 0), node(59, 0, 0, 249), node(116, 4581, 3, 0),
+    node(114, 4584, 1, 0), node(114, 4585, 2, 0), node(116, 4587, 1, 250), node(105, 4588, 1, 0),
+    node(119, 4589, 1, 0), node(109, 4590, 2, 0), node(116, 4592, 1, 0), node(59, 0, 0, 251),
+    node(111, 4593, 1, 0), node(114, 4594, 1, 0), node(104, 4595, 1, 0), node(121, 4596, 1, 0),
+    node(114, 4597, 1, 0), node(59, 0, 0, 52), node(109, 4598, 1, 0), node(59, 0, 0, 252),
+    node(100, 4599, 1, 0), node(101, 4600, 2, 0), node(103, 4602, 2, 0), node(108, 4604, 2, 0),
+    node(110, 4606, 1, 0), node(112, 4607, 1, 0), node(114, 4608, 1, 0), node(114, 4609, 1, 0),
+    node(108, 4610, 1, 0), node(115, 4611, 1, 0), node(112, 4612, 1, 0), node(100, 4613, 1, 0),
+    node(108, 4614, 1, 0), node(59, 0, 0, 253), node(101, 4615, 2, 0), node(116, 4617, 1, 0),
+    node(59, 0, 0, 254), node(98, 4618, 2, 0), node(102, 4620, 1, 0), node(100, 4621, 1, 0),
+    node(114, 4622, 1, 0), node(97, 4623, 1, 0), node(117, 4624, 1, 0), node(117, 4625, 2, 0),
+    node(59, 0, 0, 255), node(97, 4627, 1, 0), node(102, 4628, 1, 0), node(114, 4629, 1, 0),
+    node(114, 4630, 1, 0), node(116, 4631, 1, 0), node(105, 4632, 1, 0), node(97, 4633, 1, 0),
+    node(114, 4634, 2, 0), node(97, 4636, 1, 0), node(110, 4637, 1, 0), node(59, 0, 0, 256),
+    node(69, 4638, 1, 0), node(100, 4639, 1, 0), node(101, 4640, 2, 0), node(109, 4642, 1, 0),
+    node(110, 4643, 2, 0), node(112, 4645, 1, 0), node(114, 4646, 1, 0), node(115, 4647, 3, 0),
+    node(99, 4650, 6, 0), node(59, 0, 0, 114), node(103, 4656, 1, 0), node(49, 4657, 1, 257),
+    node(50, 4658, 1, 258), node(51, 4659, 1, 259), node(59, 0, 0, 260), node(69, 4660, 1, 0),
+    node(100, 4661, 2, 0), node(101, 4663, 2, 0), node(104, 4665, 1, 0), node(108, 4666, 1, 0),
+    node(109, 4667, 1, 0), node(110, 4668, 2, 0), node(112, 4670, 1, 0), node(115, 4671, 3, 0),
+    node(114, 4674, 1, 0), node(114, 4675, 2, 0), node(119, 4677, 1, 0), node(105, 4678, 1, 0),
+    node(103, 4679, 1, 0), node(59, 0, 0, 261), node(107, 4680, 1, 0), node(114, 4681, 1, 0),
+    node(100, 4682, 1, 0), node(59, 0, 0, 262), node(116, 4683, 1, 0), node(114, 4684, 1, 0),
+    node(59, 0, 0, 263), node(114, 4685, 1, 0), node(116, 4686, 1, 0), node(99, 4687, 1, 0),
+    node(110, 4688, 1, 0), node(97, 4689, 1, 0), node(115, 4690, 1, 0), node(114, 4691, 1, 0),
+    node(100, 4692, 1, 0), node(101, 4693, 1, 0), node(116, 4694, 1, 0), node(97, 4695, 1, 0),
+    node(59, 0, 0, 264), node(98, 4696, 1, 0), node(99, 4697, 1, 0), node(102, 4698, 2, 0),
+    node(97, 4700, 1, 0), node(105, 4701, 1, 0), node(100, 4702, 1, 0), node(97, 4703, 1, 0),
+    node(100, 4704, 1, 0), node(101, 4705, 1, 0), node(109, 4706, 1, 0), node(112, 4707, 1, 0),
+    node(115, 4708, 1, 0), node(116, 4709, 1, 0), node(101, 4710, 1, 0), node(114, 4711, 1, 0),
+    node(121, 4712, 1, 0), node(99, 4713, 1, 0), node(114, 4714, 1, 0), node(120, 4715, 1, 0),
+    node(104, 4716, 1, 0), node(114, 4717, 1, 0), node(114, 4718, 1, 0), node(117, 4719, 1, 0),
+    node(114, 4720, 1, 0), node(99, 4721, 1, 0), node(101, 4722, 1, 0), node(114, 4723, 1, 0),
+    node(59, 0, 0, 265), node(114, 4724, 1, 0), node(108, 4725, 1, 0), node(97, 4726, 1, 0),
+    node(115, 4727, 1, 0), node(59, 0, 0, 266), node(97, 4728, 1, 0), node(114, 4729, 2, 0),
+    node(108, 4731, 1, 0), node(111, 4732, 1, 0), node(114, 4733, 1, 0), node(114, 4734, 1, 0),
+    node(99, 4735, 1, 0), node(59, 0, 0, 53), node(111, 4736, 1, 0), node(102, 4737, 1, 0),
+    node(114, 4738, 1, 0), node(111, 4739, 1, 0), node(97, 4740, 1, 0), node(117, 4741, 1, 0),
+    node(105, 4742, 3, 0), node(112, 4745, 1, 0), node(111, 4746, 1, 0), node(114, 4747, 1, 0),
+    node(110, 4748, 1, 0), node(114, 4749, 1, 0), node(114, 4750, 1, 0), node(111, 4751, 1, 0),
+    node(108, 4752, 1, 0), node(105, 4753, 2, 0), node(114, 4755, 1, 0), node(108, 4756, 1, 267),
+    node(110, 4757, 1, 0), node(114, 4758, 1, 0), node(114, 4759, 2, 0), node(115, 4761, 1, 0),
+    node(103, 4762, 1, 0), node(101, 4763, 1, 0), node(107, 4764, 1, 0), node(110, 4765, 1, 0),
+    node(112, 4766, 3, 0), node(114, 4769, 2, 0), node(115, 4771, 2, 0), node(116, 4773, 2, 0),
+    node(59, 0, 0, 268), node(115, 4775, 1, 0), node(59, 0, 0, 46), node(98, 4776, 1, 0),
+    node(101, 4777, 1, 0), node(108, 4778, 1, 0), node(98, 4779, 1, 0), node(116, 4780, 1, 0),
+    node(59, 0, 0, 269), node(114, 4781, 1, 0), node(117, 4782, 2, 0), node(102, 4784, 1, 0),
+    node(111, 4785, 1, 0), node(114, 4786, 1, 0), node(114, 4787, 1, 0), node(98, 4788, 1, 0),
+    node(112, 4789, 1, 0), node(103, 4790, 1, 0), node(114, 4791, 1, 0), node(98, 4792, 1, 0),
+    node(103, 4793, 1, 0), node(101, 4794, 1, 0), node(59, 0, 0, 270), node(102, 4795, 1, 0),
+    node(97, 4796, 1, 0), node(114, 4797, 1, 0), node(112, 4798, 1, 0), node(114, 4799, 1, 0),
+    node(112, 4800, 1, 0), node(114, 4801, 1, 0), node(59, 0, 0, 271), node(114, 4802, 1, 0),
+    node(114, 4803, 1, 0), node(114, 4804, 1, 0), node(114, 4805, 1, 0), node(112, 4806, 1, 0),
+    node(115, 4807, 1, 0), node(111, 4808, 1, 0), node(102, 4809, 1, 0), node(108, 4810, 1, 0),
+    node(105, 4811, 1, 0), node(114, 4812, 1, 0), node(114, 4813, 1, 0), node(114, 4814, 1, 0),
+    node(99, 4815, 1, 0), node(108, 4816, 1, 0), node(114, 4817, 1, 0), node(101, 4818, 1, 0),
+    node(100, 4819, 1, 0), node(117, 4820, 1, 0), node(121, 4821, 1, 0), node(114, 4822, 1, 0),
+    node(59, 0, 0, 272), node(59, 0, 0, 57), node(59, 0, 0, 273), node(121, 4823, 1, 0),
+    node(102, 4824, 1, 0), node(114, 4825, 1, 0), node(121, 4826, 1, 0), node(108, 4827, 1, 274),
+    node(117, 4828, 1, 0), node(114, 4829, 1, 0), node(59, 0, 0, 275), node(116, 4830, 1, 0),
+    node(116, 4831, 1, 0), node(97, 4832, 1, 0), node(59, 0, 0, 276), node(121, 4833, 1, 0),
+    node(114, 4834, 1, 0), node(102, 4835, 1, 0), node(114, 4836, 1, 0), node(59, 0, 0, 277),
+    node(106, 4837, 1, 0), node(103, 4838, 1, 278), node(116, 4839, 1, 0), node(118, 4840, 1, 0),
+    node(99, 4841, 1, 279), node(118, 4842, 1, 0), node(97, 4843, 1, 0), node(114, 4844, 1, 0),
+    node(110, 4845, 1, 0), node(59, 0, 0, 280), node(121, 4846, 1, 0), node(103, 4847, 1, 281),
+    node(59, 0, 0, 282), node(103, 4848, 1, 0), node(100, 4849, 1, 0), node(59, 0, 0, 61),
+    node(115, 4850, 1, 0), node(59, 0, 0, 283), node(101, 4851, 1, 0), node(117, 4852, 1, 0),
+    node(111, 4853, 1, 0), node(59, 0, 0, 284), node(59, 0, 0, 285), node(101, 4854, 1, 0),
+    node(59, 0, 0, 286), node(101, 4855, 1, 0), node(59, 0, 0, 287), node(59, 0, 0, 64),
+    node(116, 4856, 1, 0), node(116, 4857, 1, 0), node(101, 4858, 1, 0), node(111, 4859, 1, 0),
+    node(105, 4860, 1, 0), node(99, 4861, 1, 0), node(105, 4862, 1, 0), node(59, 0, 0, 288),
+    node(108, 4863, 1, 0), node(101, 4864, 1, 0), node(108, 4865, 1, 0), node(107, 4866, 1, 0),
+    node(101, 4867, 1, 0), node(110, 4868, 2, 0), node(114, 4870, 1, 0), node(110, 4871, 1, 0),
+    node(111, 4872, 1, 0), node(59, 0, 0, 289), node(111, 4873, 1, 0), node(116, 4874, 1, 0),
+    node(115, 4875, 1, 0), node(59, 0, 0, 290), node(97, 4876, 1, 0), node(114, 4877, 1, 0),
+    node(59, 0, 0, 291), node(59, 0, 0, 292), node(59, 0, 0, 293), node(101, 4878, 1, 0),
+    node(59, 0, 0, 294), node(118, 4879, 1, 0), node(111, 4880, 1, 0), node(97, 4881, 1, 0),
+    node(114, 4882, 1, 0), node(111, 4883, 1, 0), node(101, 4884, 1, 0), node(59, 0, 0, 295),
+    node(111, 4885, 1, 0), node(113, 4886, 1, 0), node(108, 4887, 1, 0), node(65, 4888, 1, 0),
+    node(66, 4889, 1, 0), node(76, 4890, 1, 0), node(82, 4891, 1, 0), node(84, 4892, 1, 0),
+    node(97, 4893, 1, 0), node(59, 0, 0, 296), node(111, 4894, 1, 0), node(116, 4895, 1, 0),
+    node(111, 4896, 1, 0), node(99, 4897, 1, 297), node(59, 0, 0, 298), node(118, 4898, 1, 0),
+    node(101, 4899, 1, 0), node(114, 4900, 1, 0), node(121, 4901, 2, 0), node(110, 4903, 1, 0),
+    node(59, 0, 0, 299), node(108, 4904, 1, 0), node(108, 4905, 2, 0), node(108, 4907, 1, 0),
+    node(59, 0, 0, 300), node(59, 0, 0, 301), node(59, 0, 0, 76), node(116, 4908, 1, 0),
+    node(110, 4909, 1, 0), node(101, 4910, 1, 0), node(59, 0, 0, 302), node(108, 4911, 1, 0),
+    node(105, 4912, 1, 0), node(59, 0, 0, 303), node(59, 0, 0, 304), node(97, 4913, 2, 0),
+    node(118, 4915, 1, 0), node(105, 4916, 1, 0), node(99, 4917, 1, 0), node(59, 0, 0, 305),
+    node(59, 0, 0, 306), node(116, 4918, 1, 0), node(59, 0, 0, 307), node(99, 4919, 1, 0),
+    node(107, 4920, 1, 0), node(99, 4921, 1, 0), node(101, 4922, 1, 0), node(59, 0, 0, 308),
+    node(122, 4923, 1, 0), node(59, 0, 0, 309), node(111, 4924, 1, 0), node(68, 4925, 1, 0),
+    node(69, 4926, 1, 0), node(59, 0, 0, 310), node(103, 4927, 1, 0), node(59, 0, 0, 311),
+    node(116, 4928, 1, 0), node(99, 4929, 1, 312), node(59, 0, 0, 313), node(118, 4930, 1, 0),
+    node(114, 4931, 1, 0), node(105, 4932, 1, 0), node(105, 4933, 1, 0), node(103, 4934, 1, 0),
+    node(114, 4935, 1, 0), node(115, 4936, 1, 0), node(110, 4937, 1, 0), node(59, 0, 0, 314),
+    node(59, 0, 0, 315), node(59, 0, 0, 316), node(100, 4938, 1, 0), node(121, 4939, 1, 0),
+    node(59, 0, 0, 85), node(99, 4940, 1, 0), node(59, 0, 0, 317), node(59, 0, 0, 318),
+    node(99, 4941, 1, 0), node(121, 4942, 1, 0), node(59, 0, 0, 319), node(59, 0, 0, 320),
+    node(97, 4943, 1, 0), node(105, 4944, 1, 0), node(59, 0, 0, 321), node(59, 0, 0, 322),
+    node(59, 0, 0, 323), node(116, 4945, 1, 0), node(100, 4946, 1, 0), node(59, 0, 0, 324),
+    node(97, 4947, 1, 0), node(59, 0, 0, 325), node(111, 4948, 1, 0), node(105, 4949, 1, 0),
+    node(65, 4950, 2, 0), node(67, 4952, 1, 0), node(68, 4953, 1, 0), node(70, 4954, 1, 0),
+    node(82, 4955, 1, 0), node(84, 4956, 2, 0), node(85, 4958, 1, 0), node(86, 4959, 1, 0),
+    node(97, 4960, 1, 0), node(114, 4961, 1, 0), node(69, 4962, 1, 0), node(70, 4963, 1, 0),
+    node(71, 4964, 1, 0), node(76, 4965, 1, 0), node(83, 4966, 1, 0), node(84, 4967, 1, 0),
+    node(116, 4968, 1, 0), node(111, 4969, 1, 0), node(76, 4970, 1, 0), node(82, 4971, 1, 0),
+    node(108, 4972, 1, 0), node(114, 4973, 1, 0), node(59, 0, 0, 326), node(114, 4974, 2, 0),
+    node(59, 0, 0, 327), node(111, 4976, 1, 0), node(117, 4977, 1, 0), node(105, 4978, 1, 0),
+    node(115, 4979, 1, 0), node(59, 0, 0, 328), node(59, 0, 0, 329), node(59, 0, 0, 330),
+    node(116, 4980, 1, 0), node(111, 4981, 1, 0), node(105, 4982, 1, 0), node(116, 4983, 1, 0),
+    node(101, 4984, 1, 0), node(105, 4985, 1, 0), node(101, 4986, 1, 0), node(114, 4987, 1, 0),
+    node(59, 0, 0, 331), node(111, 4988, 1, 0), node(117, 4989, 1, 0), node(111, 4990, 1, 0),
+    node(108, 4991, 1, 0), node(113, 4992, 1, 0), node(120, 4993, 1, 0), node(114, 4994, 1, 0),
+    node(117, 4995, 1, 0), node(101, 4996, 2, 0), node(101, 4998, 1, 0), node(114, 4999, 1, 0),
+    node(101, 5000, 1, 0), node(105, 5001, 1, 0), node(113, 5002, 1, 0), node(117, 5003, 3, 0),
+    node(105, 5006, 1, 0), node(101, 5007, 1, 0), node(59, 0, 0, 332), node(100, 5008, 1, 0),
+    node(103, 5009, 1, 0), node(116, 5010, 1, 0), node(99, 5011, 1, 333), node(97, 5012, 1, 0),
+    node(118, 5013, 1, 0), node(114, 5014, 1, 0), node(97, 5015, 1, 0), node(114, 5016, 1, 0),
+    node(59, 0, 0, 334), node(67, 5017, 1, 0), node(59, 0, 0, 335), node(115, 5018, 1, 0),
+    node(100, 5019, 1, 0), node(101, 5020, 1, 0), node(59, 0, 0, 101), node(66, 5021, 2, 0),
+    node(80, 5023, 1, 0), node(105, 5024, 1, 0), node(77, 5025, 1, 0), node(99, 5026, 1, 0),
+    node(59, 0, 0, 336), node(101, 5027, 1, 0), node(101, 5028, 1, 0), node(117, 5029, 1, 0),
+    node(111, 5030, 1, 0), node(59, 0, 0, 337), node(59, 0, 0, 106), node(59, 0, 0, 338),
+    node(59, 0, 0, 339), node(114, 5031, 1, 0), node(116, 5032, 1, 0), node(59, 0, 0, 340),
+    node(59, 0, 0, 341), node(116, 5033, 1, 0), node(111, 5034, 1, 0), node(105, 5035, 1, 0),
+    node(114, 5036, 1, 0), node(116, 5037, 8, 0), node(59, 0, 0, 342), node(100, 5045, 1, 0),
+    node(104, 5046, 1, 0), node(59, 0, 0, 343), node(68, 5047, 1, 0), node(99, 5048, 1, 0),
+    node(59, 0, 0, 344), node(99, 5049, 1, 0), node(116, 5050, 1, 0), node(111, 5051, 1, 0),
+    node(105, 5052, 1, 0), node(99, 5053, 1, 0), node(116, 5054, 4, 0), node(97, 5058, 1, 0),
+    node(108, 5059, 1, 0), node(59, 0, 0, 345), node(59, 0, 0, 346), node(114, 5060, 1, 0),
+    node(59, 0, 0, 347), node(59, 0, 0, 348), node(101, 5061, 1, 0), node(101, 5062, 1, 0),
+    node(84, 5063, 1, 0), node(114, 5064, 1, 0), node(101, 5065, 1, 0), node(78, 5066, 1, 349),
+    node(69, 5067, 1, 0), node(121, 5068, 1, 0), node(59, 0, 0, 350), node(111, 5069, 1, 0),
+    node(105, 5070, 1, 0), node(101, 5071, 1, 0), node(97, 5072, 1, 0), node(107, 5073, 1, 0),
+    node(83, 5074, 1, 0), node(101, 5075, 4, 0), node(59, 0, 0, 351), node(108, 5079, 1, 0),
+    node(59, 0, 0, 352), node(111, 5080, 1, 0), node(116, 5081, 1, 0), node(59, 0, 0, 353),
+    node(111, 5082, 1, 0), node(121, 5083, 1, 0), node(118, 5084, 1, 0), node(99, 5085, 1, 354),
+    node(97, 5086, 1, 0), node(118, 5087, 1, 0), node(114, 5088, 1, 0), node(114, 5089, 2, 0),
+    node(110, 5091, 2, 0), node(110, 5093, 1, 0), node(59, 0, 0, 355), node(114, 5094, 1, 0),
+    node(119, 5095, 1, 0), node(117, 5096, 1, 0), node(101, 5097, 2, 0), node(114, 5099, 1, 0),
+    node(119, 5100, 1, 0), node(114, 5101, 2, 0), node(59, 0, 0, 356), node(108, 5103, 1, 0),
+    node(103, 5104, 1, 0), node(59, 0, 0, 357), node(100, 5105, 1, 0), node(59, 0, 0, 122),
+    node(104, 5106, 1, 0), node(59, 0, 0, 358), node(104, 5107, 2, 0), node(97, 5109, 1, 0),
+    node(59, 0, 0, 359), node(105, 5110, 1, 0), node(84, 5111, 1, 0), node(59, 0, 0, 360),
+    node(59, 0, 0, 361), node(115, 5112, 1, 0), node(99, 5113, 1, 0), node(101, 5114, 1, 0),
+    node(59, 0, 0, 362), node(59, 0, 0, 363), node(59, 0, 0, 364), node(59, 0, 0, 365),
+    node(59, 0, 0, 366), node(59, 0, 0, 367), node(59, 0, 0, 368), node(116, 5115, 1, 0),
+    node(99, 5116, 1, 0), node(59, 0, 0, 369), node(59, 0, 0, 370), node(59, 0, 0, 371),
+    node(59, 0, 0, 372), node(116, 5117, 1, 0), node(111, 5118, 1, 0), node(59, 0, 0, 373),
+    node(87, 5119, 1, 0), node(59, 0, 0, 374), node(59, 0, 0, 375), node(59, 0, 0, 376),
+    node(116, 5120, 1, 0), node(118, 5121, 1, 0), node(99, 5122, 1, 377), node(101, 5123, 1, 378),
+    node(103, 5124, 1, 379), node(118, 5125, 1, 0), node(115, 5126, 1, 0), node(104, 5127, 1, 0),
+    node(97, 5128, 1, 0), node(114, 5129, 1, 0), node(103, 5130, 1, 0), node(110, 5131, 1, 0),
+    node(59, 0, 0, 380), node(108, 5132, 1, 0), node(59, 0, 0, 381), node(59, 0, 0, 382),
+    node(101, 5133, 1, 0), node(115, 5134, 1, 0), node(116, 5135, 2, 0), node(112, 5137, 1, 0),
+    node(116, 5138, 1, 0), node(97, 5139, 1, 0), node(110, 5140, 1, 0), node(59, 0, 0, 383),
+    node(105, 5141, 1, 0), node(59, 0, 0, 384), node(59, 0, 0, 385), node(111, 5142, 1, 0),
+    node(103, 5143, 1, 386), node(59, 0, 0, 387), node(112, 5144, 2, 0), node(100, 5146, 1, 0),
+    node(59, 0, 0, 141), node(110, 5147, 1, 0), node(116, 5148, 1, 0), node(59, 0, 0, 388),
+    node(99, 5149, 1, 0), node(101, 5150, 1, 0), node(112, 5151, 1, 0), node(115, 5152, 1, 0),
+    node(101, 5153, 1, 0), node(101, 5154, 1, 0), node(59, 0, 0, 389), node(116, 5155, 1, 0),
+    node(103, 5156, 1, 0), node(111, 5157, 1, 0), node(117, 5158, 1, 0), node(116, 5159, 1, 0),
+    node(105, 5160, 1, 0), node(111, 5161, 1, 0), node(59, 0, 0, 390), node(59, 0, 0, 391),
+    node(101, 5162, 1, 0), node(97, 5163, 1, 0), node(105, 5164, 1, 0), node(117, 5165, 1, 0),
+    node(100, 5166, 1, 0), node(112, 5167, 1, 0), node(116, 5168, 1, 0), node(113, 5169, 1, 0),
+    node(116, 5170, 1, 0), node(114, 5171, 1, 0), node(112, 5172, 1, 0), node(101, 5173, 1, 0),
+    node(101, 5174, 1, 0), node(111, 5175, 1, 0), node(107, 5176, 3, 0), node(107, 5179, 1, 0),
+    node(50, 5180, 1, 0), node(52, 5181, 1, 0), node(52, 5182, 1, 0), node(107, 5183, 1, 0),
+    node(117, 5184, 1, 0), node(59, 0, 0, 392), node(59, 0, 0, 393), node(111, 5185, 1, 0),
+    node(105, 5186, 1, 0), node(76, 5187, 1, 0), node(82, 5188, 1, 0), node(108, 5189, 1, 0),
+    node(114, 5190, 1, 0), node(59, 0, 0, 394), node(68, 5191, 1, 0), node(85, 5192, 1, 0),
+    node(100, 5193, 1, 0), node(117, 5194, 1, 0), node(76, 5195, 1, 0), node(82, 5196, 1, 0),
+    node(108, 5197, 1, 0), node(114, 5198, 1, 0), node(59, 0, 0, 395), node(72, 5199, 1, 0),
+    node(76, 5200, 1, 0), node(82, 5201, 1, 0), node(104, 5202, 1, 0), node(108, 5203, 1, 0),
+    node(114, 5204, 1, 0), node(111, 5205, 1, 0), node(76, 5206, 1, 0), node(82, 5207, 1, 0),
+    node(108, 5208, 1, 0), node(114, 5209, 1, 0), node(59, 0, 0, 396), node(68, 5210, 1, 0),
+    node(85, 5211, 1, 0), node(100, 5212, 1, 0), node(117, 5213, 1, 0), node(105, 5214, 1, 0),
+    node(108, 5215, 1, 0), node(105, 5216, 1, 0), node(76, 5217, 1, 0), node(82, 5218, 1, 0),
+    node(108, 5219, 1, 0), node(114, 5220, 1, 0), node(59, 0, 0, 397), node(72, 5221, 1, 0),
+    node(76, 5222, 1, 0), node(82, 5223, 1, 0), node(104, 5224, 1, 0), node(108, 5225, 1, 0),
+    node(114, 5226, 1, 0), node(109, 5227, 1, 0), node(101, 5228, 1, 0), node(97, 5229, 1, 0),
+    node(59, 0, 0, 398), node(105, 5230, 1, 0), node(59, 0, 0, 399), node(101, 5231, 1, 0),
+    node(59, 0, 0, 400), node(98, 5232, 1, 0), node(104, 5233, 1, 0), node(59, 0, 0, 401),
+    node(101, 5234, 1, 0), node(59, 0, 0, 402), node(69, 5235, 1, 0), node(101, 5236, 2, 0),
+    node(116, 5238, 1, 0), node(110, 5239, 1, 0), node(114, 5240, 1, 0), node(97, 5241, 1, 0),
+    node(117, 5242, 1, 0), node(111, 5243, 1, 0), node(59, 0, 0, 403), node(116, 5244, 1, 0),
+    node(110, 5245, 1, 0), node(115, 5246, 1, 0), node(111, 5247, 1, 0), node(105, 5248, 1, 0),
+    node(99, 5249, 1, 0), node(115, 5250, 2, 0), node(59, 0, 0, 404), node(108, 5252, 1, 405),
+    node(116, 5253, 1, 0), node(59, 0, 0, 147), node(101, 5254, 1, 0), node(59, 0, 0, 406),
+    node(107, 5255, 2, 0), node(59, 0, 0, 407), node(59, 0, 0, 408), node(101, 5257, 1, 0),
+    node(108, 5258, 1, 0), node(59, 0, 0, 409), node(110, 5259, 1, 0), node(105, 5260, 1, 0),
+    node(99, 5261, 1, 0), node(115, 5262, 2, 0), node(110, 5264, 2, 0), node(97, 5266, 2, 0),
+    node(59, 0, 0, 410), node(102, 5268, 1, 0), node(108, 5269, 1, 0), node(59, 0, 0, 411),
+    node(100, 5270, 1, 0), node(110, 5271, 1, 0), node(59, 0, 0, 412), node(111, 5272, 1, 0),
+    node(59, 0, 0, 64), node(115, 5273, 1, 0), node(114, 5274, 1, 0), node(115, 5275, 1, 0),
+    node(59, 0, 0, 413), node(59, 0, 0, 414), node(101, 5276, 1, 0), node(59, 0, 0, 415),
+    node(101, 5277, 1, 0), node(116, 5278, 1, 0), node(114, 5279, 1, 0), node(114, 5280, 1, 0),
+    node(99, 5281, 1, 0), node(114, 5282, 1, 0), node(114, 5283, 1, 0), node(97, 5284, 1, 0),
+    node(117, 5285, 1, 0), node(111, 5286, 1, 0), node(114, 5287, 1, 0), node(59, 0, 0, 416),
+    node(114, 5288, 1, 0), node(121, 5289, 3, 0), node(101, 5292, 1, 0), node(101, 5293, 1, 0),
+    node(101, 5294, 1, 0), node(100, 5295, 1, 0), node(110, 5296, 1, 0), node(116, 5297, 1, 0),
+    node(116, 5298, 1, 0), node(59, 0, 0, 417), node(59, 0, 0, 418), node(101, 5299, 1, 0),
+    node(116, 5300, 1, 0), node(59, 0, 0, 419), node(59, 0, 0, 420), node(118, 5301, 1, 0),
+    node(114, 5302, 1, 0), node(99, 5303, 1, 0), node(111, 5304, 1, 0), node(103, 5305, 1, 0),
+    node(114, 5306, 1, 0), node(115, 5307, 1, 0), node(97, 5308, 1, 0), node(116, 5309, 1, 0),
+    node(104, 5310, 1, 0), node(108, 5311, 1, 0), node(114, 5312, 1, 0), node(59, 0, 0, 421),
+    node(111, 5313, 1, 0), node(115, 5314, 1, 0), node(109, 5315, 1, 0), node(110, 5316, 1, 0),
+    node(100, 5317, 1, 0), node(110, 5318, 1, 0), node(59, 0, 0, 422), node(114, 5319, 1, 0),
+    node(111, 5320, 1, 0), node(97, 5321, 1, 0), node(59, 0, 0, 423), node(113, 5322, 2, 0),
+    node(105, 5324, 1, 0), node(108, 5325, 1, 0), node(113, 5326, 1, 0), node(108, 5327, 1, 0),
+    node(97, 5328, 1, 0), node(100, 5329, 1, 0), node(104, 5330, 1, 0), node(97, 5331, 1, 0),
+    node(114, 5332, 1, 0), node(111, 5333, 1, 0), node(59, 0, 0, 424), node(59, 0, 0, 425),
+    node(59, 0, 0, 426), node(111, 5334, 1, 0), node(116, 5335, 1, 0), node(59, 0, 0, 427),
+    node(102, 5336, 1, 0), node(114, 5337, 1, 0), node(114, 5338, 1, 0), node(103, 5339, 1, 0),
+    node(59, 0, 0, 428), node(114, 5340, 1, 0), node(116, 5341, 1, 0), node(59, 0, 0, 429),
+    node(116, 5342, 1, 0), node(101, 5343, 1, 0), node(111, 5344, 1, 0), node(59, 0, 0, 430),
+    node(99, 5345, 1, 431), node(111, 5346, 1, 0), node(59, 0, 0, 432), node(116, 5347, 1, 0),
+    node(118, 5348, 1, 0), node(111, 5349, 1, 0), node(116, 5350, 1, 0), node(111, 5351, 1, 0),
+    node(114, 5352, 1, 0), node(121, 5353, 3, 0), node(49, 5356, 2, 0), node(59, 0, 0, 433),
+    node(59, 0, 0, 434), node(110, 5358, 1, 0), node(59, 0, 0, 435), node(59, 0, 0, 436),
+    node(115, 5359, 1, 0), node(115, 5360, 1, 0), node(59, 0, 0, 437), node(108, 5361, 1, 0),
+    node(118, 5362, 1, 0), node(114, 5363, 1, 0), node(108, 5364, 1, 0), node(109, 5365, 1, 0),
+    node(97, 5366, 1, 0), node(108, 5367, 1, 0), node(115, 5368, 1, 0), node(118, 5369, 2, 0),
+    node(97, 5371, 1, 0), node(116, 5372, 1, 0), node(114, 5373, 1, 0), node(59, 0, 0, 438),
+    node(116, 5374, 1, 0), node(59, 0, 0, 439), node(59, 0, 0, 163), node(59, 0, 0, 440),
+    node(59, 0, 0, 441), node(116, 5375, 1, 0), node(99, 5376, 1, 0), node(110, 5377, 1, 0),
+    node(105, 5378, 1, 0), node(108, 5379, 1, 0), node(105, 5380, 1, 0), node(103, 5381, 1, 0),
+    node(105, 5382, 1, 0), node(103, 5383, 1, 0), node(103, 5384, 1, 0), node(59, 0, 0, 442),
+    node(103, 5385, 1, 0), node(115, 5386, 1, 0), node(59, 0, 0, 443), node(59, 0, 0, 444),
+    node(108, 5387, 1, 0), node(59, 0, 0, 445), node(118, 5388, 1, 0), node(116, 5389, 1, 0),
+    node(49, 5390, 6, 0), node(50, 5396, 2, 0), node(51, 5398, 3, 0), node(52, 5401, 1, 0),
+    node(53, 5402, 2, 0), node(55, 5404, 1, 0), node(108, 5405, 1, 0), node(110, 5406, 1, 0),
+    node(59, 0, 0, 446), node(116, 5407, 1, 0), node(97, 5408, 2, 0), node(118, 5410, 1, 0),
+    node(99, 5411, 1, 0), node(59, 0, 0, 447), node(59, 0, 0, 29), node(108, 5412, 1, 0),
+    node(99, 5413, 1, 0), node(111, 5414, 1, 0), node(59, 0, 0, 448), node(101, 5415, 1, 0),
+    node(108, 5416, 1, 0), node(59, 0, 0, 449), node(59, 0, 0, 450), node(112, 5417, 1, 0),
+    node(59, 0, 0, 176), node(113, 5418, 1, 0), node(109, 5419, 1, 0), node(59, 0, 0, 451),
+    node(101, 5420, 1, 0), node(59, 0, 0, 452), node(59, 0, 0, 453), node(101, 5421, 1, 0),
+    node(108, 5422, 1, 0), node(59, 0, 0, 454), node(114, 5423, 1, 0), node(116, 5424, 1, 0),
+    node(97, 5425, 1, 0), node(101, 5426, 1, 0), node(112, 5427, 1, 0), node(114, 5428, 1, 0),
+    node(111, 5429, 1, 0), node(113, 5430, 2, 0), node(101, 5432, 1, 0), node(105, 5433, 1, 0),
+    node(116, 5434, 1, 0), node(59, 0, 0, 455), node(59, 0, 0, 179), node(115, 5435, 1, 0),
+    node(59, 0, 0, 456), node(108, 5436, 1, 0), node(99, 5437, 1, 0), node(59, 0, 0, 457),
+    node(99, 5438, 1, 0), node(119, 5439, 1, 0), node(59, 0, 0, 458), node(99, 5440, 1, 0),
+    node(116, 5441, 1, 0), node(105, 5442, 1, 0), node(111, 5443, 1, 0), node(97, 5444, 1, 0),
+    node(97, 5445, 1, 0), node(114, 5446, 1, 0), node(104, 5447, 1, 0), node(108, 5448, 1, 0),
+    node(114, 5449, 1, 0), node(59, 0, 0, 459), node(97, 5450, 1, 0), node(59, 0, 0, 460),
+    node(115, 5451, 1, 0), node(111, 5452, 1, 0), node(108, 5453, 1, 0), node(101, 5454, 1, 0),
+    node(116, 5455, 1, 0), node(99, 5456, 1, 461), node(59, 0, 0, 462), node(108, 5457, 1, 463),
+    node(118, 5458, 1, 0), node(110, 5459, 1, 0), node(116, 5460, 1, 0), node(105, 5461, 1, 0),
+    node(97, 5462, 1, 0), node(103, 5463, 1, 0), node(114, 5464, 1, 0), node(101, 5465, 1, 0),
+    node(108, 5466, 1, 0), node(112, 5467, 1, 0), node(104, 5468, 1, 0), node(59, 0, 0, 464),
+    node(100, 5469, 1, 0), node(114, 5470, 1, 0), node(110, 5471, 2, 0), node(111, 5473, 1, 0),
+    node(97, 5474, 1, 0), node(103, 5475, 1, 0), node(114, 5476, 1, 0), node(97, 5477, 1, 0),
+    node(114, 5478, 1, 0), node(59, 0, 0, 465), node(110, 5479, 1, 0), node(59, 0, 0, 466),
+    node(59, 0, 0, 467), node(100, 5480, 1, 0), node(115, 5481, 1, 0), node(59, 0, 0, 468),
+    node(59, 0, 0, 34), node(69, 5482, 1, 0), node(100, 5483, 1, 0), node(115, 5484, 2, 0),
+    node(118, 5486, 1, 0), node(100, 5487, 1, 0), node(121, 5488, 1, 0), node(59, 0, 0, 182),
+    node(99, 5489, 1, 0), node(104, 5490, 1, 0), node(59, 0, 0, 469), node(59, 0, 0, 470),
+    node(99, 5491, 1, 0), node(121, 5492, 1, 0), node(97, 5493, 2, 0), node(105, 5495, 1, 0),
+    node(101, 5496, 1, 0), node(59, 0, 0, 471), node(59, 0, 0, 472), node(59, 0, 0, 473),
+    node(59, 0, 0, 474), node(114, 5497, 1, 0), node(59, 0, 0, 475), node(105, 5498, 1, 0),
+    node(114, 5499, 1, 0), node(59, 0, 0, 476), node(116, 5500, 1, 0), node(112, 5501, 1, 0),
+    node(97, 5502, 1, 0), node(100, 5503, 1, 0), node(59, 0, 0, 477), node(100, 5504, 1, 0),
+    node(108, 5505, 1, 0), node(111, 5506, 1, 478), node(59, 0, 0, 479), node(98, 5507, 2, 0),
+    node(102, 5509, 1, 0), node(104, 5510, 1, 0), node(108, 5511, 1, 0), node(112, 5512, 1, 0),
+    node(115, 5513, 1, 0), node(116, 5514, 1, 0), node(105, 5515, 1, 0), node(59, 0, 0, 480),
+    node(115, 5516, 1, 0), node(114, 5517, 1, 0), node(107, 5518, 1, 0), node(99, 5519, 2, 0),
+    node(101, 5521, 1, 0), node(115, 5522, 1, 0), node(111, 5523, 1, 0), node(105, 5524, 1, 0),
+    node(108, 5525, 1, 0), node(59, 0, 0, 481), node(59, 0, 0, 482), node(111, 5526, 2, 0),
+    node(104, 5528, 1, 0), node(115, 5529, 1, 0), node(59, 0, 0, 483), node(97, 5530, 1, 0),
+    node(104, 5531, 1, 0), node(108, 5532, 1, 0), node(114, 5533, 1, 0), node(116, 5534, 1, 0),
+    node(59, 0, 0, 36), node(108, 5535, 1, 0), node(99, 5536, 1, 0), node(111, 5537, 1, 0),
+    node(59, 0, 0, 484), node(101, 5538, 1, 0), node(97, 5539, 1, 0), node(100, 5540, 1, 0),
+    node(101, 5541, 1, 0), node(103, 5542, 1, 0), node(115, 5543, 1, 0), node(104, 5544, 1, 0),
+    node(111, 5545, 1, 0), node(100, 5546, 1, 0), node(117, 5547, 2, 0), node(107, 5549, 1, 0),
+    node(59, 0, 0, 485), node(114, 5550, 1, 0), node(114, 5551, 1, 0), node(114, 5552, 1, 0),
+    node(105, 5553, 1, 0), node(111, 5554, 1, 0), node(115, 5555, 1, 0), node(59, 0, 0, 486),
+    node(112, 5556, 1, 0), node(59, 0, 0, 196), node(113, 5557, 1, 0), node(109, 5558, 1, 0),
+    node(103, 5559, 1, 0), node(114, 5560, 1, 0), node(107, 5561, 1, 0), node(108, 5562, 1, 0),
+    node(109, 5563, 1, 0), node(114, 5564, 1, 0), node(97, 5565, 1, 0), node(114, 5566, 1, 0),
+    node(59, 0, 0, 487), node(117, 5567, 1, 0), node(109, 5568, 1, 0), node(115, 5569, 1, 0),
+    node(97, 5570, 1, 0), node(110, 5571, 1, 0), node(59, 0, 0, 488), node(59, 0, 0, 489),
+    node(108, 5572, 1, 0), node(114, 5573, 1, 0), node(114, 5574, 1, 0), node(114, 5575, 2, 0),
+    node(105, 5577, 1, 0), node(117, 5578, 1, 0), node(59, 0, 0, 490), node(59, 0, 0, 491),
+    node(101, 5579, 1, 0), node(103, 5580, 1, 0), node(59, 0, 0, 492), node(111, 5581, 2, 0),
+    node(111, 5583, 1, 0), node(59, 0, 0, 493), node(114, 5584, 1, 0), node(116, 5585, 1, 0),
+    node(101, 5586, 1, 0), node(101, 5587, 1, 0), node(114, 5588, 1, 0), node(101, 5589, 1, 0),
+    node(97, 5590, 1, 0), node(59, 0, 0, 494), node(101, 5591, 1, 0), node(102, 5592, 1, 0),
+    node(115, 5593, 1, 0), node(104, 5594, 1, 0), node(116, 5595, 1, 0), node(59, 0, 0, 495),
+    node(116, 5596, 1, 0), node(59, 0, 0, 199), node(59, 0, 0, 496), node(59, 0, 0, 497),
+    node(101, 5597, 1, 0), node(116, 5598, 1, 0), node(101, 5599, 1, 0), node(109, 5600, 1, 0),
+    node(104, 5601, 1, 0), node(117, 5602, 1, 0), node(111, 5603, 1, 498), node(115, 5604, 1, 0),
+    node(105, 5605, 1, 0), node(111, 5606, 1, 0), node(115, 5607, 3, 0), node(59, 0, 0, 499),
+    node(59, 0, 0, 500), node(117, 5610, 1, 0), node(108, 5611, 1, 0), node(59, 0, 0, 501),
+    node(59, 0, 0, 502), node(111, 5612, 1, 0), node(105, 5613, 1, 0), node(112, 5614, 1, 0),
+    node(59, 0, 0, 503), node(116, 5615, 2, 0), node(59, 0, 0, 504), node(104, 5617, 1, 0),
+    node(115, 5618, 1, 0), node(115, 5619, 1, 0), node(97, 5620, 1, 0), node(116, 5621, 1, 0),
+    node(59, 0, 0, 505), node(59, 0, 0, 506), node(100, 5622, 1, 0), node(115, 5623, 1, 0),
+    node(114, 5624, 1, 0), node(114, 5625, 2, 0), node(59, 0, 0, 210), node(112, 5627, 2, 0),
+    node(59, 0, 0, 507), node(111, 5629, 1, 0), node(105, 5630, 1, 0), node(103, 5631, 2, 0),
+    node(59, 0, 0, 508), node(104, 5633, 1, 0), node(114, 5634, 1, 0), node(104, 5635, 1, 0),
+    node(114, 5636, 2, 0), node(116, 5638, 1, 0), node(105, 5639, 1, 0), node(97, 5640, 1, 0),
+    node(109, 5641, 1, 0), node(115, 5642, 1, 0), node(59, 0, 0, 214), node(113, 5643, 1, 0),
+    node(115, 5644, 1, 0), node(59, 0, 0, 509), node(109, 5645, 1, 0), node(59, 0, 0, 215),
+    node(114, 5646, 1, 0), node(114, 5647, 1, 0), node(114, 5648, 1, 0), node(59, 0, 0, 510),
+    node(59, 0, 0, 511), node(114, 5649, 1, 0), node(114, 5650, 1, 0), node(59, 0, 0, 512),
+    node(116, 5651, 2, 0), node(59, 0, 0, 218), node(113, 5653, 1, 0), node(115, 5654, 1, 0),
+    node(59, 0, 0, 513), node(115, 5655, 1, 0), node(109, 5656, 1, 0), node(105, 5657, 2, 0),
+    node(59, 0, 0, 514), node(59, 0, 0, 515), node(110, 5659, 4, 0), node(105, 5663, 2, 0),
+    node(59, 0, 0, 516), node(97, 5665, 1, 0), node(115, 5666, 1, 0), node(116, 5667, 1, 0),
+    node(105, 5668, 1, 0), node(117, 5669, 1, 0), node(59, 0, 0, 517), node(99, 5670, 2, 0),
+    node(114, 5672, 1, 0), node(114, 5673, 3, 0), node(104, 5676, 1, 0), node(105, 5677, 2, 0),
+    node(117, 5679, 1, 0), node(59, 0, 0, 518), node(59, 0, 0, 519), node(114, 5680, 1, 0),
+    node(59, 0, 0, 520), node(101, 5681, 2, 0), node(100, 5683, 1, 0), node(114, 5684, 1, 0),
+    node(117, 5685, 2, 0), node(59, 0, 0, 521), node(69, 5687, 1, 0), node(101, 5688, 1, 0),
+    node(115, 5689, 1, 0), node(99, 5690, 2, 0), node(59, 0, 0, 522), node(69, 5692, 1, 0),
+    node(101, 5693, 1, 0), node(115, 5694, 1, 0), node(59, 0, 0, 523), node(100, 5695, 1, 0),
+    node(59, 0, 0, 524), node(97, 5696, 1, 0), node(114, 5697, 1, 0), node(112, 5698, 1, 0),
+    node(115, 5699, 1, 0), node(114, 5700, 1, 0), node(59, 0, 0, 525), node(115, 5701, 1, 0),
+    node(59, 0, 0, 526), node(59, 0, 0, 527), node(102, 5702, 1, 0), node(114, 5703, 1, 0),
+    node(59, 0, 0, 528), node(59, 0, 0, 529), node(114, 5704, 1, 0), node(114, 5705, 1, 0),
+    node(114, 5706, 1, 0), node(109, 5707, 1, 0), node(114, 5708, 1, 0), node(104, 5709, 1, 0),
+    node(114, 5710, 2, 0), node(97, 5712, 1, 0), node(116, 5713, 1, 0), node(59, 0, 0, 530),
+    node(59, 0, 0, 531), node(99, 5714, 1, 532), node(104, 5715, 1, 0), node(97, 5716, 1, 0),
+    node(59, 0, 0, 533), node(59, 0, 0, 534), node(108, 5717, 1, 0), node(103, 5718, 1, 0),
+    node(114, 5719, 1, 0), node(59, 0, 0, 535), node(118, 5720, 1, 0), node(114, 5721, 1, 0),
+    node(59, 0, 0, 536), node(114, 5722, 1, 0), node(114, 5723, 1, 0), node(111, 5724, 1, 0),
+    node(101, 5725, 1, 0), node(114, 5726, 1, 0), node(97, 5727, 1, 0), node(114, 5728, 1, 0),
+    node(59, 0, 0, 537), node(117, 5729, 1, 0), node(59, 0, 0, 538), node(59, 0, 0, 539),
+    node(112, 5730, 1, 0), node(115, 5731, 1, 0), node(114, 5732, 1, 0), node(114, 5733, 2, 0),
+    node(59, 0, 0, 229), node(59, 0, 0, 230), node(111, 5735, 1, 0), node(59, 0, 0, 540),
+    node(111, 5736, 1, 0), node(59, 0, 0, 541), node(115, 5737, 1, 0), node(59, 0, 0, 542),
+    node(100, 5738, 1, 0), node(101, 5739, 1, 0), node(59, 0, 0, 232), node(114, 5740, 1, 0),
+    node(59, 0, 0, 234), node(108, 5741, 1, 0), node(105, 5742, 1, 0), node(108, 5743, 1, 0),
+    node(59, 0, 0, 543), node(110, 5744, 1, 0), node(111, 5745, 1, 0), node(105, 5746, 1, 0),
+    node(59, 0, 0, 145), node(101, 5747, 1, 0), node(59, 0, 0, 544), node(97, 5748, 1, 0),
+    node(101, 5749, 1, 0), node(104, 5750, 1, 0), node(99, 5751, 1, 0), node(107, 5752, 1, 0),
+    node(59, 0, 0, 545), node(97, 5753, 1, 0), node(98, 5754, 1, 0), node(99, 5755, 1, 0),
+    node(100, 5756, 2, 0), node(101, 5758, 1, 0), node(109, 5759, 1, 0), node(115, 5760, 1, 0),
+    node(116, 5761, 1, 0), node(116, 5762, 1, 0), node(59, 0, 0, 546), node(100, 5763, 1, 547),
+    node(59, 0, 0, 548), node(101, 5764, 1, 0), node(59, 0, 0, 49), node(97, 5765, 1, 0),
+    node(99, 5766, 1, 0), node(101, 5767, 1, 0), node(110, 5768, 3, 0), node(115, 5771, 1, 0),
+    node(101, 5772, 2, 0), node(59, 0, 0, 549), node(112, 5774, 1, 0), node(105, 5775, 1, 0),
+    node(59, 0, 0, 550), node(97, 5776, 1, 0), node(108, 5777, 1, 0), node(115, 5778, 1, 0),
+    node(59, 0, 0, 551), node(116, 5779, 1, 0), node(109, 5780, 1, 0), node(101, 5781, 1, 0),
+    node(59, 0, 0, 552), node(115, 5782, 1, 0), node(59, 0, 0, 553), node(59, 0, 0, 554),
+    node(109, 5783, 1, 0), node(59, 0, 0, 555), node(101, 5784, 1, 0), node(105, 5785, 1, 0),
+    node(116, 5786, 2, 0), node(59, 0, 0, 106), node(114, 5788, 1, 0), node(59, 0, 0, 556),
+    node(105, 5789, 1, 0), node(114, 5790, 1, 0), node(59, 0, 0, 557), node(59, 0, 0, 558),
+    node(116, 5791, 1, 0), node(99, 5792, 1, 0), node(112, 5793, 1, 0), node(59, 0, 0, 559),
+    node(100, 5794, 1, 0), node(101, 5795, 1, 0), node(108, 5796, 1, 0), node(111, 5797, 1, 560),
+    node(59, 0, 0, 561), node(97, 5798, 1, 0), node(98, 5799, 2, 0), node(99, 5801, 1, 0),
+    node(102, 5802, 1, 0), node(104, 5803, 1, 0), node(108, 5804, 1, 0), node(112, 5805, 1, 0),
+    node(115, 5806, 1, 0), node(116, 5807, 1, 0), node(119, 5808, 1, 0), node(105, 5809, 1, 0),
+    node(111, 5810, 2, 0), node(114, 5812, 1, 0), node(107, 5813, 1, 0), node(99, 5814, 2, 0),
+    node(101, 5816, 1, 0), node(115, 5817, 1, 0), node(111, 5818, 1, 0), node(105, 5819, 1, 0),
+    node(108, 5820, 1, 0), node(59, 0, 0, 562), node(59, 0, 0, 563), node(104, 5821, 1, 0),
+    node(111, 5822, 2, 0), node(59, 0, 0, 564), node(59, 0, 0, 17), node(105, 5824, 1, 0),
+    node(112, 5825, 1, 0), node(115, 5826, 1, 0), node(59, 0, 0, 565), node(104, 5827, 1, 0),
+    node(111, 5828, 1, 0), node(100, 5829, 1, 0), node(117, 5830, 2, 0), node(59, 0, 0, 566),
+    node(116, 5832, 6, 0), node(59, 0, 0, 567), node(110, 5838, 1, 0), node(114, 5839, 1, 0),
+    node(114, 5840, 1, 0), node(115, 5841, 1, 0), node(100, 5842, 1, 0), node(103, 5843, 1, 0),
+    node(114, 5844, 1, 0), node(107, 5845, 1, 0), node(114, 5846, 1, 0), node(59, 0, 0, 568),
+    node(117, 5847, 1, 0), node(109, 5848, 1, 0), node(59, 0, 0, 569), node(103, 5849, 1, 0),
+    node(108, 5850, 1, 0), node(114, 5851, 1, 0), node(117, 5852, 1, 0), node(59, 0, 0, 570),
+    node(59, 0, 0, 571), node(111, 5853, 2, 0), node(101, 5855, 1, 0), node(101, 5856, 1, 0),
+    node(59, 0, 0, 572), node(101, 5857, 1, 0), node(102, 5858, 1, 0), node(108, 5859, 1, 0),
+    node(104, 5860, 1, 0), node(116, 5861, 1, 0), node(111, 5862, 1, 0), node(59, 0, 0, 573),
+    node(111, 5863, 1, 0), node(101, 5864, 1, 0), node(105, 5865, 1, 0), node(99, 5866, 1, 0),
+    node(59, 0, 0, 574), node(112, 5867, 1, 0), node(105, 5868, 1, 0), node(108, 5869, 1, 0),
+    node(109, 5870, 1, 0), node(59, 0, 0, 575), node(98, 5871, 1, 0), node(101, 5872, 1, 0),
+    node(114, 5873, 1, 0), node(104, 5874, 1, 0), node(114, 5875, 2, 0), node(59, 0, 0, 250),
+    node(59, 0, 0, 576), node(97, 5877, 1, 0), node(105, 5878, 1, 0), node(110, 5879, 1, 0),
+    node(59, 0, 0, 577), node(119, 5880, 1, 0), node(112, 5881, 1, 0), node(99, 5882, 1, 0),
+    node(59, 0, 0, 578), node(116, 5883, 2, 0), node(97, 5885, 3, 0), node(111, 5888, 1, 0),
+    node(59, 0, 0, 579), node(113, 5889, 1, 0), node(59, 0, 0, 580), node(69, 5890, 1, 0),
+    node(59, 0, 0, 581), node(69, 5891, 1, 0), node(101, 5892, 1, 0), node(108, 5893, 1, 0),
+    node(97, 5894, 1, 0), node(114, 5895, 1, 0), node(108, 5896, 1, 0), node(104, 5897, 1, 0),
+    node(97, 5898, 1, 0), node(59, 0, 0, 204), node(101, 5899, 1, 0), node(59, 0, 0, 582),
+    node(115, 5900, 1, 0), node(99, 5901, 1, 0), node(59, 0, 0, 583), node(97, 5902, 1, 0),
+    node(59, 0, 0, 584), node(101, 5903, 1, 0), node(59, 0, 0, 233), node(112, 5904, 2, 0),
+    node(112, 5906, 2, 0), node(98, 5908, 3, 0), node(112, 5911, 3, 0), node(114, 5914, 2, 0),
+    node(59, 0, 0, 585), node(114, 5916, 1, 0), node(59, 0, 0, 586), node(109, 5917, 1, 0),
+    node(108, 5918, 1, 0), node(114, 5919, 1, 0), node(59, 0, 0, 587), node(102, 5920, 1, 0),
+    node(105, 5921, 1, 0), node(115, 5922, 1, 0), node(59, 0, 0, 588), node(111, 5923, 1, 0),
+    node(59, 0, 0, 589), node(100, 5924, 1, 0), node(117, 5925, 1, 0), node(69, 5926, 1, 0),
+    node(101, 5927, 1, 0), node(108, 5928, 1, 0), node(97, 5929, 1, 0), node(101, 5930, 1, 0),
+    node(105, 5931, 1, 0), node(117, 5932, 2, 0), node(59, 0, 0, 51), node(97, 5934, 1, 0),
+    node(99, 5935, 1, 0), node(101, 5936, 1, 0), node(110, 5937, 3, 0), node(115, 5940, 1, 0),
+    node(59, 0, 0, 590), node(59, 0, 0, 257), node(59, 0, 0, 258), node(59, 0, 0, 259),
+    node(59, 0, 0, 591), node(111, 5941, 1, 0), node(115, 5942, 1, 0), node(59, 0, 0, 592),
+    node(100, 5943, 1, 0), node(115, 5944, 2, 0), node(97, 5946, 1, 0), node(117, 5947, 1, 0),
+    node(69, 5948, 1, 0), node(101, 5949, 1, 0), node(108, 5950, 1, 0), node(101, 5951, 1, 0),
+    node(105, 5952, 1, 0), node(117, 5953, 2, 0), node(114, 5955, 1, 0), node(104, 5956, 1, 0),
+    node(114, 5957, 2, 0), node(97, 5959, 1, 0), node(103, 5960, 1, 593), node(101, 5961, 1, 0),
+    node(59, 0, 0, 594), node(111, 5962, 1, 0), node(105, 5963, 1, 0), node(59, 0, 0, 595),
+    node(101, 5964, 1, 0), node(101, 5965, 2, 0), node(97, 5967, 3, 0), node(107, 5970, 2, 0),
+    node(115, 5972, 1, 0), node(112, 5973, 1, 0), node(105, 5974, 1, 0), node(110, 5975, 1, 596),
+    node(101, 5976, 1, 0), node(115, 5977, 3, 597), node(59, 0, 0, 598), node(59, 0, 0, 599),
+    node(111, 5980, 1, 0), node(105, 5981, 1, 0), node(59, 0, 0, 600), node(111, 5982, 1, 0),
+    node(59, 0, 0, 601), node(109, 5983, 1, 0), node(101, 5984, 1, 0), node(110, 5985, 1, 0),
+    node(111, 5986, 1, 0), node(59, 0, 0, 602), node(105, 5987, 1, 0), node(108, 5988, 1, 0),
+    node(98, 5989, 1, 0), node(105, 5990, 1, 0), node(122, 5991, 1, 0), node(59, 0, 0, 603),
+    node(59, 0, 0, 604), node(121, 5992, 1, 0), node(111, 5993, 1, 0), node(116, 5994, 1, 0),
+    node(101, 5995, 1, 0), node(59, 0, 0, 605), node(59, 0, 0, 606), node(116, 5996, 1, 0),
+    node(59, 0, 0, 607), node(121, 5997, 1, 0), node(118, 5998, 1, 0), node(99, 5999, 1, 608),
+    node(114, 6000, 1, 0), node(97, 6001, 1, 0), node(114, 6002, 1, 0), node(104, 6003, 1, 0),
+    node(118, 6004, 1, 0), node(108, 6005, 1, 0), node(114, 6006, 1, 0), node(107, 6007, 1, 0),
+    node(114, 6008, 1, 0), node(111, 6009, 1, 0), node(105, 6010, 1, 0), node(114, 6011, 1, 0),
+    node(110, 6012, 1, 0), node(59, 0, 0, 609), node(114, 6013, 1, 0), node(119, 6014, 1, 0),
+    node(114, 6015, 1, 0), node(115, 6016, 1, 0), node(59, 0, 0, 610), node(104, 6017, 1, 0),
+    node(108, 6018, 1, 0), node(97, 6019, 1, 0), node(114, 6020, 1, 0), node(111, 6021, 1, 0),
+    node(103, 6022, 1, 0), node(105, 6023, 1, 0), node(59, 0, 0, 611), node(116, 6024, 1, 0),
+    node(100, 6025, 1, 0), node(59, 0, 0, 612), node(102, 6026, 1, 0), node(114, 6027, 1, 0),
+    node(59, 0, 0, 267), node(103, 6028, 1, 0), node(59, 0, 0, 613), node(59, 0, 0, 614),
+    node(118, 6029, 1, 0), node(104, 6030, 1, 0), node(114, 6031, 1, 0), node(112, 6032, 1, 0),
+    node(97, 6033, 1, 0), node(111, 6034, 1, 0), node(104, 6035, 1, 0), node(105, 6036, 1, 0),
+    node(114, 6037, 1, 0), node(59, 0, 0, 615), node(104, 6038, 1, 0), node(105, 6039, 1, 0),
+    node(117, 6040, 2, 0), node(104, 6042, 1, 0), node(114, 6043, 1, 0), node(104, 6044, 1, 0),
+    node(97, 6045, 1, 0), node(113, 6046, 1, 0), node(105, 6047, 1, 0), node(97, 6048, 1, 0),
+    node(59, 0, 0, 616), node(105, 6049, 1, 0), node(98, 6050, 1, 0), node(112, 6051, 1, 0),
+    node(59, 0, 0, 617), node(112, 6052, 1, 0), node(105, 6053, 1, 0), node(59, 0, 0, 618),
+    node(110, 6054, 2, 0), node(110, 6056, 2, 0), node(122, 6058, 1, 0), node(99, 6059, 1, 0),
+    node(97, 6060, 1, 0), node(101, 6061, 2, 0), node(114, 6063, 1, 0), node(59, 0, 0, 619),
+    node(116, 6064, 1, 0), node(59, 0, 0, 620), node(59, 0, 0, 621), node(99, 6065, 1, 0),
+    node(59, 0, 0, 622), node(105, 6066, 1, 0), node(114, 6067, 1, 0), node(114, 6068, 1, 0),
+    node(114, 6069, 1, 0), node(114, 6070, 1, 0), node(59, 0, 0, 623), node(59, 0, 0, 624),
+    node(116, 6071, 1, 0), node(59, 0, 0, 625), node(117, 6072, 1, 0), node(109, 6073, 1, 0),
+    node(114, 6074, 1, 0), node(114, 6075, 1, 0), node(59, 0, 0, 626), node(117, 6076, 1, 0),
+    node(117, 6077, 1, 0), node(105, 6078, 1, 0), node(59, 0, 0, 124), node(103, 6079, 1, 0),
+    node(116, 6080, 1, 0), node(59, 0, 0, 627), node(99, 6081, 1, 0), node(59, 0, 0, 628),
+    node(59, 0, 0, 629), node(59, 0, 0, 630), node(59, 0, 0, 631), node(59, 0, 0, 274),
+    node(116, 6082, 1, 0), node(111, 6083, 1, 0), node(59, 0, 0, 632), node(114, 6084, 1, 0),
+    node(59, 0, 0, 633), node(59, 0, 0, 634), node(97, 6085, 1, 0), node(59, 0, 0, 635),
+    node(59, 0, 0, 636), node(59, 0, 0, 637), node(59, 0, 0, 278), node(101, 6086, 1, 638),
+    node(101, 6087, 1, 0), node(59, 0, 0, 279), node(101, 6088, 1, 639), node(59, 0, 0, 640),
+    node(59, 0, 0, 641), node(59, 0, 0, 642), node(70, 6089, 1, 0), node(59, 0, 0, 281),
+    node(110, 6090, 1, 0), node(101, 6091, 1, 643), node(108, 6092, 1, 0), node(100, 6093, 1, 0),
+    node(115, 6094, 1, 0), node(117, 6095, 1, 0), node(59, 0, 0, 644), node(113, 6096, 1, 0),
+    node(101, 6097, 1, 0), node(97, 6098, 1, 0), node(121, 6099, 1, 0), node(110, 6100, 1, 0),
+    node(108, 6101, 1, 645), node(59, 0, 0, 646), node(110, 6102, 1, 0), node(108, 6103, 1, 0),
+    node(114, 6104, 1, 0), node(101, 6105, 4, 0), node(119, 6109, 1, 0), node(67, 6110, 1, 0),
+    node(59, 0, 0, 647), node(101, 6111, 1, 0), node(117, 6112, 1, 0), node(116, 6113, 1, 0),
+    node(117, 6114, 1, 0), node(100, 6115, 1, 0), node(101, 6116, 1, 0), node(59, 0, 0, 648),
+    node(112, 6117, 1, 0), node(97, 6118, 1, 0), node(114, 6119, 1, 0), node(59, 0, 0, 649),
+    node(110, 6120, 1, 0), node(59, 0, 0, 650), node(105, 6121, 1, 0), node(110, 6122, 1, 0),
+    node(114, 6123, 1, 0), node(116, 6124, 1, 0), node(117, 6125, 1, 0), node(101, 6126, 6, 0),
+    node(114, 6132, 1, 0), node(114, 6133, 1, 0), node(101, 6134, 1, 0), node(105, 6135, 1, 0),
+    node(101, 6136, 1, 0), node(114, 6137, 1, 0), node(107, 6138, 1, 0), node(101, 6139, 1, 651),
+    node(110, 6140, 1, 0), node(59, 0, 0, 297), node(101, 6141, 1, 652), node(110, 6142, 1, 0),
+    node(59, 0, 0, 653), node(83, 6143, 1, 0), node(86, 6144, 1, 0), node(59, 0, 0, 654),
+    node(111, 6145, 1, 0), node(59, 0, 0, 655), node(84, 6146, 1, 0), node(105, 6147, 1, 0),
+    node(115, 6148, 1, 0), node(101, 6149, 1, 0), node(100, 6150, 2, 0), node(108, 6152, 1, 0),
+    node(101, 6153, 1, 0), node(59, 0, 0, 656), node(100, 6154, 1, 0), node(101, 6155, 1, 0),
+    node(108, 6156, 1, 0), node(59, 0, 0, 657), node(101, 6157, 1, 0), node(121, 6158, 1, 0),
+    node(59, 0, 0, 658), node(59, 0, 0, 659), node(114, 6159, 1, 0), node(111, 6160, 1, 0),
+    node(107, 6161, 1, 0), node(111, 6162, 1, 0), node(113, 6163, 1, 0), node(59, 0, 0, 660),
+    node(101, 6164, 1, 661), node(59, 0, 0, 312), node(101, 6165, 1, 662), node(59, 0, 0, 663),
+    node(110, 6166, 1, 0), node(101, 6167, 1, 0), node(114, 6168, 1, 0), node(115, 6169, 1, 0),
+    node(105, 6170, 1, 0), node(59, 0, 0, 664), node(101, 6171, 1, 0), node(59, 0, 0, 665),
+    node(59, 0, 0, 666), node(121, 6172, 1, 0), node(59, 0, 0, 667), node(59, 0, 0, 668),
+    node(108, 6173, 1, 0), node(101, 6174, 1, 0), node(97, 6175, 1, 0), node(99, 6176, 1, 0),
+    node(110, 6177, 1, 0), node(108, 6178, 1, 0), node(110, 6179, 1, 0), node(114, 6180, 1, 0),
+    node(101, 6181, 1, 0), node(111, 6182, 2, 0), node(108, 6184, 1, 0), node(105, 6185, 1, 0),
+    node(101, 6186, 1, 0), node(114, 6187, 1, 0), node(112, 6188, 3, 0), node(101, 6191, 1, 0),
+    node(114, 6192, 1, 0), node(105, 6193, 1, 0), node(113, 6194, 1, 0), node(117, 6195, 1, 0),
+    node(114, 6196, 1, 0), node(101, 6197, 1, 0), node(108, 6198, 1, 0), node(105, 6199, 1, 0),
+    node(97, 6200, 1, 0), node(116, 6201, 1, 0), node(101, 6202, 1, 0), node(105, 6203, 1, 0),
+    node(101, 6204, 1, 0), node(105, 6205, 1, 0), node(76, 6206, 1, 0), node(82, 6207, 1, 0),
+    node(107, 6208, 1, 0), node(109, 6209, 1, 0), node(110, 6210, 1, 0), node(80, 6211, 1, 0),
+    node(101, 6212, 1, 0), node(110, 6213, 1, 0), node(108, 6214, 1, 0), node(105, 6215, 1, 0),
+    node(100, 6216, 2, 0), node(110, 6218, 1, 0), node(97, 6219, 1, 0), node(101, 6220, 1, 0),
+    node(110, 6221, 1, 0), node(112, 6222, 1, 0), node(117, 6223, 1, 0), node(101, 6224, 1, 0),
+    node(117, 6225, 1, 0), node(105, 6226, 1, 0), node(101, 6227, 1, 0), node(109, 6228, 1, 0),
+    node(102, 6229, 1, 0), node(115, 6230, 1, 0), node(115, 6231, 1, 0), node(101, 6232, 1, 0),
+    node(118, 6233, 1, 0), node(103, 6234, 1, 0), node(117, 6235, 1, 0), node(98, 6236, 1, 0),
+    node(99, 6237, 1, 0), node(112, 6238, 1, 0), node(108, 6239, 1, 0), node(114, 6240, 1, 0),
+    node(101, 6241, 1, 669), node(59, 0, 0, 670), node(101, 6242, 1, 671), node(59, 0, 0, 333),
+    node(99, 6243, 1, 0), node(101, 6244, 1, 672), node(59, 0, 0, 673), node(59, 0, 0, 226),
+    node(111, 6245, 1, 0), node(117, 6246, 1, 0), node(104, 6247, 1, 674), node(101, 6248, 1, 675),
+    node(115, 6249, 1, 0), node(97, 6250, 1, 0), node(114, 6251, 1, 0), node(97, 6252, 1, 0),
+    node(97, 6253, 1, 0), node(105, 6254, 1, 0), node(97, 6255, 1, 0), node(100, 6256, 1, 0),
+    node(59, 0, 0, 676), node(99, 6257, 1, 0), node(114, 6258, 1, 0), node(59, 0, 0, 677),
+    node(101, 6259, 1, 0), node(108, 6260, 1, 0), node(110, 6261, 1, 0), node(108, 6262, 1, 0),
+    node(115, 6263, 1, 0), node(65, 6264, 2, 0), node(67, 6266, 1, 0), node(68, 6267, 1, 0),
+    node(70, 6268, 1, 0), node(84, 6269, 2, 0), node(85, 6271, 1, 0), node(86, 6272, 1, 0),
+    node(97, 6273, 1, 0), node(73, 6274, 1, 0), node(116, 6275, 1, 0), node(101, 6276, 1, 0),
+    node(121, 6277, 1, 0), node(121, 6278, 1, 0), node(101, 6279, 1, 0), node(110, 6280, 1, 0),
+    node(108, 6281, 1, 0), node(59, 0, 0, 678), node(68, 6282, 1, 0), node(76, 6283, 1, 0),
+    node(82, 6284, 1, 0), node(85, 6285, 1, 0), node(59, 0, 0, 679), node(67, 6286, 1, 0),
+    node(101, 6287, 4, 0), node(116, 6291, 2, 0), node(101, 6293, 1, 0), node(104, 6294, 1, 0),
+    node(115, 6295, 1, 0), node(116, 6296, 1, 0), node(59, 0, 0, 349), node(59, 0, 0, 680),
+    node(59, 0, 0, 681), node(110, 6297, 1, 0), node(108, 6298, 1, 0), node(102, 6299, 1, 0),
+    node(59, 0, 0, 682), node(83, 6300, 1, 0), node(112, 6301, 1, 0), node(59, 0, 0, 252),
+    node(69, 6302, 1, 0), node(70, 6303, 1, 0), node(84, 6304, 1, 0), node(101, 6305, 1, 0),
+    node(107, 6306, 1, 0), node(101, 6307, 1, 683), node(99, 6308, 1, 0), node(59, 0, 0, 684),
+    node(101, 6309, 1, 0), node(59, 0, 0, 354), node(99, 6310, 1, 0), node(101, 6311, 1, 685),
+    node(59, 0, 0, 686), node(66, 6312, 2, 0), node(80, 6314, 1, 0), node(59, 0, 0, 622),
+    node(80, 6315, 1, 0), node(59, 0, 0, 687), node(111, 6316, 1, 0), node(110, 6317, 1, 0),
+    node(105, 6318, 1, 0), node(59, 0, 0, 145), node(65, 6319, 1, 0), node(111, 6320, 1, 0),
+    node(110, 6321, 1, 0), node(76, 6322, 1, 0), node(82, 6323, 1, 0), node(111, 6324, 1, 0),
+    node(59, 0, 0, 688), node(101, 6325, 1, 0), node(59, 0, 0, 689), node(59, 0, 0, 690),
+    node(108, 6326, 1, 0), node(114, 6327, 1, 0), node(99, 6328, 1, 0), node(104, 6329, 1, 0),
+    node(104, 6330, 1, 0), node(59, 0, 0, 691), node(59, 0, 0, 692), node(101, 6331, 1, 693),
+    node(59, 0, 0, 694), node(101, 6332, 1, 0), node(110, 6333, 1, 0), node(105, 6334, 1, 0),
+    node(101, 6335, 1, 695), node(101, 6336, 1, 0), node(59, 0, 0, 377), node(59, 0, 0, 378),
+    node(59, 0, 0, 379), node(101, 6337, 1, 696), node(121, 6338, 1, 0), node(59, 0, 0, 697),
+    node(59, 0, 0, 698), node(59, 0, 0, 699), node(59, 0, 0, 700), node(100, 6339, 1, 0),
+    node(111, 6340, 1, 0), node(59, 0, 0, 137), node(100, 6341, 2, 0), node(59, 0, 0, 701),
+    node(118, 6343, 1, 0), node(104, 6344, 1, 0), node(59, 0, 0, 281), node(114, 6345, 1, 0),
+    node(59, 0, 0, 702), node(114, 6346, 1, 0), node(120, 6347, 2, 0), node(59, 0, 0, 386),
+    node(59, 0, 0, 22), node(101, 6349, 1, 0), node(101, 6350, 1, 703), node(105, 6351, 1, 0),
+    node(59, 0, 0, 704), node(111, 6352, 1, 0), node(112, 6353, 1, 0), node(114, 6354, 1, 0),
+    node(105, 6355, 1, 0), node(101, 6356, 1, 0), node(100, 6357, 2, 0), node(98, 6359, 1, 0),
+    node(59, 0, 0, 705), node(59, 0, 0, 706), node(115, 6360, 2, 0), node(121, 6362, 1, 0),
+    node(59, 0, 0, 707), node(117, 6363, 1, 0), node(101, 6364, 1, 0), node(112, 6365, 1, 0),
+    node(114, 6366, 1, 0), node(112, 6367, 1, 0), node(111, 6368, 1, 0), node(108, 6369, 1, 0),
+    node(105, 6370, 1, 0), node(99, 6371, 1, 0), node(97, 6372, 1, 0), node(105, 6373, 1, 0),
+    node(108, 6374, 1, 0), node(101, 6375, 1, 0), node(100, 6376, 1, 0), node(119, 6377, 1, 0),
+    node(108, 6378, 1, 0), node(115, 6379, 1, 0), node(116, 6380, 1, 0), node(59, 0, 0, 708),
+    node(59, 0, 0, 709), node(59, 0, 0, 710), node(59, 0, 0, 711), node(59, 0, 0, 712),
+    node(105, 6381, 1, 0), node(109, 6382, 1, 0), node(101, 6383, 1, 0), node(59, 0, 0, 713),
+    node(59, 0, 0, 714), node(59, 0, 0, 715), node(59, 0, 0, 716), node(59, 0, 0, 717),
+    node(59, 0, 0, 718), node(59, 0, 0, 719), node(59, 0, 0, 720), node(59, 0, 0, 721),
+    node(59, 0, 0, 722), node(59, 0, 0, 723), node(59, 0, 0, 724), node(59, 0, 0, 725),
+    node(59, 0, 0, 726), node(59, 0, 0, 727), node(59, 0, 0, 728), node(59, 0, 0, 729),
+    node(59, 0, 0, 730), node(120, 6384, 1, 0), node(59, 0, 0, 731), node(59, 0, 0, 732),
+    node(59, 0, 0, 733), node(59, 0, 0, 734), node(59, 0, 0, 735), node(59, 0, 0, 736),
+    node(59, 0, 0, 737), node(59, 0, 0, 738), node(110, 6385, 1, 0), node(117, 6386, 1, 0),
+    node(109, 6387, 1, 0), node(59, 0, 0, 739), node(59, 0, 0, 740), node(59, 0, 0, 741),
+    node(59, 0, 0, 742), node(59, 0, 0, 743), node(59, 0, 0, 744), node(59, 0, 0, 745),
+    node(59, 0, 0, 746), node(59, 0, 0, 747), node(59, 0, 0, 748), node(101, 6388, 1, 0),
+    node(59, 0, 0, 644), node(114, 6389, 1, 749), node(59, 0, 0, 750), node(59, 0, 0, 751),
+    node(59, 0, 0, 752), node(115, 6390, 1, 0), node(116, 6391, 1, 0), node(59, 0, 0, 753),
+    node(59, 0, 0, 754), node(113, 6392, 1, 0), node(101, 6393, 1, 0), node(100, 6394, 1, 0),
+    node(99, 6395, 1, 0), node(112, 6396, 1, 0), node(112, 6397, 1, 0), node(116, 6398, 1, 0),
+    node(59, 0, 0, 755), node(59, 0, 0, 658), node(59, 0, 0, 756), node(110, 6399, 1, 0),
+    node(108, 6400, 1, 757), node(59, 0, 0, 758), node(59, 0, 0, 759), node(115, 6401, 1, 0),
+    node(59, 0, 0, 405), node(121, 6402, 1, 0), node(114, 6403, 1, 0), node(59, 0, 0, 760),
+    node(109, 6404, 1, 0), node(113, 6405, 1, 0), node(101, 6406, 2, 0), node(105, 6408, 1, 0),
+    node(100, 6409, 1, 0), node(105, 6410, 1, 0), node(59, 0, 0, 761), node(117, 6411, 1, 0),
+    node(59, 0, 0, 762), node(101, 6412, 2, 0), node(59, 0, 0, 763), node(116, 6414, 1, 0),
+    node(110, 6415, 1, 0), node(101, 6416, 2, 0), node(111, 6418, 1, 0), node(116, 6419, 1, 0),
+    node(100, 6420, 1, 0), node(114, 6421, 1, 0), node(59, 0, 0, 764), node(59, 0, 0, 765),
+    node(59, 0, 0, 766), node(59, 0, 0, 767), node(59, 0, 0, 768), node(114, 6422, 2, 0),
+    node(59, 0, 0, 769), node(59, 0, 0, 770), node(114, 6424, 2, 0), node(99, 6426, 1, 0),
+    node(112, 6427, 1, 0), node(112, 6428, 1, 0), node(116, 6429, 1, 0), node(59, 0, 0, 771),
+    node(114, 6430, 2, 0), node(101, 6432, 1, 0), node(118, 6433, 1, 0), node(119, 6434, 1, 0),
+    node(110, 6435, 1, 772), node(97, 6436, 1, 0), node(59, 0, 0, 773), node(59, 0, 0, 774),
+    node(105, 6437, 1, 0), node(59, 0, 0, 775), node(121, 6438, 1, 0), node(114, 6439, 1, 0),
+    node(104, 6440, 1, 0), node(59, 0, 0, 776), node(111, 6441, 1, 0), node(59, 0, 0, 777),
+    node(110, 6442, 1, 0), node(101, 6443, 1, 0), node(59, 0, 0, 778), node(101, 6444, 1, 0),
+    node(59, 0, 0, 779), node(121, 6445, 1, 0), node(116, 6446, 1, 0), node(59, 0, 0, 780),
+    node(59, 0, 0, 781), node(110, 6447, 1, 0), node(59, 0, 0, 782), node(109, 6448, 1, 0),
+    node(59, 0, 0, 783), node(101, 6449, 2, 154), node(120, 6451, 1, 0), node(110, 6452, 1, 0),
+    node(112, 6453, 1, 0), node(114, 6454, 1, 0), node(59, 0, 0, 784), node(100, 6455, 1, 0),
+    node(110, 6456, 1, 0), node(117, 6457, 1, 0), node(117, 6458, 1, 0), node(101, 6459, 1, 0),
+    node(114, 6460, 1, 0), node(111, 6461, 1, 0), node(97, 6462, 1, 0), node(114, 6463, 1, 0),
+    node(110, 6464, 1, 0), node(112, 6465, 1, 0), node(107, 6466, 1, 0), node(59, 0, 0, 785),
+    node(59, 0, 0, 786), node(59, 0, 0, 787), node(59, 0, 0, 788), node(108, 6467, 1, 0),
+    node(97, 6468, 1, 0), node(59, 0, 0, 789), node(101, 6469, 1, 790), node(114, 6470, 1, 0),
+    node(110, 6471, 1, 0), node(59, 0, 0, 431), node(110, 6472, 1, 0), node(59, 0, 0, 791),
+    node(101, 6473, 1, 792), node(116, 6474, 1, 0), node(101, 6475, 1, 0), node(116, 6476, 1, 0),
+    node(59, 0, 0, 793), node(59, 0, 0, 794), node(115, 6477, 1, 0), node(118, 6478, 1, 0),
+    node(51, 6479, 1, 0), node(52, 6480, 1, 0), node(59, 0, 0, 795), node(108, 6481, 1, 0),
+    node(59, 0, 0, 796), node(111, 6482, 1, 0), node(59, 0, 0, 797), node(99, 6483, 1, 0),
+    node(111, 6484, 1, 0), node(59, 0, 0, 439), node(110, 6485, 1, 0), node(115, 6486, 1, 0),
+    node(116, 6487, 1, 0), node(59, 0, 0, 798), node(68, 6488, 1, 0), node(114, 6489, 1, 0),
+    node(59, 0, 0, 799), node(59, 0, 0, 800), node(59, 0, 0, 784), node(59, 0, 0, 801),
+    node(116, 6490, 1, 0), node(101, 6491, 1, 0), node(110, 6492, 1, 0), node(101, 6493, 1, 0),
+    node(103, 6494, 1, 0), node(59, 0, 0, 802), node(103, 6495, 1, 0), node(59, 0, 0, 803),
+    node(59, 0, 0, 804), node(59, 0, 0, 805), node(59, 0, 0, 806), node(108, 6496, 1, 0),
+    node(59, 0, 0, 807), node(105, 6497, 1, 0), node(50, 6498, 1, 456), node(51, 6499, 1, 0),
+    node(52, 6500, 1, 808), node(53, 6501, 1, 0), node(54, 6502, 1, 0), node(56, 6503, 1, 0),
+    node(51, 6504, 1, 0), node(53, 6505, 1, 0), node(52, 6506, 1, 809), node(53, 6507, 1, 0),
+    node(56, 6508, 1, 0), node(53, 6509, 1, 0), node(54, 6510, 1, 0), node(56, 6511, 1, 0),
+    node(56, 6512, 1, 0), node(59, 0, 0, 810), node(59, 0, 0, 811), node(101, 6513, 1, 0),
+    node(59, 0, 0, 812), node(100, 6514, 1, 0), node(101, 6515, 1, 0), node(59, 0, 0, 813),
+    node(97, 6516, 1, 0), node(59, 0, 0, 814), node(116, 6517, 2, 0), node(115, 6519, 1, 0),
+    node(59, 0, 0, 815), node(114, 6520, 1, 0), node(59, 0, 0, 175), node(59, 0, 0, 816),
+    node(59, 0, 0, 817), node(59, 0, 0, 818), node(59, 0, 0, 819), node(59, 0, 0, 820),
+    node(59, 0, 0, 821), node(114, 6521, 1, 0), node(115, 6522, 1, 0), node(112, 6523, 1, 0),
+    node(114, 6524, 1, 0), node(116, 6525, 1, 0), node(108, 6526, 1, 0), node(113, 6527, 1, 0),
+    node(115, 6528, 1, 0), node(109, 6529, 1, 0), node(110, 6530, 1, 0), node(112, 6531, 1, 0),
+    node(116, 6532, 1, 0), node(121, 6533, 1, 0), node(105, 6534, 1, 0), node(59, 0, 0, 822),
+    node(59, 0, 0, 823), node(115, 6535, 2, 0), node(112, 6537, 1, 0), node(110, 6538, 1, 0),
+    node(114, 6539, 1, 0), node(114, 6540, 1, 0), node(59, 0, 0, 824), node(116, 6541, 1, 0),
+    node(101, 6542, 1, 0), node(105, 6543, 1, 0), node(114, 6544, 1, 0), node(104, 6545, 1, 0),
+    node(107, 6546, 1, 0), node(108, 6547, 1, 0), node(110, 6548, 1, 0), node(101, 6549, 1, 825),
+    node(59, 0, 0, 461), node(59, 0, 0, 463), node(101, 6550, 1, 826), node(116, 6551, 1, 0),
+    node(59, 0, 0, 598), node(110, 6552, 1, 0), node(59, 0, 0, 827), node(59, 0, 0, 828),
+    node(59, 0, 0, 829), node(59, 0, 0, 8), node(105, 6553, 1, 0), node(97, 6554, 1, 0),
+    node(59, 0, 0, 830), node(59, 0, 0, 831), node(101, 6555, 1, 0), node(59, 0, 0, 832),
+    node(116, 6556, 1, 0), node(116, 6557, 1, 0), node(108, 6558, 1, 0), node(101, 6559, 1, 0),
+    node(99, 6560, 1, 0), node(114, 6561, 1, 0), node(111, 6562, 1, 0), node(59, 0, 0, 833),
+    node(59, 0, 0, 834), node(116, 6563, 1, 835), node(59, 0, 0, 836), node(111, 6564, 1, 0),
+    node(59, 0, 0, 837), node(118, 6565, 1, 0), node(59, 0, 0, 34), node(101, 6566, 1, 0),
+    node(59, 0, 0, 838), node(59, 0, 0, 839), node(59, 0, 0, 840), node(121, 6567, 1, 0),
+    node(59, 0, 0, 841), node(59, 0, 0, 842), node(118, 6568, 1, 0), node(108, 6569, 1, 0),
+    node(110, 6570, 1, 0), node(59, 0, 0, 843), node(108, 6571, 1, 0), node(59, 0, 0, 844),
+    node(101, 6572, 1, 0), node(116, 6573, 1, 0), node(110, 6574, 1, 0), node(97, 6575, 1, 0),
+    node(59, 0, 0, 845), node(101, 6576, 1, 0), node(59, 0, 0, 478), node(59, 0, 0, 846),
+    node(102, 6577, 1, 0), node(115, 6578, 1, 0), node(107, 6579, 1, 0), node(112, 6580, 1, 0),
+    node(108, 6581, 1, 0), node(105, 6582, 1, 0), node(108, 6583, 1, 0), node(108, 6584, 1, 0),
+    node(59, 0, 0, 847), node(59, 0, 0, 848), node(59, 0, 0, 849), node(101, 6585, 1, 0),
+    node(107, 6586, 1, 0), node(59, 0, 0, 850), node(108, 6587, 2, 0), node(110, 6589, 1, 0),
+    node(108, 6590, 1, 0), node(59, 0, 0, 851), node(59, 0, 0, 852), node(114, 6591, 1, 0),
+    node(97, 6592, 1, 0), node(104, 6593, 1, 0), node(114, 6594, 1, 0), node(97, 6595, 1, 0),
+    node(101, 6596, 1, 0), node(105, 6597, 1, 0), node(104, 6598, 1, 0), node(97, 6599, 1, 0),
+    node(59, 0, 0, 853), node(116, 6600, 2, 0), node(115, 6602, 1, 0), node(112, 6603, 1, 0),
+    node(111, 6604, 1, 0), node(113, 6605, 2, 0), node(116, 6607, 1, 0), node(105, 6608, 1, 0),
+    node(116, 6609, 1, 0), node(114, 6610, 1, 0), node(59, 0, 0, 854), node(59, 0, 0, 855),
+    node(108, 6611, 1, 0), node(59, 0, 0, 856), node(59, 0, 0, 857), node(110, 6612, 1, 0),
+    node(100, 6613, 1, 0), node(59, 0, 0, 858), node(116, 6614, 1, 0), node(116, 6615, 2, 0),
+    node(114, 6617, 1, 0), node(59, 0, 0, 195), node(59, 0, 0, 859), node(59, 0, 0, 860),
+    node(59, 0, 0, 861), node(59, 0, 0, 862), node(101, 6618, 1, 0), node(97, 6619, 1, 0),
+    node(105, 6620, 1, 0), node(114, 6621, 1, 0), node(59, 0, 0, 863), node(115, 6622, 1, 0),
+    node(101, 6623, 1, 0), node(116, 6624, 1, 0), node(114, 6625, 1, 0), node(103, 6626, 1, 0),
+    node(116, 6627, 1, 0), node(59, 0, 0, 864), node(110, 6628, 1, 0), node(59, 0, 0, 865),
+    node(100, 6629, 1, 0), node(59, 0, 0, 866), node(111, 6630, 1, 0), node(59, 0, 0, 867),
+    node(59, 0, 0, 868), node(59, 0, 0, 869), node(114, 6631, 1, 0), node(107, 6632, 1, 0),
+    node(59, 0, 0, 870), node(59, 0, 0, 871), node(101, 6633, 1, 0), node(115, 6634, 1, 0),
+    node(114, 6635, 1, 0), node(115, 6636, 1, 0), node(114, 6637, 1, 0), node(59, 0, 0, 872),
+    node(59, 0, 0, 873), node(104, 6638, 1, 0), node(97, 6639, 1, 0), node(110, 6640, 1, 0),
+    node(59, 0, 0, 874), node(115, 6641, 1, 0), node(111, 6642, 4, 0), node(114, 6646, 1, 0),
+    node(97, 6647, 1, 0), node(59, 0, 0, 875), node(114, 6648, 1, 0), node(59, 0, 0, 498),
+    node(116, 6649, 1, 0), node(114, 6650, 1, 0), node(116, 6651, 1, 876), node(59, 0, 0, 877),
+    node(98, 6652, 1, 0), node(100, 6653, 2, 0), node(115, 6655, 1, 0), node(115, 6656, 1, 0),
+    node(115, 6657, 1, 0), node(109, 6658, 1, 0), node(59, 0, 0, 878), node(97, 6659, 1, 0),
+    node(114, 6660, 1, 0), node(116, 6661, 1, 0), node(104, 6662, 1, 0), node(104, 6663, 1, 0),
+    node(59, 0, 0, 70), node(101, 6664, 1, 0), node(59, 0, 0, 879), node(59, 0, 0, 880),
+    node(111, 6665, 1, 0), node(59, 0, 0, 881), node(97, 6666, 1, 0), node(59, 0, 0, 882),
+    node(101, 6667, 1, 0), node(110, 6668, 1, 0), node(108, 6669, 1, 0), node(59, 0, 0, 883),
+    node(100, 6670, 1, 0), node(59, 0, 0, 884), node(59, 0, 0, 885), node(107, 6671, 1, 0),
+    node(59, 0, 0, 886), node(111, 6672, 1, 0), node(59, 0, 0, 887), node(118, 6673, 1, 0),
+    node(114, 6674, 1, 0), node(59, 0, 0, 888), node(116, 6675, 2, 0), node(59, 0, 0, 213),
+    node(108, 6677, 1, 0), node(59, 0, 0, 889), node(59, 0, 0, 890), node(59, 0, 0, 891),
+    node(59, 0, 0, 892), node(59, 0, 0, 893), node(59, 0, 0, 894), node(97, 6678, 1, 0),
+    node(114, 6679, 1, 0), node(59, 0, 0, 217), node(108, 6680, 1, 0), node(59, 0, 0, 219),
+    node(59, 0, 0, 895), node(59, 0, 0, 896), node(101, 6681, 1, 0), node(59, 0, 0, 897),
+    node(69, 6682, 1, 0), node(100, 6683, 1, 0), node(118, 6684, 3, 0), node(59, 0, 0, 898),
+    node(118, 6687, 3, 0), node(108, 6690, 1, 0), node(108, 6691, 1, 0), node(59, 0, 0, 899),
+    node(110, 6692, 1, 0), node(101, 6693, 1, 0), node(59, 0, 0, 220), node(101, 6694, 1, 0),
+    node(59, 0, 0, 900), node(59, 0, 0, 901), node(99, 6695, 1, 0), node(119, 6696, 1, 0),
+    node(116, 6697, 1, 0), node(59, 0, 0, 902), node(101, 6698, 1, 0), node(101, 6699, 1, 0),
+    node(116, 6700, 2, 0), node(59, 0, 0, 903), node(113, 6702, 1, 0), node(59, 0, 0, 514),
+    node(59, 0, 0, 516), node(98, 6703, 1, 0), node(112, 6704, 1, 0), node(59, 0, 0, 904),
+    node(59, 0, 0, 905), node(101, 6705, 1, 0), node(59, 0, 0, 221), node(101, 6706, 1, 0),
+    node(59, 0, 0, 906), node(59, 0, 0, 907), node(101, 6707, 1, 0), node(101, 6708, 1, 908),
+    node(110, 6709, 1, 0), node(111, 6710, 1, 0), node(59, 0, 0, 909), node(104, 6711, 1, 0),
+    node(114, 6712, 1, 0), node(104, 6713, 1, 0), node(105, 6714, 1, 0), node(114, 6715, 1, 0),
+    node(105, 6716, 1, 0), node(114, 6717, 1, 0), node(105, 6718, 1, 0), node(59, 0, 0, 910),
+    node(59, 0, 0, 911), node(107, 6719, 1, 0), node(59, 0, 0, 912), node(111, 6720, 1, 0),
+    node(114, 6721, 1, 0), node(101, 6722, 1, 913), node(59, 0, 0, 532), node(59, 0, 0, 914),
+    node(99, 6723, 1, 0), node(100, 6724, 1, 0), node(59, 0, 0, 915), node(59, 0, 0, 916),
+    node(101, 6725, 1, 917), node(59, 0, 0, 918), node(59, 0, 0, 919), node(59, 0, 0, 920),
+    node(115, 6726, 1, 0), node(59, 0, 0, 921), node(59, 0, 0, 922), node(59, 0, 0, 923),
+    node(111, 6727, 1, 0), node(115, 6728, 1, 0), node(59, 0, 0, 924), node(59, 0, 0, 925),
+    node(59, 0, 0, 926), node(59, 0, 0, 541), node(111, 6729, 1, 0), node(102, 6730, 1, 0),
+    node(112, 6731, 1, 0), node(104, 6732, 1, 927), node(101, 6733, 1, 928), node(115, 6734, 2, 0),
+    node(59, 0, 0, 929), node(108, 6736, 1, 0), node(109, 6737, 1, 0), node(59, 0, 0, 930),
+    node(116, 6738, 1, 0), node(100, 6739, 1, 0), node(108, 6740, 1, 0), node(110, 6741, 1, 0),
+    node(116, 6742, 1, 0), node(59, 0, 0, 931), node(102, 6743, 1, 0), node(107, 6744, 2, 0),
+    node(118, 6746, 1, 0), node(99, 6747, 1, 0), node(59, 0, 0, 932), node(105, 6748, 1, 0),
+    node(111, 6749, 1, 0), node(117, 6750, 1, 0), node(59, 0, 0, 933), node(110, 6751, 1, 48),
+    node(105, 6752, 1, 0), node(119, 6753, 1, 0), node(105, 6754, 1, 0), node(59, 0, 0, 547),
+    node(59, 0, 0, 934), node(112, 6755, 1, 0), node(117, 6756, 1, 0), node(113, 6757, 1, 0),
+    node(97, 6758, 1, 0), node(101, 6759, 1, 0), node(115, 6760, 1, 0), node(105, 6761, 1, 0),
+    node(59, 0, 0, 935), node(115, 6762, 1, 0), node(59, 0, 0, 936), node(109, 6763, 1, 0),
+    node(108, 6764, 1, 0), node(105, 6765, 1, 0), node(117, 6766, 1, 0), node(111, 6767, 1, 0),
+    node(59, 0, 0, 937), node(108, 6768, 1, 0), node(112, 6769, 1, 0), node(101, 6770, 1, 0),
+    node(114, 6771, 1, 0), node(110, 6772, 1, 0), node(59, 0, 0, 938), node(101, 6773, 1, 0),
+    node(59, 0, 0, 939), node(108, 6774, 1, 0), node(59, 0, 0, 940), node(101, 6775, 1, 0),
+    node(59, 0, 0, 346), node(116, 6776, 1, 0), node(59, 0, 0, 941), node(59, 0, 0, 942),
+    node(101, 6777, 1, 0), node(59, 0, 0, 560), node(112, 6778, 1, 0), node(59, 0, 0, 943),
+    node(102, 6779, 1, 0), node(59, 0, 0, 944), node(115, 6780, 1, 0), node(107, 6781, 1, 0),
+    node(112, 6782, 1, 0), node(108, 6783, 1, 0), node(105, 6784, 1, 0), node(108, 6785, 1, 0),
+    node(59, 0, 0, 945), node(108, 6786, 1, 0), node(59, 0, 0, 946), node(110, 6787, 1, 0),
+    node(59, 0, 0, 947), node(59, 0, 0, 948), node(101, 6788, 1, 0), node(107, 6789, 1, 0),
+    node(59, 0, 0, 949), node(108, 6790, 2, 0), node(110, 6792, 1, 0), node(108, 6793, 1, 0),
+    node(59, 0, 0, 950), node(97, 6794, 1, 0), node(59, 0, 0, 951), node(114, 6795, 1, 0),
+    node(110, 6796, 1, 0), node(97, 6797, 1, 0), node(59, 0, 0, 342), node(116, 6798, 1, 0),
+    node(114, 6799, 1, 0), node(59, 0, 0, 952), node(59, 0, 0, 953), node(108, 6800, 1, 0),
+    node(97, 6801, 1, 0), node(104, 6802, 1, 0), node(108, 6803, 1, 0), node(114, 6804, 1, 0),
+    node(115, 6805, 1, 0), node(116, 6806, 1, 0), node(103, 6807, 1, 0), node(59, 0, 0, 954),
+    node(59, 0, 0, 955), node(116, 6808, 2, 0), node(59, 0, 0, 956), node(59, 0, 0, 957),
+    node(59, 0, 0, 958), node(59, 0, 0, 959), node(59, 0, 0, 960), node(115, 6810, 1, 0),
+    node(101, 6811, 1, 0), node(116, 6812, 1, 0), node(105, 6813, 1, 0), node(59, 0, 0, 961),
+    node(111, 6814, 1, 0), node(59, 0, 0, 962), node(114, 6815, 1, 0), node(101, 6816, 1, 0),
+    node(115, 6817, 1, 0), node(59, 0, 0, 963), node(59, 0, 0, 964), node(116, 6818, 1, 0),
+    node(97, 6819, 1, 0), node(101, 6820, 1, 0), node(59, 0, 0, 965), node(110, 6821, 1, 0),
+    node(59, 0, 0, 966), node(108, 6822, 1, 0), node(59, 0, 0, 967), node(59, 0, 0, 968),
+    node(109, 6823, 1, 0), node(105, 6824, 1, 0), node(59, 0, 0, 969), node(59, 0, 0, 970),
+    node(59, 0, 0, 971), node(59, 0, 0, 972), node(107, 6825, 1, 0), node(59, 0, 0, 973),
+    node(111, 6826, 1, 0), node(114, 6827, 1, 0), node(110, 6828, 1, 0), node(59, 0, 0, 974),
+    node(110, 6829, 1, 0), node(59, 0, 0, 975), node(121, 6830, 1, 0), node(109, 6831, 1, 0),
+    node(112, 6832, 1, 0), node(59, 0, 0, 976), node(102, 6833, 1, 0), node(118, 6834, 1, 0),
+    node(116, 6835, 1, 0), node(59, 0, 0, 579), node(59, 0, 0, 977), node(59, 0, 0, 978),
+    node(59, 0, 0, 979), node(117, 6836, 1, 0), node(114, 6837, 1, 0), node(59, 0, 0, 479),
+    node(115, 6838, 1, 0), node(112, 6839, 1, 0), node(114, 6840, 1, 0), node(59, 0, 0, 980),
+    node(59, 0, 0, 981), node(121, 6841, 1, 0), node(114, 6842, 1, 0), node(115, 6843, 2, 0),
+    node(59, 0, 0, 982), node(115, 6845, 1, 0), node(59, 0, 0, 983), node(115, 6846, 1, 0),
+    node(59, 0, 0, 984), node(101, 6847, 1, 0), node(115, 6848, 1, 0), node(59, 0, 0, 985),
+    node(101, 6849, 1, 0), node(115, 6850, 1, 0), node(101, 6851, 1, 0), node(102, 6852, 1, 0),
+    node(59, 0, 0, 561), node(110, 6853, 1, 0), node(101, 6854, 1, 0), node(102, 6855, 1, 0),
+    node(59, 0, 0, 986), node(103, 6856, 1, 0), node(59, 0, 0, 199), node(116, 6857, 1, 0),
+    node(111, 6858, 1, 0), node(108, 6859, 1, 0), node(59, 0, 0, 987), node(59, 0, 0, 988),
+    node(117, 6860, 1, 0), node(114, 6861, 1, 0), node(116, 6862, 3, 0), node(109, 6865, 1, 0),
+    node(98, 6866, 1, 0), node(112, 6867, 1, 0), node(112, 6868, 1, 0), node(117, 6869, 1, 0),
+    node(113, 6870, 1, 0), node(97, 6871, 1, 0), node(101, 6872, 1, 0), node(115, 6873, 1, 0),
+    node(105, 6874, 1, 0), node(116, 6875, 1, 0), node(117, 6876, 1, 0), node(111, 6877, 1, 0),
+    node(111, 6878, 1, 0), node(117, 6879, 1, 0), node(114, 6880, 1, 0), node(108, 6881, 1, 0),
+    node(59, 0, 0, 989), node(59, 0, 0, 990), node(117, 6882, 1, 0), node(116, 6883, 3, 0),
+    node(109, 6886, 1, 0), node(98, 6887, 1, 0), node(112, 6888, 1, 0), node(59, 0, 0, 991),
+    node(107, 6889, 1, 0), node(59, 0, 0, 992), node(111, 6890, 1, 0), node(114, 6891, 1, 0),
+    node(59, 0, 0, 593), node(116, 6892, 1, 0), node(110, 6893, 1, 0), node(108, 6894, 1, 0),
+    node(99, 6895, 1, 0), node(52, 6896, 1, 0), node(102, 6897, 1, 0), node(59, 0, 0, 993),
+    node(115, 6898, 1, 0), node(118, 6899, 1, 0), node(97, 6900, 1, 0), node(115, 6901, 1, 0),
+    node(112, 6902, 1, 0), node(59, 0, 0, 22), node(109, 6903, 1, 0), node(59, 0, 0, 596),
+    node(59, 0, 0, 994), node(59, 0, 0, 597), node(98, 6904, 2, 0), node(100, 6906, 1, 0),
+    node(116, 6907, 1, 0), node(114, 6908, 1, 0), node(114, 6909, 1, 0), node(101, 6910, 1, 0),
+    node(59, 0, 0, 680), node(103, 6911, 1, 0), node(116, 6912, 1, 0), node(110, 6913, 1, 0),
+    node(117, 6914, 1, 0), node(59, 0, 0, 995), node(109, 6915, 1, 0), node(105, 6916, 1, 0),
+    node(59, 0, 0, 996), node(107, 6917, 1, 0), node(59, 0, 0, 997), node(97, 6918, 1, 0),
+    node(101, 6919, 1, 998), node(59, 0, 0, 999), node(101, 6920, 1, 0), node(59, 0, 0, 608),
+    node(59, 0, 0, 1000), node(99, 6921, 1, 0), node(59, 0, 0, 1001), node(116, 6922, 1, 0),
+    node(101, 6923, 1, 1002), node(59, 0, 0, 1003), node(59, 0, 0, 1004), node(59, 0, 0, 1005),
+    node(110, 6924, 2, 0), node(112, 6926, 1, 0), node(59, 0, 0, 1006), node(59, 0, 0, 1007),
+    node(59, 0, 0, 1008), node(111, 6927, 1, 0), node(110, 6928, 1, 0), node(112, 6929, 1, 0),
+    node(59, 0, 0, 1009), node(59, 0, 0, 356), node(111, 6930, 1, 0), node(114, 6931, 1, 0),
+    node(110, 6932, 2, 0), node(112, 6934, 1, 0), node(59, 0, 0, 1010), node(59, 0, 0, 1011),
+    node(59, 0, 0, 1012), node(101, 6935, 1, 0), node(59, 0, 0, 1013), node(59, 0, 0, 1014),
+    node(108, 6936, 1, 0), node(59, 0, 0, 1015), node(59, 0, 0, 1016), node(116, 6937, 1, 0),
+    node(115, 6938, 1, 0), node(112, 6939, 1, 0), node(116, 6940, 1, 0), node(105, 6941, 1, 0),
+    node(59, 0, 0, 238), node(111, 6942, 1, 0), node(111, 6943, 1, 0), node(103, 6944, 1, 0),
+    node(98, 6945, 1, 0), node(112, 6946, 1, 0), node(101, 6947, 1, 0), node(105, 6948, 1, 0),
+    node(59, 0, 0, 1017), node(114, 6949, 1, 0), node(59, 0, 0, 1018), node(112, 6950, 1, 0),
+    node(114, 6951, 1, 0), node(59, 0, 0, 1019), node(59, 0, 0, 1020), node(59, 0, 0, 1021),
+    node(59, 0, 0, 551), node(59, 0, 0, 1022), node(69, 6952, 1, 0), node(101, 6953, 1, 0),
+    node(69, 6954, 1, 0), node(101, 6955, 1, 0), node(97, 6956, 1, 0), node(59, 0, 0, 1023),
+    node(114, 6957, 1, 0), node(59, 0, 0, 136), node(113, 6958, 1, 0), node(112, 6959, 1, 0),
+    node(104, 6960, 1, 0), node(59, 0, 0, 1024), node(59, 0, 0, 1025), node(59, 0, 0, 1026),
+    node(59, 0, 0, 1027), node(59, 0, 0, 1028), node(59, 0, 0, 1029), node(59, 0, 0, 1030),
+    node(115, 6961, 1, 0), node(101, 6962, 1, 0), node(59, 0, 0, 1031), node(59, 0, 0, 1032),
+    node(112, 6963, 1, 0), node(115, 6964, 1, 0), node(59, 0, 0, 1033), node(101, 6965, 1, 0),
+    node(101, 6966, 1, 1034), node(59, 0, 0, 1035), node(101, 6967, 1, 0), node(110, 6968, 1, 0),
+    node(102, 6969, 1, 0), node(114, 6970, 1, 0), node(59, 0, 0, 638), node(59, 0, 0, 1036),
+    node(59, 0, 0, 639), node(117, 6971, 1, 0), node(59, 0, 0, 1037), node(59, 0, 0, 643),
+    node(97, 6972, 1, 0), node(59, 0, 0, 1038), node(101, 6973, 1, 0), node(108, 6974, 1, 0),
+    node(59, 0, 0, 402), node(59, 0, 0, 1039), node(108, 6975, 1, 0), node(115, 6976, 1, 0),
+    node(59, 0, 0, 1040), node(59, 0, 0, 645), node(116, 6977, 1, 0), node(97, 6978, 1, 0),
+    node(68, 6979, 1, 0), node(68, 6980, 1, 0), node(77, 6981, 1, 0), node(80, 6982, 1, 0),
+    node(84, 6983, 1, 0), node(105, 6984, 1, 0), node(117, 6985, 1, 0), node(59, 0, 0, 1041),
+    node(101, 6986, 1, 0), node(59, 0, 0, 1042), node(114, 6987, 1, 0), node(117, 6988, 1, 0),
+    node(114, 6989, 1, 0), node(59, 0, 0, 1043), node(104, 6990, 1, 0), node(59, 0, 0, 1044),
+    node(59, 0, 0, 1045), node(116, 6991, 1, 0), node(100, 6992, 1, 0), node(101, 6993, 1, 0),
+    node(59, 0, 0, 1046), node(97, 6994, 1, 0), node(67, 6995, 1, 0), node(68, 6996, 1, 0),
+    node(76, 6997, 2, 0), node(82, 6999, 1, 0), node(85, 7000, 1, 0), node(86, 7001, 1, 0),
+    node(114, 7002, 1, 0), node(101, 7003, 1, 0), node(102, 7004, 1, 0), node(103, 7005, 1, 0),
+    node(101, 7006, 2, 0), node(114, 7008, 1, 0), node(59, 0, 0, 1047), node(59, 0, 0, 651),
+    node(59, 0, 0, 1048), node(59, 0, 0, 652), node(116, 7009, 1, 0), node(109, 7010, 1, 0),
+    node(101, 7011, 1, 0), node(110, 7012, 1, 0), node(105, 7013, 1, 0), node(98, 7014, 1, 0),
+    node(59, 0, 0, 801), node(110, 7015, 1, 0), node(83, 7016, 1, 0), node(86, 7017, 1, 0),
+    node(59, 0, 0, 1049), node(114, 7018, 1, 0), node(59, 0, 0, 1050), node(59, 0, 0, 1051),
+    node(59, 0, 0, 1052), node(114, 7019, 6, 0), node(59, 0, 0, 1053), node(116, 7025, 1, 0),
+    node(110, 7026, 1, 0), node(59, 0, 0, 1054), node(119, 7027, 1, 0), node(117, 7028, 1, 0),
+    node(59, 0, 0, 661), node(59, 0, 0, 662), node(97, 7029, 1, 0), node(115, 7030, 1, 0),
+    node(97, 7031, 1, 0), node(101, 7032, 1, 0), node(98, 7033, 1, 0), node(59, 0, 0, 1055),
+    node(59, 0, 0, 1056), node(59, 0, 0, 1057), node(59, 0, 0, 1058), node(59, 0, 0, 1059),
+    node(101, 7034, 1, 0), node(59, 0, 0, 1060), node(59, 0, 0, 1061), node(103, 7035, 1, 0),
+    node(114, 7036, 1, 0), node(105, 7037, 1, 0), node(117, 7038, 1, 0), node(119, 7039, 1, 0),
+    node(111, 7040, 1, 0), node(103, 7041, 1, 0), node(101, 7042, 3, 0), node(105, 7045, 1, 0),
+    node(68, 7046, 1, 0), node(84, 7047, 1, 0), node(86, 7048, 1, 0), node(99, 7049, 1, 0),
+    node(114, 7050, 1, 0), node(103, 7051, 1, 0), node(117, 7052, 1, 0), node(108, 7053, 1, 0),
+    node(101, 7054, 1, 0), node(115, 7055, 1, 0), node(97, 7056, 1, 0), node(108, 7057, 1, 0),
+    node(114, 7058, 1, 0), node(59, 0, 0, 1062), node(102, 7059, 1, 0), node(103, 7060, 1, 0),
+    node(102, 7061, 1, 0), node(103, 7062, 1, 0), node(101, 7063, 1, 0), node(105, 7064, 1, 0),
+    node(59, 0, 0, 1063), node(83, 7065, 1, 0), node(116, 7066, 1, 0), node(108, 7067, 1, 0),
+    node(59, 0, 0, 1064), node(59, 0, 0, 1065), node(59, 0, 0, 1066), node(118, 7068, 1, 0),
+    node(71, 7069, 1, 0), node(76, 7070, 1, 0), node(101, 7071, 1, 0), node(107, 7072, 1, 0),
+    node(97, 7073, 1, 0), node(103, 7074, 1, 0), node(67, 7075, 1, 0), node(98, 7076, 1, 0),
+    node(109, 7077, 1, 0), node(97, 7078, 1, 0), node(115, 7079, 1, 0), node(97, 7080, 1, 0),
+    node(112, 7081, 2, 0), node(116, 7083, 1, 0), node(115, 7084, 6, 0), node(116, 7090, 1, 0),
+    node(99, 7091, 1, 0), node(101, 7092, 1, 0), node(104, 7093, 1, 0), node(97, 7094, 1, 0),
+    node(115, 7095, 1, 0), node(99, 7096, 1, 0), node(101, 7097, 1, 0), node(100, 7098, 1, 0),
+    node(116, 7099, 1, 0), node(59, 0, 0, 669), node(59, 0, 0, 671), node(59, 0, 0, 1067),
+    node(59, 0, 0, 672), node(110, 7100, 1, 0), node(114, 7101, 1, 0), node(59, 0, 0, 674),
+    node(59, 0, 0, 675), node(59, 0, 0, 1068), node(114, 7102, 1, 0), node(97, 7103, 1, 0),
+    node(114, 7104, 1, 0), node(108, 7105, 1, 0), node(110, 7106, 1, 0), node(114, 7107, 1, 0),
+    node(101, 7108, 1, 0), node(116, 7109, 1, 0), node(116, 7110, 1, 0), node(59, 0, 0, 1069),
+    node(59, 0, 0, 1070), node(59, 0, 0, 1071), node(59, 0, 0, 1072), node(101, 7111, 2, 0),
+    node(110, 7113, 1, 0), node(114, 7114, 1, 0), node(101, 7115, 1, 0), node(111, 7116, 2, 0),
+    node(108, 7118, 1, 0), node(101, 7119, 1, 0), node(114, 7120, 1, 0), node(112, 7121, 3, 0),
+    node(101, 7124, 1, 0), node(114, 7125, 1, 0), node(109, 7126, 1, 0), node(97, 7127, 1, 0),
+    node(108, 7128, 1, 0), node(59, 0, 0, 1073), node(59, 0, 0, 1074), node(59, 0, 0, 1075),
+    node(59, 0, 0, 1076), node(59, 0, 0, 1077), node(111, 7129, 1, 0), node(101, 7130, 1, 0),
+    node(105, 7131, 1, 0), node(112, 7132, 1, 0), node(105, 7133, 1, 0), node(59, 0, 0, 255),
+    node(73, 7134, 1, 0), node(83, 7135, 1, 0), node(85, 7136, 1, 0), node(59, 0, 0, 113),
+    node(69, 7137, 1, 0), node(100, 7138, 1, 0), node(97, 7139, 1, 0), node(101, 7140, 1, 0),
+    node(59, 0, 0, 115), node(59, 0, 0, 1078), node(59, 0, 0, 1079), node(111, 7141, 1, 0),
+    node(112, 7142, 1, 0), node(97, 7143, 1, 0), node(113, 7144, 1, 0), node(117, 7145, 1, 0),
+    node(105, 7146, 1, 0), node(68, 7147, 1, 0), node(59, 0, 0, 1080), node(59, 0, 0, 683),
+    node(105, 7148, 1, 0), node(59, 0, 0, 1081), node(59, 0, 0, 1082), node(59, 0, 0, 685),
+    node(97, 7149, 1, 0), node(114, 7150, 1, 0), node(97, 7151, 1, 0), node(108, 7152, 1, 0),
+    node(119, 7153, 3, 0), node(65, 7156, 1, 0), node(108, 7157, 1, 0), node(114, 7158, 1, 0),
+    node(119, 7159, 1, 0), node(97, 7160, 1, 0), node(101, 7161, 1, 0), node(105, 7162, 1, 0),
+    node(110, 7163, 1, 0), node(59, 0, 0, 1083), node(59, 0, 0, 1084), node(59, 0, 0, 359),
+    node(97, 7164, 1, 0), node(105, 7165, 1, 0), node(59, 0, 0, 1085), node(59, 0, 0, 693),
+    node(59, 0, 0, 1086), node(59, 0, 0, 1087), node(100, 7166, 1, 0), node(59, 0, 0, 695),
+    node(59, 0, 0, 1088), node(59, 0, 0, 696), node(109, 7167, 1, 0), node(59, 0, 0, 1089),
+    node(112, 7168, 1, 0), node(59, 0, 0, 1090), node(97, 7169, 8, 0), node(98, 7177, 2, 0),
+    node(59, 0, 0, 1091), node(114, 7179, 1, 0), node(59, 0, 0, 1092), node(59, 0, 0, 22),
+    node(101, 7180, 1, 0), node(113, 7181, 1, 0), node(59, 0, 0, 703), node(110, 7182, 1, 0),
+    node(110, 7183, 1, 0), node(115, 7184, 1, 0), node(105, 7185, 1, 0), node(109, 7186, 2, 0),
+    node(59, 0, 0, 1093), node(59, 0, 0, 1094), node(103, 7188, 1, 0), node(114, 7189, 1, 0),
+    node(59, 0, 0, 1095), node(101, 7190, 1, 0), node(118, 7191, 1, 0), node(59, 0, 0, 286),
+    node(110, 7192, 1, 0), node(59, 0, 0, 621), node(99, 7193, 1, 0), node(59, 0, 0, 622),
+    node(116, 7194, 1, 0), node(117, 7195, 1, 0), node(109, 7196, 1, 0), node(117, 7197, 1, 0),
+    node(114, 7198, 1, 0), node(97, 7199, 1, 0), node(117, 7200, 1, 0), node(59, 0, 0, 124),
+    node(103, 7201, 1, 0), node(59, 0, 0, 947), node(111, 7202, 1, 0), node(113, 7203, 1, 0),
+    node(114, 7204, 1, 0), node(118, 7205, 1, 0), node(59, 0, 0, 145), node(59, 0, 0, 1096),
+    node(59, 0, 0, 1097), node(117, 7206, 1, 0), node(115, 7207, 1, 0), node(101, 7208, 1, 0),
+    node(59, 0, 0, 1098), node(59, 0, 0, 749), node(117, 7209, 1, 0), node(59, 0, 0, 401),
+    node(59, 0, 0, 754), node(59, 0, 0, 1099), node(59, 0, 0, 1100), node(117, 7210, 1, 0),
+    node(59, 0, 0, 1101), node(59, 0, 0, 1102), node(59, 0, 0, 1103), node(59, 0, 0, 1104),
+    node(59, 0, 0, 757), node(109, 7211, 1, 0), node(118, 7212, 1, 0), node(100, 7213, 1, 0),
+    node(97, 7214, 1, 0), node(59, 0, 0, 409), node(97, 7215, 1, 0), node(100, 7216, 5, 0),
+    node(110, 7221, 1, 0), node(59, 0, 0, 1105), node(114, 7222, 1, 0), node(105, 7223, 1, 0),
+    node(59, 0, 0, 1037), node(113, 7224, 1, 0), node(59, 0, 0, 1106), node(59, 0, 0, 1107),
+    node(109, 7225, 1, 0), node(120, 7226, 1, 0), node(116, 7227, 1, 0), node(59, 0, 0, 536),
+    node(59, 0, 0, 1108), node(59, 0, 0, 1109), node(108, 7228, 1, 0), node(114, 7229, 1, 0),
+    node(59, 0, 0, 1110), node(112, 7230, 1, 0), node(97, 7231, 1, 0), node(59, 0, 0, 1111),
+    node(59, 0, 0, 1112), node(59, 0, 0, 1113), node(59, 0, 0, 1114), node(109, 7232, 1, 0),
+    node(113, 7233, 2, 0), node(101, 7235, 1, 0), node(101, 7236, 1, 0), node(59, 0, 0, 772),
+    node(114, 7237, 1, 0), node(110, 7238, 1, 0), node(59, 0, 0, 1115), node(59, 0, 0, 1116),
+    node(59, 0, 0, 1117), node(119, 7239, 1, 0), node(59, 0, 0, 1118), node(114, 7240, 1, 0),
+    node(113, 7241, 1, 0), node(118, 7242, 1, 0), node(59, 0, 0, 1119), node(100, 7243, 2, 0),
+    node(97, 7245, 1, 0), node(59, 0, 0, 154), node(111, 7246, 1, 0), node(59, 0, 0, 1120),
+    node(59, 0, 0, 1121), node(59, 0, 0, 1122), node(59, 0, 0, 1123), node(111, 7247, 1, 0),
+    node(117, 7248, 1, 0), node(115, 7249, 1, 0), node(97, 7250, 1, 0), node(98, 7251, 1, 0),
+    node(114, 7252, 1, 0), node(119, 7253, 1, 0), node(114, 7254, 1, 0), node(111, 7255, 1, 0),
+    node(59, 0, 0, 1124), node(59, 0, 0, 1125), node(59, 0, 0, 1126), node(101, 7256, 1, 0),
+    node(114, 7257, 1, 0), node(59, 0, 0, 790), node(59, 0, 0, 1127), node(59, 0, 0, 1128),
+    node(59, 0, 0, 1129), node(59, 0, 0, 792), node(59, 0, 0, 1130), node(114, 7258, 1, 0),
+    node(59, 0, 0, 1131), node(101, 7259, 1, 0), node(59, 0, 0, 794), node(59, 0, 0, 1132),
+    node(59, 0, 0, 1133), node(59, 0, 0, 1134), node(110, 7260, 1, 0), node(59, 0, 0, 430),
+    node(110, 7261, 1, 0), node(116, 7262, 2, 0), node(59, 0, 0, 1135), node(59, 0, 0, 1136),
+    node(68, 7264, 1, 0), node(115, 7265, 1, 0), node(97, 7266, 1, 0), node(110, 7267, 1, 0),
+    node(103, 7268, 1, 0), node(59, 0, 0, 1137), node(59, 0, 0, 1138), node(59, 0, 0, 1139),
+    node(59, 0, 0, 1049), node(110, 7269, 1, 0), node(59, 0, 0, 456), node(59, 0, 0, 1140),
+    node(59, 0, 0, 808), node(59, 0, 0, 1141), node(59, 0, 0, 1142), node(59, 0, 0, 1143),
+    node(59, 0, 0, 1144), node(59, 0, 0, 1145), node(59, 0, 0, 809), node(59, 0, 0, 1146),
+    node(59, 0, 0, 1147), node(59, 0, 0, 1148), node(59, 0, 0, 1149), node(59, 0, 0, 1150),
+    node(59, 0, 0, 1151), node(59, 0, 0, 1152), node(59, 0, 0, 1153), node(59, 0, 0, 1154),
+    node(110, 7270, 1, 0), node(59, 0, 0, 1155), node(111, 7271, 2, 0), node(59, 0, 0, 1156),
+    node(111, 7273, 1, 0), node(59, 0, 0, 1157), node(116, 7274, 1, 0), node(114, 7275, 1, 0),
+    node(59, 0, 0, 1158), node(59, 0, 0, 821), node(101, 7276, 1, 0), node(108, 7277, 1, 0),
+    node(115, 7278, 1, 0), node(59, 0, 0, 453), node(101, 7279, 1, 0), node(59, 0, 0, 1159),
+    node(59, 0, 0, 309), node(59, 0, 0, 1160), node(114, 7280, 1, 0), node(59, 0, 0, 1161),
+    node(117, 7281, 1, 0), node(59, 0, 0, 500), node(59, 0, 0, 1162), node(111, 7282, 1, 0),
+    node(111, 7283, 1, 0), node(59, 0, 0, 1163), node(102, 7284, 1, 0), node(103, 7285, 1, 0),
+    node(59, 0, 0, 1164), node(59, 0, 0, 458), node(59, 0, 0, 1165), node(59, 0, 0, 1166),
+    node(59, 0, 0, 420), node(59, 0, 0, 825), node(59, 0, 0, 826), node(59, 0, 0, 553),
+    node(59, 0, 0, 1167), node(110, 7286, 1, 0), node(114, 7287, 1, 0), node(59, 0, 0, 1168),
+    node(105, 7288, 1, 0), node(59, 0, 0, 830), node(59, 0, 0, 1169), node(114, 7289, 1, 0),
+    node(97, 7290, 1, 0), node(104, 7291, 1, 0), node(100, 7292, 1, 0), node(59, 0, 0, 835),
+    node(116, 7293, 1, 0), node(59, 0, 0, 1170), node(59, 0, 0, 1171), node(59, 0, 0, 1172),
+    node(59, 0, 0, 1173), node(59, 0, 0, 1174), node(59, 0, 0, 1175), node(59, 0, 0, 1176),
+    node(59, 0, 0, 1177), node(121, 7294, 1, 0), node(59, 0, 0, 327), node(59, 0, 0, 1178),
+    node(59, 0, 0, 477), node(115, 7295, 1, 0), node(59, 0, 0, 1179), node(59, 0, 0, 1180),
+    node(59, 0, 0, 1181), node(59, 0, 0, 1182), node(109, 7296, 1, 0), node(59, 0, 0, 1183),
+    node(59, 0, 0, 1184), node(59, 0, 0, 481), node(59, 0, 0, 492), node(100, 7297, 1, 0),
+    node(117, 7298, 1, 0), node(59, 0, 0, 1185), node(59, 0, 0, 1186), node(59, 0, 0, 706),
+    node(114, 7299, 1, 0), node(97, 7300, 1, 0), node(114, 7301, 1, 0), node(114, 7302, 1, 0),
+    node(102, 7303, 1, 0), node(103, 7304, 1, 0), node(114, 7305, 1, 0), node(110, 7306, 1, 0),
+    node(59, 0, 0, 1187), node(111, 7307, 2, 0), node(59, 0, 0, 1188), node(112, 7309, 1, 0),
+    node(116, 7310, 1, 0), node(103, 7311, 1, 0), node(113, 7312, 1, 0), node(114, 7313, 1, 0),
+    node(109, 7314, 1, 0), node(59, 0, 0, 1189), node(59, 0, 0, 1190), node(59, 0, 0, 1191),
+    node(101, 7315, 1, 0), node(59, 0, 0, 1192), node(59, 0, 0, 1193), node(59, 0, 0, 1194),
+    node(97, 7316, 1, 0), node(111, 7317, 1, 0), node(102, 7318, 1, 0), node(112, 7319, 1, 0),
+    node(103, 7320, 1, 0), node(114, 7321, 1, 0), node(59, 0, 0, 1195), node(115, 7322, 1, 0),
+    node(59, 0, 0, 1196), node(59, 0, 0, 1197), node(101, 7323, 1, 0), node(59, 0, 0, 1198),
+    node(101, 7324, 1, 0), node(59, 0, 0, 1199), node(59, 0, 0, 1200), node(59, 0, 0, 965),
+    node(59, 0, 0, 1201), node(59, 0, 0, 1202), node(59, 0, 0, 1203), node(59, 0, 0, 1204),
+    node(116, 7325, 1, 0), node(59, 0, 0, 1205), node(97, 7326, 1, 0), node(114, 7327, 1, 0),
+    node(101, 7328, 1, 0), node(101, 7329, 1, 0), node(59, 0, 0, 200), node(100, 7330, 1, 0),
+    node(108, 7331, 1, 0), node(117, 7332, 1, 0), node(59, 0, 0, 1206), node(59, 0, 0, 1207),
+    node(101, 7333, 1, 0), node(59, 0, 0, 140), node(59, 0, 0, 1208), node(59, 0, 0, 876),
+    node(59, 0, 0, 1209), node(59, 0, 0, 1210), node(117, 7334, 1, 0), node(59, 0, 0, 39),
+    node(59, 0, 0, 1211), node(59, 0, 0, 20), node(97, 7335, 1, 0), node(114, 7336, 1, 0),
+    node(105, 7337, 1, 0), node(97, 7338, 1, 0), node(59, 0, 0, 1212), node(59, 0, 0, 1213),
+    node(59, 0, 0, 1214), node(120, 7339, 1, 0), node(108, 7340, 2, 0), node(59, 0, 0, 1215),
+    node(59, 0, 0, 1216), node(59, 0, 0, 1217), node(111, 7342, 1, 0), node(59, 0, 0, 1218),
+    node(119, 7343, 1, 0), node(59, 0, 0, 1219), node(59, 0, 0, 599), node(59, 0, 0, 1220),
+    node(115, 7344, 1, 0), node(97, 7345, 1, 0), node(114, 7346, 1, 0), node(105, 7347, 1, 0),
+    node(97, 7348, 1, 0), node(59, 0, 0, 1221), node(59, 0, 0, 1222), node(111, 7349, 1, 0),
+    node(97, 7350, 1, 0), node(98, 7351, 1, 0), node(99, 7352, 1, 0), node(97, 7353, 1, 0),
+    node(98, 7354, 1, 0), node(99, 7355, 1, 0), node(108, 7356, 1, 0), node(59, 0, 0, 1223),
+    node(116, 7357, 1, 0), node(59, 0, 0, 1224), node(113, 7358, 1, 0), node(59, 0, 0, 1225),
+    node(59, 0, 0, 1226), node(97, 7359, 1, 0), node(59, 0, 0, 1227), node(59, 0, 0, 1228),
+    node(109, 7360, 1, 0), node(112, 7361, 1, 0), node(59, 0, 0, 903), node(101, 7362, 1, 0),
+    node(101, 7363, 1, 0), node(116, 7364, 2, 0), node(113, 7366, 1, 0), node(116, 7367, 2, 0),
+    node(59, 0, 0, 908), node(103, 7369, 1, 0), node(59, 0, 0, 1229), node(59, 0, 0, 1230),
+    node(59, 0, 0, 1231), node(59, 0, 0, 1232), node(110, 7370, 1, 0), node(59, 0, 0, 1233),
+    node(101, 7371, 1, 0), node(59, 0, 0, 1234), node(101, 7372, 1, 0), node(59, 0, 0, 1235),
+    node(119, 7373, 1, 0), node(59, 0, 0, 1236), node(59, 0, 0, 913), node(59, 0, 0, 1237),
+    node(59, 0, 0, 1238), node(59, 0, 0, 917), node(115, 7374, 1, 0), node(110, 7375, 1, 0),
+    node(59, 0, 0, 1239), node(102, 7376, 1, 0), node(59, 0, 0, 1240), node(101, 7377, 1, 0),
+    node(59, 0, 0, 927), node(59, 0, 0, 928), node(59, 0, 0, 1241), node(97, 7378, 1, 0),
+    node(101, 7379, 1, 0), node(59, 0, 0, 1242), node(59, 0, 0, 1243), node(59, 0, 0, 1244),
+    node(59, 0, 0, 1245), node(107, 7380, 1, 0), node(59, 0, 0, 329), node(111, 7381, 1, 0),
+    node(59, 0, 0, 458), node(104, 7382, 1, 0), node(59, 0, 0, 458), node(105, 7383, 1, 0),
+    node(114, 7384, 1, 0), node(59, 0, 0, 1246), node(59, 0, 0, 1247), node(59, 0, 0, 48),
+    node(109, 7385, 1, 0), node(111, 7386, 1, 0), node(110, 7387, 1, 0), node(112, 7388, 1, 0),
+    node(114, 7389, 1, 0), node(59, 0, 0, 240), node(112, 7390, 1, 0), node(113, 7391, 1, 0),
+    node(105, 7392, 1, 0), node(109, 7393, 1, 0), node(59, 0, 0, 336), node(59, 0, 0, 1248),
+    node(97, 7394, 1, 0), node(110, 7395, 1, 0), node(114, 7396, 1, 0), node(59, 0, 0, 551),
+    node(59, 0, 0, 1249), node(59, 0, 0, 1250), node(59, 0, 0, 1251), node(110, 7397, 1, 0),
+    node(116, 7398, 1, 0), node(113, 7399, 1, 0), node(59, 0, 0, 1252), node(59, 0, 0, 1253),
+    node(121, 7400, 1, 0), node(59, 0, 0, 559), node(59, 0, 0, 1254), node(115, 7401, 1, 0),
+    node(59, 0, 0, 1255), node(59, 0, 0, 1256), node(59, 0, 0, 1257), node(59, 0, 0, 1258),
+    node(109, 7402, 1, 0), node(59, 0, 0, 1259), node(59, 0, 0, 1260), node(97, 7403, 1, 0),
+    node(59, 0, 0, 562), node(59, 0, 0, 571), node(100, 7404, 1, 0), node(117, 7405, 1, 0),
+    node(59, 0, 0, 1261), node(59, 0, 0, 1262), node(114, 7406, 1, 0), node(59, 0, 0, 951),
+    node(101, 7407, 1, 0), node(114, 7408, 1, 0), node(59, 0, 0, 1263), node(59, 0, 0, 1264),
+    node(59, 0, 0, 1265), node(114, 7409, 1, 0), node(97, 7410, 1, 0), node(101, 7411, 1, 0),
+    node(105, 7412, 1, 0), node(113, 7413, 1, 0), node(104, 7414, 1, 0), node(100, 7415, 1, 0),
+    node(59, 0, 0, 1266), node(97, 7416, 1, 0), node(59, 0, 0, 1267), node(115, 7417, 1, 0),
+    node(59, 0, 0, 1268), node(110, 7418, 1, 0), node(59, 0, 0, 1269), node(59, 0, 0, 962),
+    node(59, 0, 0, 1270), node(59, 0, 0, 1271), node(114, 7419, 1, 0), node(114, 7420, 1, 0),
+    node(59, 0, 0, 1272), node(59, 0, 0, 1273), node(59, 0, 0, 1274), node(59, 0, 0, 1275),
+    node(110, 7421, 1, 0), node(59, 0, 0, 1276), node(119, 7422, 1, 0), node(59, 0, 0, 601),
+    node(117, 7423, 1, 0), node(59, 0, 0, 811), node(59, 0, 0, 1277), node(105, 7424, 1, 0),
+    node(97, 7425, 1, 0), node(59, 0, 0, 1278), node(59, 0, 0, 1278), node(59, 0, 0, 1279),
+    node(115, 7426, 1, 0), node(114, 7427, 1, 0), node(101, 7428, 1, 0), node(59, 0, 0, 1280),
+    node(115, 7429, 1, 0), node(59, 0, 0, 1281), node(59, 0, 0, 1282), node(59, 0, 0, 1283),
+    node(117, 7430, 1, 0), node(59, 0, 0, 1284), node(59, 0, 0, 1285), node(59, 0, 0, 1286),
+    node(101, 7431, 1, 0), node(59, 0, 0, 1287), node(101, 7432, 1, 0), node(59, 0, 0, 255),
+    node(59, 0, 0, 585), node(59, 0, 0, 974), node(59, 0, 0, 980), node(59, 0, 0, 348),
+    node(104, 7433, 1, 0), node(59, 0, 0, 1288), node(116, 7434, 1, 0), node(116, 7435, 1, 0),
+    node(115, 7436, 1, 0), node(114, 7437, 1, 0), node(59, 0, 0, 256), node(101, 7438, 1, 0),
+    node(110, 7439, 1, 0), node(59, 0, 0, 1289), node(59, 0, 0, 1290), node(59, 0, 0, 1291),
+    node(112, 7440, 1, 0), node(114, 7441, 1, 0), node(59, 0, 0, 248), node(112, 7442, 1, 0),
+    node(113, 7443, 1, 0), node(105, 7444, 1, 0), node(109, 7445, 1, 0), node(59, 0, 0, 1292),
+    node(98, 7446, 1, 0), node(116, 7447, 1, 0), node(108, 7448, 1, 0), node(98, 7449, 1, 0),
+    node(114, 7450, 1, 0), node(116, 7451, 1, 0), node(115, 7452, 1, 0), node(59, 0, 0, 260),
+    node(101, 7453, 1, 0), node(110, 7454, 1, 0), node(59, 0, 0, 1293), node(59, 0, 0, 1294),
+    node(59, 0, 0, 1295), node(59, 0, 0, 1296), node(119, 7455, 1, 0), node(59, 0, 0, 1297),
+    node(59, 0, 0, 1298), node(59, 0, 0, 1299), node(59, 0, 0, 1300), node(59, 0, 0, 1301),
+    node(59, 0, 0, 1302), node(111, 7456, 1, 0), node(121, 7457, 1, 0), node(59, 0, 0, 1303),
+    node(112, 7458, 1, 0), node(105, 7459, 1, 0), node(59, 0, 0, 1304), node(59, 0, 0, 252),
+    node(59, 0, 0, 1305), node(97, 7460, 1, 0), node(59, 0, 0, 1306), node(59, 0, 0, 1307),
+    node(59, 0, 0, 1308), node(107, 7461, 1, 0), node(59, 0, 0, 1309), node(108, 7462, 1, 0),
+    node(59, 0, 0, 1310), node(117, 7463, 1, 0), node(115, 7464, 1, 0), node(101, 7465, 1, 0),
+    node(117, 7466, 1, 0), node(59, 0, 0, 1311), node(100, 7467, 2, 0), node(59, 0, 0, 998),
+    node(59, 0, 0, 1312), node(59, 0, 0, 1313), node(59, 0, 0, 1314), node(59, 0, 0, 1002),
+    node(59, 0, 0, 1315), node(101, 7469, 1, 0), node(59, 0, 0, 1316), node(119, 7470, 1, 0),
+    node(97, 7471, 1, 0), node(111, 7472, 1, 0), node(110, 7473, 1, 0), node(114, 7474, 1, 0),
+    node(59, 0, 0, 1317), node(101, 7475, 1, 0), node(59, 0, 0, 1318), node(59, 0, 0, 1319),
+    node(101, 7476, 1, 0), node(59, 0, 0, 1320), node(105, 7477, 1, 0), node(112, 7478, 1, 0),
+    node(104, 7479, 1, 0), node(59, 0, 0, 544), node(112, 7480, 1, 0), node(59, 0, 0, 566),
+    node(109, 7481, 1, 0), node(115, 7482, 1, 0), node(115, 7483, 1, 0), node(116, 7484, 1, 0),
+    node(97, 7485, 1, 0), node(59, 0, 0, 1321), node(59, 0, 0, 1322), node(59, 0, 0, 616),
+    node(59, 0, 0, 1323), node(59, 0, 0, 1324), node(59, 0, 0, 1325), node(59, 0, 0, 1326),
+    node(103, 7486, 1, 0), node(59, 0, 0, 1327), node(59, 0, 0, 1328), node(59, 0, 0, 54),
+    node(59, 0, 0, 55), node(59, 0, 0, 1329), node(59, 0, 0, 1330), node(59, 0, 0, 1331),
+    node(59, 0, 0, 1332), node(59, 0, 0, 692), node(59, 0, 0, 1034), node(59, 0, 0, 1333),
+    node(59, 0, 0, 1334), node(59, 0, 0, 131), node(114, 7487, 1, 0), node(110, 7488, 1, 0),
+    node(115, 7489, 1, 0), node(59, 0, 0, 1095), node(108, 7490, 1, 0), node(68, 7491, 1, 0),
+    node(59, 0, 0, 66), node(59, 0, 0, 1335), node(59, 0, 0, 405), node(111, 7492, 1, 0),
+    node(111, 7493, 1, 0), node(105, 7494, 1, 0), node(108, 7495, 1, 0), node(105, 7496, 1, 0),
+    node(115, 7497, 1, 0), node(114, 7498, 1, 0), node(110, 7499, 1, 0), node(73, 7500, 1, 0),
+    node(99, 7501, 1, 0), node(67, 7502, 1, 0), node(100, 7503, 1, 0), node(105, 7504, 1, 0),
+    node(59, 0, 0, 421), node(110, 7505, 1, 0), node(108, 7506, 1, 0), node(111, 7507, 1, 0),
+    node(111, 7508, 2, 0), node(101, 7510, 1, 0), node(111, 7511, 1, 0), node(105, 7512, 1, 0),
+    node(112, 7513, 2, 0), node(101, 7515, 1, 0), node(111, 7516, 1, 0), node(118, 7517, 1, 0),
+    node(116, 7518, 3, 0), node(104, 7521, 1, 0), node(59, 0, 0, 264), node(65, 7522, 1, 0),
+    node(111, 7523, 1, 0), node(59, 0, 0, 34), node(97, 7524, 1, 0), node(114, 7525, 1, 0),
+    node(59, 0, 0, 1336), node(108, 7526, 1, 0), node(114, 7527, 1, 0), node(116, 7528, 1, 0),
+    node(109, 7529, 1, 0), node(101, 7530, 1, 0), node(116, 7531, 1, 0), node(69, 7532, 1, 0),
+    node(70, 7533, 1, 0), node(71, 7534, 1, 0), node(76, 7535, 1, 0), node(83, 7536, 1, 0),
+    node(84, 7537, 1, 0), node(83, 7538, 1, 0), node(116, 7539, 1, 0), node(110, 7540, 1, 0),
+    node(97, 7541, 1, 0), node(114, 7542, 1, 0), node(59, 0, 0, 556), node(108, 7543, 1, 0),
+    node(99, 7544, 1, 0), node(108, 7545, 1, 0), node(116, 7546, 1, 0), node(108, 7547, 1, 0),
+    node(111, 7548, 1, 0), node(108, 7549, 1, 0), node(98, 7550, 1, 0), node(110, 7551, 2, 0),
+    node(111, 7553, 1, 0), node(104, 7554, 1, 0), node(59, 0, 0, 776), node(65, 7555, 1, 0),
+    node(86, 7556, 1, 0), node(97, 7557, 1, 0), node(111, 7558, 1, 0), node(101, 7559, 1, 0),
+    node(101, 7560, 1, 0), node(116, 7561, 1, 0), node(111, 7562, 1, 0), node(104, 7563, 1, 0),
+    node(97, 7564, 1, 0), node(108, 7565, 1, 0), node(97, 7566, 1, 0), node(115, 7567, 1, 0),
+    node(110, 7568, 1, 0), node(100, 7569, 1, 0), node(114, 7570, 1, 0), node(116, 7571, 2, 0),
+    node(104, 7573, 1, 0), node(116, 7574, 2, 0), node(104, 7576, 1, 0), node(102, 7577, 1, 0),
+    node(103, 7578, 1, 0), node(112, 7579, 1, 0), node(114, 7580, 1, 0), node(117, 7581, 1, 0),
+    node(101, 7582, 3, 0), node(114, 7585, 1, 0), node(101, 7586, 1, 0), node(59, 0, 0, 1337),
+    node(59, 0, 0, 1338), node(107, 7587, 1, 0), node(114, 7588, 1, 0), node(97, 7589, 1, 0),
+    node(108, 7590, 1, 0), node(101, 7591, 1, 0), node(108, 7592, 2, 0), node(116, 7594, 1, 0),
+    node(116, 7595, 1, 0), node(68, 7596, 1, 0), node(69, 7597, 1, 0), node(84, 7598, 1, 0),
+    node(59, 0, 0, 219), node(69, 7599, 1, 0), node(71, 7600, 1, 0), node(76, 7601, 1, 0),
+    node(83, 7602, 1, 0), node(84, 7603, 1, 0), node(101, 7604, 1, 0), node(101, 7605, 1, 0),
+    node(114, 7606, 1, 0), node(116, 7607, 1, 0), node(114, 7608, 1, 0), node(101, 7609, 1, 0),
+    node(101, 7610, 1, 0), node(114, 7611, 1, 0), node(101, 7612, 4, 0), node(105, 7616, 1, 0),
+    node(59, 0, 0, 1339), node(108, 7617, 1, 0), node(59, 0, 0, 921), node(99, 7618, 2, 0),
+    node(101, 7620, 1, 0), node(68, 7621, 1, 0), node(117, 7622, 1, 0), node(101, 7623, 1, 0),
+    node(115, 7624, 4, 0), node(59, 0, 0, 550), node(105, 7628, 1, 0), node(69, 7629, 2, 0),
+    node(85, 7631, 1, 0), node(103, 7632, 1, 0), node(114, 7633, 1, 0), node(105, 7634, 1, 0),
+    node(117, 7635, 1, 0), node(119, 7636, 1, 0), node(111, 7637, 1, 0), node(101, 7638, 3, 0),
+    node(105, 7641, 1, 0), node(68, 7642, 1, 0), node(84, 7643, 1, 0), node(86, 7644, 1, 0),
+    node(99, 7645, 1, 0), node(114, 7646, 1, 0), node(112, 7647, 1, 0), node(114, 7648, 1, 0),
+    node(97, 7649, 1, 0), node(119, 7650, 1, 0), node(102, 7651, 1, 0), node(103, 7652, 1, 0),
+    node(65, 7653, 1, 0), node(114, 7654, 1, 0), node(110, 7655, 1, 0), node(117, 7656, 2, 0),
+    node(110, 7658, 1, 0), node(113, 7659, 1, 0), node(115, 7660, 4, 0), node(116, 7664, 1, 0),
+    node(116, 7665, 2, 0), node(114, 7667, 1, 0), node(97, 7668, 1, 0), node(99, 7669, 1, 0),
+    node(117, 7670, 1, 0), node(108, 7671, 1, 0), node(108, 7672, 1, 0), node(111, 7673, 1, 0),
+    node(114, 7674, 1, 0), node(114, 7675, 1, 0), node(97, 7676, 1, 0), node(114, 7677, 1, 0),
+    node(117, 7678, 1, 0), node(59, 0, 0, 607), node(66, 7679, 1, 0), node(68, 7680, 1, 0),
+    node(114, 7681, 1, 0), node(105, 7682, 1, 0), node(114, 7683, 1, 0), node(59, 0, 0, 605),
+    node(114, 7684, 1, 0), node(102, 7685, 1, 0), node(103, 7686, 1, 0), node(59, 0, 0, 1340),
+    node(108, 7687, 4, 0), node(110, 7691, 1, 0), node(116, 7692, 1, 0), node(59, 0, 0, 697),
+    node(101, 7693, 1, 0), node(97, 7694, 1, 0), node(98, 7695, 1, 0), node(99, 7696, 1, 0),
+    node(100, 7697, 1, 0), node(101, 7698, 1, 0), node(102, 7699, 1, 0), node(103, 7700, 1, 0),
+    node(104, 7701, 1, 0), node(59, 0, 0, 1341), node(100, 7702, 1, 0), node(59, 0, 0, 1342),
+    node(113, 7703, 1, 0), node(59, 0, 0, 1043), node(116, 7704, 1, 0), node(103, 7705, 1, 0),
+    node(105, 7706, 1, 0), node(109, 7707, 1, 0), node(59, 0, 0, 399), node(101, 7708, 1, 0),
+    node(101, 7709, 1, 0), node(107, 7710, 1, 0), node(59, 0, 0, 1095), node(59, 0, 0, 1343),
+    node(59, 0, 0, 997), node(59, 0, 0, 1024), node(59, 0, 0, 1030), node(115, 7711, 1, 0),
+    node(101, 7712, 1, 0), node(112, 7713, 1, 0), node(59, 0, 0, 986), node(110, 7714, 1, 0),
+    node(115, 7715, 1, 0), node(101, 7716, 1, 0), node(122, 7717, 1, 0), node(117, 7718, 1, 0),
+    node(105, 7719, 1, 0), node(59, 0, 0, 1344), node(115, 7720, 1, 0), node(59, 0, 0, 932),
+    node(115, 7721, 1, 0), node(98, 7722, 1, 0), node(112, 7723, 1, 0), node(59, 0, 0, 1345),
+    node(59, 0, 0, 1346), node(111, 7724, 1, 0), node(114, 7725, 1, 0), node(114, 7726, 1, 0),
+    node(82, 7727, 1, 0), node(83, 7728, 1, 0), node(97, 7729, 1, 0), node(99, 7730, 1, 0),
+    node(100, 7731, 1, 0), node(116, 7732, 1, 0), node(59, 0, 0, 1347), node(116, 7733, 1, 0),
+    node(59, 0, 0, 1037), node(101, 7734, 1, 0), node(101, 7735, 1, 0), node(59, 0, 0, 1348),
+    node(59, 0, 0, 1349), node(59, 0, 0, 1350), node(59, 0, 0, 1351), node(112, 7736, 1, 0),
+    node(59, 0, 0, 1352), node(112, 7737, 1, 0), node(115, 7738, 1, 0), node(101, 7739, 1, 0),
+    node(100, 7740, 1, 0), node(114, 7741, 1, 0), node(116, 7742, 1, 0), node(59, 0, 0, 940),
+    node(59, 0, 0, 1044), node(59, 0, 0, 789), node(59, 0, 0, 1353), node(59, 0, 0, 421),
+    node(115, 7743, 1, 0), node(59, 0, 0, 1153), node(110, 7744, 1, 0), node(116, 7745, 1, 0),
+    node(115, 7746, 1, 0), node(59, 0, 0, 1246), node(114, 7747, 1, 0), node(97, 7748, 1, 0),
+    node(111, 7749, 1, 0), node(110, 7750, 1, 0), node(112, 7751, 1, 0), node(119, 7752, 1, 0),
+    node(59, 0, 0, 1354), node(114, 7753, 1, 0), node(115, 7754, 1, 0), node(116, 7755, 1, 0),
+    node(59, 0, 0, 437), node(59, 0, 0, 1129), node(103, 7756, 1, 0), node(108, 7757, 1, 0),
+    node(59, 0, 0, 1355), node(108, 7758, 1, 0), node(116, 7759, 1, 0), node(116, 7760, 1, 0),
+    node(100, 7761, 1, 0), node(116, 7762, 1, 0), node(116, 7763, 1, 0), node(59, 0, 0, 1356),
+    node(108, 7764, 1, 0), node(120, 7765, 1, 0), node(59, 0, 0, 1357), node(111, 7766, 1, 0),
+    node(115, 7767, 1, 0), node(101, 7768, 1, 0), node(59, 0, 0, 31), node(113, 7769, 1, 0),
+    node(59, 0, 0, 1358), node(105, 7770, 1, 0), node(119, 7771, 1, 0), node(119, 7772, 1, 0),
+    node(116, 7773, 1, 0), node(104, 7774, 1, 0), node(101, 7775, 1, 0), node(116, 7776, 1, 0),
+    node(101, 7777, 1, 0), node(115, 7778, 1, 0), node(108, 7779, 1, 0), node(107, 7780, 1, 0),
+    node(59, 0, 0, 834), node(59, 0, 0, 1359), node(118, 7781, 1, 0), node(59, 0, 0, 1360),
+    node(59, 0, 0, 1361), node(59, 0, 0, 1362), node(59, 0, 0, 1363), node(59, 0, 0, 1364),
+    node(114, 7782, 1, 0), node(111, 7783, 1, 0), node(112, 7784, 1, 0), node(116, 7785, 1, 0),
+    node(104, 7786, 1, 0), node(101, 7787, 1, 0), node(116, 7788, 1, 0), node(59, 0, 0, 1365),
+    node(114, 7789, 1, 0), node(114, 7790, 1, 0), node(59, 0, 0, 871), node(116, 7791, 1, 0),
+    node(103, 7792, 1, 0), node(59, 0, 0, 38), node(59, 0, 0, 491), node(114, 7793, 1, 0),
+    node(99, 7794, 1, 0), node(120, 7795, 1, 0), node(116, 7796, 2, 0), node(115, 7798, 1, 0),
+    node(104, 7799, 1, 0), node(111, 7800, 1, 0), node(59, 0, 0, 1366), node(59, 0, 0, 197),
+    node(114, 7801, 1, 0), node(59, 0, 0, 1367), node(114, 7802, 1, 0), node(59, 0, 0, 1368),
+    node(113, 7803, 1, 0), node(59, 0, 0, 497), node(111, 7804, 1, 0), node(101, 7805, 1, 0),
+    node(112, 7806, 1, 0), node(100, 7807, 1, 0), node(59, 0, 0, 1369), node(112, 7808, 1, 0),
+    node(114, 7809, 1, 0), node(103, 7810, 1, 0), node(114, 7811, 1, 0), node(59, 0, 0, 209),
+    node(59, 0, 0, 881), node(115, 7812, 1, 0), node(116, 7813, 1, 0), node(59, 0, 0, 886),
+    node(59, 0, 0, 1220), node(110, 7814, 1, 0), node(114, 7815, 1, 0), node(103, 7816, 1, 0),
+    node(110, 7817, 1, 0), node(116, 7818, 1, 0), node(59, 0, 0, 897), node(59, 0, 0, 1370),
+    node(59, 0, 0, 1371), node(59, 0, 0, 898), node(59, 0, 0, 1372), node(59, 0, 0, 1373),
+    node(101, 7819, 1, 0), node(59, 0, 0, 1374), node(59, 0, 0, 517), node(114, 7820, 1, 0),
+    node(105, 7821, 1, 0), node(97, 7822, 1, 0), node(59, 0, 0, 1375), node(59, 0, 0, 1376),
+    node(59, 0, 0, 1020), node(101, 7823, 1, 0), node(59, 0, 0, 518), node(59, 0, 0, 1021),
+    node(101, 7824, 1, 0), node(108, 7825, 1, 0), node(59, 0, 0, 1377), node(59, 0, 0, 1378),
+    node(59, 0, 0, 1379), node(59, 0, 0, 912), node(59, 0, 0, 1380), node(59, 0, 0, 1381),
+    node(59, 0, 0, 541), node(59, 0, 0, 1382), node(115, 7826, 1, 0), node(108, 7827, 1, 0),
+    node(59, 0, 0, 1383), node(114, 7828, 1, 0), node(59, 0, 0, 1384), node(114, 7829, 1, 0),
+    node(59, 0, 0, 1385), node(59, 0, 0, 1386), node(59, 0, 0, 1387), node(116, 7830, 1, 0),
+    node(114, 7831, 1, 0), node(108, 7832, 1, 0), node(112, 7833, 1, 0), node(113, 7834, 1, 0),
+    node(109, 7835, 1, 0), node(59, 0, 0, 937), node(114, 7836, 1, 0), node(101, 7837, 1, 0),
+    node(102, 7838, 1, 0), node(105, 7839, 1, 0), node(59, 0, 0, 1388), node(59, 0, 0, 1136),
+    node(118, 7840, 1, 0), node(59, 0, 0, 1389), node(59, 0, 0, 1390), node(108, 7841, 1, 0),
+    node(59, 0, 0, 1391), node(59, 0, 0, 1392), node(59, 0, 0, 1393), node(59, 0, 0, 343),
+    node(116, 7842, 1, 0), node(114, 7843, 1, 0), node(114, 7844, 1, 0), node(102, 7845, 1, 0),
+    node(103, 7846, 1, 0), node(117, 7847, 1, 0), node(114, 7848, 1, 0), node(111, 7849, 1, 0),
+    node(99, 7850, 1, 0), node(59, 0, 0, 1394), node(116, 7851, 1, 0), node(105, 7852, 1, 0),
+    node(59, 0, 0, 1395), node(116, 7853, 1, 0), node(59, 0, 0, 973), node(115, 7854, 1, 0),
+    node(100, 7855, 1, 0), node(114, 7856, 1, 0), node(59, 0, 0, 1396), node(59, 0, 0, 1397),
+    node(116, 7857, 1, 0), node(108, 7858, 1, 0), node(105, 7859, 1, 0), node(116, 7860, 2, 0),
+    node(116, 7862, 2, 0), node(116, 7864, 2, 0), node(59, 0, 0, 1398), node(59, 0, 0, 1399),
+    node(59, 0, 0, 1400), node(59, 0, 0, 1401), node(113, 7866, 2, 0), node(101, 7868, 1, 0),
+    node(114, 7869, 1, 0), node(108, 7870, 1, 0), node(112, 7871, 1, 0), node(113, 7872, 1, 0),
+    node(109, 7873, 1, 0), node(59, 0, 0, 969), node(59, 0, 0, 1402), node(59, 0, 0, 1403),
+    node(59, 0, 0, 1404), node(59, 0, 0, 1405), node(59, 0, 0, 1406), node(59, 0, 0, 1407),
+    node(59, 0, 0, 1408), node(113, 7874, 2, 0), node(101, 7876, 1, 0), node(59, 0, 0, 992),
+    node(114, 7877, 1, 0), node(109, 7878, 1, 0), node(112, 7879, 1, 0), node(109, 7880, 1, 0),
+    node(114, 7881, 1, 0), node(59, 0, 0, 1409), node(101, 7882, 5, 0), node(115, 7887, 1, 0),
+    node(59, 0, 0, 1410), node(59, 0, 0, 1411), node(109, 7888, 1, 0), node(108, 7889, 1, 0),
+    node(114, 7890, 1, 0), node(114, 7891, 1, 0), node(59, 0, 0, 607), node(114, 7892, 1, 0),
+    node(111, 7893, 1, 0), node(59, 0, 0, 610), node(111, 7894, 1, 0), node(114, 7895, 1, 0),
+    node(59, 0, 0, 1412), node(108, 7896, 1, 0), node(97, 7897, 1, 0), node(105, 7898, 1, 0),
+    node(116, 7899, 1, 0), node(97, 7900, 1, 0), node(101, 7901, 1, 0), node(101, 7902, 1, 0),
+    node(97, 7903, 1, 0), node(110, 7904, 1, 0), node(59, 0, 0, 1413), node(59, 0, 0, 1414),
+    node(99, 7905, 1, 0), node(104, 7906, 1, 0), node(105, 7907, 1, 0), node(105, 7908, 1, 0),
+    node(116, 7909, 1, 0), node(116, 7910, 1, 0), node(110, 7911, 1, 0), node(117, 7912, 1, 0),
+    node(109, 7913, 1, 0), node(101, 7914, 1, 0), node(108, 7915, 1, 0), node(116, 7916, 1, 0),
+    node(110, 7917, 1, 0), node(116, 7918, 1, 0), node(108, 7919, 1, 0), node(59, 0, 0, 1415),
+    node(99, 7920, 1, 0), node(116, 7921, 1, 0), node(59, 0, 0, 784), node(110, 7922, 1, 0),
+    node(116, 7923, 1, 0), node(119, 7924, 1, 0), node(102, 7925, 1, 0), node(110, 7926, 1, 0),
+    node(103, 7927, 1, 0), node(65, 7928, 1, 0), node(68, 7929, 1, 0), node(114, 7930, 1, 0),
+    node(119, 7931, 3, 0), node(101, 7934, 1, 0), node(82, 7935, 1, 0), node(84, 7936, 1, 0),
+    node(86, 7937, 1, 0), node(116, 7938, 2, 0), node(114, 7940, 1, 0), node(119, 7941, 1, 0),
+    node(108, 7942, 1, 0), node(121, 7943, 1, 0), node(100, 7944, 1, 0), node(105, 7945, 1, 0),
+    node(105, 7946, 1, 0), node(97, 7947, 1, 0), node(114, 7948, 1, 0), node(114, 7949, 1, 0),
+    node(113, 7950, 1, 0), node(117, 7951, 1, 0), node(114, 7952, 1, 0), node(101, 7953, 1, 0),
+    node(108, 7954, 1, 0), node(105, 7955, 1, 0), node(112, 7956, 1, 0), node(97, 7957, 1, 0),
+    node(72, 7958, 1, 0), node(108, 7959, 1, 0), node(121, 7960, 1, 0), node(59, 0, 0, 181),
+    node(116, 7961, 1, 0), node(101, 7962, 2, 0), node(114, 7964, 1, 0), node(101, 7965, 1, 0),
+    node(119, 7966, 3, 0), node(105, 7969, 1, 0), node(108, 7970, 1, 0), node(84, 7971, 1, 0),
+    node(86, 7972, 1, 0), node(114, 7973, 1, 0), node(116, 7974, 2, 0), node(114, 7976, 1, 0),
+    node(101, 7977, 1, 0), node(110, 7978, 1, 0), node(119, 7979, 1, 0), node(101, 7980, 1, 0),
+    node(99, 7981, 1, 0), node(111, 7982, 1, 0), node(119, 7983, 1, 0), node(116, 7984, 1, 0),
+    node(108, 7985, 1, 0), node(69, 7986, 1, 0), node(116, 7987, 1, 0), node(59, 0, 0, 1416),
+    node(116, 7988, 1, 0), node(101, 7989, 1, 0), node(111, 7990, 1, 0), node(65, 7991, 1, 0),
+    node(82, 7992, 1, 0), node(116, 7993, 1, 0), node(97, 7994, 1, 0), node(114, 7995, 1, 0),
+    node(116, 7996, 1, 0), node(116, 7997, 1, 0), node(104, 7998, 1, 0), node(97, 7999, 1, 0),
+    node(102, 8000, 1, 0), node(115, 8001, 1, 0), node(77, 8002, 1, 0), node(84, 8003, 1, 0),
+    node(86, 8004, 1, 0), node(101, 8005, 1, 0), node(115, 8006, 1, 0), node(105, 8007, 1, 0),
+    node(117, 8008, 1, 0), node(112, 8009, 1, 0), node(101, 8010, 1, 0), node(110, 8011, 1, 0),
+    node(59, 0, 0, 41), node(84, 8012, 1, 0), node(115, 8013, 1, 0), node(101, 8014, 1, 0),
+    node(111, 8015, 1, 0), node(113, 8016, 1, 0), node(114, 8017, 1, 0), node(113, 8018, 1, 0),
+    node(114, 8019, 1, 0), node(101, 8020, 1, 0), node(108, 8021, 1, 0), node(105, 8022, 1, 0),
+    node(100, 8023, 2, 0), node(100, 8025, 1, 0), node(115, 8026, 1, 0), node(84, 8027, 1, 0),
+    node(101, 8028, 1, 0), node(116, 8029, 2, 0), node(101, 8031, 1, 0), node(115, 8032, 1, 0),
+    node(59, 0, 0, 520), node(69, 8033, 1, 0), node(70, 8034, 1, 0), node(84, 8035, 1, 0),
+    node(99, 8036, 1, 0), node(121, 8037, 2, 0), node(101, 8039, 1, 0), node(107, 8040, 1, 0),
+    node(110, 8041, 1, 0), node(59, 0, 0, 543), node(115, 8042, 1, 0), node(112, 8043, 1, 0),
+    node(59, 0, 0, 49), node(69, 8044, 1, 0), node(83, 8045, 1, 0), node(84, 8046, 1, 0),
+    node(111, 8047, 1, 0), node(108, 8048, 1, 0), node(113, 8049, 1, 0), node(112, 8050, 1, 0),
+    node(108, 8051, 1, 0), node(111, 8052, 1, 0), node(108, 8053, 1, 0), node(98, 8054, 1, 0),
+    node(110, 8055, 2, 0), node(111, 8057, 1, 0), node(59, 0, 0, 1017), node(65, 8058, 1, 0),
+    node(86, 8059, 1, 0), node(97, 8060, 1, 0), node(111, 8061, 1, 0), node(101, 8062, 1, 0),
+    node(101, 8063, 1, 0), node(116, 8064, 1, 0), node(111, 8065, 1, 0), node(108, 8066, 1, 0),
+    node(114, 8067, 1, 0), node(121, 8068, 1, 0), node(110, 8069, 1, 0), node(116, 8070, 1, 0),
+    node(104, 8071, 1, 0), node(114, 8072, 1, 0), node(99, 8073, 1, 0), node(116, 8074, 1, 0),
+    node(98, 8075, 1, 0), node(112, 8076, 1, 0), node(105, 8077, 1, 0), node(117, 8078, 1, 0),
+    node(59, 0, 0, 51), node(69, 8079, 1, 0), node(83, 8080, 1, 0), node(84, 8081, 1, 0),
+    node(59, 0, 0, 42), node(59, 0, 0, 260), node(69, 8082, 1, 0), node(101, 8083, 1, 0),
+    node(99, 8084, 1, 0), node(101, 8085, 1, 0), node(97, 8086, 1, 0), node(108, 8087, 1, 0),
+    node(100, 8088, 1, 0), node(116, 8089, 1, 0), node(59, 0, 0, 1417), node(59, 0, 0, 1197),
+    node(99, 8090, 2, 0), node(101, 8092, 1, 0), node(115, 8093, 1, 0), node(97, 8094, 1, 0),
+    node(111, 8095, 1, 0), node(114, 8096, 1, 0), node(98, 8097, 1, 0), node(111, 8098, 1, 0),
+    node(114, 8099, 1, 0), node(116, 8100, 1, 0), node(104, 8101, 1, 0), node(66, 8102, 1, 0),
+    node(76, 8103, 1, 0), node(83, 8104, 1, 0), node(84, 8105, 1, 0), node(83, 8106, 1, 0),
+    node(104, 8107, 1, 0), node(59, 0, 0, 1418), node(59, 0, 0, 1419), node(59, 0, 0, 1420),
+    node(59, 0, 0, 1421), node(59, 0, 0, 1422), node(59, 0, 0, 1423), node(59, 0, 0, 1424),
+    node(59, 0, 0, 1425), node(59, 0, 0, 1426), node(59, 0, 0, 1427), node(59, 0, 0, 139),
+    node(59, 0, 0, 1428), node(59, 0, 0, 705), node(108, 8108, 1, 0), node(101, 8109, 1, 0),
+    node(113, 8110, 1, 0), node(59, 0, 0, 1094), node(59, 0, 0, 1429), node(59, 0, 0, 1329),
+    node(115, 8111, 1, 0), node(59, 0, 0, 1331), node(103, 8112, 1, 0), node(59, 0, 0, 1332),
+    node(59, 0, 0, 692), node(101, 8113, 1, 0), node(97, 8114, 1, 0), node(97, 8115, 1, 0),
+    node(59, 0, 0, 1209), node(59, 0, 0, 1305), node(59, 0, 0, 1430), node(59, 0, 0, 1431),
+    node(116, 8116, 1, 0), node(107, 8117, 1, 0), node(114, 8118, 1, 0), node(59, 0, 0, 16),
+    node(59, 0, 0, 45), node(115, 8119, 1, 0), node(105, 8120, 1, 0), node(97, 8121, 1, 0),
+    node(59, 0, 0, 1432), node(59, 0, 0, 761), node(110, 8122, 1, 0), node(115, 8123, 1, 0),
+    node(59, 0, 0, 1433), node(114, 8124, 1, 0), node(117, 8125, 1, 0), node(59, 0, 0, 773),
+    node(103, 8126, 1, 0), node(111, 8127, 1, 0), node(59, 0, 0, 1434), node(117, 8128, 1, 0),
+    node(116, 8129, 1, 0), node(59, 0, 0, 429), node(59, 0, 0, 1210), node(101, 8130, 1, 0),
+    node(114, 8131, 1, 0), node(119, 8132, 1, 0), node(97, 8133, 1, 0), node(111, 8134, 1, 0),
+    node(59, 0, 0, 677), node(59, 0, 0, 1435), node(59, 0, 0, 1436), node(59, 0, 0, 794),
+    node(116, 8135, 1, 0), node(101, 8136, 1, 0), node(59, 0, 0, 1437), node(105, 8137, 1, 0),
+    node(105, 8138, 1, 0), node(111, 8139, 1, 0), node(59, 0, 0, 1438), node(59, 0, 0, 170),
+    node(59, 0, 0, 1439), node(59, 0, 0, 450), node(120, 8140, 1, 0), node(115, 8141, 1, 0),
+    node(115, 8142, 1, 0), node(113, 8143, 1, 0), node(116, 8144, 1, 0), node(59, 0, 0, 1276),
+    node(59, 0, 0, 1296), node(97, 8145, 1, 0), node(116, 8146, 1, 0), node(59, 0, 0, 316),
+    node(59, 0, 0, 8), node(59, 0, 0, 1440), node(59, 0, 0, 375), node(59, 0, 0, 1169),
+    node(59, 0, 0, 1441), node(59, 0, 0, 1442), node(59, 0, 0, 1443), node(119, 8147, 2, 0),
+    node(111, 8149, 1, 0), node(97, 8150, 1, 0), node(116, 8151, 3, 0), node(101, 8154, 1, 0),
+    node(59, 0, 0, 192), node(59, 0, 0, 1444), node(111, 8155, 1, 0), node(114, 8156, 1, 0),
+    node(116, 8157, 1, 0), node(59, 0, 0, 1121), node(104, 8158, 1, 0), node(59, 0, 0, 486),
+    node(97, 8159, 1, 0), node(114, 8160, 1, 0), node(116, 8161, 1, 0), node(116, 8162, 1, 0),
+    node(119, 8163, 2, 0), node(59, 0, 0, 1124), node(59, 0, 0, 1445), node(113, 8165, 1, 0),
+    node(119, 8166, 1, 0), node(102, 8167, 1, 0), node(59, 0, 0, 1446), node(97, 8168, 1, 0),
+    node(59, 0, 0, 878), node(111, 8169, 1, 0), node(104, 8170, 1, 0), node(114, 8171, 1, 0),
+    node(59, 0, 0, 331), node(59, 0, 0, 1447), node(116, 8172, 1, 0), node(111, 8173, 1, 0),
+    node(104, 8174, 1, 0), node(116, 8175, 1, 0), node(59, 0, 0, 1448), node(108, 8176, 1, 0),
+    node(114, 8177, 1, 0), node(100, 8178, 1, 0), node(114, 8179, 1, 0), node(113, 8180, 2, 0),
+    node(113, 8182, 2, 0), node(101, 8184, 2, 0), node(59, 0, 0, 1449), node(59, 0, 0, 233),
+    node(107, 8186, 1, 0), node(59, 0, 0, 1450), node(59, 0, 0, 1451), node(111, 8187, 1, 0),
+    node(121, 8188, 1, 0), node(114, 8189, 1, 0), node(59, 0, 0, 549), node(59, 0, 0, 1248),
+    node(59, 0, 0, 1452), node(59, 0, 0, 1453), node(59, 0, 0, 1454), node(111, 8190, 1, 0),
+    node(59, 0, 0, 1455), node(115, 8191, 1, 0), node(59, 0, 0, 17), node(111, 8192, 1, 0),
+    node(112, 8193, 1, 0), node(116, 8194, 2, 0), node(104, 8196, 1, 0), node(105, 8197, 1, 0),
+    node(101, 8198, 1, 0), node(116, 8199, 1, 0), node(104, 8200, 1, 0), node(59, 0, 0, 1456),
+    node(59, 0, 0, 1457), node(59, 0, 0, 1458), node(59, 0, 0, 974), node(59, 0, 0, 204),
+    node(97, 8201, 1, 0), node(109, 8202, 1, 0), node(59, 0, 0, 1459), node(116, 8203, 1, 0),
+    node(59, 0, 0, 984), node(101, 8204, 1, 0), node(59, 0, 0, 985), node(101, 8205, 1, 0),
+    node(101, 8206, 1, 0), node(112, 8207, 1, 0), node(59, 0, 0, 589), node(113, 8208, 1, 0),
+    node(113, 8209, 2, 0), node(111, 8211, 1, 0), node(121, 8212, 1, 0), node(114, 8213, 1, 0),
+    node(59, 0, 0, 574), node(59, 0, 0, 1275), node(59, 0, 0, 592), node(113, 8214, 1, 0),
+    node(113, 8215, 2, 0), node(101, 8217, 1, 0), node(59, 0, 0, 1303), node(114, 8218, 1, 0),
+    node(59, 0, 0, 252), node(59, 0, 0, 1460), node(59, 0, 0, 612), node(100, 8219, 1, 0),
+    node(108, 8220, 1, 0), node(113, 8221, 1, 0), node(114, 8222, 1, 0), node(59, 0, 0, 1461),
+    node(59, 0, 0, 1462), node(101, 8223, 1, 0), node(105, 8224, 1, 0), node(59, 0, 0, 1315),
+    node(114, 8225, 1, 0), node(110, 8226, 2, 0), node(119, 8228, 1, 0), node(59, 0, 0, 1317),
+    node(111, 8229, 1, 0), node(59, 0, 0, 1173), node(110, 8230, 1, 0), node(111, 8231, 1, 0),
+    node(59, 0, 0, 1278), node(116, 8232, 1, 0), node(116, 8233, 1, 0), node(59, 0, 0, 1303),
+    node(103, 8234, 1, 0), node(116, 8235, 1, 0), node(59, 0, 0, 974), node(115, 8236, 1, 0),
+    node(102, 8237, 1, 0), node(59, 0, 0, 876), node(59, 0, 0, 534), node(117, 8238, 1, 0),
+    node(115, 8239, 1, 0), node(101, 8240, 1, 0), node(67, 8241, 1, 0), node(121, 8242, 2, 0),
+    node(59, 0, 0, 798), node(116, 8244, 1, 0), node(59, 0, 0, 1108), node(111, 8245, 1, 0),
+    node(97, 8246, 1, 0), node(105, 8247, 1, 0), node(116, 8248, 1, 0), node(59, 0, 0, 53),
+    node(110, 8249, 1, 0), node(116, 8250, 3, 0), node(103, 8253, 2, 0), node(104, 8255, 1, 0),
+    node(114, 8256, 1, 0), node(111, 8257, 1, 0), node(116, 8258, 1, 0), node(59, 0, 0, 419),
+    node(66, 8259, 1, 0), node(85, 8260, 1, 0), node(59, 0, 0, 1463), node(105, 8261, 1, 0),
+    node(101, 8262, 1, 0), node(101, 8263, 1, 0), node(84, 8264, 1, 0), node(86, 8265, 1, 0),
+    node(114, 8266, 1, 0), node(59, 0, 0, 417), node(108, 8267, 1, 0), node(83, 8268, 1, 0),
+    node(101, 8269, 1, 0), node(117, 8270, 1, 0), node(97, 8271, 1, 0), node(108, 8272, 1, 0),
+    node(121, 8273, 1, 0), node(102, 8274, 1, 0), node(117, 8275, 1, 0), node(108, 8276, 1, 0),
+    node(101, 8277, 1, 0), node(115, 8278, 1, 0), node(97, 8279, 1, 0), node(108, 8280, 1, 0),
+    node(97, 8281, 1, 0), node(108, 8282, 1, 0), node(117, 8283, 1, 0), node(59, 0, 0, 754),
+    node(73, 8284, 1, 0), node(105, 8285, 1, 0), node(67, 8286, 1, 0), node(84, 8287, 1, 0),
+    node(102, 8288, 1, 0), node(66, 8289, 1, 0), node(59, 0, 0, 479), node(66, 8290, 1, 0),
+    node(82, 8291, 1, 0), node(110, 8292, 1, 0), node(101, 8293, 1, 0), node(101, 8294, 1, 0),
+    node(101, 8295, 1, 0), node(59, 0, 0, 1190), node(65, 8296, 1, 0), node(86, 8297, 1, 0),
+    node(114, 8298, 1, 0), node(99, 8299, 1, 0), node(103, 8300, 1, 0), node(110, 8301, 1, 0),
+    node(86, 8302, 1, 0), node(116, 8303, 1, 0), node(114, 8304, 2, 0), node(59, 0, 0, 475),
+    node(97, 8306, 1, 0), node(71, 8307, 1, 0), node(113, 8308, 1, 0), node(101, 8309, 1, 0),
+    node(69, 8310, 1, 0), node(59, 0, 0, 491), node(119, 8311, 1, 0), node(114, 8312, 1, 0),
+    node(105, 8313, 1, 0), node(65, 8314, 1, 0), node(114, 8315, 1, 0), node(105, 8316, 1, 0),
+    node(97, 8317, 1, 0), node(65, 8318, 1, 0), node(116, 8319, 1, 0), node(99, 8320, 1, 0),
+    node(59, 0, 0, 329), node(59, 0, 0, 39), node(101, 8321, 1, 0), node(104, 8322, 1, 0),
+    node(101, 8323, 1, 0), node(97, 8324, 1, 0), node(115, 8325, 1, 0), node(110, 8326, 1, 0),
+    node(101, 8327, 1, 0), node(59, 0, 0, 1464), node(86, 8328, 1, 0), node(116, 8329, 1, 0),
+    node(105, 8330, 1, 0), node(59, 0, 0, 1220), node(114, 8331, 7, 0), node(119, 8338, 1, 0),
+    node(117, 8339, 1, 0), node(105, 8340, 1, 0), node(117, 8341, 1, 0), node(101, 8342, 1, 0),
+    node(115, 8343, 1, 0), node(97, 8344, 1, 0), node(108, 8345, 1, 0), node(71, 8346, 1, 0),
+    node(76, 8347, 1, 0), node(101, 8348, 1, 0), node(101, 8349, 1, 0), node(114, 8350, 1, 0),
+    node(83, 8351, 1, 0), node(59, 0, 0, 1020), node(69, 8352, 1, 0), node(100, 8353, 1, 0),
+    node(101, 8354, 1, 0), node(113, 8355, 1, 0), node(117, 8356, 1, 0), node(105, 8357, 1, 0),
+    node(97, 8358, 1, 0), node(68, 8359, 1, 0), node(81, 8360, 1, 0), node(59, 0, 0, 1465),
+    node(101, 8361, 1, 0), node(116, 8362, 1, 0), node(59, 0, 0, 48), node(108, 8363, 1, 0),
+    node(113, 8364, 1, 0), node(108, 8365, 1, 0), node(105, 8366, 1, 0), node(110, 8367, 2, 0),
+    node(101, 8369, 1, 0), node(117, 8370, 1, 0), node(69, 8371, 1, 0), node(101, 8372, 1, 0),
+    node(119, 8373, 3, 0), node(105, 8376, 1, 0), node(108, 8377, 1, 0), node(84, 8378, 1, 0),
+    node(86, 8379, 1, 0), node(114, 8380, 1, 0), node(114, 8381, 1, 0), node(101, 8382, 1, 0),
+    node(110, 8383, 1, 0), node(119, 8384, 1, 0), node(101, 8385, 1, 0), node(99, 8386, 1, 0),
+    node(111, 8387, 1, 0), node(119, 8388, 1, 0), node(105, 8389, 1, 0), node(111, 8390, 1, 0),
+    node(101, 8391, 1, 0), node(65, 8392, 1, 0), node(65, 8393, 1, 0), node(116, 8394, 1, 0),
+    node(114, 8395, 1, 0), node(108, 8396, 1, 0), node(101, 8397, 1, 0), node(115, 8398, 1, 0),
+    node(101, 8399, 1, 0), node(111, 8400, 1, 0), node(97, 8401, 1, 0), node(113, 8402, 1, 0),
+    node(108, 8403, 1, 0), node(105, 8404, 1, 0), node(113, 8405, 1, 0), node(59, 0, 0, 1302),
+    node(101, 8406, 1, 0), node(59, 0, 0, 1304), node(108, 8407, 1, 0), node(69, 8408, 1, 0),
+    node(101, 8409, 1, 0), node(59, 0, 0, 595), node(101, 8410, 1, 0), node(107, 8411, 1, 0),
+    node(110, 8412, 1, 0), node(59, 0, 0, 1009), node(114, 8413, 1, 0), node(119, 8414, 1, 0),
+    node(111, 8415, 1, 0), node(114, 8416, 1, 0), node(119, 8417, 1, 0), node(111, 8418, 1, 0),
+    node(65, 8419, 1, 0), node(116, 8420, 1, 0), node(97, 8421, 1, 0), node(105, 8422, 1, 0),
+    node(101, 8423, 1, 0), node(105, 8424, 1, 0), node(112, 8425, 1, 0), node(83, 8426, 1, 0),
+    node(111, 8427, 1, 0), node(59, 0, 0, 1098), node(59, 0, 0, 751), node(59, 0, 0, 1330),
+    node(108, 8428, 1, 0), node(110, 8429, 1, 0), node(114, 8430, 1, 0), node(110, 8431, 1, 0),
+    node(59, 0, 0, 876), node(59, 0, 0, 760), node(111, 8432, 1, 0), node(116, 8433, 1, 0),
+    node(114, 8434, 1, 0), node(115, 8435, 1, 0), node(116, 8436, 1, 0), node(59, 0, 0, 289),
+    node(101, 8437, 1, 0), node(99, 8438, 1, 0), node(101, 8439, 1, 0), node(119, 8440, 2, 0),
+    node(105, 8442, 1, 0), node(105, 8443, 1, 0), node(59, 0, 0, 970), node(119, 8444, 1, 0),
+    node(59, 0, 0, 419), node(114, 8445, 1, 0), node(111, 8446, 1, 0), node(114, 8447, 1, 0),
+    node(115, 8448, 1, 0), node(111, 8449, 1, 0), node(97, 8450, 1, 0), node(116, 8451, 1, 0),
+    node(59, 0, 0, 167), node(59, 0, 0, 169), node(115, 8452, 1, 0), node(59, 0, 0, 455),
+    node(59, 0, 0, 1161), node(114, 8453, 1, 0), node(97, 8454, 1, 0), node(59, 0, 0, 479),
+    node(116, 8455, 1, 0), node(111, 8456, 1, 0), node(114, 8457, 1, 0), node(97, 8458, 1, 0),
+    node(104, 8459, 1, 0), node(115, 8460, 1, 0), node(116, 8461, 1, 0), node(120, 8462, 1, 0),
+    node(59, 0, 0, 191), node(114, 8463, 1, 0), node(101, 8464, 1, 0), node(114, 8465, 1, 0),
+    node(105, 8466, 1, 0), node(111, 8467, 1, 0), node(97, 8468, 1, 0), node(108, 8469, 1, 0),
+    node(114, 8470, 1, 0), node(59, 0, 0, 495), node(110, 8471, 1, 0), node(116, 8472, 1, 0),
+    node(110, 8473, 1, 0), node(119, 8474, 1, 0), node(116, 8475, 1, 0), node(111, 8476, 1, 0),
+    node(59, 0, 0, 509), node(119, 8477, 1, 0), node(116, 8478, 1, 0), node(59, 0, 0, 513),
+    node(59, 0, 0, 516), node(111, 8479, 1, 0), node(59, 0, 0, 514), node(97, 8480, 1, 0),
+    node(59, 0, 0, 905), node(113, 8481, 1, 0), node(59, 0, 0, 907), node(113, 8482, 1, 0),
+    node(108, 8483, 1, 0), node(114, 8484, 1, 0), node(59, 0, 0, 445), node(120, 8485, 1, 0),
+    node(101, 8486, 1, 0), node(111, 8487, 1, 0), node(110, 8488, 1, 0), node(59, 0, 0, 338),
+    node(119, 8489, 2, 0), node(111, 8491, 1, 0), node(97, 8492, 1, 0), node(104, 8493, 1, 0),
+    node(116, 8494, 1, 0), node(103, 8495, 1, 0), node(101, 8496, 1, 0), node(115, 8497, 1, 0),
+    node(101, 8498, 1, 0), node(108, 8499, 1, 0), node(105, 8500, 1, 0), node(59, 0, 0, 1283),
+    node(113, 8501, 1, 0), node(113, 8502, 1, 0), node(112, 8503, 1, 0), node(104, 8504, 1, 0),
+    node(59, 0, 0, 588), node(59, 0, 0, 988), node(113, 8505, 1, 0), node(120, 8506, 1, 0),
+    node(101, 8507, 1, 0), node(111, 8508, 1, 0), node(59, 0, 0, 591), node(59, 0, 0, 990),
+    node(113, 8509, 1, 0), node(59, 0, 0, 1302), node(111, 8510, 1, 0), node(111, 8511, 1, 0),
+    node(101, 8512, 1, 0), node(59, 0, 0, 602), node(105, 8513, 1, 0), node(102, 8514, 1, 0),
+    node(103, 8515, 1, 0), node(111, 8516, 1, 0), node(108, 8517, 1, 0), node(114, 8518, 1, 0),
+    node(115, 8519, 1, 0), node(110, 8520, 1, 0), node(103, 8521, 1, 0), node(59, 0, 0, 551),
+    node(110, 8522, 1, 0), node(110, 8523, 1, 0), node(108, 8524, 1, 0), node(105, 8525, 1, 0),
+    node(59, 0, 0, 286), node(102, 8526, 1, 0), node(115, 8527, 1, 0), node(59, 0, 0, 925),
+    node(115, 8528, 1, 0), node(111, 8529, 1, 0), node(68, 8530, 1, 0), node(81, 8531, 1, 0),
+    node(101, 8532, 1, 0), node(99, 8533, 1, 0), node(108, 8534, 4, 0), node(97, 8538, 1, 0),
+    node(111, 8539, 1, 0), node(65, 8540, 1, 0), node(65, 8541, 1, 0), node(82, 8542, 1, 0),
+    node(84, 8543, 1, 0), node(76, 8544, 1, 0), node(82, 8545, 1, 0), node(116, 8546, 2, 0),
+    node(114, 8548, 1, 0), node(119, 8549, 1, 0), node(105, 8550, 1, 0), node(97, 8551, 1, 0),
+    node(112, 8552, 1, 0), node(103, 8553, 1, 0), node(101, 8554, 1, 0), node(99, 8555, 1, 0),
+    node(101, 8556, 1, 0), node(101, 8557, 1, 0), node(111, 8558, 1, 0), node(83, 8559, 1, 0),
+    node(109, 8560, 1, 0), node(59, 0, 0, 439), node(109, 8561, 1, 0), node(108, 8562, 1, 0),
+    node(108, 8563, 1, 0), node(83, 8564, 1, 0), node(59, 0, 0, 303), node(97, 8565, 1, 0),
+    node(108, 8566, 1, 0), node(97, 8567, 1, 0), node(115, 8568, 1, 0), node(110, 8569, 1, 0),
+    node(100, 8570, 1, 0), node(99, 8571, 1, 0), node(76, 8572, 1, 0), node(109, 8573, 1, 0),
+    node(59, 0, 0, 33), node(111, 8574, 1, 0), node(111, 8575, 1, 0), node(105, 8576, 1, 0),
+    node(59, 0, 0, 327), node(114, 8577, 1, 0), node(97, 8578, 1, 0), node(105, 8579, 1, 0),
+    node(103, 8580, 1, 0), node(66, 8581, 1, 0), node(101, 8582, 1, 0), node(99, 8583, 1, 0),
+    node(114, 8584, 1, 0), node(101, 8585, 1, 0), node(111, 8586, 1, 0), node(116, 8587, 1, 0),
+    node(108, 8588, 1, 0), node(86, 8589, 1, 0), node(101, 8590, 1, 0), node(111, 8591, 1, 0),
+    node(59, 0, 0, 855), node(66, 8592, 1, 0), node(114, 8593, 1, 0), node(114, 8594, 1, 0),
+    node(117, 8595, 1, 0), node(114, 8596, 1, 0), node(113, 8597, 1, 0), node(59, 0, 0, 843),
+    node(114, 8598, 1, 0), node(103, 8599, 1, 0), node(114, 8600, 1, 0), node(114, 8601, 1, 0),
+    node(103, 8602, 1, 0), node(114, 8603, 1, 0), node(114, 8604, 1, 0), node(65, 8605, 1, 0),
+    node(101, 8606, 1, 0), node(100, 8607, 1, 0), node(105, 8608, 2, 0), node(114, 8610, 1, 0),
+    node(116, 8611, 1, 0), node(76, 8612, 1, 0), node(103, 8613, 1, 0), node(110, 8614, 1, 0),
+    node(101, 8615, 1, 0), node(59, 0, 0, 897), node(108, 8616, 1, 0), node(59, 0, 0, 215),
+    node(69, 8617, 1, 0), node(70, 8618, 1, 0), node(71, 8619, 1, 0), node(76, 8620, 1, 0),
+    node(83, 8621, 1, 0), node(84, 8622, 1, 0), node(110, 8623, 1, 0), node(97, 8624, 1, 0),
+    node(97, 8625, 1, 0), node(97, 8626, 1, 0), node(97, 8627, 1, 0), node(115, 8628, 1, 0),
+    node(110, 8629, 1, 0), node(100, 8630, 1, 0), node(114, 8631, 1, 0), node(101, 8632, 1, 0),
+    node(115, 8633, 3, 0), node(69, 8636, 1, 0), node(105, 8637, 1, 0), node(117, 8638, 2, 0),
+    node(113, 8640, 1, 0), node(115, 8641, 4, 0), node(116, 8645, 2, 0), node(117, 8647, 1, 0),
+    node(108, 8648, 1, 0), node(108, 8649, 1, 0), node(108, 8650, 1, 0), node(111, 8651, 1, 0),
+    node(117, 8652, 1, 0), node(116, 8653, 1, 0), node(104, 8654, 1, 0), node(97, 8655, 1, 0),
+    node(117, 8656, 1, 0), node(97, 8657, 1, 0), node(108, 8658, 1, 0), node(59, 0, 0, 647),
+    node(97, 8659, 1, 0), node(109, 8660, 1, 0), node(105, 8661, 1, 0), node(113, 8662, 1, 0),
+    node(66, 8663, 1, 0), node(59, 0, 0, 561), node(66, 8664, 1, 0), node(76, 8665, 1, 0),
+    node(110, 8666, 1, 0), node(101, 8667, 1, 0), node(101, 8668, 1, 0), node(101, 8669, 1, 0),
+    node(59, 0, 0, 1264), node(114, 8670, 1, 0), node(99, 8671, 1, 0), node(103, 8672, 1, 0),
+    node(110, 8673, 1, 0), node(86, 8674, 1, 0), node(116, 8675, 1, 0), node(114, 8676, 2, 0),
+    node(59, 0, 0, 556), node(101, 8678, 1, 0), node(119, 8679, 1, 0), node(100, 8680, 1, 0),
+    node(114, 8681, 1, 0), node(114, 8682, 1, 0), node(65, 8683, 1, 0), node(111, 8684, 1, 0),
+    node(101, 8685, 1, 0), node(114, 8686, 1, 0), node(101, 8687, 1, 0), node(114, 8688, 1, 0),
+    node(110, 8689, 1, 0), node(108, 8690, 1, 0), node(117, 8691, 1, 0), node(97, 8692, 1, 0),
+    node(108, 8693, 1, 0), node(117, 8694, 1, 0), node(59, 0, 0, 1466), node(59, 0, 0, 579),
+    node(113, 8695, 1, 0), node(59, 0, 0, 22), node(59, 0, 0, 1467), node(101, 8696, 1, 0),
+    node(116, 8697, 1, 0), node(59, 0, 0, 1468), node(110, 8698, 1, 0), node(119, 8699, 1, 0),
+    node(105, 8700, 1, 0), node(59, 0, 0, 1446), node(119, 8701, 1, 0), node(114, 8702, 1, 0),
+    node(65, 8703, 1, 0), node(114, 8704, 1, 0), node(110, 8705, 1, 0), node(112, 8706, 1, 0),
+    node(108, 8707, 1, 0), node(97, 8708, 1, 0), node(112, 8709, 1, 0), node(110, 8710, 1, 0),
+    node(101, 8711, 2, 0), node(103, 8713, 1, 0), node(101, 8714, 1, 0), node(103, 8715, 1, 0),
+    node(119, 8716, 2, 0), node(59, 0, 0, 530), node(99, 8718, 1, 0), node(104, 8719, 1, 0),
+    node(59, 0, 0, 410), node(99, 8720, 1, 0), node(99, 8721, 1, 0), node(59, 0, 0, 774),
+    node(108, 8722, 1, 0), node(114, 8723, 1, 0), node(116, 8724, 1, 0), node(109, 8725, 1, 0),
+    node(101, 8726, 1, 0), node(114, 8727, 1, 0), node(110, 8728, 2, 0), node(59, 0, 0, 158),
+    node(115, 8730, 1, 0), node(110, 8731, 1, 0), node(108, 8732, 1, 0), node(115, 8733, 1, 0),
+    node(59, 0, 0, 166), node(114, 8734, 1, 0), node(114, 8735, 1, 0), node(97, 8736, 1, 0),
+    node(110, 8737, 2, 0), node(114, 8739, 1, 0), node(114, 8740, 1, 0), node(97, 8741, 1, 0),
+    node(113, 8742, 1, 0), node(105, 8743, 1, 0), node(59, 0, 0, 188), node(59, 0, 0, 187),
+    node(59, 0, 0, 1194), node(114, 8744, 1, 0), node(103, 8745, 1, 0), node(59, 0, 0, 623),
+    node(114, 8746, 1, 0), node(101, 8747, 1, 0), node(105, 8748, 1, 0), node(59, 0, 0, 1469),
+    node(59, 0, 0, 1470), node(103, 8749, 1, 0), node(59, 0, 0, 893), node(97, 8750, 1, 0),
+    node(119, 8751, 1, 0), node(59, 0, 0, 894), node(97, 8752, 1, 0), node(119, 8753, 1, 0),
+    node(108, 8754, 1, 0), node(59, 0, 0, 904), node(59, 0, 0, 906), node(101, 8755, 1, 0),
+    node(105, 8756, 1, 0), node(59, 0, 0, 548), node(113, 8757, 1, 0), node(120, 8758, 1, 0),
+    node(115, 8759, 1, 0), node(59, 0, 0, 561), node(116, 8760, 1, 0), node(111, 8761, 1, 0),
+    node(114, 8762, 1, 0), node(97, 8763, 1, 0), node(97, 8764, 1, 0), node(97, 8765, 1, 0),
+    node(116, 8766, 1, 0), node(101, 8767, 1, 0), node(59, 0, 0, 1266), node(108, 8768, 1, 0),
+    node(110, 8769, 1, 0), node(59, 0, 0, 1286), node(59, 0, 0, 1287), node(115, 8770, 1, 0),
+    node(105, 8771, 1, 0), node(59, 0, 0, 987), node(59, 0, 0, 573), node(113, 8772, 1, 0),
+    node(120, 8773, 1, 0), node(59, 0, 0, 989), node(120, 8774, 1, 0), node(119, 8775, 1, 0),
+    node(102, 8776, 1, 0), node(103, 8777, 1, 0), node(116, 8778, 1, 0), node(104, 8779, 1, 0),
+    node(119, 8780, 1, 0), node(101, 8781, 1, 0), node(105, 8782, 1, 0), node(59, 0, 0, 1014),
+    node(59, 0, 0, 797), node(59, 0, 0, 794), node(101, 8783, 1, 0), node(101, 8784, 1, 0),
+    node(101, 8785, 2, 0), node(111, 8787, 1, 0), node(101, 8788, 1, 0), node(59, 0, 0, 1239),
+    node(59, 0, 0, 1241), node(110, 8789, 1, 0), node(111, 8790, 1, 0), node(117, 8791, 1, 0),
+    node(103, 8792, 1, 0), node(107, 8793, 1, 0), node(65, 8794, 1, 0), node(68, 8795, 1, 0),
+    node(71, 8796, 1, 0), node(84, 8797, 1, 0), node(108, 8798, 1, 0), node(117, 8799, 1, 0),
+    node(114, 8800, 1, 0), node(114, 8801, 1, 0), node(105, 8802, 1, 0), node(101, 8803, 1, 0),
+    node(101, 8804, 1, 0), node(105, 8805, 1, 0), node(65, 8806, 1, 0), node(84, 8807, 1, 0),
+    node(111, 8808, 1, 0), node(110, 8809, 1, 0), node(99, 8810, 1, 0), node(114, 8811, 1, 0),
+    node(65, 8812, 1, 0), node(104, 8813, 1, 0), node(86, 8814, 1, 0), node(116, 8815, 1, 0),
+    node(101, 8816, 1, 0), node(99, 8817, 1, 0), node(119, 8818, 1, 0), node(113, 8819, 1, 0),
+    node(97, 8820, 1, 0), node(59, 0, 0, 955), node(69, 8821, 1, 0), node(83, 8822, 1, 0),
+    node(109, 8823, 1, 0), node(108, 8824, 2, 0), node(69, 8826, 1, 0), node(116, 8827, 1, 0),
+    node(59, 0, 0, 31), node(116, 8828, 1, 0), node(101, 8829, 1, 0), node(101, 8830, 1, 0),
+    node(105, 8831, 1, 0), node(112, 8832, 1, 0), node(110, 8833, 1, 0), node(109, 8834, 1, 0),
+    node(109, 8835, 1, 0), node(97, 8836, 1, 0), node(114, 8837, 1, 0), node(103, 8838, 1, 0),
+    node(59, 0, 0, 851), node(114, 8839, 1, 0), node(86, 8840, 1, 0), node(116, 8841, 1, 0),
+    node(114, 8842, 1, 0), node(99, 8843, 1, 0), node(119, 8844, 1, 0), node(111, 8845, 1, 0),
+    node(101, 8846, 3, 0), node(101, 8849, 1, 0), node(99, 8850, 1, 0), node(114, 8851, 2, 0),
+    node(97, 8853, 1, 0), node(114, 8854, 1, 0), node(101, 8855, 1, 0), node(97, 8856, 1, 0),
+    node(59, 0, 0, 38), node(117, 8857, 1, 0), node(111, 8858, 1, 0), node(104, 8859, 1, 0),
+    node(114, 8860, 1, 0), node(111, 8861, 1, 0), node(104, 8862, 1, 0), node(114, 8863, 1, 0),
+    node(114, 8864, 1, 0), node(114, 8865, 1, 0), node(59, 0, 0, 1471), node(105, 8866, 1, 0),
+    node(99, 8867, 1, 0), node(110, 8868, 1, 0), node(121, 8869, 1, 0), node(101, 8870, 1, 0),
+    node(101, 8871, 1, 0), node(83, 8872, 1, 0), node(116, 8873, 1, 0), node(114, 8874, 1, 0),
+    node(100, 8875, 1, 0), node(113, 8876, 1, 0), node(117, 8877, 1, 0), node(114, 8878, 1, 0),
+    node(101, 8879, 1, 0), node(108, 8880, 1, 0), node(105, 8881, 1, 0), node(72, 8882, 1, 0),
+    node(108, 8883, 1, 0), node(110, 8884, 1, 0), node(108, 8885, 1, 0), node(116, 8886, 1, 0),
+    node(59, 0, 0, 504), node(116, 8887, 1, 0), node(101, 8888, 1, 0), node(101, 8889, 1, 0),
+    node(115, 8890, 1, 0), node(59, 0, 0, 220), node(69, 8891, 1, 0), node(83, 8892, 1, 0),
+    node(108, 8893, 1, 0), node(97, 8894, 1, 0), node(98, 8895, 1, 0), node(112, 8896, 1, 0),
+    node(117, 8897, 1, 0), node(59, 0, 0, 221), node(69, 8898, 1, 0), node(83, 8899, 1, 0),
+    node(84, 8900, 1, 0), node(59, 0, 0, 1021), node(69, 8901, 1, 0), node(97, 8902, 1, 0),
+    node(108, 8903, 1, 0), node(100, 8904, 1, 0), node(66, 8905, 1, 0), node(117, 8906, 1, 0),
+    node(111, 8907, 1, 0), node(59, 0, 0, 594), node(101, 8908, 1, 0), node(110, 8909, 1, 0),
+    node(97, 8910, 1, 0), node(110, 8911, 1, 0), node(100, 8912, 1, 0), node(108, 8913, 1, 0),
+    node(101, 8914, 1, 0), node(108, 8915, 1, 0), node(117, 8916, 1, 0), node(114, 8917, 1, 0),
+    node(97, 8918, 1, 0), node(101, 8919, 1, 0), node(103, 8920, 1, 0), node(66, 8921, 1, 0),
+    node(101, 8922, 1, 0), node(99, 8923, 1, 0), node(111, 8924, 1, 0), node(116, 8925, 1, 0),
+    node(108, 8926, 1, 0), node(86, 8927, 1, 0), node(101, 8928, 1, 0), node(111, 8929, 1, 0),
+    node(59, 0, 0, 953), node(66, 8930, 1, 0), node(115, 8931, 1, 0), node(59, 0, 0, 939),
+    node(59, 0, 0, 1472), node(114, 8932, 1, 0), node(114, 8933, 1, 0), node(114, 8934, 1, 0),
+    node(119, 8935, 1, 0), node(59, 0, 0, 1107), node(115, 8936, 1, 0), node(116, 8937, 2, 0),
+    node(115, 8939, 1, 0), node(59, 0, 0, 983), node(59, 0, 0, 589), node(97, 8940, 1, 0),
+    node(110, 8941, 1, 0), node(100, 8942, 1, 0), node(97, 8943, 1, 0), node(117, 8944, 1, 0),
+    node(116, 8945, 1, 0), node(104, 8946, 1, 0), node(65, 8947, 1, 0), node(59, 0, 0, 615),
+    node(117, 8948, 1, 0), node(59, 0, 0, 613), node(114, 8949, 1, 0), node(114, 8950, 1, 0),
+    node(59, 0, 0, 204), node(101, 8951, 1, 0), node(97, 8952, 1, 0), node(100, 8953, 1, 0),
+    node(99, 8954, 1, 0), node(97, 8955, 1, 0), node(59, 0, 0, 707), node(100, 8956, 1, 0),
+    node(117, 8957, 1, 0), node(101, 8958, 1, 0), node(59, 0, 0, 585), node(108, 8959, 1, 0),
+    node(108, 8960, 1, 0), node(114, 8961, 1, 0), node(59, 0, 0, 531), node(59, 0, 0, 914),
+    node(59, 0, 0, 769), node(59, 0, 0, 770), node(101, 8962, 1, 0), node(105, 8963, 1, 0),
+    node(59, 0, 0, 782), node(101, 8964, 1, 0), node(100, 8965, 1, 0), node(111, 8966, 1, 0),
+    node(108, 8967, 1, 0), node(114, 8968, 1, 0), node(59, 0, 0, 160), node(59, 0, 0, 300),
+    node(101, 8969, 1, 0), node(101, 8970, 1, 0), node(111, 8971, 1, 0), node(114, 8972, 1, 0),
+    node(105, 8973, 1, 0), node(100, 8974, 1, 0), node(117, 8975, 1, 0), node(111, 8976, 1, 0),
+    node(114, 8977, 1, 0), node(114, 8978, 1, 0), node(117, 8979, 1, 0), node(109, 8980, 1, 0),
+    node(111, 8981, 1, 0), node(104, 8982, 1, 0), node(114, 8983, 1, 0), node(102, 8984, 1, 0),
+    node(103, 8985, 1, 0), node(108, 8986, 1, 0), node(114, 8987, 1, 0), node(59, 0, 0, 900),
+    node(114, 8988, 1, 0), node(59, 0, 0, 901), node(108, 8989, 1, 0), node(102, 8990, 1, 0),
+    node(103, 8991, 1, 0), node(59, 0, 0, 934), node(59, 0, 0, 936), node(59, 0, 0, 308),
+    node(97, 8992, 1, 0), node(110, 8993, 2, 0), node(114, 8995, 1, 0), node(114, 8996, 1, 0),
+    node(114, 8997, 1, 0), node(114, 8998, 1, 0), node(105, 8999, 1, 0), node(113, 9000, 1, 0),
+    node(101, 9001, 1, 0), node(117, 9002, 1, 0), node(105, 9003, 1, 0), node(59, 0, 0, 544),
+    node(59, 0, 0, 966), node(59, 0, 0, 968), node(59, 0, 0, 22), node(110, 9004, 1, 0),
+    node(116, 9005, 2, 0), node(104, 9007, 1, 0), node(97, 9008, 1, 0), node(116, 9009, 1, 0),
+    node(59, 0, 0, 615), node(102, 9010, 1, 0), node(103, 9011, 1, 0), node(113, 9012, 2, 0),
+    node(113, 9014, 2, 0), node(108, 9016, 1, 0), node(114, 9017, 1, 0), node(110, 9018, 1, 0),
+    node(114, 9019, 1, 0), node(116, 9020, 1, 0), node(117, 9021, 1, 0), node(111, 9022, 1, 0),
+    node(114, 9023, 1, 0), node(119, 9024, 1, 0), node(99, 9025, 1, 0), node(111, 9026, 2, 0),
+    node(114, 9028, 1, 0), node(105, 9029, 1, 0), node(68, 9030, 1, 0), node(114, 9031, 1, 0),
+    node(114, 9032, 1, 0), node(114, 9033, 1, 0), node(103, 9034, 1, 0), node(101, 9035, 1, 0),
+    node(102, 9036, 1, 0), node(103, 9037, 1, 0), node(114, 9038, 1, 0), node(101, 9039, 1, 0),
+    node(119, 9040, 1, 0), node(65, 9041, 1, 0), node(97, 9042, 1, 0), node(59, 0, 0, 1473),
+    node(114, 9043, 1, 0), node(116, 9044, 1, 0), node(101, 9045, 1, 0), node(111, 9046, 1, 0),
+    node(86, 9047, 1, 0), node(116, 9048, 1, 0), node(59, 0, 0, 1469), node(117, 9049, 1, 0),
+    node(108, 9050, 1, 0), node(59, 0, 0, 25), node(113, 9051, 1, 0), node(97, 9052, 1, 0),
+    node(59, 0, 0, 30), node(76, 9053, 1, 0), node(113, 9054, 1, 0), node(101, 9055, 1, 0),
+    node(69, 9056, 1, 0), node(59, 0, 0, 453), node(59, 0, 0, 309), node(110, 9057, 1, 0),
+    node(59, 0, 0, 402), node(59, 0, 0, 621), node(109, 9058, 1, 0), node(101, 9059, 1, 0),
+    node(99, 9060, 1, 0), node(59, 0, 0, 846), node(104, 9061, 1, 0), node(97, 9062, 1, 0),
+    node(101, 9063, 1, 0), node(111, 9064, 1, 0), node(111, 9065, 1, 0), node(116, 9066, 1, 0),
+    node(59, 0, 0, 1470), node(114, 9067, 1, 0), node(59, 0, 0, 1019), node(66, 9068, 1, 0),
+    node(69, 9069, 1, 0), node(99, 9070, 1, 0), node(116, 9071, 1, 0), node(59, 0, 0, 1003),
+    node(66, 9072, 1, 0), node(114, 9073, 1, 0), node(111, 9074, 1, 0), node(97, 9075, 1, 0),
+    node(108, 9076, 1, 0), node(97, 9077, 1, 0), node(119, 9078, 1, 0), node(116, 9079, 1, 0),
+    node(111, 9080, 1, 0), node(119, 9081, 1, 0), node(116, 9082, 1, 0), node(111, 9083, 1, 0),
+    node(111, 9084, 1, 0), node(114, 9085, 1, 0), node(117, 9086, 1, 0), node(107, 9087, 1, 0),
+    node(83, 9088, 1, 0), node(84, 9089, 1, 0), node(114, 9090, 1, 0), node(115, 9091, 1, 0),
+    node(112, 9092, 1, 0), node(59, 0, 0, 1219), node(116, 9093, 1, 0), node(101, 9094, 1, 0),
+    node(117, 9095, 1, 0), node(108, 9096, 1, 0), node(101, 9097, 1, 0), node(115, 9098, 1, 0),
+    node(97, 9099, 1, 0), node(108, 9100, 1, 0), node(117, 9101, 1, 0), node(59, 0, 0, 1215),
+    node(103, 9102, 1, 0), node(59, 0, 0, 218), node(101, 9103, 1, 0), node(69, 9104, 1, 0),
+    node(59, 0, 0, 895), node(97, 9105, 1, 0), node(115, 9106, 1, 0), node(113, 9107, 1, 0),
+    node(108, 9108, 1, 0), node(101, 9109, 1, 0), node(110, 9110, 1, 0), node(115, 9111, 1, 0),
+    node(101, 9112, 1, 0), node(97, 9113, 1, 0), node(113, 9114, 1, 0), node(108, 9115, 1, 0),
+    node(105, 9116, 1, 0), node(113, 9117, 1, 0), node(108, 9118, 1, 0), node(69, 9119, 1, 0),
+    node(101, 9120, 1, 0), node(97, 9121, 1, 0), node(98, 9122, 1, 0), node(116, 9123, 1, 0),
+    node(115, 9124, 1, 0), node(101, 9125, 1, 0), node(108, 9126, 1, 0), node(116, 9127, 1, 0),
+    node(101, 9128, 1, 0), node(59, 0, 0, 551), node(110, 9129, 1, 0), node(105, 9130, 1, 0),
+    node(105, 9131, 1, 0), node(97, 9132, 1, 0), node(114, 9133, 1, 0), node(102, 9134, 1, 0),
+    node(59, 0, 0, 950), node(114, 9135, 1, 0), node(86, 9136, 1, 0), node(116, 9137, 1, 0),
+    node(119, 9138, 1, 0), node(111, 9139, 1, 0), node(101, 9140, 3, 0), node(101, 9143, 1, 0),
+    node(99, 9144, 1, 0), node(114, 9145, 2, 0), node(97, 9147, 1, 0), node(59, 0, 0, 1474),
+    node(111, 9148, 1, 0), node(111, 9149, 1, 0), node(114, 9150, 1, 0), node(59, 0, 0, 607),
+    node(101, 9151, 1, 0), node(59, 0, 0, 984), node(69, 9152, 1, 0), node(101, 9153, 1, 0),
+    node(108, 9154, 1, 0), node(116, 9155, 1, 0), node(101, 9156, 1, 0), node(108, 9157, 1, 0),
+    node(97, 9158, 1, 0), node(59, 0, 0, 389), node(101, 9159, 1, 0), node(114, 9160, 1, 0),
+    node(109, 9161, 1, 0), node(111, 9162, 1, 0), node(114, 9163, 1, 0), node(59, 0, 0, 616),
+    node(114, 9164, 1, 0), node(101, 9165, 1, 0), node(101, 9166, 1, 0), node(99, 9167, 1, 0),
+    node(111, 9168, 1, 0), node(112, 9169, 1, 0), node(59, 0, 0, 488), node(101, 9170, 4, 0),
+    node(101, 9174, 1, 0), node(105, 9175, 1, 0), node(102, 9176, 1, 0), node(103, 9177, 1, 0),
+    node(115, 9178, 1, 0), node(103, 9179, 1, 0), node(119, 9180, 1, 0), node(101, 9181, 1, 0),
+    node(105, 9182, 1, 0), node(59, 0, 0, 25), node(113, 9183, 1, 0), node(119, 9184, 1, 0),
+    node(111, 9185, 1, 0), node(108, 9186, 1, 0), node(111, 9187, 1, 0), node(112, 9188, 1, 0),
+    node(119, 9189, 1, 0), node(111, 9190, 1, 0), node(112, 9191, 1, 0), node(105, 9192, 1, 0),
+    node(101, 9193, 1, 0), node(119, 9194, 1, 0), node(116, 9195, 1, 0), node(111, 9196, 1, 0),
+    node(116, 9197, 1, 0), node(104, 9198, 1, 0), node(101, 9199, 1, 0), node(114, 9200, 1, 0),
+    node(114, 9201, 1, 0), node(101, 9202, 1, 0), node(116, 9203, 2, 0), node(104, 9205, 1, 0),
+    node(105, 9206, 1, 0), node(100, 9207, 1, 0), node(117, 9208, 1, 0), node(111, 9209, 1, 0),
+    node(112, 9210, 1, 0), node(114, 9211, 1, 0), node(114, 9212, 1, 0), node(109, 9213, 1, 0),
+    node(59, 0, 0, 799), node(108, 9214, 1, 0), node(115, 9215, 1, 0), node(108, 9216, 1, 0),
+    node(59, 0, 0, 427), node(59, 0, 0, 494), node(101, 9217, 1, 0), node(116, 9218, 2, 0),
+    node(114, 9220, 1, 0), node(97, 9221, 1, 0), node(116, 9222, 1, 0), node(104, 9223, 1, 0),
+    node(59, 0, 0, 1324), node(113, 9224, 1, 0), node(59, 0, 0, 1326), node(113, 9225, 1, 0),
+    node(101, 9226, 1, 0), node(105, 9227, 1, 0), node(59, 0, 0, 21), node(101, 9228, 1, 0),
+    node(111, 9229, 1, 0), node(98, 9230, 1, 0), node(116, 9231, 1, 0), node(97, 9232, 1, 0),
+    node(105, 9233, 1, 0), node(117, 9234, 1, 0), node(116, 9235, 1, 0), node(117, 9236, 1, 0),
+    node(97, 9237, 1, 0), node(108, 9238, 1, 0), node(59, 0, 0, 23), node(73, 9239, 1, 0),
+    node(111, 9240, 1, 0), node(111, 9241, 1, 0), node(104, 9242, 1, 0), node(59, 0, 0, 649),
+    node(116, 9243, 2, 0), node(104, 9245, 1, 0), node(114, 9246, 1, 0), node(101, 9247, 1, 0),
+    node(59, 0, 0, 605), node(114, 9248, 1, 0), node(108, 9249, 1, 0), node(114, 9250, 1, 0),
+    node(86, 9251, 1, 0), node(99, 9252, 1, 0), node(114, 9253, 2, 0), node(101, 9255, 1, 0),
+    node(111, 9256, 1, 0), node(97, 9257, 1, 0), node(108, 9258, 1, 0), node(117, 9259, 1, 0),
+    node(108, 9260, 1, 0), node(101, 9261, 1, 0), node(117, 9262, 1, 0), node(114, 9263, 1, 0),
+    node(113, 9264, 1, 0), node(101, 9265, 1, 0), node(97, 9266, 1, 0), node(115, 9267, 1, 0),
+    node(107, 9268, 1, 0), node(116, 9269, 1, 0), node(99, 9270, 1, 0), node(99, 9271, 1, 0),
+    node(114, 9272, 2, 0), node(119, 9274, 1, 0), node(111, 9275, 1, 0), node(59, 0, 0, 1475),
+    node(97, 9276, 1, 0), node(113, 9277, 1, 0), node(116, 9278, 1, 0), node(111, 9279, 1, 0),
+    node(97, 9280, 1, 0), node(59, 0, 0, 1476), node(119, 9281, 1, 0), node(116, 9282, 1, 0),
+    node(59, 0, 0, 36), node(108, 9283, 1, 0), node(59, 0, 0, 1029), node(65, 9284, 1, 0),
+    node(119, 9285, 1, 0), node(59, 0, 0, 1028), node(97, 9286, 1, 0), node(119, 9287, 1, 0),
+    node(119, 9288, 1, 0), node(111, 9289, 1, 0), node(109, 9290, 1, 0), node(83, 9291, 1, 0),
+    node(112, 9292, 1, 0), node(104, 9293, 1, 0), node(71, 9294, 1, 0), node(115, 9295, 1, 0),
+    node(97, 9296, 1, 0), node(105, 9297, 1, 0), node(59, 0, 0, 888), node(97, 9298, 1, 0),
+    node(108, 9299, 1, 0), node(97, 9300, 1, 0), node(115, 9301, 1, 0), node(110, 9302, 1, 0),
+    node(100, 9303, 1, 0), node(109, 9304, 1, 0), node(108, 9305, 1, 0), node(114, 9306, 1, 0),
+    node(113, 9307, 1, 0), node(116, 9308, 1, 0), node(76, 9309, 1, 0), node(117, 9310, 1, 0),
+    node(97, 9311, 1, 0), node(109, 9312, 1, 0), node(103, 9313, 1, 0), node(101, 9314, 1, 0),
+    node(114, 9315, 1, 0), node(108, 9316, 1, 0), node(117, 9317, 1, 0), node(97, 9318, 1, 0),
+    node(108, 9319, 1, 0), node(117, 9320, 1, 0), node(59, 0, 0, 903), node(113, 9321, 1, 0),
+    node(59, 0, 0, 209), node(114, 9322, 1, 0), node(108, 9323, 1, 0), node(101, 9324, 1, 0),
+    node(105, 9325, 1, 0), node(59, 0, 0, 82), node(59, 0, 0, 240), node(69, 9326, 1, 0),
+    node(59, 0, 0, 937), node(116, 9327, 1, 0), node(98, 9328, 1, 0), node(108, 9329, 1, 0),
+    node(99, 9330, 1, 0), node(59, 0, 0, 943), node(116, 9331, 1, 0), node(97, 9332, 1, 0),
+    node(101, 9333, 1, 0), node(111, 9334, 1, 0), node(59, 0, 0, 200), node(114, 9335, 1, 0),
+    node(59, 0, 0, 1022), node(66, 9336, 1, 0), node(69, 9337, 1, 0), node(99, 9338, 1, 0),
+    node(116, 9339, 1, 0), node(59, 0, 0, 1004), node(66, 9340, 1, 0), node(114, 9341, 1, 0),
+    node(119, 9342, 1, 0), node(119, 9343, 1, 0), node(111, 9344, 1, 0), node(99, 9345, 1, 0),
+    node(113, 9346, 1, 0), node(116, 9347, 2, 0), node(59, 0, 0, 248), node(69, 9349, 1, 0),
+    node(59, 0, 0, 969), node(59, 0, 0, 592), node(108, 9350, 1, 0), node(115, 9351, 1, 0),
+    node(114, 9352, 1, 0), node(59, 0, 0, 1001), node(119, 9353, 1, 0), node(111, 9354, 1, 0),
+    node(97, 9355, 1, 0), node(59, 0, 0, 55), node(59, 0, 0, 1159), node(101, 9356, 1, 0),
+    node(119, 9357, 1, 0), node(59, 0, 0, 1033), node(59, 0, 0, 1013), node(100, 9358, 1, 0),
+    node(108, 9359, 1, 0), node(114, 9360, 1, 0), node(102, 9361, 1, 0), node(103, 9362, 1, 0),
+    node(116, 9363, 1, 0), node(104, 9364, 1, 0), node(59, 0, 0, 1120), node(101, 9365, 1, 0),
+    node(115, 9366, 1, 0), node(102, 9367, 1, 0), node(103, 9368, 1, 0), node(59, 0, 0, 791),
+    node(59, 0, 0, 1180), node(119, 9369, 1, 0), node(59, 0, 0, 1183), node(119, 9370, 1, 0),
+    node(59, 0, 0, 855), node(115, 9371, 1, 0), node(119, 9372, 2, 0), node(111, 9374, 1, 0),
+    node(103, 9375, 1, 0), node(115, 9376, 1, 0), node(59, 0, 0, 1029), node(97, 9377, 1, 0),
+    node(119, 9378, 1, 0), node(59, 0, 0, 1181), node(116, 9379, 1, 0), node(59, 0, 0, 1090),
+    node(111, 9380, 1, 0), node(111, 9381, 1, 0), node(108, 9382, 1, 0), node(59, 0, 0, 896),
+    node(101, 9383, 1, 0), node(116, 9384, 2, 0), node(108, 9386, 1, 0), node(111, 9387, 1, 0),
+    node(112, 9388, 1, 0), node(119, 9389, 1, 0), node(111, 9390, 1, 0), node(111, 9391, 1, 0),
+    node(111, 9392, 1, 0), node(101, 9393, 1, 0), node(59, 0, 0, 233), node(59, 0, 0, 974),
+    node(111, 9394, 1, 0), node(113, 9395, 1, 0), node(59, 0, 0, 572), node(101, 9396, 1, 0),
+    node(114, 9397, 1, 0), node(114, 9398, 1, 0), node(59, 0, 0, 1003), node(116, 9399, 1, 0),
+    node(59, 0, 0, 1323), node(59, 0, 0, 1325), node(102, 9400, 1, 0), node(103, 9401, 1, 0),
+    node(110, 9402, 1, 0), node(117, 9403, 1, 0), node(108, 9404, 1, 0), node(101, 9405, 1, 0),
+    node(108, 9406, 1, 0), node(115, 9407, 1, 0), node(116, 9408, 1, 0), node(59, 0, 0, 155),
+    node(98, 9409, 1, 0), node(118, 9410, 1, 0), node(100, 9411, 1, 0), node(110, 9412, 1, 0),
+    node(119, 9413, 1, 0), node(119, 9414, 1, 0), node(116, 9415, 1, 0), node(65, 9416, 1, 0),
+    node(82, 9417, 1, 0), node(116, 9418, 1, 0), node(111, 9419, 1, 0), node(59, 0, 0, 1016),
+    node(114, 9420, 1, 0), node(66, 9421, 1, 0), node(111, 9422, 1, 0), node(101, 9423, 1, 0),
+    node(116, 9424, 1, 0), node(59, 0, 0, 854), node(66, 9425, 1, 0), node(99, 9426, 1, 0),
+    node(114, 9427, 2, 0), node(114, 9429, 1, 0), node(83, 9430, 1, 0), node(97, 9431, 1, 0),
+    node(108, 9432, 1, 0), node(115, 9433, 1, 0), node(97, 9434, 1, 0), node(59, 0, 0, 1477),
+    node(117, 9435, 1, 0), node(59, 0, 0, 396), node(59, 0, 0, 32), node(59, 0, 0, 35),
+    node(101, 9436, 1, 0), node(65, 9437, 1, 0), node(107, 9438, 1, 0), node(116, 9439, 1, 0),
+    node(59, 0, 0, 780), node(66, 9440, 1, 0), node(59, 0, 0, 457), node(114, 9441, 1, 0),
+    node(114, 9442, 1, 0), node(117, 9443, 1, 0), node(111, 9444, 1, 0), node(114, 9445, 1, 0),
+    node(114, 9446, 1, 0), node(59, 0, 0, 179), node(101, 9447, 1, 0), node(59, 0, 0, 192),
+    node(114, 9448, 1, 0), node(59, 0, 0, 1032), node(114, 9449, 1, 0), node(59, 0, 0, 1031),
+    node(59, 0, 0, 992), node(119, 9450, 1, 0), node(83, 9451, 1, 0), node(112, 9452, 1, 0),
+    node(97, 9453, 1, 0), node(105, 9454, 1, 0), node(114, 9455, 1, 0), node(59, 0, 0, 10),
+    node(99, 9456, 1, 0), node(99, 9457, 1, 0), node(108, 9458, 1, 0), node(69, 9459, 1, 0),
+    node(116, 9460, 1, 0), node(59, 0, 0, 523), node(116, 9461, 1, 0), node(101, 9462, 1, 0),
+    node(112, 9463, 1, 0), node(101, 9464, 3, 0), node(59, 0, 0, 524), node(117, 9467, 1, 0),
+    node(101, 9468, 1, 0), node(101, 9469, 1, 0), node(97, 9470, 1, 0), node(110, 9471, 1, 0),
+    node(101, 9472, 1, 0), node(108, 9473, 1, 0), node(116, 9474, 2, 0), node(115, 9476, 1, 0),
+    node(59, 0, 0, 905), node(97, 9477, 1, 0), node(110, 9478, 1, 0), node(100, 9479, 1, 0),
+    node(97, 9480, 1, 0), node(117, 9481, 1, 0), node(59, 0, 0, 514), node(101, 9482, 1, 0),
+    node(59, 0, 0, 869), node(115, 9483, 1, 0), node(113, 9484, 1, 0), node(59, 0, 0, 42),
+    node(114, 9485, 1, 0), node(105, 9486, 1, 0), node(107, 9487, 1, 0), node(65, 9488, 1, 0),
+    node(99, 9489, 1, 0), node(99, 9490, 1, 0), node(114, 9491, 2, 0), node(59, 0, 0, 1478),
+    node(97, 9493, 1, 0), node(113, 9494, 1, 0), node(116, 9495, 1, 0), node(111, 9496, 1, 0),
+    node(97, 9497, 1, 0), node(59, 0, 0, 1479), node(59, 0, 0, 419), node(59, 0, 0, 479),
+    node(119, 9498, 1, 0), node(116, 9499, 1, 0), node(117, 9500, 1, 0), node(59, 0, 0, 985),
+    node(69, 9501, 1, 0), node(113, 9502, 1, 0), node(59, 0, 0, 411), node(105, 9503, 1, 0),
+    node(111, 9504, 1, 0), node(59, 0, 0, 912), node(119, 9505, 1, 0), node(116, 9506, 1, 0),
+    node(59, 0, 0, 1480), node(110, 9507, 1, 0), node(111, 9508, 1, 0), node(101, 9509, 1, 0),
+    node(105, 9510, 1, 0), node(116, 9511, 1, 0), node(104, 9512, 1, 0), node(59, 0, 0, 1110),
+    node(116, 9513, 1, 0), node(59, 0, 0, 1038), node(59, 0, 0, 778), node(116, 9514, 1, 0),
+    node(104, 9515, 1, 0), node(59, 0, 0, 1256), node(110, 9516, 1, 0), node(59, 0, 0, 857),
+    node(59, 0, 0, 457), node(115, 9517, 1, 0), node(111, 9518, 1, 0), node(97, 9519, 1, 0),
+    node(59, 0, 0, 1202), node(114, 9520, 1, 0), node(59, 0, 0, 1032), node(59, 0, 0, 1257),
+    node(119, 9521, 1, 0), node(119, 9522, 1, 0), node(59, 0, 0, 516), node(113, 9523, 1, 0),
+    node(59, 0, 0, 902), node(101, 9524, 1, 0), node(59, 0, 0, 1259), node(119, 9525, 1, 0),
+    node(59, 0, 0, 953), node(115, 9526, 1, 0), node(111, 9527, 1, 0), node(119, 9528, 1, 0),
+    node(119, 9529, 1, 0), node(115, 9530, 1, 0), node(110, 9531, 1, 0), node(59, 0, 0, 872),
+    node(113, 9532, 1, 0), node(111, 9533, 1, 0), node(114, 9534, 1, 0), node(59, 0, 0, 1004),
+    node(116, 9535, 1, 0), node(104, 9536, 1, 0), node(116, 9537, 1, 0), node(114, 9538, 1, 0),
+    node(101, 9539, 1, 0), node(59, 0, 0, 962), node(59, 0, 0, 536), node(101, 9540, 1, 0),
+    node(101, 9541, 1, 0), node(108, 9542, 1, 0), node(101, 9543, 1, 0), node(101, 9544, 1, 0),
+    node(116, 9545, 1, 0), node(59, 0, 0, 417), node(59, 0, 0, 475), node(65, 9546, 1, 0),
+    node(114, 9547, 1, 0), node(105, 9548, 1, 0), node(65, 9549, 1, 0), node(119, 9550, 1, 0),
+    node(111, 9551, 1, 0), node(97, 9552, 1, 0), node(119, 9553, 1, 0), node(99, 9554, 1, 0),
+    node(111, 9555, 1, 0), node(97, 9556, 1, 0), node(116, 9557, 1, 0), node(59, 0, 0, 952),
+    node(66, 9558, 1, 0), node(101, 9559, 1, 0), node(113, 9560, 1, 0), node(114, 9561, 1, 0),
+    node(83, 9562, 1, 0), node(115, 9563, 1, 0), node(108, 9564, 1, 0), node(97, 9565, 1, 0),
+    node(116, 9566, 1, 0), node(114, 9567, 1, 0), node(101, 9568, 1, 0), node(111, 9569, 1, 0),
+    node(97, 9570, 1, 0), node(59, 0, 0, 1481), node(59, 0, 0, 1482), node(97, 9571, 1, 0),
+    node(114, 9572, 1, 0), node(59, 0, 0, 1483), node(59, 0, 0, 1484), node(114, 9573, 1, 0),
+    node(114, 9574, 1, 0), node(114, 9575, 1, 0), node(59, 0, 0, 973), node(112, 9576, 1, 0),
+    node(97, 9577, 1, 0), node(99, 9578, 1, 0), node(110, 9579, 1, 0), node(101, 9580, 1, 0),
+    node(101, 9581, 1, 0), node(97, 9582, 1, 0), node(59, 0, 0, 214), node(113, 9583, 1, 0),
+    node(101, 9584, 1, 0), node(69, 9585, 1, 0), node(59, 0, 0, 889), node(59, 0, 0, 882),
+    node(59, 0, 0, 896), node(66, 9586, 1, 0), node(69, 9587, 1, 0), node(97, 9588, 1, 0),
+    node(114, 9589, 1, 0), node(115, 9590, 1, 0), node(108, 9591, 1, 0), node(116, 9592, 1, 0),
+    node(110, 9593, 1, 0), node(101, 9594, 3, 0), node(59, 0, 0, 1485), node(69, 9597, 1, 0),
+    node(101, 9598, 1, 0), node(108, 9599, 1, 0), node(116, 9600, 1, 0), node(101, 9601, 1, 0),
+    node(108, 9602, 1, 0), node(97, 9603, 1, 0), node(81, 9604, 1, 0), node(59, 0, 0, 1486),
+    node(117, 9605, 1, 0), node(105, 9606, 1, 0), node(98, 9607, 1, 0), node(101, 9608, 1, 0),
+    node(114, 9609, 1, 0), node(107, 9610, 1, 0), node(116, 9611, 1, 0), node(59, 0, 0, 781),
+    node(66, 9612, 1, 0), node(114, 9613, 1, 0), node(117, 9614, 1, 0), node(111, 9615, 1, 0),
+    node(114, 9616, 1, 0), node(114, 9617, 1, 0), node(59, 0, 0, 561), node(105, 9618, 1, 0),
+    node(97, 9619, 1, 0), node(113, 9620, 1, 0), node(117, 9621, 1, 0), node(115, 9622, 1, 0),
+    node(119, 9623, 1, 0), node(59, 0, 0, 886), node(111, 9624, 1, 0), node(59, 0, 0, 1025),
+    node(119, 9625, 1, 0), node(102, 9626, 1, 0), node(103, 9627, 1, 0), node(59, 0, 0, 919),
+    node(116, 9628, 1, 0), node(59, 0, 0, 1114), node(59, 0, 0, 780), node(116, 9629, 1, 0),
+    node(59, 0, 0, 854), node(59, 0, 0, 864), node(110, 9630, 1, 0), node(114, 9631, 1, 0),
+    node(114, 9632, 1, 0), node(59, 0, 0, 890), node(59, 0, 0, 891), node(59, 0, 0, 1221),
+    node(113, 9633, 1, 0), node(110, 9634, 1, 0), node(59, 0, 0, 954), node(110, 9635, 1, 0),
+    node(115, 9636, 1, 0), node(59, 0, 0, 945), node(59, 0, 0, 1270), node(59, 0, 0, 797),
+    node(59, 0, 0, 963), node(119, 9637, 1, 0), node(111, 9638, 1, 0), node(59, 0, 0, 1019),
+    node(116, 9639, 1, 0), node(105, 9640, 1, 0), node(73, 9641, 1, 0), node(81, 9642, 1, 0),
+    node(67, 9643, 1, 0), node(59, 0, 0, 378), node(101, 9644, 1, 0), node(59, 0, 0, 817),
+    node(59, 0, 0, 994), node(101, 9645, 1, 0), node(114, 9646, 1, 0), node(114, 9647, 1, 0),
+    node(103, 9648, 1, 0), node(114, 9649, 1, 0), node(59, 0, 0, 556), node(119, 9650, 1, 0),
+    node(114, 9651, 1, 0), node(59, 0, 0, 787), node(116, 9652, 1, 0), node(114, 9653, 1, 0),
+    node(114, 9654, 1, 0), node(111, 9655, 1, 0), node(97, 9656, 1, 0), node(59, 0, 0, 1487),
+    node(117, 9657, 1, 0), node(101, 9658, 1, 0), node(113, 9659, 1, 0), node(59, 0, 0, 169),
+    node(59, 0, 0, 29), node(108, 9660, 1, 0), node(59, 0, 0, 477), node(114, 9661, 1, 0),
+    node(116, 9662, 1, 0), node(114, 9663, 1, 0), node(114, 9664, 1, 0), node(108, 9665, 1, 0),
+    node(59, 0, 0, 1488), node(59, 0, 0, 191), node(111, 9666, 1, 0), node(111, 9667, 1, 0),
+    node(97, 9668, 1, 0), node(99, 9669, 1, 0), node(101, 9670, 1, 0), node(83, 9671, 1, 0),
+    node(97, 9672, 1, 0), node(59, 0, 0, 210), node(108, 9673, 1, 0), node(117, 9674, 1, 0),
+    node(114, 9675, 1, 0), node(113, 9676, 1, 0), node(97, 9677, 1, 0), node(113, 9678, 1, 0),
+    node(108, 9679, 1, 0), node(71, 9680, 1, 0), node(115, 9681, 1, 0), node(59, 0, 0, 517),
+    node(69, 9682, 1, 0), node(116, 9683, 1, 0), node(59, 0, 0, 902), node(66, 9684, 1, 0),
+    node(69, 9685, 1, 0), node(113, 9686, 1, 0), node(116, 9687, 2, 0), node(59, 0, 0, 518),
+    node(69, 9689, 1, 0), node(59, 0, 0, 1489), node(59, 0, 0, 907), node(108, 9690, 1, 0),
+    node(117, 9691, 1, 0), node(97, 9692, 1, 0), node(117, 9693, 1, 0), node(114, 9694, 1, 0),
+    node(116, 9695, 1, 0), node(114, 9696, 1, 0), node(101, 9697, 1, 0), node(111, 9698, 1, 0),
+    node(97, 9699, 1, 0), node(59, 0, 0, 1490), node(97, 9700, 1, 0), node(114, 9701, 1, 0),
+    node(59, 0, 0, 1491), node(59, 0, 0, 1492), node(111, 9702, 1, 0), node(108, 9703, 1, 0),
+    node(117, 9704, 1, 0), node(97, 9705, 1, 0), node(59, 0, 0, 1493), node(59, 0, 0, 1000),
+    node(114, 9706, 1, 0), node(110, 9707, 1, 0), node(116, 9708, 1, 0), node(104, 9709, 1, 0),
+    node(59, 0, 0, 926), node(59, 0, 0, 781), node(115, 9710, 1, 0), node(114, 9711, 1, 0),
+    node(111, 9712, 1, 0), node(59, 0, 0, 1227), node(59, 0, 0, 952), node(115, 9713, 1, 0),
+    node(59, 0, 0, 961), node(59, 0, 0, 325), node(119, 9714, 1, 0), node(59, 0, 0, 1022),
+    node(97, 9715, 1, 0), node(110, 9716, 1, 0), node(117, 9717, 1, 0), node(111, 9718, 1, 0),
+    node(65, 9719, 1, 0), node(103, 9720, 1, 0), node(114, 9721, 1, 0), node(111, 9722, 1, 0),
+    node(104, 9723, 1, 0), node(114, 9724, 1, 0), node(59, 0, 0, 613), node(59, 0, 0, 233),
+    node(111, 9725, 1, 0), node(59, 0, 0, 1494), node(59, 0, 0, 1495), node(114, 9726, 1, 0),
+    node(114, 9727, 1, 0), node(97, 9728, 1, 0), node(59, 0, 0, 1496), node(117, 9729, 1, 0),
+    node(59, 0, 0, 170), node(111, 9730, 1, 0), node(59, 0, 0, 862), node(59, 0, 0, 1497),
+    node(59, 0, 0, 1498), node(59, 0, 0, 872), node(119, 9731, 1, 0), node(119, 9732, 1, 0),
+    node(99, 9733, 1, 0), node(101, 9734, 1, 0), node(59, 0, 0, 1480), node(112, 9735, 1, 0),
+    node(116, 9736, 1, 0), node(66, 9737, 1, 0), node(97, 9738, 1, 0), node(59, 0, 0, 503),
+    node(117, 9739, 1, 0), node(114, 9740, 1, 0), node(117, 9741, 1, 0), node(59, 0, 0, 513),
+    node(114, 9742, 1, 0), node(59, 0, 0, 1499), node(113, 9743, 1, 0), node(59, 0, 0, 898),
+    node(97, 9744, 1, 0), node(113, 9745, 1, 0), node(117, 9746, 1, 0), node(59, 0, 0, 1500),
+    node(69, 9747, 1, 0), node(113, 9748, 1, 0), node(59, 0, 0, 883), node(111, 9749, 1, 0),
+    node(108, 9750, 1, 0), node(109, 9751, 1, 0), node(105, 9752, 1, 0), node(59, 0, 0, 559),
+    node(111, 9753, 1, 0), node(116, 9754, 1, 0), node(114, 9755, 1, 0), node(114, 9756, 1, 0),
+    node(108, 9757, 1, 0), node(59, 0, 0, 1501), node(110, 9758, 1, 0), node(59, 0, 0, 1286),
+    node(97, 9759, 1, 0), node(108, 9760, 1, 0), node(59, 0, 0, 1502), node(59, 0, 0, 786),
+    node(59, 0, 0, 873), node(116, 9761, 1, 0), node(59, 0, 0, 865), node(111, 9762, 1, 0),
+    node(119, 9763, 1, 0), node(59, 0, 0, 955), node(59, 0, 0, 341), node(108, 9764, 1, 0),
+    node(116, 9765, 1, 0), node(111, 9766, 1, 0), node(110, 9767, 1, 0), node(99, 9768, 1, 0),
+    node(114, 9769, 1, 0), node(111, 9770, 1, 0), node(119, 9771, 1, 0), node(116, 9772, 1, 0),
+    node(111, 9773, 1, 0), node(114, 9774, 1, 0), node(59, 0, 0, 1503), node(59, 0, 0, 1504),
+    node(114, 9775, 1, 0), node(97, 9776, 1, 0), node(119, 9777, 1, 0), node(59, 0, 0, 1027),
+    node(59, 0, 0, 1026), node(101, 9778, 1, 0), node(59, 0, 0, 1480), node(97, 9779, 1, 0),
+    node(101, 9780, 1, 0), node(97, 9781, 1, 0), node(108, 9782, 1, 0), node(97, 9783, 1, 0),
+    node(59, 0, 0, 1505), node(97, 9784, 1, 0), node(101, 9785, 1, 0), node(117, 9786, 1, 0),
+    node(114, 9787, 1, 0), node(117, 9788, 1, 0), node(97, 9789, 1, 0), node(113, 9790, 1, 0),
+    node(117, 9791, 1, 0), node(116, 9792, 1, 0), node(59, 0, 0, 934), node(59, 0, 0, 865),
+    node(117, 9793, 1, 0), node(119, 9794, 1, 0), node(59, 0, 0, 959), node(59, 0, 0, 1506),
+    node(59, 0, 0, 1507), node(59, 0, 0, 963), node(59, 0, 0, 982), node(108, 9795, 1, 0),
+    node(59, 0, 0, 966), node(59, 0, 0, 964), node(119, 9796, 1, 0), node(59, 0, 0, 1027),
+    node(68, 9797, 1, 0), node(101, 9798, 1, 0), node(116, 9799, 1, 0), node(116, 9800, 1, 0),
+    node(117, 9801, 1, 0), node(97, 9802, 1, 0), node(119, 9803, 1, 0), node(59, 0, 0, 1028),
+    node(65, 9804, 1, 0), node(119, 9805, 1, 0), node(59, 0, 0, 1508), node(101, 9806, 1, 0),
+    node(114, 9807, 1, 0), node(59, 0, 0, 864), node(59, 0, 0, 1480), node(99, 9808, 1, 0),
+    node(114, 9809, 1, 0), node(114, 9810, 1, 0), node(59, 0, 0, 213), node(108, 9811, 1, 0),
+    node(108, 9812, 1, 0), node(97, 9813, 1, 0), node(97, 9814, 1, 0), node(59, 0, 0, 1509),
+    node(97, 9815, 1, 0), node(108, 9816, 1, 0), node(117, 9817, 1, 0), node(97, 9818, 1, 0),
+    node(101, 9819, 1, 0), node(109, 9820, 1, 0), node(59, 0, 0, 954), node(59, 0, 0, 1287),
+    node(59, 0, 0, 822), node(59, 0, 0, 4), node(103, 9821, 1, 0), node(101, 9822, 1, 0),
+    node(111, 9823, 1, 0), node(116, 9824, 1, 0), node(108, 9825, 1, 0), node(59, 0, 0, 179),
+    node(114, 9826, 1, 0), node(59, 0, 0, 1031), node(59, 0, 0, 1510), node(101, 9827, 1, 0),
+    node(101, 9828, 1, 0), node(59, 0, 0, 7), node(59, 0, 0, 516), node(59, 0, 0, 509),
+    node(59, 0, 0, 1221), node(116, 9829, 1, 0), node(108, 9830, 1, 0), node(108, 9831, 1, 0),
+    node(59, 0, 0, 1375), node(97, 9832, 1, 0), node(108, 9833, 1, 0), node(59, 0, 0, 852),
+    node(59, 0, 0, 788), node(114, 9834, 1, 0), node(59, 0, 0, 951), node(117, 9835, 1, 0),
+    node(101, 9836, 1, 0), node(59, 0, 0, 1042), node(114, 9837, 1, 0), node(59, 0, 0, 585),
+    node(59, 0, 0, 1480), node(101, 9838, 1, 0), node(59, 0, 0, 1224), node(59, 0, 0, 1227),
+    node(108, 9839, 1, 0), node(59, 0, 0, 1228), node(97, 9840, 1, 0), node(114, 9841, 1, 0),
+    node(59, 0, 0, 777), node(111, 9842, 1, 0), node(114, 9843, 1, 0), node(59, 0, 0, 1376),
+    node(108, 9844, 1, 0), node(73, 9845, 1, 0), node(119, 9846, 1, 0), node(59, 0, 0, 1511),
+    node(59, 0, 0, 1434), node(110, 9847, 1, 0), node(59, 0, 0, 1026), node(116, 9848, 1, 0),
+    node(101, 9849, 1, 0), node(103, 9850, 1, 0), node(114, 9851, 1, 0), node(97, 9852, 1, 0),
+    node(108, 9853, 1, 0), node(59, 0, 0, 1428),
+];
+
+pub static VALUES: [&str; 1512] = [
+    "",
+    "\u{3E}", "\u{3C}", "\u{26}", "\u{2145}", "\u{D0}", "\u{22D9}", "\u{226B}", "\u{2111}",
+    "\u{22D8}", "\u{226A}", "\u{39C}", "\u{39D}", "\u{2A54}", "\u{3A0}", "\u{2ABB}", "\u{AE}",
+    "\u{211C}", "\u{2ABC}", "\u{39E}", "\u{223E}", "\u{2061}", "\u{2248}", "\u{2146}", "\u{B0}",
+    "\u{2147}", "\u{2A9A}", "\u{2A99}", "\u{F0}", "\u{2267}", "\u{2265}", "\u{2277}", "\u{2063}",
+    "\u{2148}", "\u{2208}", "\u{2062}", "\u{2266}", "\u{2264}", "\u{2276}", "\u{2213}", "\u{3BC}",
+    "\u{2260}", "\u{220B}", "\u{AC}", "\u{3BD}", "\u{24C8}", "\u{2228}", "\u{3C0}", "\u{B1}",
+    "\u{227A}", "\u{211E}", "\u{227B}", "\u{AD}", "\u{A8}", "\u{2118}", "\u{2240}", "\u{3BE}",
+    "\u{A5}", "\u{410}", "\u{1D504}", "\u{2A53}", "\u{C4}", "\u{411}", "\u{1D505}", "\u{A9}",
+    "\u{22D2}", "\u{212D}", "\u{3A7}", "\u{22D3}", "\u{414}", "\u{2207}", "\u{1D507}", "\u{14A}",
+    "\u{42D}", "\u{1D508}", "\u{397}", "\u{CB}", "\u{424}", "\u{1D509}", "\u{413}", "\u{1D50A}",
+    "\u{5E}", "\u{210C}", "\u{418}", "\u{222C}", "\u{CF}", "\u{419}", "\u{1D50D}", "\u{41A}",
+    "\u{1D50E}", "\u{41B}", "\u{1D50F}", "\u{21B0}", "\u{2905}", "\u{41C}", "\u{1D510}", "\u{41D}",
+    "\u{1D511}", "\u{2AEC}", "\u{41E}", "\u{1D512}", "\u{D6}", "\u{41F}", "\u{1D513}", "\u{3A6}",
+    "\u{3A8}", "\u{22}", "\u{1D514}", "\u{420}", "\u{3A1}", "\u{21B1}", "\u{421}", "\u{1D516}",
+    "\u{22D0}", "\u{2211}", "\u{22D1}", "\u{9}", "\u{3A4}", "\u{422}", "\u{1D517}", "\u{423}",
+    "\u{1D518}", "\u{DC}", "\u{412}", "\u{22C1}", "\u{1D519}", "\u{1D51A}", "\u{1D51B}", "\u{42B}",
+    "\u{1D51C}", "\u{417}", "\u{2128}", "\u{223E}\u{333}", "\u{223F}", "\u{430}", "\u{1D51E}", "\u{2227}",
+    "\u{2220}", "\u{2A70}", "\u{224A}", "\u{2A}", "\u{E4}", "\u{431}", "\u{1D51F}", "\u{3D}\u{20E5}",
+    "\u{22A5}", "\u{2229}", "\u{A2}", "\u{1D520}", "\u{3C7}", "\u{25CB}", "\u{222A}", "\u{434}",
+    "\u{1D521}", "\u{F7}", "\u{2D9}", "\u{44D}", "\u{1D522}", "\u{2A96}", "\u{2113}", "\u{2A95}",
+    "\u{14B}", "\u{3B7}", "\u{EB}", "\u{444}", "\u{1D523}", "\u{2A8C}", "\u{2A86}", "\u{433}",
+    "\u{22DB}", "\u{2A7E}", "\u{1D524}", "\u{2A92}", "\u{2AA5}", "\u{2AA4}", "\u{2269}", "\u{2A88}",
+    "\u{1D525}", "\u{438}", "\u{21D4}", "\u{1D526}", "\u{222B}", "\u{EF}", "\u{439}", "\u{1D527}",
+    "\u{43A}", "\u{1D528}", "\u{2A8B}", "\u{2A85}", "\u{2AAB}", "\u{43B}", "\u{22DA}", "\u{2A7D}",
+    "\u{1D529}", "\u{2A91}", "\u{2268}", "\u{2A87}", "\u{25CA}", "\u{200E}", "\u{AF}", "\u{21A6}",
+    "\u{43C}", "\u{1D52A}", "\u{2127}", "\u{2223}", "\u{22D9}\u{338}", "\u{226B}\u{20D2}", "\u{22D8}\u{338}", "\u{226A}\u{20D2}",
+    "\u{2249}", "\u{A0}", "\u{43D}", "\u{1D52B}", "\u{2267}\u{338}", "\u{2271}", "\u{226F}", "\u{22FC}",
+    "\u{2266}\u{338}", "\u{2270}", "\u{226E}", "\u{2280}", "\u{2281}", "\u{23}", "\u{43E}", "\u{1D52C}",
+    "\u{29C1}", "\u{3A9}", "\u{29C0}", "\u{2A5D}", "\u{AA}", "\u{BA}", "\u{2A5B}", "\u{F6}",
+    "\u{2225}", "\u{B6}", "\u{43F}", "\u{1D52D}", "\u{3C6}", "\u{3D6}", "\u{2AB3}", "\u{2AAF}",
+    "\u{3C8}", "\u{1D52E}", "\u{440}", "\u{1D52F}", "\u{3C1}", "\u{200F}", "\u{2AB4}", "\u{2AB0}",
+    "\u{441}", "\u{A7}", "\u{1D530}", "\u{223C}", "\u{2AAA}", "\u{2F}", "\u{25A1}", "\u{2282}",
+    "\u{B9}", "\u{B2}", "\u{B3}", "\u{2283}", "\u{3C4}", "\u{442}", "\u{1D531}", "\u{22A4}",
+    "\u{443}", "\u{1D532}", "\u{FC}", "\u{432}", "\u{1D533}", "\u{1D534}", "\u{1D535}", "\u{44B}",
+    "\u{1D536}", "\u{FF}", "\u{437}", "\u{1D537}", "\u{200D}", "\u{C6}", "\u{C2}", "\u{1D538}",
+    "\u{C5}", "\u{1D49C}", "\u{2AE7}", "\u{392}", "\u{1D539}", "\u{212C}", "\u{427}", "\u{10A}",
+    "\u{2102}", "\u{1D49E}", "\u{402}", "\u{405}", "\u{40F}", "\u{21A1}", "\u{1D53B}", "\u{1D49F}",
+    "\u{CA}", "\u{116}", "\u{1D53C}", "\u{2130}", "\u{2A73}", "\u{1D53D}", "\u{2131}", "\u{403}",
+    "\u{120}", "\u{1D53E}", "\u{1D4A2}", "\u{210D}", "\u{210B}", "\u{415}", "\u{401}", "\u{CE}",
+    "\u{130}", "\u{1D540}", "\u{399}", "\u{2110}", "\u{1D541}", "\u{1D4A5}", "\u{425}", "\u{40C}",
+    "\u{1D542}", "\u{1D4A6}", "\u{409}", "\u{27EA}", "\u{219E}", "\u{1D543}", "\u{2112}", "\u{1D544}",
+    "\u{2133}", "\u{40A}", "\u{2115}", "\u{1D4A9}", "\u{D4}", "\u{1D546}", "\u{1D4AA}", "\u{2119}",
+    "\u{1D4AB}", "\u{211A}", "\u{1D4AC}", "\u{27EB}", "\u{21A0}", "\u{211D}", "\u{211B}", "\u{428}",
+    "\u{1D54A}", "\u{221A}", "\u{1D4AE}", "\u{22C6}", "\u{DE}", "\u{426}", "\u{1D54B}", "\u{1D4AF}",
+    "\u{219F}", "\u{DB}", "\u{1D54C}", "\u{3D2}", "\u{1D4B0}", "\u{2AEB}", "\u{2016}", "\u{1D54D}",
+    "\u{1D4B1}", "\u{1D54E}", "\u{1D4B2}", "\u{1D54F}", "\u{1D4B3}", "\u{42F}", "\u{407}", "\u{42E}",
+    "\u{1D550}", "\u{1D4B4}", "\u{178}", "\u{416}", "\u{17B}", "\u{396}", "\u{2124}", "\u{1D4B5}",
+    "\u{E2}", "\u{B4}", "\u{E6}", "\u{2A5C}", "\u{2A5A}", "\u{29A4}", "\u{1D552}", "\u{224B}",
+    "\u{27}", "\u{E5}", "\u{1D4B6}", "\u{2AED}", "\u{23B5}", "\u{3B2}", "\u{2136}", "\u{2310}",
+    "\u{1D553}", "\u{2550}", "\u{2551}", "\u{2500}", "\u{2502}", "\u{1D4B7}", "\u{223D}", "\u{5C}",
+    "\u{2022}", "\u{224E}", "\u{2229}\u{FE00}", "\u{10B}", "\u{B8}", "\u{447}", "\u{29C3}", "\u{2C6}",
+    "\u{2257}", "\u{2201}", "\u{2245}", "\u{1D554}", "\u{1D4B8}", "\u{2ACF}", "\u{2AD0}", "\u{222A}\u{FE00}",
+    "\u{21D3}", "\u{2965}", "\u{2193}", "\u{2010}", "\u{22C4}", "\u{452}", "\u{1D555}", "\u{1D4B9}",
+    "\u{455}", "\u{29F6}", "\u{25BF}", "\u{45F}", "\u{2251}", "\u{2256}", "\u{EA}", "\u{117}",
+    "\u{2003}", "\u{2002}", "\u{1D556}", "\u{22D5}", "\u{3B5}", "\u{212F}", "\u{2242}", "\u{20AC}",
+    "\u{21}", "\u{266D}", "\u{192}", "\u{1D557}", "\u{22D4}", "\u{1D4BB}", "\u{121}", "\u{22DB}\u{FE00}",
+    "\u{453}", "\u{2A8A}", "\u{1D558}", "\u{210A}", "\u{2273}", "\u{2AA7}", "\u{2269}\u{FE00}", "\u{BD}",
+    "\u{2194}", "\u{210F}", "\u{1D559}", "\u{1D4BD}", "\u{EE}", "\u{435}", "\u{A1}", "\u{22B7}",
+    "\u{451}", "\u{1D55A}", "\u{3B9}", "\u{1D4BE}", "\u{1D55B}", "\u{1D4BF}", "\u{445}", "\u{45C}",
+    "\u{1D55C}", "\u{1D4C0}", "\u{21D0}", "\u{2962}", "\u{27E8}", "\u{AB}", "\u{2190}", "\u{2AAD}",
+    "\u{7B}", "\u{2936}", "\u{21B2}", "\u{22DA}\u{FE00}", "\u{459}", "\u{2A89}", "\u{1D55D}", "\u{29EB}",
+    "\u{28}", "\u{1D4C1}", "\u{2272}", "\u{5B}", "\u{2AA6}", "\u{25C3}", "\u{2268}\u{FE00}", "\u{2642}",
+    "\u{2720}", "\u{B5}", "\u{2ADB}", "\u{2026}", "\u{1D55E}", "\u{1D4C2}", "\u{226B}\u{338}", "\u{226A}\u{338}",
+    "\u{2220}\u{20D2}", "\u{2A70}\u{338}", "\u{2A43}", "\u{2A42}", "\u{2A7E}\u{338}", "\u{22FA}", "\u{45A}", "\u{2025}",
+    "\u{2A7D}\u{338}", "\u{2224}", "\u{1D55F}", "\u{2226}", "\u{2AAF}\u{338}", "\u{2AB0}\u{338}", "\u{1D4C3}", "\u{2241}",
+    "\u{2284}", "\u{2285}", "\u{2279}", "\u{2278}", "\u{224D}\u{20D2}", "\u{2265}\u{20D2}", "\u{3E}\u{20D2}", "\u{2264}\u{20D2}",
+    "\u{3C}\u{20D2}", "\u{229B}", "\u{229A}", "\u{F4}", "\u{2A38}", "\u{2299}", "\u{2DB}", "\u{222E}",
+    "\u{29B6}", "\u{1D560}", "\u{29B7}", "\u{2A56}", "\u{2134}", "\u{2298}", "\u{2202}", "\u{3D5}",
+    "\u{2B}", "\u{1D561}", "\u{A3}", "\u{2AB7}", "\u{2AB5}", "\u{220F}", "\u{221D}", "\u{1D4C5}",
+    "\u{2A0C}", "\u{1D562}", "\u{1D4C6}", "\u{21D2}", "\u{2964}", "\u{223D}\u{331}", "\u{27E9}", "\u{BB}",
+    "\u{2192}", "\u{7D}", "\u{2937}", "\u{21B3}", "\u{25AD}", "\u{3F1}", "\u{2DA}", "\u{1D563}",
+    "\u{29}", "\u{1D4C7}", "\u{5D}", "\u{25B9}", "\u{2AB8}", "\u{2AB6}", "\u{22C5}", "\u{3B}",
+    "\u{2736}", "\u{448}", "\u{2243}", "\u{2A9E}", "\u{2A9D}", "\u{2AAC}", "\u{29C4}", "\u{1D564}",
+    "\u{25AA}", "\u{1D4C8}", "\u{2606}", "\u{2AC5}", "\u{2286}", "\u{266A}", "\u{2AC6}", "\u{2287}",
+    "\u{DF}", "\u{23B4}", "\u{20DB}", "\u{FE}", "\u{D7}", "\u{222D}", "\u{2928}", "\u{1D565}",
+    "\u{2929}", "\u{225C}", "\u{1D4C9}", "\u{446}", "\u{21D1}", "\u{2963}", "\u{2191}", "\u{FB}",
+    "\u{1D566}", "\u{3C5}", "\u{1D4CA}", "\u{25B5}", "\u{21D5}", "\u{2AE8}", "\u{2195}", "\u{7C}",
+    "\u{1D567}", "\u{1D4CB}", "\u{1D568}", "\u{1D4CC}", "\u{22C2}", "\u{22C3}", "\u{27FC}", "\u{22FB}",
+    "\u{1D569}", "\u{1D4CD}", "\u{44F}", "\u{457}", "\u{1D56A}", "\u{1D4CE}", "\u{44E}", "\u{17C}",
+    "\u{3B6}", "\u{436}", "\u{1D56B}", "\u{1D4CF}", "\u{200C}", "\u{C1}", "\u{C0}", "\u{391}",
+    "\u{100}", "\u{104}", "\u{C3}", "\u{2D8}", "\u{C7}", "\u{108}", "\u{2237}", "\u{2A2F}",
+    "\u{2AE4}", "\u{394}", "\u{C9}", "\u{C8}", "\u{112}", "\u{118}", "\u{2A75}", "\u{393}",
+    "\u{11C}", "\u{2C7}", "\u{124}", "\u{132}", "\u{CD}", "\u{CC}", "\u{12A}", "\u{12E}",
+    "\u{406}", "\u{134}", "\u{404}", "\u{39A}", "\u{D1}", "\u{152}", "\u{D3}", "\u{D2}",
+    "\u{14C}", "\u{D8}", "\u{D5}", "\u{2033}", "\u{2910}", "\u{15C}", "\u{3A3}", "\u{2122}",
+    "\u{40B}", "\u{398}", "\u{DA}", "\u{40E}", "\u{D9}", "\u{16A}", "\u{172}", "\u{16E}",
+    "\u{22AB}", "\u{22A9}", "\u{174}", "\u{22C0}", "\u{DD}", "\u{176}", "\u{E1}", "\u{E0}",
+    "\u{2135}", "\u{3B1}", "\u{101}", "\u{2A3F}", "\u{221F}", "\u{105}", "\u{E3}", "\u{2A11}",
+    "\u{224C}", "\u{201E}", "\u{3F6}", "\u{2423}", "\u{2592}", "\u{2591}", "\u{2593}", "\u{2588}",
+    "\u{2557}", "\u{2554}", "\u{2556}", "\u{2553}", "\u{2566}", "\u{2569}", "\u{2564}", "\u{2567}",
+    "\u{255D}", "\u{255A}", "\u{255C}", "\u{2559}", "\u{256C}", "\u{2563}", "\u{2560}", "\u{256B}",
+    "\u{2562}", "\u{255F}", "\u{2555}", "\u{2552}", "\u{2510}", "\u{250C}", "\u{2565}", "\u{2568}",
+    "\u{252C}", "\u{2534}", "\u{255B}", "\u{2558}", "\u{2518}", "\u{2514}", "\u{256A}", "\u{2561}",
+    "\u{255E}", "\u{253C}", "\u{2524}", "\u{251C}", "\u{A6}", "\u{204F}", "\u{22CD}", "\u{29C5}",
+    "\u{2AAE}", "\u{224F}", "\u{2041}", "\u{2A4D}", "\u{E7}", "\u{109}", "\u{2A4C}", "\u{2713}",
+    "\u{2663}", "\u{3A}", "\u{2C}", "\u{21B5}", "\u{2717}", "\u{2AD1}", "\u{2AD2}", "\u{22EF}",
+    "\u{22DE}", "\u{22DF}", "\u{2A45}", "\u{A4}", "\u{22CE}", "\u{22CF}", "\u{2231}", "\u{22A3}",
+    "\u{2DD}", "\u{21CA}", "\u{3B4}", "\u{21C3}", "\u{21C2}", "\u{2666}", "\u{22F2}", "\u{2250}",
+    "\u{22F1}", "\u{25BE}", "\u{21F5}", "\u{296F}", "\u{2A77}", "\u{E9}", "\u{2252}", "\u{E8}",
+    "\u{113}", "\u{2205}", "\u{119}", "\u{2A71}", "\u{3F5}", "\u{2261}", "\u{2253}", "\u{2971}",
+    "\u{2203}", "\u{FB00}", "\u{FB01}", "\u{66}\u{6A}", "\u{FB02}", "\u{25B1}", "\u{2AD9}", "\u{BC}",
+    "\u{BE}", "\u{2044}", "\u{2322}", "\u{3B3}", "\u{11D}", "\u{2AA9}", "\u{2137}", "\u{22E7}",
+    "\u{60}", "\u{2A8E}", "\u{2A90}", "\u{2A7A}", "\u{22D7}", "\u{21AD}", "\u{125}", "\u{21FF}",
+    "\u{ED}", "\u{EC}", "\u{2129}", "\u{133}", "\u{12B}", "\u{131}", "\u{1B5}", "\u{221E}",
+    "\u{12F}", "\u{2A3C}", "\u{BF}", "\u{22F9}", "\u{22F4}", "\u{456}", "\u{135}", "\u{237}",
+    "\u{454}", "\u{3BA}", "\u{21DA}", "\u{290E}", "\u{2991}", "\u{21E4}", "\u{2AAD}\u{FE00}", "\u{290C}",
+    "\u{2772}", "\u{298B}", "\u{2308}", "\u{201C}", "\u{2AA8}", "\u{21BD}", "\u{21BC}", "\u{2584}",
+    "\u{21C7}", "\u{25FA}", "\u{22E6}", "\u{27EC}", "\u{21FD}", "\u{27E6}", "\u{2985}", "\u{21C6}",
+    "\u{21CB}", "\u{22BF}", "\u{2A8D}", "\u{2A8F}", "\u{2018}", "\u{2A79}", "\u{22D6}", "\u{22B4}",
+    "\u{25C2}", "\u{223A}", "\u{2014}", "\u{B7}", "\u{2212}", "\u{22B8}", "\u{224B}\u{338}", "\u{149}",
+    "\u{266E}", "\u{224E}\u{338}", "\u{2247}", "\u{2013}", "\u{21D7}", "\u{2197}", "\u{2250}\u{338}", "\u{2242}\u{338}",
+    "\u{2275}", "\u{21CE}", "\u{21AE}", "\u{2AF2}", "\u{21CD}", "\u{219A}", "\u{2274}", "\u{22EA}",
+    "\u{2209}", "\u{220C}", "\u{2202}\u{338}", "\u{21CF}", "\u{219B}", "\u{22EB}", "\u{2244}", "\u{2AC5}\u{338}",
+    "\u{2288}", "\u{2AC6}\u{338}", "\u{2289}", "\u{F1}", "\u{2007}", "\u{223C}\u{20D2}", "\u{21D6}", "\u{2196}",
+    "\u{F3}", "\u{229D}", "\u{153}", "\u{29BF}", "\u{F2}", "\u{29B5}", "\u{21BA}", "\u{29BE}",
+    "\u{203E}", "\u{14D}", "\u{3C9}", "\u{29B9}", "\u{2295}", "\u{21BB}", "\u{F8}", "\u{F5}",
+    "\u{233D}", "\u{2AFD}", "\u{260E}", "\u{229E}", "\u{2A72}", "\u{227C}", "\u{2032}", "\u{2AB9}",
+    "\u{227E}", "\u{3F}", "\u{21DB}", "\u{290F}", "\u{2992}", "\u{29A5}", "\u{21E5}", "\u{2933}",
+    "\u{219D}", "\u{2236}", "\u{290D}", "\u{2773}", "\u{298C}", "\u{2309}", "\u{201D}", "\u{21C1}",
+    "\u{21C0}", "\u{21C4}", "\u{21CC}", "\u{2AEE}", "\u{27ED}", "\u{21FE}", "\u{27E7}", "\u{2986}",
+    "\u{21C9}", "\u{2019}", "\u{22B5}", "\u{25B8}", "\u{201A}", "\u{227D}", "\u{15D}", "\u{2ABA}",
+    "\u{227F}", "\u{22A1}", "\u{2A66}", "\u{21D8}", "\u{2198}", "\u{2216}", "\u{266F}", "\u{3C3}",
+    "\u{2AA0}", "\u{2A9F}", "\u{2246}", "\u{2323}", "\u{2AAC}\u{FE00}", "\u{2293}", "\u{2294}", "\u{228F}",
+    "\u{2290}", "\u{2605}", "\u{2ACB}", "\u{228A}", "\u{2ACC}", "\u{228B}", "\u{21D9}", "\u{2199}",
+    "\u{3B8}", "\u{2DC}", "\u{29CD}", "\u{45B}", "\u{226C}", "\u{FA}", "\u{45E}", "\u{21C5}",
+    "\u{296E}", "\u{F9}", "\u{21BF}", "\u{21BE}", "\u{2580}", "\u{25F8}", "\u{16B}", "\u{173}",
+    "\u{228E}", "\u{16F}", "\u{25F9}", "\u{22F0}", "\u{25B4}", "\u{21C8}", "\u{2AE9}", "\u{22A8}",
+    "\u{22A2}", "\u{225A}", "\u{22B2}", "\u{2282}\u{20D2}", "\u{2283}\u{20D2}", "\u{22B3}", "\u{175}", "\u{25EF}",
+    "\u{25BD}", "\u{27FA}", "\u{27F7}", "\u{27F8}", "\u{27F5}", "\u{2A00}", "\u{27F9}", "\u{27F6}",
+    "\u{25B3}", "\u{FD}", "\u{177}", "\u{102}", "\u{2254}", "\u{2306}", "\u{106}", "\u{10C}",
+    "\u{2A74}", "\u{222F}", "\u{224D}", "\u{2021}", "\u{10E}", "\u{20DC}", "\u{110}", "\u{11A}",
+    "\u{2200}", "\u{3DC}", "\u{11E}", "\u{122}", "\u{42A}", "\u{126}", "\u{128}", "\u{408}",
+    "\u{136}", "\u{139}", "\u{39B}", "\u{13D}", "\u{13B}", "\u{13F}", "\u{141}", "\u{143}",
+    "\u{147}", "\u{145}", "\u{150}", "\u{2A37}", "\u{154}", "\u{2916}", "\u{158}", "\u{156}",
+    "\u{429}", "\u{42C}", "\u{15A}", "\u{160}", "\u{15E}", "\u{164}", "\u{162}", "\u{166}",
+    "\u{16C}", "\u{170}", "\u{168}", "\u{2AE6}", "\u{22AA}", "\u{179}", "\u{17D}", "\u{103}",
+    "\u{2A55}", "\u{2221}", "\u{2222}", "\u{2A6F}", "\u{22BD}", "\u{2305}", "\u{2235}", "\u{22C8}",
+    "\u{29C9}", "\u{2035}", "\u{107}", "\u{2A44}", "\u{2A4B}", "\u{2A47}", "\u{2A40}", "\u{10D}",
+    "\u{2AEF}", "\u{40}", "\u{2218}", "\u{2210}", "\u{2117}", "\u{21B6}", "\u{2A46}", "\u{2A4A}",
+    "\u{228D}", "\u{21B7}", "\u{232D}", "\u{2020}", "\u{2138}", "\u{10F}", "\u{297F}", "\u{22C7}",
+    "\u{231E}", "\u{230D}", "\u{24}", "\u{231F}", "\u{230C}", "\u{111}", "\u{2A6E}", "\u{11B}",
+    "\u{2255}", "\u{2A98}", "\u{2A97}", "\u{2004}", "\u{2005}", "\u{29E3}", "\u{3D}", "\u{225F}",
+    "\u{2640}", "\u{FB03}", "\u{FB04}", "\u{2153}", "\u{2155}", "\u{2159}", "\u{215B}", "\u{2154}",
+    "\u{2156}", "\u{2157}", "\u{215C}", "\u{2158}", "\u{215A}", "\u{215D}", "\u{215E}", "\u{1F5}",
+    "\u{3DD}", "\u{11F}", "\u{2A80}", "\u{2A94}", "\u{2995}", "\u{2978}", "\u{200A}", "\u{44A}",
+    "\u{2665}", "\u{22B9}", "\u{223B}", "\u{2015}", "\u{127}", "\u{2043}", "\u{29DC}", "\u{2105}",
+    "\u{22BA}", "\u{22F3}", "\u{129}", "\u{458}", "\u{3F0}", "\u{137}", "\u{138}", "\u{291B}",
+    "\u{13A}", "\u{3BB}", "\u{291D}", "\u{21A9}", "\u{21AB}", "\u{2939}", "\u{21A2}", "\u{2919}",
+    "\u{13E}", "\u{13C}", "\u{2A7F}", "\u{2A93}", "\u{297C}", "\u{230A}", "\u{296A}", "\u{296B}",
+    "\u{140}", "\u{23B0}", "\u{2A2D}", "\u{2217}", "\u{5F}", "\u{2993}", "\u{296D}", "\u{2039}",
+    "\u{142}", "\u{22CB}", "\u{22C9}", "\u{2976}", "\u{2996}", "\u{25AE}", "\u{2A29}", "\u{2AF0}",
+    "\u{229F}", "\u{2238}", "\u{22A7}", "\u{22AF}", "\u{22AE}", "\u{144}", "\u{224F}\u{338}", "\u{148}",
+    "\u{146}", "\u{2924}", "\u{2262}", "\u{2204}", "\u{22EC}", "\u{22F9}\u{338}", "\u{2AFD}\u{20E5}", "\u{22E0}",
+    "\u{2933}\u{338}", "\u{219D}\u{338}", "\u{22ED}", "\u{22E1}", "\u{2116}", "\u{22AD}", "\u{2904}", "\u{22AC}",
+    "\u{2902}", "\u{2903}", "\u{2923}", "\u{2927}", "\u{151}", "\u{29BC}", "\u{2296}", "\u{22B6}",
+    "\u{2297}", "\u{2AF3}", "\u{25}", "\u{2E}", "\u{2030}", "\u{2214}", "\u{2A25}", "\u{22E8}",
+    "\u{22B0}", "\u{2008}", "\u{2057}", "\u{291C}", "\u{155}", "\u{2975}", "\u{291E}", "\u{21AA}",
+    "\u{21AC}", "\u{2945}", "\u{21A3}", "\u{291A}", "\u{159}", "\u{157}", "\u{297D}", "\u{230B}",
+    "\u{296C}", "\u{23B1}", "\u{2A2E}", "\u{2994}", "\u{203A}", "\u{22CC}", "\u{22CA}", "\u{15B}",
+    "\u{161}", "\u{15F}", "\u{22E9}", "\u{2925}", "\u{449}", "\u{3C2}", "\u{2A6A}", "\u{2A33}",
+    "\u{44C}", "\u{233F}", "\u{2660}", "\u{2293}\u{FE00}", "\u{2294}\u{FE00}", "\u{2291}", "\u{2292}", "\u{2ABD}",
+    "\u{2AC7}", "\u{2AD5}", "\u{2AD3}", "\u{2ABE}", "\u{2AC8}", "\u{2AD4}", "\u{2AD6}", "\u{2926}",
+    "\u{292A}", "\u{2316}", "\u{165}", "\u{163}", "\u{2315}", "\u{2234}", "\u{3D1}", "\u{2009}",
+    "\u{22A0}", "\u{2A30}", "\u{2336}", "\u{2AF1}", "\u{2034}", "\u{25EC}", "\u{167}", "\u{16D}",
+    "\u{171}", "\u{297E}", "\u{231C}", "\u{230F}", "\u{231D}", "\u{230E}", "\u{169}", "\u{299C}",
+    "\u{22BB}", "\u{22EE}", "\u{2ACB}\u{FE00}", "\u{228A}\u{FE00}", "\u{2ACC}\u{FE00}", "\u{228B}\u{FE00}", "\u{2A5F}", "\u{2259}",
+    "\u{2A01}", "\u{2A02}", "\u{2A06}", "\u{2A04}", "\u{17A}", "\u{17E}", "\u{2230}", "\u{395}",
+    "\u{A}", "\u{2060}", "\u{39F}", "\u{3A5}", "\u{22BE}", "\u{237C}", "\u{29B0}", "\u{2261}\u{20E5}",
+    "\u{2A50}", "\u{29B2}", "\u{29C2}", "\u{2A6D}", "\u{2938}", "\u{2935}", "\u{293D}", "\u{293C}",
+    "\u{29B1}", "\u{29A6}", "\u{2A78}", "\u{2A82}", "\u{2A7C}", "\u{2948}", "\u{22F5}", "\u{291F}",
+    "\u{2973}", "\u{298F}", "\u{298D}", "\u{2967}", "\u{2A81}", "\u{2A34}", "\u{2A7B}", "\u{2966}",
+    "\u{2A2A}", "\u{22F7}", "\u{22F6}", "\u{22FE}", "\u{22FD}", "\u{2A14}", "\u{22E2}", "\u{22E3}",
+    "\u{29DE}", "\u{22B4}\u{20D2}", "\u{22B5}\u{20D2}", "\u{29BB}", "\u{3BF}", "\u{2A57}", "\u{2031}", "\u{210E}",
+    "\u{2A22}", "\u{2A26}", "\u{2A27}", "\u{2A16}", "\u{2920}", "\u{2974}", "\u{298E}", "\u{2990}",
+    "\u{2969}", "\u{2A35}", "\u{2968}", "\u{2A24}", "\u{2972}", "\u{2AC3}", "\u{2AC1}", "\u{2ABF}",
+    "\u{2979}", "\u{2AD8}", "\u{2AC4}", "\u{27C9}", "\u{2AD7}", "\u{297B}", "\u{2AC2}", "\u{2AC0}",
+    "\u{2ADA}", "\u{2A39}", "\u{2A3B}", "\u{29A7}", "\u{299A}", "\u{21DD}", "\u{2911}", "\u{2AA1}",
+    "\u{2949}", "\u{2A58}", "\u{29A8}", "\u{29A9}", "\u{29AA}", "\u{29AB}", "\u{29AC}", "\u{29AD}",
+    "\u{29AE}", "\u{29AF}", "\u{299D}", "\u{2233}", "\u{23B6}", "\u{27C8}", "\u{2A49}", "\u{2A10}",
+    "\u{2A48}", "\u{2232}", "\u{27FF}", "\u{23E7}", "\u{29E5}", "\u{2A0D}", "\u{2A84}", "\u{29DD}",
+    "\u{2A17}", "\u{29B4}", "\u{294B}", "\u{2A83}", "\u{294A}", "\u{21A5}", "\u{2A6D}\u{338}", "\u{22F5}\u{338}",
+    "\u{2A36}", "\u{2A23}", "\u{2A15}", "\u{232E}", "\u{2312}", "\u{2313}", "\u{29B3}", "\u{2A12}",
+    "\u{29CE}", "\u{2A13}", "\u{29E4}", "\u{2A31}", "\u{2A3A}", "\u{23E2}", "\u{311}", "\u{226D}",
+    "\u{23DE}", "\u{205F}\u{200A}", "\u{23DF}", "\u{2912}", "\u{21A7}", "\u{21A4}", "\u{205F}", "\u{29F4}",
+    "\u{2913}", "\u{2970}", "\u{295A}", "\u{2952}", "\u{2AA2}", "\u{295B}", "\u{2953}", "\u{200B}",
+    "\u{294E}", "\u{29CF}", "\u{2960}", "\u{2958}", "\u{228F}\u{338}", "\u{23DC}", "\u{25FB}", "\u{2951}",
+    "\u{227F}\u{338}", "\u{29D0}", "\u{295C}", "\u{2954}", "\u{23DD}", "\u{295E}", "\u{2956}", "\u{25FC}",
+    "\u{2961}", "\u{2959}", "\u{2AA1}\u{338}", "\u{2290}\u{338}", "\u{294F}", "\u{2758}", "\u{295F}", "\u{2957}",
+    "\u{29CF}\u{338}", "\u{295D}", "\u{2955}", "\u{2950}", "\u{29D0}\u{338}", "\u{25AB}", "\u{2AA2}\u{338}",
+];
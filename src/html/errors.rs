@@ -0,0 +1,286 @@
+use std::fmt;
+
+/// Every parse error the tokenizer and tree builder can report. The tokenizer errors are the
+/// ones defined by the WHATWG spec, the tree builder errors carry the tag name they're about.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    AbruptClosingOfEmptyComment,
+    AbruptDoctypePublicIdentifier,
+    AbruptDoctypeSystemIdentifier,
+    AbsenceOfDigitsInNumericCharacterReference,
+    CdataInHtmlContent,
+    CharacterReferenceOutsideUnicodeRange,
+    ControlCharacterInInputStream,
+    ControlCharacterReference,
+    DuplicateAttribute,
+    EndTagWithAttributes,
+    EndTagWithTrailingSolidus,
+    EofBeforeTagName,
+    EofInCdata,
+    EofInComment,
+    EofInDoctype,
+    EofInScriptHtmlCommentLikeText,
+    EofInTag,
+    IncorrectlyClosedComment,
+    IncorrectlyOpenedComment,
+    InvalidCharacterSequenceAfterDoctypeName,
+    InvalidFirstCharacterOfTagName,
+    MissingAttributeValue,
+    MissingDoctypeName,
+    MissingDoctypePublicIdentifier,
+    MissingDoctypeSystemIdentifier,
+    MissingEndTagName,
+    MissingQuoteBeforeDoctypePublicIdentifier,
+    MissingQuoteBeforeDoctypeSystemIdentifier,
+    MissingSemicolonAfterCharacterReference,
+    MissingWhitespaceAfterDoctypePublicKeyword,
+    MissingWhitespaceAfterDoctypeSystemKeyword,
+    MissingWhitespaceBeforeDoctypeName,
+    MissingWhitespaceBetweenAttributes,
+    MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers,
+    NestedComment,
+    NoncharacterCharacterReference,
+    NoncharacterInInputStream,
+    NonVoidHtmlElementStartTagWithTrailingSolidus,
+    NullCharacterReference,
+    SurrogateCharacterReference,
+    SurrogateInInputStream,
+    UnexpectedCharacterAfterDoctypeSystemIdentifier,
+    UnexpectedCharacterInAttributeName,
+    UnexpectedCharacterInUnquotedAttributeValue,
+    UnexpectedEqualsSignBeforeAttributeName,
+    UnexpectedNullCharacter,
+    UnexpectedQuestionMarkInsteadOfTagName,
+    UnexpectedSolidusInTag,
+    UnknownNamedCharacterReference,
+
+    /// A DOCTYPE other than `<!DOCTYPE html>` or one of the allowed legacy ones.
+    NonConformingDoctype,
+    /// The document didn't start with a DOCTYPE.
+    MissingDoctype,
+    /// A DOCTYPE after the start of the document.
+    UnexpectedDoctype,
+    /// A start tag that isn't allowed where it appears, it is ignored or handled specially.
+    UnexpectedStartTag(String),
+    /// An end tag without a matching open element, or one that isn't allowed where it appears.
+    UnexpectedEndTag(String),
+    /// An end tag that closed other elements that were still open.
+    EndTagTooEarly(String),
+    /// Formatting elements like `<b><i></b></i>` that had to be fixed by the adoption agency algorithm.
+    MisnestedFormattingElement(String),
+    /// Text inside a table that was moved in front of it.
+    UnexpectedCharacterInTable,
+    /// Non-whitespace text where none is allowed, like after `</html>`.
+    UnexpectedCharacter,
+    /// The input ended while elements other than `html`, `body` and the like were still open.
+    EofWithOpenElements,
+}
+
+impl ParseError {
+    /// The kebab-case error code, as used by the spec and browser tooling.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseError::AbruptClosingOfEmptyComment => "abrupt-closing-of-empty-comment",
+            ParseError::AbruptDoctypePublicIdentifier => "abrupt-doctype-public-identifier",
+            ParseError::AbruptDoctypeSystemIdentifier => "abrupt-doctype-system-identifier",
+            ParseError::AbsenceOfDigitsInNumericCharacterReference => "absence-of-digits-in-numeric-character-reference",
+            ParseError::CdataInHtmlContent => "cdata-in-html-content",
+            ParseError::CharacterReferenceOutsideUnicodeRange => "character-reference-outside-unicode-range",
+            ParseError::ControlCharacterInInputStream => "control-character-in-input-stream",
+            ParseError::ControlCharacterReference => "control-character-reference",
+            ParseError::DuplicateAttribute => "duplicate-attribute",
+            ParseError::EndTagWithAttributes => "end-tag-with-attributes",
+            ParseError::EndTagWithTrailingSolidus => "end-tag-with-trailing-solidus",
+            ParseError::EofBeforeTagName => "eof-before-tag-name",
+            ParseError::EofInCdata => "eof-in-cdata",
+            ParseError::EofInComment => "eof-in-comment",
+            ParseError::EofInDoctype => "eof-in-doctype",
+            ParseError::EofInScriptHtmlCommentLikeText => "eof-in-script-html-comment-like-text",
+            ParseError::EofInTag => "eof-in-tag",
+            ParseError::IncorrectlyClosedComment => "incorrectly-closed-comment",
+            ParseError::IncorrectlyOpenedComment => "incorrectly-opened-comment",
+            ParseError::InvalidCharacterSequenceAfterDoctypeName => "invalid-character-sequence-after-doctype-name",
+            ParseError::InvalidFirstCharacterOfTagName => "invalid-first-character-of-tag-name",
+            ParseError::MissingAttributeValue => "missing-attribute-value",
+            ParseError::MissingDoctypeName => "missing-doctype-name",
+            ParseError::MissingDoctypePublicIdentifier => "missing-doctype-public-identifier",
+            ParseError::MissingDoctypeSystemIdentifier => "missing-doctype-system-identifier",
+            ParseError::MissingEndTagName => "missing-end-tag-name",
+            ParseError::MissingQuoteBeforeDoctypePublicIdentifier => "missing-quote-before-doctype-public-identifier",
+            ParseError::MissingQuoteBeforeDoctypeSystemIdentifier => "missing-quote-before-doctype-system-identifier",
+            ParseError::MissingSemicolonAfterCharacterReference => "missing-semicolon-after-character-reference",
+            ParseError::MissingWhitespaceAfterDoctypePublicKeyword => "missing-whitespace-after-doctype-public-keyword",
+            ParseError::MissingWhitespaceAfterDoctypeSystemKeyword => "missing-whitespace-after-doctype-system-keyword",
+            ParseError::MissingWhitespaceBeforeDoctypeName => "missing-whitespace-before-doctype-name",
+            ParseError::MissingWhitespaceBetweenAttributes => "missing-whitespace-between-attributes",
+            ParseError::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers => "missing-whitespace-between-doctype-public-and-system-identifiers",
+            ParseError::NestedComment => "nested-comment",
+            ParseError::NoncharacterCharacterReference => "noncharacter-character-reference",
+            ParseError::NoncharacterInInputStream => "noncharacter-in-input-stream",
+            ParseError::NonVoidHtmlElementStartTagWithTrailingSolidus => "non-void-html-element-start-tag-with-trailing-solidus",
+            ParseError::NullCharacterReference => "null-character-reference",
+            ParseError::SurrogateCharacterReference => "surrogate-character-reference",
+            ParseError::SurrogateInInputStream => "surrogate-in-input-stream",
+            ParseError::UnexpectedCharacterAfterDoctypeSystemIdentifier => "unexpected-character-after-doctype-system-identifier",
+            ParseError::UnexpectedCharacterInAttributeName => "unexpected-character-in-attribute-name",
+            ParseError::UnexpectedCharacterInUnquotedAttributeValue => "unexpected-character-in-unquoted-attribute-value",
+            ParseError::UnexpectedEqualsSignBeforeAttributeName => "unexpected-equals-sign-before-attribute-name",
+            ParseError::UnexpectedNullCharacter => "unexpected-null-character",
+            ParseError::UnexpectedQuestionMarkInsteadOfTagName => "unexpected-question-mark-instead-of-tag-name",
+            ParseError::UnexpectedSolidusInTag => "unexpected-solidus-in-tag",
+            ParseError::UnknownNamedCharacterReference => "unknown-named-character-reference",
+            ParseError::NonConformingDoctype => "non-conforming-doctype",
+            ParseError::MissingDoctype => "missing-doctype",
+            ParseError::UnexpectedDoctype => "unexpected-doctype",
+            ParseError::UnexpectedStartTag(_) => "unexpected-start-tag",
+            ParseError::UnexpectedEndTag(_) => "unexpected-end-tag",
+            ParseError::EndTagTooEarly(_) => "end-tag-too-early",
+            ParseError::MisnestedFormattingElement(_) => "misnested-formatting-element",
+            ParseError::UnexpectedCharacterInTable => "unexpected-character-in-table",
+            ParseError::UnexpectedCharacter => "unexpected-character",
+            ParseError::EofWithOpenElements => "eof-with-open-elements"
+        }
+    }
+
+    /// Whether the error was reported by the tokenizer, as opposed to the tree builder.
+    pub fn is_tokenizer_error(&self) -> bool {
+        !matches!(self, ParseError::NonConformingDoctype | ParseError::MissingDoctype
+            | ParseError::UnexpectedDoctype | ParseError::UnexpectedStartTag(_)
+            | ParseError::UnexpectedEndTag(_) | ParseError::EndTagTooEarly(_)
+            | ParseError::MisnestedFormattingElement(_) | ParseError::UnexpectedCharacterInTable
+            | ParseError::UnexpectedCharacter | ParseError::EofWithOpenElements)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedStartTag(tag) => write!(f, "{} <{}>", self.code(), tag),
+            ParseError::UnexpectedEndTag(tag) | ParseError::EndTagTooEarly(tag)
+            | ParseError::MisnestedFormattingElement(tag) => write!(f, "{} </{}>", self.code(), tag),
+            _ => f.write_str(self.code())
+        }
+    }
+}
+
+/// The errors of the DOM tree mutation methods, named after the `DOMException` each one
+/// throws in the DOM Standard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DomException {
+    /// The node can't go there, like an element into a text node, a node into itself, or a
+    /// second root element into a document.
+    HierarchyRequestError,
+    /// The child the operation is relative to isn't a child of the parent.
+    NotFoundError,
+    /// Documents can't be adopted into another document.
+    NotSupportedError,
+    /// A name that isn't allowed, like an attribute name with whitespace in it.
+    InvalidCharacterError,
+    /// A prefix that doesn't go with the namespace, like `xlink:` without one.
+    NamespaceError,
+    /// An empty class token, or a `dataset` name that can't be turned into an attribute name.
+    SyntaxError,
+}
+
+impl fmt::Display for DomException {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DomException::HierarchyRequestError => "HierarchyRequestError",
+            DomException::NotFoundError => "NotFoundError",
+            DomException::NotSupportedError => "NotSupportedError",
+            DomException::InvalidCharacterError => "InvalidCharacterError",
+            DomException::NamespaceError => "NamespaceError",
+            DomException::SyntaxError => "SyntaxError"
+        })
+    }
+}
+
+impl std::error::Error for DomException {}
+
+/// Where in the source an error happened. Lines and columns start at 1, the offset is in bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SourcePosition {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+impl fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReportedError {
+    pub error: ParseError,
+    pub position: SourcePosition,
+}
+
+impl fmt::Display for ReportedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.position, self.error)
+    }
+}
+
+/// All the errors found while parsing a document, in the order they were found.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseErrorReport {
+    errors: Vec<ReportedError>,
+}
+
+impl ParseErrorReport {
+    pub fn new() -> ParseErrorReport {
+        ParseErrorReport { errors: Vec::new() }
+    }
+
+    pub fn report(&mut self, error: ParseError, position: SourcePosition) {
+        self.errors.push(ReportedError { error, position });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ReportedError> {
+        self.errors.iter()
+    }
+
+    /// The errors with the given code, for example `"duplicate-attribute"`.
+    pub fn with_code<'a>(&'a self, code: &'a str) -> impl Iterator<Item = &'a ReportedError> {
+        self.errors.iter().filter(move |reported| reported.error.code() == code)
+    }
+
+    /// Removes the errors the filter rejects, so known and accepted errors can be ignored.
+    pub fn retain(&mut self, filter: impl FnMut(&ReportedError) -> bool) {
+        self.errors.retain(filter);
+    }
+
+    /// Moves the errors of another report into this one, keeping them sorted by position.
+    pub fn merge(&mut self, other: ParseErrorReport) {
+        self.errors.extend(other.errors);
+        self.errors.sort_by_key(|reported| reported.position.offset);
+    }
+}
+
+impl fmt::Display for ParseErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for reported in &self.errors {
+            writeln!(f, "{}", reported)?;
+        }
+        Ok(())
+    }
+}
+
+impl IntoIterator for ParseErrorReport {
+    type Item = ReportedError;
+    type IntoIter = std::vec::IntoIter<ReportedError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
@@ -0,0 +1,243 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::html::encoding::{Decoder, Encoding};
+use crate::html::errors::{ParseError, ParseErrorReport, SourcePosition};
+
+/// The preprocessed input stream the lexer reads from. It normalizes newlines, reports
+/// code points that aren't allowed in HTML, keeps track of source positions and lets the
+/// lexer look ahead without consuming.
+///
+/// Input can be given all at once, or pushed in chunks as it arrives followed by `finish`.
+/// Until then, running out of input means more may come rather than the end of the file.
+pub struct InputStream {
+    /// Shared with the token text that was taken straight from it.
+    input: Rc<str>,
+    /// Byte offset of the next character in `input`.
+    pos: usize,
+    /// Byte offset of the last consumed character in `input`.
+    last: usize,
+    /// Chunks pushed since the last `prepare`, not yet part of `input`.
+    pending: String,
+    /// Bytes of consumed input that were dropped from the front of `input`.
+    dropped: usize,
+    finished: bool,
+    /// Decodes the chunks given as bytes, and keeps the start of a sequence split between two.
+    decoder: Decoder,
+    /// Position of the last consumed character.
+    position: SourcePosition,
+    /// Position of the next character.
+    next_position: SourcePosition,
+}
+
+impl InputStream {
+    /// A stream over a complete input.
+    pub fn new(input: String) -> InputStream {
+        let mut stream = InputStream::incremental();
+        stream.input = Rc::from(input);
+        stream.finished = true;
+        stream
+    }
+
+    /// An empty stream that is fed with `push_str` or `push_bytes` and ended with `finish`.
+    pub fn incremental() -> InputStream {
+        let start = SourcePosition { line: 1, column: 1, offset: 0 };
+        InputStream {
+            input: Rc::from(""),
+            pos: 0,
+            last: 0,
+            pending: String::new(),
+            dropped: 0,
+            finished: false,
+            decoder: Encoding::Utf8.new_decoder(),
+            position: start,
+            next_position: start,
+        }
+    }
+
+    pub fn push_str(&mut self, chunk: &str) {
+        self.pending.push_str(chunk);
+    }
+
+    /// Moves pushed chunks into the buffer, must be called before reading from the stream.
+    /// The buffer is replaced by what's left of it and the new input, tokens taken from the
+    /// old buffer keep it alive for as long as they need it.
+    pub fn prepare(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let mut input = String::with_capacity(self.input.len() - self.pos + self.pending.len());
+        input.push_str(&self.input[self.pos..]);
+        input.push_str(&self.pending);
+        self.pending.clear();
+        self.input = Rc::from(input);
+        self.dropped += self.pos;
+        self.last = self.last.saturating_sub(self.pos);
+        self.pos = 0;
+    }
+
+    /// Inserts text at the current position, ahead of the input that wasn't read yet. This is
+    /// where markup written by scripts goes, positions after it count it as part of the input.
+    pub fn insert_str(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        let mut input = String::with_capacity(text.len() + self.input.len() - self.pos);
+        input.push_str(text);
+        input.push_str(&self.input[self.pos..]);
+        self.input = Rc::from(input);
+        self.dropped += self.pos;
+        self.last = 0;
+        self.pos = 0;
+    }
+
+    /// Sets the encoding of the chunks pushed with `push_bytes`, UTF-8 unless set. Must be
+    /// called before the first one.
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.decoder = encoding.new_decoder();
+    }
+
+    /// Appends bytes in the stream's encoding, a sequence split across chunks is completed by
+    /// the next one. Invalid sequences become U+FFFD.
+    pub fn push_bytes(&mut self, chunk: &[u8]) {
+        let decoded = self.decoder.decode(chunk, false);
+        self.push_str(&decoded);
+    }
+
+    /// Marks the end of the input, after this running out of characters is the end of the file.
+    pub fn finish(&mut self) {
+        let rest = self.decoder.decode(&[], true);
+        self.push_str(&rest);
+        self.finished = true;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Whether at least `count` characters can be consumed without waiting for more input.
+    /// Once the input is finished, everything is available since running out means EOF.
+    pub fn has_available(&self, count: usize) -> bool {
+        if self.finished {
+            return true;
+        }
+        // A trailing CR might still turn out to be the start of a CRLF
+        let rest = &self.input[self.pos..];
+        let rest = rest.strip_suffix('\r').unwrap_or(rest);
+        normalize_newlines(rest.chars()).take(count).count() == count
+    }
+
+    /// The position of the last consumed character, or the end of the input once it ran out.
+    pub fn position(&self) -> SourcePosition {
+        self.position
+    }
+
+    /// The buffer the last consumed character was read from and its offset in it,
+    /// which lets token text share the input instead of copying it.
+    pub fn source(&self) -> (&Rc<str>, usize) {
+        (&self.input, self.last)
+    }
+
+    /// Consumes the next character. CR and CRLF come out as a single LF, and noncharacters
+    /// and controls are reported to `errors` the first time they're consumed.
+    ///
+    /// Surrogates can't appear here since Rust strings are always valid UTF-8, a decoder has
+    /// already replaced them by the time the input reaches the stream.
+    pub fn next(&mut self, errors: &mut ParseErrorReport) -> Option<char> {
+        self.position = self.next_position;
+        let mut char = self.input[self.pos..].chars().next()?;
+        self.last = self.pos;
+        self.pos += char.len_utf8();
+        if char == '\r' {
+            if self.input[self.pos..].starts_with('\n') {
+                self.pos += 1;
+            }
+            char = '\n';
+        }
+
+        if is_noncharacter(char) {
+            errors.report(ParseError::NoncharacterInInputStream, self.position);
+        } else if is_disallowed_control(char) {
+            errors.report(ParseError::ControlCharacterInInputStream, self.position);
+        }
+
+        self.next_position.offset = self.dropped + self.pos;
+        if char == '\n' {
+            self.next_position.line += 1;
+            self.next_position.column = 1;
+        } else {
+            self.next_position.column += 1;
+        }
+        Some(char)
+    }
+
+    /// The upcoming characters without consuming them, with newlines already normalized.
+    pub fn lookahead(&self) -> impl Iterator<Item = char> + '_ {
+        normalize_newlines(self.input[self.pos..].chars())
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.lookahead().next()
+    }
+
+    /// Consumes `expected` if the upcoming characters match it, ignoring ASCII case if asked to.
+    /// Used by the markup declaration open state for `--`, `DOCTYPE` and `[CDATA[`.
+    pub fn consume_if_next_is(&mut self, expected: &str, ignore_case: bool, errors: &mut ParseErrorReport) -> bool {
+        let matches = match self.input[self.pos..].get(..expected.len()) {
+            Some(next) if ignore_case => next.eq_ignore_ascii_case(expected),
+            Some(next) => next == expected,
+            None => false
+        };
+        if matches {
+            for _ in expected.chars() {
+                self.next(errors);
+            }
+        }
+        matches
+    }
+
+    /// Consumes the run of characters up to the next one `stop` matches, a CR, or one that has
+    /// to be reported, and returns the range it covers in the buffer. This is the common case
+    /// of plain text, which needs none of the work `next` does per character.
+    pub fn consume_run(&mut self, stop: impl Fn(char) -> bool) -> Range<usize> {
+        let start = self.pos;
+        for char in self.input[start..].chars() {
+            if char == '\r' || stop(char) || is_noncharacter(char) || is_disallowed_control(char) {
+                break;
+            }
+            self.position = self.next_position;
+            self.last = self.pos;
+            self.pos += char.len_utf8();
+            if char == '\n' {
+                self.next_position.line += 1;
+                self.next_position.column = 1;
+            } else {
+                self.next_position.column += 1;
+            }
+        }
+        self.next_position.offset = self.dropped + self.pos;
+        start..self.pos
+    }
+}
+
+fn normalize_newlines(chars: impl Iterator<Item = char>) -> impl Iterator<Item = char> {
+    let mut after_cr = false;
+    chars.filter_map(move |char| {
+        let skip = after_cr && char == '\n';
+        after_cr = char == '\r';
+        match char {
+            _ if skip => None,
+            '\r' => Some('\n'),
+            _ => Some(char)
+        }
+    })
+}
+
+fn is_noncharacter(char: char) -> bool {
+    matches!(char, '\u{FDD0}'..='\u{FDEF}') || (char as u32) & 0xFFFE == 0xFFFE
+}
+
+/// Controls other than NULL and ASCII whitespace.
+fn is_disallowed_control(char: char) -> bool {
+    matches!(char, '\u{0001}'..='\u{0008}' | '\u{000B}' | '\u{000E}'..='\u{001F}' | '\u{007F}'..='\u{009F}')
+}
@@ -1,19 +1,51 @@
-use std::str::Chars;
+use std::collections::VecDeque;
 
-use crate::html::tokens::{TagData, Token, TokenType};
+use crate::html::encoding::Encoding;
+use crate::html::entities;
+use crate::html::errors::{ParseError, ParseErrorReport, SourcePosition};
+use crate::html::input::InputStream;
+use crate::html::atoms::Atom;
+use crate::html::text::Text;
+use crate::html::tokens::{Attribute, DoctypeData, TagData, Token};
 
-pub struct Lexer<'a> {
-    pos: usize,
+/// Numeric references to C1 controls are mapped as if they were windows-1252 bytes.
+const C1_REPLACEMENTS: [(u32, char); 27] = [
+    (0x80, '\u{20AC}'), (0x82, '\u{201A}'), (0x83, '\u{0192}'), (0x84, '\u{201E}'),
+    (0x85, '\u{2026}'), (0x86, '\u{2020}'), (0x87, '\u{2021}'), (0x88, '\u{02C6}'),
+    (0x89, '\u{2030}'), (0x8A, '\u{0160}'), (0x8B, '\u{2039}'), (0x8C, '\u{0152}'),
+    (0x8E, '\u{017D}'), (0x91, '\u{2018}'), (0x92, '\u{2019}'), (0x93, '\u{201C}'),
+    (0x94, '\u{201D}'), (0x95, '\u{2022}'), (0x96, '\u{2013}'), (0x97, '\u{2014}'),
+    (0x98, '\u{02DC}'), (0x99, '\u{2122}'), (0x9A, '\u{0161}'), (0x9B, '\u{203A}'),
+    (0x9C, '\u{0153}'), (0x9E, '\u{017E}'), (0x9F, '\u{0178}'),
+];
+
+pub struct Lexer {
     reconsume: Option<char>,
-    input: Chars<'a>,
+    input: InputStream,
     state: LexerState,
-    buffer: &'a mut Vec<char>,
-    error_handler: &'a dyn Fn(&str),
-    elements: Vec<Token>,
+    /// The temporary buffer, used for character references and end tag names in raw text.
+    buffer: Vec<char>,
+    errors: ParseErrorReport,
+    /// Tokens that were produced together and are waiting to be returned one by one.
+    pending: VecDeque<Token>,
     return_state: Option<LexerState>,
+    tag: TagData,
+    /// The name of the current tag, interned once the tag is emitted.
+    tag_name: Text,
+    attribute: (Text, Text),
+    /// Whether the current attribute's name was already on the tag, it's dropped if so.
+    duplicate_attribute: bool,
+    last_start_tag: Option<Atom>,
+    /// The current run of character data.
+    text: Text,
+    comment: Text,
+    doctype: DoctypeData,
+    character_reference_code: u32,
+    in_foreign_content: bool,
 }
 
-enum LexerState {
+#[derive(Clone, Copy, PartialEq)]
+pub enum LexerState {
     DataState,
     RCDATAState,
     RAWTEXTState,
@@ -25,6 +57,9 @@ enum LexerState {
     RCDATALessThanSignState,
     RCDATAEndTagOpenState,
     RCDATAEndTagNameState,
+    RAWTEXTLessThanSignState,
+    RAWTEXTEndTagOpenState,
+    RAWTEXTEndTagNameState,
     ScriptDataLessThanSignState,
     ScriptDataEndTagOpenState,
     ScriptDataEndTagNameState,
@@ -93,857 +128,1943 @@ enum LexerState {
     NumericCharacterReferenceEndState,
 }
 
-impl<'a> Lexer<'a> {
-    pub fn new(input: String, error_handler: &'a dyn Fn(&str)) -> Lexer<'a> {
+impl Lexer {
+    pub fn new(input: String) -> Lexer {
+        Lexer::from_input(InputStream::new(input))
+    }
+
+    /// A lexer for input that arrives in chunks, see `push_str`, `push_bytes` and `finish`.
+    pub fn incremental() -> Lexer {
+        Lexer::from_input(InputStream::incremental())
+    }
+
+    fn from_input(input: InputStream) -> Lexer {
         Lexer {
-            pos: 0,
             reconsume: Option::None,
-            input: input.chars(),
+            input,
             state: LexerState::DataState,
-            buffer: &mut vec![],
-            error_handler,
-            elements: vec![],
+            buffer: vec![],
+            errors: ParseErrorReport::new(),
+            pending: VecDeque::new(),
             return_state: Option::None,
+            tag: TagData::new(),
+            tag_name: Text::new(),
+            attribute: (Text::new(), Text::new()),
+            duplicate_attribute: false,
+            last_start_tag: Option::None,
+            text: Text::new(),
+            comment: Text::new(),
+            doctype: DoctypeData::new(),
+            character_reference_code: 0,
+            in_foreign_content: false,
         }
     }
 
-    pub fn get_top_token(self) -> Token {
-        return match self.elements.top {
-            Some(element) => {
-                element
-            }
-            None => {
-                Token::EndOfFile()
+    pub fn push_str(&mut self, chunk: &str) {
+        self.input.push_str(chunk);
+    }
+
+    /// Sets the encoding `push_bytes` decodes, UTF-8 unless set.
+    pub fn set_encoding(&mut self, encoding: Encoding) {
+        self.input.set_encoding(encoding);
+    }
+
+    /// Pushes encoded bytes, a character may be split across chunks.
+    pub fn push_bytes(&mut self, chunk: &[u8]) {
+        self.input.push_bytes(chunk);
+    }
+
+    /// Inserts text right after the last token, for markup written by a script.
+    pub fn insert_str(&mut self, text: &str) {
+        self.input.insert_str(text);
+    }
+
+    /// Signals that no more input is coming, letting the lexer emit the end of file.
+    pub fn finish(&mut self) {
+        self.input.finish();
+    }
+
+    /// Whether the current state has enough input to run without guessing at what comes next.
+    /// Most states look at one character, a few need to look further ahead.
+    fn can_step(&self) -> bool {
+        let needed: usize = match self.state {
+            LexerState::NumericCharacterReferenceEndState => 0,
+            // `--`, `DOCTYPE` or `[CDATA[`
+            LexerState::MarkupDeclarationOpenState => 7,
+            // A character followed by the rest of `PUBLIC` or `SYSTEM`
+            LexerState::AfterDOCTYPENameState => 6,
+            LexerState::NamedCharacterReferenceState => {
+                // Decidable once the name is known to have ended, or is longer than any entity
+                return self.input.is_finished() || self.reconsume.into_iter()
+                    .chain(self.input.lookahead())
+                    .take(entities::LONGEST_NAME + 1)
+                    .enumerate()
+                    .any(|(index, char)| index == entities::LONGEST_NAME || !char.is_ascii_alphanumeric());
             }
+            _ => 1
+        };
+        let needed = if self.reconsume.is_some() { needed.saturating_sub(1) } else { needed };
+        self.input.has_available(needed)
+    }
+
+    /// The errors found so far.
+    pub fn errors(&self) -> &ParseErrorReport {
+        &self.errors
+    }
+
+    pub fn take_errors(&mut self) -> ParseErrorReport {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// The position of the last consumed character, which the tree builder reports its errors at.
+    pub fn position(&self) -> SourcePosition {
+        self.input.position()
+    }
+
+    fn error(&mut self, error: ParseError) {
+        self.errors.report(error, self.input.position());
+    }
+
+    /// Switches the tokenizer state, used by the tree builder when it sees elements
+    /// like `<title>`, `<style>`, `<script>` or `<plaintext>`.
+    pub fn set_state(&mut self, state: LexerState) {
+        self.state = state;
+    }
+
+    /// Tells the lexer whether the adjusted current node is outside the HTML namespace,
+    /// which is the only place CDATA sections are allowed.
+    pub fn set_in_foreign_content(&mut self, in_foreign_content: bool) {
+        self.in_foreign_content = in_foreign_content;
+    }
+
+    /// An end tag is appropriate if its name matches the last emitted start tag.
+    fn is_appropriate_end_tag(&self) -> bool {
+        match &self.last_start_tag {
+            Some(name) => self.tag.end_tag && name.as_str() == self.tag_name.as_str(),
+            None => false
         }
     }
 
-    pub fn next_character(mut self) -> Option<char> {
-        match self.reconsume {
-            Some(char) => {
-                self.reconsume = Option::None;
-                Option::Some(char)
-            }
-            None => {
-                self.input.next()
-            }
+    /// Starts a new attribute on the current tag, finishing the previous one.
+    fn start_attribute(&mut self) {
+        let (name, value) = std::mem::take(&mut self.attribute);
+        if !name.is_empty() && !std::mem::take(&mut self.duplicate_attribute) {
+            self.tag.attributes.push(Attribute { name: Atom::new(&name), value });
         }
     }
 
-    pub fn next_token(mut self) -> Token {
-        loop {
-            match &self.State {
-                DataState => {
-                    match self.input.next() {
-                        Some(char) => {
-                            match char {
-                                '&' => {
-                                    self.return_state = Option::Some(LexerState::DataState);
-                                    self.buffer = &mut vec!['&'];
-                                    self.state = LexerState::CharacterReferenceState;
-                                }
-                                '<' => {
-                                    self.state = LexerState::TagOpenState;
-                                }
-                                '\u{0000}' => {
-                                    (self.error_handler)("unexpected-null-character");
-                                    return Token::Character('\u{0000}');
-                                }
-                                _ => {
-                                    return Token::Character(char);
-                                }
-                            }
-                        }
-                        _ => {
-                            return Token::EndOfFile();
-                        }
-                    }
-                }
-                RCDATAState => {
-                    //TODO
-                }
-                RAWTEXTState => {
-                    //TODO
-                }
-                ScriptDataState => {
-                    //TODO
-                }
-                PLAINTEXTState => {
-                    //TODO
-                }
-                TagOpenState => {
-                    match self.input.next() {
-                        Some(char) => {
-                            match char {
-                                'a'..='Z' => {
-                                    self.reconsume = Option::Some(char);
-                                    self.state = LexerState::TagNameState;
-                                    return Token::StartTag();
-                                }
-                                '!' => {
-                                    self.state = LexerState::MarkupDeclarationOpenState;
-                                }
-                                '/' => {
-                                    self.state = LexerState::EndTagOpenState;
-                                }
-                                '?' => {
-                                    (self.error_handler)("unexpected-question-mark-instead-of-tag-name");
-                                    self.reconsume = Option::Some('?');
-                                    self.state = LexerState::BogusCommentState;
-                                    return Token::Comment(vec![]);
-                                }
-                                _ => {
-                                    (self.error_handler)("invalid-first-character-of-tag-name");
-                                    self.state = LexerState::DataState;
-                                    self.reconsume = Option::Some(char);
-                                    return Token::Character('>');
-                                }
-                            }
-                        }
-                        None => {
-                            self.state = LexerState::DataState;
-                            (self.error_handler)("eof-before-tag-name");
-                            Token::Character('>')
-                        }
-                    }
-                }
-                EndTagOpenState => {
-                    match self.next_character() {
-                        Some(char) => {
-                            match char {
-                                'a'..='Z' => {
-                                    self.state = LexerState::TagNameState;
-                                    self.reconsume = Option::Some(char);
-                                    return Token::EndTag();
-                                }
-                                '>' => {
-                                    (self.error_handler)("missing-end-tag-name");
-                                    self.state = LexerState::TagNameState;
-                                }
-                                _ => {
-                                    (self.error_handler)("invalid-first-character-of-tag-name");
-                                    self.state = LexerState::BogusCommentState;
-                                    return Token::StartComment();
-                                }
-                            }
-                        }
-                        None => {
-                            return if self.buffer.is_empty() {
-                                (self.error_handler)("eof-before-tag-name");
-                                self.buffer.push('<');
-                                Token::Character('<')
-                            } else {
-                                self.state = LexerState::DataState;
-                                Token::Character('\u{002F}')
-                            }
-                        }
-                    }
-                }
-                TagNameState => {
-                    match self.next_character() {
-                        Some(char) => {
-                            match char {
-                                'A'..='Z' => {
-                                    self.tag.tag_name.push(char.to_ascii_lowercase());
-                                }
-                                '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
-                                    self.state = LexerState::BeforeAttributeNameState;
-                                    return self.next_token();
-                                }
-                                '/' => {
-                                    self.state = LexerState::SelfClosingStartTagState;
-                                    return self.next_token();
-                                }
-                                '>' => {
-                                    self.state = LexerState::DataState;
-                                    return Token::Tag(self.tag);
-                                }
-                                '\u{0000}' => {
-                                    (self.error_handler)("unexpected-null-character");
-                                    self.tag.tag_name.push('\u{FFFD}');
-                                }
-                                _ => {
-                                    self.tag.tag_name.push(char);
-                                }
-                            }
-                        }
-                        None => {
-                            (self.error_handler)("eof-in-tag");
-                            return Token::EndOfFile();
-                        }
-                    }
-                }
-                RCDATALessThanSignState => {
-                    //TODO
-                }
-                RCDATAEndTagOpenState => {
-                    //TODO
-                }
-                RCDATAEndTagNameState => {
-                    //TODO
-                }
-                ScriptDataLessThanSignState => {
-                    //TODO
-                }
-                ScriptDataEndTagOpenState => {
-                    //TODO
-                }
-                ScriptDataEndTagNameState => {
-                    //TODO
-                }
-                ScriptDataEscapeStartState => {
-                    //TODO
-                }
-                ScriptDataEscapeStartDashState => {
-                    //TODO
-                }
-                ScriptDataEscapedState => {
-                    //TODO
-                }
-                ScriptDataEscapedDashState => {
-                    //TODO
-                }
-                ScriptDataEscapedDashDashState => {
-                    //TODO
-                }
-                ScriptDataEscapedLessThanSignState => {
-                    //TODO
-                }
-                ScriptDataEscapedEndTagOpenState => {
-                    //TODO
-                }
-                ScriptDataEscapedEndTagNameState => {
-                    //TODO
-                }
-                ScriptDataDoubleEscapeStartState => {
-                    //TODO
-                }
-                ScriptDataDoubleEscapedState => {
-                    //TODO
-                }
-                ScriptDataDoubleEscapedDashState => {
-                    //TODO
-                }
-                ScriptDataDoubleEscapedDashDashState => {
-                    //TODO
-                }
-                ScriptDataDoubleEscapedLessThanSignState => {
-                    //TODO
-                }
-                ScriptDataDoubleEscapeEndState => {
-                    //TODO
-                }
-                BeforeAttributeNameState => {
-                    match self.next_character() {
-                        Some(char) => {
-                            match char {
-                                '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
-                                    //Ignored
-                                }
-                                '/' | '>' => {
-                                    self.reconsume = Option::Some(char);
-                                    self.state = LexerState::AfterAttributeNameState;
-                                    return self.next_token();
-                                }
-                                '=' => {
-                                    (self.error_handler)("unexpected-equals-sign-before-attribute-name");
-                                    self.state = LexerState::AttributeNameState;
-                                    return Token::StartAttribute();
-                                }
-                                _ => {
-                                    self.reconsume = Option::Some(char);
-                                    self.State = LexerState::AttributeNameState;
-                                }
-                            }
-                        }
-                        None => {
-                            self.state = LexerState::AfterAttributeNameState;
-                        }
-                    }
-                }
-                AttributeNameState => {
-                    match self.next_character() {
-                        Some(char) => {
-                            match char {
-                                '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' | '/' | '>' => {
-                                    self.reconsume = Option::Some(char);
-                                    self.state = LexerState::AfterAttributeNameState;
-                                }
-                                '=' => {
-                                    self.state = LexerState::BeforeAttributeValueState;
-                                }
-                                'A'..='Z' => {
-                                    match self.get_top_token() {
-                                        Token::Attribute { mut name, value } => {
-                                            name.push(char.to_ascii_lowercase());
-                                        }
-                                        _ => {} // Doesn't happen
-                                    }
-                                }
-                                '\u{0000}' => {
-                                    (self.error_handler)("unexpected-null-character");
-                                    match self.get_top_token() {
-                                        Token::Attribute { mut name, value } => {
-                                            name.push('\u{FFFD}');
-                                        }
-                                        _ => {} // Doesn't happen
-                                    }
-                                }
-                                '\"' | '\'' | '<' => {
-                                    (self.error_handler)("unexpected-character-in-attribute-name");
-                                    match self.get_top_token() {
-                                        Token::Attribute { mut name, value } => {
-                                            name.push(char);
-                                        }
-                                        _ => {} // Doesn't happen
-                                    }
-                                }
-                                _ => {
-                                    match self.get_top_token() {
-                                        Token::Attribute { mut name, value } => {
-                                            name.push(char);
-                                        }
-                                        _ => {} // Doesn't happen
-                                    }
-                                }
-                            }
-                        }
-                        None => {
-                            self.state = LexerState::AfterAttributeNameState;
-                        }
-                    }
-                }
-                AfterAttributeNameState => {
-                    //TODO
-                }
-                BeforeAttributeValueState => {
-                    //TODO
-                }
-                AttributeValueDoubleQuotedState => {
-                    //TODO
-                }
-                AttributeValueSingleQuotedState => {
-                    //TODO
-                }
-                AttributeValueUnquotedState => {
-                    //TODO
-                }
-                AfterAttributeValueQuotedState => {
-                    //TODO
-                }
-                SelfClosingStartTagState => {
-                    //TODO
-                }
-                BogusCommentState => {
-                    //TODO
-                }
-                MarkupDeclarationOpenState => {
-                    //TODO
-                }
-                CommentStartState => {
-                    //TODO
-                }
-                CommentStartDashState => {
-                    //TODO
-                }
-                CommentState => {
-                    //TODO
-                }
-                CommentLessThanSignState => {
-                    //TODO
-                }
-                CommentLessThanSignBangState => {
-                    //TODO
-                }
-                CommentLessThanSignBangDashState => {
-                    //TODO
-                }
-                CommentLessThanSignBangDashDashState => {
-                    //TODO
-                }
-                CommentEndDashState => {
-                    //TODO
-                }
-                CommentEndState => {
-                    //TODO
-                }
-                CommentEndBangState => {
-                    //TODO
-                }
-                DOCTYPEState => {
-                    //TODO
-                }
-                BeforeDOCTYPENameState => {
-                    //TODO
-                }
-                DOCTYPENameState => {
-                    //TODO
-                }
-                AfterDOCTYPENameState => {
-                    //TODO
-                }
-                AfterDOCTYPEPublicKeywordState => {
-                    //TODO
-                }
-                BeforeDOCTYPEPublicIdentifierState => {
-                    //TODO
-                }
-                DOCTYPEPublicIdentifierDoubleQuotedState => {
-                    //TODO
-                }
-                DOCTYPEPublicIdentifierSingleQuotedState => {
-                    //TODO
-                }
-                AfterDOCTYPEPublicIdentifierState => {
-                    //TODO
-                }
-                BetweenDOCTYPEPublicAndSystemIdentifiersState => {
-                    //TODO
-                }
-                AfterDOCTYPESystemKeywordState => {
-                    //TODO
-                }
-                BeforeDOCTYPESystemIdentifierState => {
-                    //TODO
-                }
-                DOCTYPESystemIdentifierDoubleQuotedState => {
-                    //TODO
-                }
-                DOCTYPESystemIdentifierSingleQuotedState => {
-                    //TODO
-                }
-                AfterDOCTYPESystemIdentifierState => {
-                    //TODO
-                }
-                BogusDOCTYPEState => {
-                    //TODO
-                }
-                CDATASectionState => {
-                    //TODO
-                }
-                CDATASectionBracketState => {
-                    //TODO
-                }
-                CDATASectionEndState => {
-                    //TODO
-                }
-                CharacterReferenceState => {
-                    match self.input.next() {
-                        Some(char) => {
-                            match char {
-                                'a'..='Z' => {
-                                    self.reconsume = Option::Some(char);
-                                    self.state = LexerState::NamedCharacterReferenceState;
-                                }
-                                '#' => {
-                                    self.buffer.push('#');
-                                    self.state = LexerState::NumericCharacterReferenceState;
-                                }
-                                _ => {
-                                    self.reconsume = Option::Some(char);
-                                    match self.return_state {
-                                        Some(state) => {
-                                            self.state = state;
-                                        }
-                                        None => {} //Never happens
-                                    }
-                                    self.buffer = &mut vec![];
-                                    return Token::CharacterReference('&');
-                                }
-                            }
-                        }
-                        None => {
-                            match self.return_state {
-                                Some(state) => {
-                                    self.state = state;
-                                }
-                                None => {} //Never happens
-                            }
-                            self.buffer = &mut vec![];
-                            return Token::CharacterReference('&');
-                        }
-                    }
-                }
-                NamedCharacterReferenceState => {
-                    //TODO
-                }
-                AmbiguousAmpersandState => {
-                    //TODO
-                }
-                NumericCharacterReferenceState => {
-                    //TODO
-                }
-                HexadecimalCharacterReferenceStartState => {
-                    //TODO
-                }
-                DecimalCharacterReferenceStartState => {
-                    //TODO
-                }
-                HexadecimalCharacterReferenceState => {
-                    //TODO
-                }
-                DecimalCharacterReferenceState => {
-                    //TODO
-                }
-                NumericCharacterReferenceEndState => {
-                    //TODO
-                }
+    /// Leaving the attribute name state, an attribute that's already on the tag is reported
+    /// and the first occurrence is kept.
+    fn leave_attribute_name(&mut self, state: LexerState) {
+        let name = self.attribute.0.as_str();
+        if self.tag.attributes.iter().any(|attribute| attribute.name == name) {
+            self.error(ParseError::DuplicateAttribute);
+            self.duplicate_attribute = true;
+        }
+        self.state = state;
+    }
+
+    fn emit_tag(&mut self) -> Token {
+        self.start_attribute();
+        let mut tag = std::mem::replace(&mut self.tag, TagData::new());
+        tag.name = Atom::new(&std::mem::take(&mut self.tag_name));
+        if tag.end_tag {
+            if !tag.attributes.is_empty() {
+                self.error(ParseError::EndTagWithAttributes);
             }
+            if tag.self_closing {
+                self.error(ParseError::EndTagWithTrailingSolidus);
+            }
+        } else {
+            self.last_start_tag = Option::Some(tag.name.clone());
         }
+        Token::Tag(tag)
     }
-}
 
-pub struct AfterAttributeNameState {
-    tag: TagData,
-    attribute: (Vec<char>, Vec<char>),
-}
+    /// Hitting EOF inside a tag drops the tag and emits the EOF.
+    fn eof_in_tag(&mut self) -> Token {
+        self.error(ParseError::EofInTag);
+        self.tag = TagData::new();
+        self.tag_name.clear();
+        self.attribute = (Text::new(), Text::new());
+        Token::EndOfFile()
+    }
 
-impl LexerState for AfterAttributeNameState {
-    fn next_token(mut self, mut self: Lexer) -> Option<Token> {
-        loop {
-            match self.next_character() {
-                Some(char) => {
-                    match char {
-                        '\u{0009}' | '\u{000A}' | '\u{000C}' => {
-                            //Ignored
-                        }
-                        '/' => {
-                            self.tag.attributes.insert(self.attribute.0, self.attribute.1);
-                            self.State = Box::new(SelfClosingStartTagState { tag: self.tag });
-                            return self.next_token();
-                        }
-                        '=' => {
-                            self.State = Box::new(BeforeAttributeValueState { tag: self.tag, attribute: self.attribute });
-                            return self.next_token();
-                        }
-                        '>' => {
-                            self.tag.attributes.insert(self.attribute.0, self.attribute.1);
-                            self.State = Box::new(DataState {});
-                            return Token::Tag(self.tag);
-                        }
-                        _ => {
-                            self.tag.attributes.insert(self.attribute.0, self.attribute.1);
-                            self.State = Box::new(AttributeNameState { tag: self.tag, attribute: (vec![], vec![]) });
-                            return self.next_token();
-                        }
-                    }
+    /// Adds a character to the current run of character data.
+    fn emit_character(&mut self, char: char) {
+        self.text.push_from(self.input.source(), char);
+    }
+
+    /// Adds the plain text following the last character to the current run, up to the
+    /// next character `stop` matches.
+    fn emit_run(&mut self, stop: impl Fn(char) -> bool) {
+        let span = self.input.consume_run(stop);
+        self.text.push_span(self.input.source().0, span);
+    }
+
+    fn emit_comment(&mut self) -> Token {
+        Token::Comment(std::mem::take(&mut self.comment))
+    }
+
+    /// Hitting EOF inside a comment emits what was collected so far, the data state then emits the EOF.
+    fn eof_in_comment(&mut self) -> Token {
+        self.error(ParseError::EofInComment);
+        self.state = LexerState::DataState;
+        self.emit_comment()
+    }
+
+    fn consume_if_next_is(&mut self, expected: &str, ignore_case: bool) -> bool {
+        self.input.consume_if_next_is(expected, ignore_case, &mut self.errors)
+    }
+
+    fn emit_doctype(&mut self) -> Token {
+        Token::Doctype(std::mem::replace(&mut self.doctype, DoctypeData::new()))
+    }
+
+    /// Hitting EOF inside a DOCTYPE emits it with force-quirks set, the data state then emits the EOF.
+    fn eof_in_doctype(&mut self) -> Token {
+        self.error(ParseError::EofInDoctype);
+        self.doctype.force_quirks = true;
+        self.state = LexerState::DataState;
+        self.emit_doctype()
+    }
+
+    /// Anything unexpected where an identifier should start sends the rest of the DOCTYPE to the bogus state.
+    fn bogus_doctype(&mut self, error: ParseError, char: char) {
+        self.error(error);
+        self.doctype.force_quirks = true;
+        self.reconsume = Option::Some(char);
+        self.state = LexerState::BogusDOCTYPEState;
+    }
+
+    fn start_doctype_identifier(&mut self, public: bool, quote: char) {
+        self.state = match (public, quote) {
+            (true, '"') => LexerState::DOCTYPEPublicIdentifierDoubleQuotedState,
+            (true, _) => LexerState::DOCTYPEPublicIdentifierSingleQuotedState,
+            (false, '"') => LexerState::DOCTYPESystemIdentifierDoubleQuotedState,
+            (false, _) => LexerState::DOCTYPESystemIdentifierSingleQuotedState,
+        };
+        if public {
+            self.doctype.public_id = Option::Some(Text::new());
+        } else {
+            self.doctype.system_id = Option::Some(Text::new());
+        }
+    }
+
+    /// Shared by the after public/system keyword states (`keyword` set) and the before public/system
+    /// identifier states, which only differ in what whitespace and a missing separator mean.
+    fn doctype_identifier_start(&mut self, char: Option<char>, public: bool, keyword: bool) -> Option<Token> {
+        match char {
+            Some('\u{0009}' | '\u{000A}' | '\u{000C}' | ' ') => {
+                if keyword {
+                    self.state = if public {
+                        LexerState::BeforeDOCTYPEPublicIdentifierState
+                    } else {
+                        LexerState::BeforeDOCTYPESystemIdentifierState
+                    };
                 }
-                None => {
-                    (self.error_handler)("eof-in-tag");
-                    return Token::Tag(self.tag);
+                None
+            }
+            Some(quote @ ('"' | '\'')) => {
+                if keyword {
+                    self.error(if public {
+                        ParseError::MissingWhitespaceAfterDoctypePublicKeyword
+                    } else {
+                        ParseError::MissingWhitespaceAfterDoctypeSystemKeyword
+                    });
                 }
+                self.start_doctype_identifier(public, quote);
+                None
+            }
+            Some('>') => {
+                self.error(if public {
+                    ParseError::MissingDoctypePublicIdentifier
+                } else {
+                    ParseError::MissingDoctypeSystemIdentifier
+                });
+                self.doctype.force_quirks = true;
+                self.state = LexerState::DataState;
+                Some(self.emit_doctype())
             }
+            Some(char) => {
+                self.bogus_doctype(if public {
+                    ParseError::MissingQuoteBeforeDoctypePublicIdentifier
+                } else {
+                    ParseError::MissingQuoteBeforeDoctypeSystemIdentifier
+                }, char);
+                None
+            }
+            None => Some(self.eof_in_doctype())
         }
     }
-}
-
-pub struct SelfClosingStartTagState {
-    tag: TagData,
-}
 
-impl LexerState for SelfClosingStartTagState {
-    fn next_token(mut self, mut self: Lexer) -> Option<Token> {
-        match self.next_character() {
-            Some(char) => {
-                match char {
-                    '>' => {
-                        self.tag.self_closing = true;
-                        self.State = Box::new(DataState {});
-                        return Token::Tag(self.tag);
-                    }
-                    _ => {
-                        (self.error_handler)("unexpected-solidus-in-tag");
-                        self.reconsume = Option::Some(char);
-                        self.State = Box::new(BeforeAttributeNameState { tag: self.tag });
-                        return self.next_token();
-                    }
-                }
+    /// Shared by the four quoted public/system identifier states.
+    fn doctype_identifier(&mut self, char: Option<char>, public: bool, quote: char) -> Option<Token> {
+        match char {
+            Some(char) if char == quote => {
+                self.state = if public {
+                    LexerState::AfterDOCTYPEPublicIdentifierState
+                } else {
+                    LexerState::AfterDOCTYPESystemIdentifierState
+                };
+                None
             }
-            None => {
-                (self.error_handler)("eof-in-tag");
-                Token::EndOfFile()
+            Some('>') => {
+                self.error(if public {
+                    ParseError::AbruptDoctypePublicIdentifier
+                } else {
+                    ParseError::AbruptDoctypeSystemIdentifier
+                });
+                self.doctype.force_quirks = true;
+                self.state = LexerState::DataState;
+                Some(self.emit_doctype())
             }
+            Some(char) => {
+                let char = if char == '\u{0000}' {
+                    self.error(ParseError::UnexpectedNullCharacter);
+                    '\u{FFFD}'
+                } else {
+                    char
+                };
+                let identifier = if public { &mut self.doctype.public_id } else { &mut self.doctype.system_id };
+                identifier.get_or_insert_with(Text::new).push_from(self.input.source(), char);
+                None
+            }
+            None => Some(self.eof_in_doctype())
         }
     }
-}
 
-pub struct BeforeAttributeValueState {
-    tag: TagData,
-    attribute: (Vec<char>, Vec<char>),
-}
+    fn peek_character(&self) -> Option<char> {
+        self.reconsume.or_else(|| self.input.peek())
+    }
 
-impl LexerState for BeforeAttributeValueState {
-    fn next_token(mut self, mut self: Lexer) -> Option<Token> {
-        loop {
-            match self.next_character() {
-                Some(char) => {
-                    match char {
-                        '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
-                            //Ignored
-                        }
-                        '\"' => {
-                            self.State = Box::new(AttributeValueDoubleQuotedState {
-                                tag: self.tag,
-                                attribute: self.attribute,
-                                ending: false,
-                            });
-                            return self.next_token();
-                        }
-                        '\'' => {
-                            self.State = Box::new(AttributeValueSingleQuotedState {
-                                tag: self.tag,
-                                attribute: self.attribute,
-                                ending: false,
-                            });
-                            return self.next_token();
-                        }
-                        '>' => {
-                            (self.error_handler)("missing-attribute-value");
-                            self.State = Box::new(DataState {});
-                            self.tag.attributes.insert(self.attribute.0, self.attribute.1);
-                            return Token::Tag(self.tag);
-                        }
-                        _ => {
-                            self.reconsume = Option::Some(char);
-                            self.State = Box::new(AttributeValueUnquotedState {
-                                tag: self.tag,
-                                attribute: self.attribute,
-                                ending: false,
-                            });
-                            return self.next_token();
-                        }
-                    }
-                }
-                None => {
-                    self.State = Box::new(AttributeValueUnquotedState {
-                        tag: self.tag,
-                        attribute: self.attribute,
-                        ending: false,
-                    });
-                    return self.next_token();
-                }
+    fn in_attribute_value(&self) -> bool {
+        matches!(self.return_state, Some(LexerState::AttributeValueDoubleQuotedState
+            | LexerState::AttributeValueSingleQuotedState
+            | LexerState::AttributeValueUnquotedState))
+    }
+
+    /// Flushes the temporary buffer of a character reference into the attribute value
+    /// it came from, or out as character tokens.
+    fn flush_character_reference(&mut self) {
+        let buffer = std::mem::take(&mut self.buffer);
+        let in_attribute_value = self.in_attribute_value();
+        for char in buffer {
+            if in_attribute_value {
+                self.attribute.1.push_from(self.input.source(), char);
+            } else {
+                self.emit_character(char);
             }
         }
     }
-}
 
-pub struct AttributeValueDoubleQuotedState {
-    tag: TagData,
-    attribute: (Vec<char>, Vec<char>),
-    ending: bool,
-}
+    fn return_from_character_reference(&mut self) {
+        if let Some(state) = self.return_state.take() {
+            self.state = state;
+        }
+    }
 
-impl LexerState for AttributeValueDoubleQuotedState {
-    fn next_token(mut self, mut self: Lexer) -> Option<Token> {
-        loop {
-            match self.next_character() {
-                Some(char) => {
-                    match char {
-                        '\"' => {
-                            self.State = Box::new(AfterAttributeValueQuotedState {
-                                tag: self.tag,
-                                attribute: self.attribute,
-                                ending: false,
-                            });
-                            return self.next_token();
-                        }
-                        '&' => {
-                            self.State = Box::new(CharacterReferenceState { return_State: self.State });
-                            return self.next_token();
-                        }
-                        '\u{0000}' => {
-                            (self.error_handler)("unexpected-null-character");
-                            self.attribute.1.push('\u{FFFD}');
-                        }
-                        _ => {
-                            self.attribute.1.push(char);
-                        }
-                    }
-                }
-                None => {
-                    return if !self.ending {
-                        self.ending = true;
-                        Token::Tag(self.tag)
-                    } else {
-                        (self.error_handler)("eof-in-tag");
-                        Token::EndOfFile()
-                    };
+    /// Checks the character reference code and turns it into the character it stands for.
+    fn numeric_character_reference_end(&mut self) -> char {
+        let code = self.character_reference_code;
+        match code {
+            0 => {
+                self.error(ParseError::NullCharacterReference);
+                '\u{FFFD}'
+            }
+            0x110000.. => {
+                self.error(ParseError::CharacterReferenceOutsideUnicodeRange);
+                '\u{FFFD}'
+            }
+            0xD800..=0xDFFF => {
+                self.error(ParseError::SurrogateCharacterReference);
+                '\u{FFFD}'
+            }
+            0xFDD0..=0xFDEF => {
+                self.error(ParseError::NoncharacterCharacterReference);
+                char::from_u32(code).unwrap_or('\u{FFFD}')
+            }
+            _ if code & 0xFFFE == 0xFFFE => {
+                self.error(ParseError::NoncharacterCharacterReference);
+                char::from_u32(code).unwrap_or('\u{FFFD}')
+            }
+            0x0D | 0x00..=0x08 | 0x0B | 0x0E..=0x1F | 0x7F..=0x9F => {
+                self.error(ParseError::ControlCharacterReference);
+                match C1_REPLACEMENTS.iter().find(|(control, _)| *control == code) {
+                    Some((_, replacement)) => *replacement,
+                    None => char::from_u32(code).unwrap_or('\u{FFFD}')
                 }
             }
+            _ => char::from_u32(code).unwrap_or('\u{FFFD}')
         }
     }
-}
 
-pub struct AttributeValueSingleQuotedState {
-    tag: TagData,
-    attribute: (Vec<char>, Vec<char>),
-    ending: bool,
-}
+    /// Queues `</` and the temporary buffer as character tokens, used when an end tag turns out not to be one.
+    fn emit_end_tag_buffer(&mut self) {
+        self.emit_character('<');
+        self.emit_character('/');
+        for char in self.buffer.clone() {
+            self.emit_character(char);
+        }
+    }
 
-impl LexerState for AttributeValueSingleQuotedState {
-    fn next_token(mut self, mut self: Lexer) -> Option<Token> {
-        loop {
-            match self.next_character() {
-                Some(char) => {
-                    match char {
-                        '\'' => {
-                            self.State = Box::new(AfterAttributeValueQuotedState {
-                                tag: self.tag,
-                                attribute: self.attribute,
-                                ending: false,
-                            });
-                            return self.next_token();
-                        }
-                        '&' => {
-                            self.State = Box::new(CharacterReferenceState { return_State: self.State });
-                            return self.next_token();
-                        }
-                        '\u{0000}' => {
-                            (self.error_handler)("unexpected-null-character");
-                            self.attribute.1.push('\u{FFFD}');
-                        }
-                        _ => {
-                            self.attribute.1.push(char);
-                        }
-                    }
-                }
-                None => {
-                    return if !self.ending {
-                        self.ending = true;
-                        Token::Tag(self.tag)
-                    } else {
-                        (self.error_handler)("eof-in-tag");
-                        Token::EndOfFile()
-                    };
-                }
+    /// Shared by the RCDATA, RAWTEXT and script data (escaped) end tag name states, which only
+    /// differ in the state they fall back to when the end tag isn't appropriate.
+    fn end_tag_name(&mut self, char: Option<char>, fallback: LexerState) -> Option<Token> {
+        match char {
+            Some('\u{0009}' | '\u{000A}' | '\u{000C}' | ' ') if self.is_appropriate_end_tag() => {
+                self.state = LexerState::BeforeAttributeNameState;
+                None
+            }
+            Some('/') if self.is_appropriate_end_tag() => {
+                self.state = LexerState::SelfClosingStartTagState;
+                None
+            }
+            Some('>') if self.is_appropriate_end_tag() => {
+                self.state = LexerState::DataState;
+                Some(self.emit_tag())
+            }
+            Some(char) if char.is_ascii_alphabetic() => {
+                self.tag_name.push_from(self.input.source(), char.to_ascii_lowercase());
+                self.buffer.push(char);
+                None
+            }
+            _ => {
+                self.reconsume = char;
+                self.emit_end_tag_buffer();
+                self.tag = TagData::new();
+                self.tag_name.clear();
+                self.state = fallback;
+                None
             }
         }
     }
-}
 
-pub struct AttributeValueUnquotedState {
-    tag: TagData,
-    attribute: (Vec<char>, Vec<char>),
-    ending: bool,
-}
+    /// Shared by the RCDATA, RAWTEXT and script data end tag open states.
+    fn end_tag_open(&mut self, char: Option<char>, name_state: LexerState, fallback: LexerState) {
+        self.reconsume = char;
+        match char {
+            Some(char) if char.is_ascii_alphabetic() => {
+                self.tag = TagData::new();
+                self.tag_name.clear();
+                self.tag.end_tag = true;
+                self.state = name_state;
+            }
+            _ => {
+                self.emit_character('<');
+                self.emit_character('/');
+                self.state = fallback;
+            }
+        }
+    }
 
-impl LexerState for AttributeValueUnquotedState {
-    fn next_token(mut self, mut self: Lexer) -> Option<Token> {
+    fn next_character(&mut self) -> Option<char> {
+        match self.reconsume.take() {
+            Some(char) => Option::Some(char),
+            None => self.input.next(&mut self.errors)
+        }
+    }
+
+    /// Produces the next token, or `None` if the input ran out before one could be decided
+    /// on and more of it has to be pushed first. Once the input is finished, this always
+    /// returns a token, ending with `Token::EndOfFile()`.
+    ///
+    /// Character data comes out in runs, which are only split where other tokens or the
+    /// end of the available input interrupt them.
+    pub fn next_token(&mut self) -> Option<Token> {
+        self.input.prepare();
         loop {
-            match self.next_character() {
-                Some(char) => {
-                    match char {
-                        '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
-                            self.State = Box::new(BeforeAttributeNameState { tag: self.tag });
-                            return self.next_token();
-                        }
-                        '&' => {
-                            self.State = Box::new(CharacterReferenceState { return_State: self.State });
-                            return self.next_token();
-                        }
-                        '>' => {
-                            self.State = Box::new(DataState {});
-                            self.tag.attributes.insert(self.attribute.0, self.attribute.1);
-                            return Token::Tag(self.tag);
-                        }
-                        '\u{0000}' => {
-                            (self.error_handler)("unexpected-null-character");
-                            self.attribute.1.push('\u{FFFD}');
-                        }
-                        '\"' | '\'' | '<' | '=' | '`' => {
-                            (self.error_handler)("unexpected-character-in-unquoted-attribute-value");
-                            self.attribute.1.push(char);
-                        }
-                        _ => {
-                            self.attribute.1.push(char);
-                        }
+            if let Some(token) = self.pending.pop_front() {
+                return Some(token);
+            }
+            if !self.can_step() {
+                return self.take_text();
+            }
+            if let Some(token) = self.step() {
+                return match self.take_text() {
+                    Some(text) => {
+                        self.pending.push_back(token);
+                        Some(text)
                     }
-                }
-                None => {
-                    return if !self.ending {
-                        self.ending = true;
-                        Token::Tag(self.tag)
-                    } else {
-                        (self.error_handler)("eof-in-tag");
-                        Token::EndOfFile()
-                    };
-                }
+                    None => Some(token)
+                };
             }
         }
     }
-}
 
-pub struct AfterAttributeValueQuotedState {
-    tag: TagData,
-    attribute: (Vec<char>, Vec<char>),
-    ending: bool,
-}
+    fn take_text(&mut self) -> Option<Token> {
+        if self.text.is_empty() {
+            None
+        } else {
+            Some(Token::Characters(std::mem::take(&mut self.text)))
+        }
+    }
 
-impl LexerState for AfterAttributeValueQuotedState {
-    fn next_token(mut self, mut self: Lexer) -> Option<Token> {
-        loop {
-            match self.next_character() {
-                Some(char) => {
-                    match char {
-                        '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
-                            self.State = Box::new(BeforeAttributeNameState { tag: self.tag });
-                            return self.next_token();
+    /// Runs the current state once. Characters go into the current text run, anything
+    /// else is returned.
+    fn step(&mut self) -> Option<Token> {
+        match self.state {
+            LexerState::DataState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '&' => {
+                                self.return_state = Option::Some(LexerState::DataState);
+                                self.buffer = vec!['&'];
+                                self.state = LexerState::CharacterReferenceState;
+                            }
+                            '<' => {
+                                self.state = LexerState::TagOpenState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.emit_character('\u{0000}');
+                            }
+                            _ => {
+                                self.emit_character(char);
+                                self.emit_run(|char| matches!(char, '&' | '<' | '\0'));
+                            }
                         }
-                        '/' => {
-                            self.State = Box::new(SelfClosingStartTagState { tag: self.tag });
-                            return self.next_token();
+                    }
+                    None => {
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::RCDATAState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '&' => {
+                                self.return_state = Option::Some(LexerState::RCDATAState);
+                                self.buffer = vec!['&'];
+                                self.state = LexerState::CharacterReferenceState;
+                            }
+                            '<' => {
+                                self.state = LexerState::RCDATALessThanSignState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.emit_character(char);
+                                self.emit_run(|char| matches!(char, '&' | '<' | '\0'));
+                            }
                         }
-                        '\u{0000}' => {
-                            self.attribute.1.push('\u{FFFD}');
+                    }
+                    None => {
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::RAWTEXTState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '<' => {
+                                self.state = LexerState::RAWTEXTLessThanSignState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.emit_character(char);
+                                self.emit_run(|char| matches!(char, '<' | '\0'));
+                            }
                         }
-                        _ => {
-                            return if !self.ending {
-                                self.ending = true;
-                                Token::Tag(self.tag)
-                            } else {
-                                (self.error_handler)("missing-whitespace-between-attributes");
-                                Token::EndOfFile()
-                            };
+                    }
+                    None => {
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::ScriptDataState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '<' => {
+                                self.state = LexerState::ScriptDataLessThanSignState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.emit_character(char);
+                                self.emit_run(|char| matches!(char, '<' | '\0'));
+                            }
                         }
                     }
+                    None => {
+                        return Some(Token::EndOfFile());
+                    }
                 }
-                None => {
-                    (self.error_handler)("eof-in-tag");
-                    return Token::EndOfFile();
+            }
+            LexerState::PLAINTEXTState => {
+                match self.next_character() {
+                    Some('\u{0000}') => {
+                        self.error(ParseError::UnexpectedNullCharacter);
+                        self.emit_character('\u{FFFD}');
+                    }
+                    Some(char) => {
+                        self.emit_character(char);
+                        self.emit_run(|char| char == '\0');
+                    }
+                    None => {
+                        return Some(Token::EndOfFile());
+                    }
                 }
             }
-        }
-    }
-}
-
-pub struct NamedCharacterReferenceState {}
-
-impl LexerState for NamedCharacterReferenceState {
-    fn next_token(self, mut self: Lexer) -> Option<Token> {
-        Token::EndOfFile()
-    }
-}
-
-pub struct NumericCharacterReferenceState {}
-
-impl LexerState for NumericCharacterReferenceState {
-    fn next_token(self, mut self: Lexer) -> Option<Token> {
-        Token::EndOfFile()
-    }
-}
-
-pub struct MarkupDeclarationOpenState {}
-
-impl LexerState for MarkupDeclarationOpenState {
-    fn next_token(self, mut self: Lexer) -> Option<Token> {
-        Token::EndOfFile()
+            LexerState::TagOpenState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            'A'..='Z' | 'a'..='z' => {
+                                self.tag = TagData::new();
+                                self.tag_name.clear();
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::TagNameState;
+                            }
+                            '!' => {
+                                self.state = LexerState::MarkupDeclarationOpenState;
+                            }
+                            '/' => {
+                                self.state = LexerState::EndTagOpenState;
+                            }
+                            '?' => {
+                                self.error(ParseError::UnexpectedQuestionMarkInsteadOfTagName);
+                                self.comment.clear();
+                                self.reconsume = Option::Some('?');
+                                self.state = LexerState::BogusCommentState;
+                            }
+                            _ => {
+                                self.error(ParseError::InvalidFirstCharacterOfTagName);
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::DataState;
+                                self.emit_character('<');
+                            }
+                        }
+                    }
+                    None => {
+                        self.error(ParseError::EofBeforeTagName);
+                        self.emit_character('<');
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::EndTagOpenState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            'A'..='Z' | 'a'..='z' => {
+                                self.tag = TagData::new();
+                                self.tag_name.clear();
+                                self.tag.end_tag = true;
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::TagNameState;
+                            }
+                            '>' => {
+                                self.error(ParseError::MissingEndTagName);
+                                self.state = LexerState::DataState;
+                            }
+                            _ => {
+                                self.error(ParseError::InvalidFirstCharacterOfTagName);
+                                self.comment.clear();
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::BogusCommentState;
+                            }
+                        }
+                    }
+                    None => {
+                        self.error(ParseError::EofBeforeTagName);
+                        self.emit_character('<');
+                        self.emit_character('/');
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::TagNameState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                self.state = LexerState::BeforeAttributeNameState;
+                            }
+                            '/' => {
+                                self.state = LexerState::SelfClosingStartTagState;
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_tag());
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.tag_name.push_from(self.input.source(), '\u{FFFD}');
+                            }
+                            _ => {
+                                self.tag_name.push_from(self.input.source(), char.to_ascii_lowercase());
+                                let span = self.input.consume_run(|char| char.is_ascii_uppercase() || matches!(char, '\t' | '\n' | '\u{000C}' | ' ' | '/' | '>' | '\0'));
+                                self.tag_name.push_span(self.input.source().0, span);
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_tag());
+                    }
+                }
+            }
+            LexerState::RCDATALessThanSignState => {
+                match self.next_character() {
+                    Some('/') => {
+                        self.buffer.clear();
+                        self.state = LexerState::RCDATAEndTagOpenState;
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::RCDATAState;
+                        self.emit_character('<');
+                    }
+                }
+            }
+            LexerState::RCDATAEndTagOpenState => {
+                let char = self.next_character();
+                self.end_tag_open(char, LexerState::RCDATAEndTagNameState, LexerState::RCDATAState);
+            }
+            LexerState::RCDATAEndTagNameState => {
+                let char = self.next_character();
+                if let Some(token) = self.end_tag_name(char, LexerState::RCDATAState) {
+                    return Some(token);
+                }
+            }
+            LexerState::RAWTEXTLessThanSignState => {
+                match self.next_character() {
+                    Some('/') => {
+                        self.buffer.clear();
+                        self.state = LexerState::RAWTEXTEndTagOpenState;
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::RAWTEXTState;
+                        self.emit_character('<');
+                    }
+                }
+            }
+            LexerState::RAWTEXTEndTagOpenState => {
+                let char = self.next_character();
+                self.end_tag_open(char, LexerState::RAWTEXTEndTagNameState, LexerState::RAWTEXTState);
+            }
+            LexerState::RAWTEXTEndTagNameState => {
+                let char = self.next_character();
+                if let Some(token) = self.end_tag_name(char, LexerState::RAWTEXTState) {
+                    return Some(token);
+                }
+            }
+            LexerState::ScriptDataLessThanSignState => {
+                match self.next_character() {
+                    Some('/') => {
+                        self.buffer.clear();
+                        self.state = LexerState::ScriptDataEndTagOpenState;
+                    }
+                    Some('!') => {
+                        self.state = LexerState::ScriptDataEscapeStartState;
+                        self.emit_character('<');
+                        self.emit_character('!');
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::ScriptDataState;
+                        self.emit_character('<');
+                    }
+                }
+            }
+            LexerState::ScriptDataEndTagOpenState => {
+                let char = self.next_character();
+                self.end_tag_open(char, LexerState::ScriptDataEndTagNameState, LexerState::ScriptDataState);
+            }
+            LexerState::ScriptDataEndTagNameState => {
+                let char = self.next_character();
+                if let Some(token) = self.end_tag_name(char, LexerState::ScriptDataState) {
+                    return Some(token);
+                }
+            }
+            LexerState::ScriptDataEscapeStartState => {
+                match self.next_character() {
+                    Some('-') => {
+                        self.state = LexerState::ScriptDataEscapeStartDashState;
+                        self.emit_character('-');
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::ScriptDataState;
+                    }
+                }
+            }
+            LexerState::ScriptDataEscapeStartDashState => {
+                match self.next_character() {
+                    Some('-') => {
+                        self.state = LexerState::ScriptDataEscapedDashDashState;
+                        self.emit_character('-');
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::ScriptDataState;
+                    }
+                }
+            }
+            LexerState::ScriptDataEscapedState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '-' => {
+                                self.state = LexerState::ScriptDataEscapedDashState;
+                                self.emit_character('-');
+                            }
+                            '<' => {
+                                self.state = LexerState::ScriptDataEscapedLessThanSignState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.emit_character(char);
+                            }
+                        }
+                    }
+                    None => {
+                        self.error(ParseError::EofInScriptHtmlCommentLikeText);
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::ScriptDataEscapedDashState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '-' => {
+                                self.state = LexerState::ScriptDataEscapedDashDashState;
+                                self.emit_character('-');
+                            }
+                            '<' => {
+                                self.state = LexerState::ScriptDataEscapedLessThanSignState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.state = LexerState::ScriptDataEscapedState;
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.state = LexerState::ScriptDataEscapedState;
+                                self.emit_character(char);
+                            }
+                        }
+                    }
+                    None => {
+                        self.error(ParseError::EofInScriptHtmlCommentLikeText);
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::ScriptDataEscapedDashDashState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '-' => {
+                                self.emit_character('-');
+                            }
+                            '<' => {
+                                self.state = LexerState::ScriptDataEscapedLessThanSignState;
+                            }
+                            '>' => {
+                                self.state = LexerState::ScriptDataState;
+                                self.emit_character('>');
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.state = LexerState::ScriptDataEscapedState;
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.state = LexerState::ScriptDataEscapedState;
+                                self.emit_character(char);
+                            }
+                        }
+                    }
+                    None => {
+                        self.error(ParseError::EofInScriptHtmlCommentLikeText);
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::ScriptDataEscapedLessThanSignState => {
+                match self.next_character() {
+                    Some('/') => {
+                        self.buffer.clear();
+                        self.state = LexerState::ScriptDataEscapedEndTagOpenState;
+                    }
+                    Some(char) if char.is_ascii_alphabetic() => {
+                        self.buffer.clear();
+                        self.reconsume = Option::Some(char);
+                        self.state = LexerState::ScriptDataDoubleEscapeStartState;
+                        self.emit_character('<');
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::ScriptDataEscapedState;
+                        self.emit_character('<');
+                    }
+                }
+            }
+            LexerState::ScriptDataEscapedEndTagOpenState => {
+                let char = self.next_character();
+                self.end_tag_open(char, LexerState::ScriptDataEscapedEndTagNameState, LexerState::ScriptDataEscapedState);
+            }
+            LexerState::ScriptDataEscapedEndTagNameState => {
+                let char = self.next_character();
+                if let Some(token) = self.end_tag_name(char, LexerState::ScriptDataEscapedState) {
+                    return Some(token);
+                }
+            }
+            LexerState::ScriptDataDoubleEscapeStartState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' | '/' | '>' => {
+                                self.state = if self.buffer == ['s', 'c', 'r', 'i', 'p', 't'] {
+                                    LexerState::ScriptDataDoubleEscapedState
+                                } else {
+                                    LexerState::ScriptDataEscapedState
+                                };
+                                self.emit_character(char);
+                            }
+                            'A'..='Z' | 'a'..='z' => {
+                                self.buffer.push(char.to_ascii_lowercase());
+                                self.emit_character(char);
+                            }
+                            _ => {
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::ScriptDataEscapedState;
+                            }
+                        }
+                    }
+                    None => {
+                        self.state = LexerState::ScriptDataEscapedState;
+                    }
+                }
+            }
+            LexerState::ScriptDataDoubleEscapedState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '-' => {
+                                self.state = LexerState::ScriptDataDoubleEscapedDashState;
+                                self.emit_character('-');
+                            }
+                            '<' => {
+                                self.state = LexerState::ScriptDataDoubleEscapedLessThanSignState;
+                                self.emit_character('<');
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.emit_character(char);
+                            }
+                        }
+                    }
+                    None => {
+                        self.error(ParseError::EofInScriptHtmlCommentLikeText);
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::ScriptDataDoubleEscapedDashState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '-' => {
+                                self.state = LexerState::ScriptDataDoubleEscapedDashDashState;
+                                self.emit_character('-');
+                            }
+                            '<' => {
+                                self.state = LexerState::ScriptDataDoubleEscapedLessThanSignState;
+                                self.emit_character('<');
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.state = LexerState::ScriptDataDoubleEscapedState;
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.state = LexerState::ScriptDataDoubleEscapedState;
+                                self.emit_character(char);
+                            }
+                        }
+                    }
+                    None => {
+                        self.error(ParseError::EofInScriptHtmlCommentLikeText);
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::ScriptDataDoubleEscapedDashDashState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '-' => {
+                                self.emit_character('-');
+                            }
+                            '<' => {
+                                self.state = LexerState::ScriptDataDoubleEscapedLessThanSignState;
+                                self.emit_character('<');
+                            }
+                            '>' => {
+                                self.state = LexerState::ScriptDataState;
+                                self.emit_character('>');
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.state = LexerState::ScriptDataDoubleEscapedState;
+                                self.emit_character('\u{FFFD}');
+                            }
+                            _ => {
+                                self.state = LexerState::ScriptDataDoubleEscapedState;
+                                self.emit_character(char);
+                            }
+                        }
+                    }
+                    None => {
+                        self.error(ParseError::EofInScriptHtmlCommentLikeText);
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::ScriptDataDoubleEscapedLessThanSignState => {
+                match self.next_character() {
+                    Some('/') => {
+                        self.buffer.clear();
+                        self.state = LexerState::ScriptDataDoubleEscapeEndState;
+                        self.emit_character('/');
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::ScriptDataDoubleEscapedState;
+                    }
+                }
+            }
+            LexerState::ScriptDataDoubleEscapeEndState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' | '/' | '>' => {
+                                self.state = if self.buffer == ['s', 'c', 'r', 'i', 'p', 't'] {
+                                    LexerState::ScriptDataEscapedState
+                                } else {
+                                    LexerState::ScriptDataDoubleEscapedState
+                                };
+                                self.emit_character(char);
+                            }
+                            'A'..='Z' | 'a'..='z' => {
+                                self.buffer.push(char.to_ascii_lowercase());
+                                self.emit_character(char);
+                            }
+                            _ => {
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::ScriptDataDoubleEscapedState;
+                            }
+                        }
+                    }
+                    None => {
+                        self.state = LexerState::ScriptDataDoubleEscapedState;
+                    }
+                }
+            }
+            LexerState::BeforeAttributeNameState => {
+                match self.next_character() {
+                    Some('\u{0009}' | '\u{000A}' | '\u{000C}' | ' ') => {
+                        //Ignored
+                    }
+                    Some('=') => {
+                        self.error(ParseError::UnexpectedEqualsSignBeforeAttributeName);
+                        self.start_attribute();
+                        self.attribute.0.push_from(self.input.source(), '=');
+                        self.state = LexerState::AttributeNameState;
+                    }
+                    char @ (Some('/' | '>') | None) => {
+                        self.reconsume = char;
+                        self.state = LexerState::AfterAttributeNameState;
+                    }
+                    char => {
+                        self.start_attribute();
+                        self.reconsume = char;
+                        self.state = LexerState::AttributeNameState;
+                    }
+                }
+            }
+            LexerState::AttributeNameState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' | '/' | '>' => {
+                                self.reconsume = Option::Some(char);
+                                self.leave_attribute_name(LexerState::AfterAttributeNameState);
+                            }
+                            '=' => {
+                                self.leave_attribute_name(LexerState::BeforeAttributeValueState);
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.attribute.0.push_from(self.input.source(), '\u{FFFD}');
+                            }
+                            '\"' | '\'' | '<' => {
+                                self.error(ParseError::UnexpectedCharacterInAttributeName);
+                                self.attribute.0.push_from(self.input.source(), char);
+                            }
+                            _ => {
+                                self.attribute.0.push_from(self.input.source(), char.to_ascii_lowercase());
+                                let span = self.input.consume_run(|char| char.is_ascii_uppercase() || matches!(char, '\t' | '\n' | '\u{000C}' | ' ' | '/' | '>' | '=' | '\0' | '"' | '\'' | '<'));
+                                self.attribute.0.push_span(self.input.source().0, span);
+                            }
+                        }
+                    }
+                    None => {
+                        self.leave_attribute_name(LexerState::AfterAttributeNameState);
+                    }
+                }
+            }
+            LexerState::AfterAttributeNameState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                //Ignored
+                            }
+                            '/' => {
+                                self.state = LexerState::SelfClosingStartTagState;
+                            }
+                            '=' => {
+                                self.state = LexerState::BeforeAttributeValueState;
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_tag());
+                            }
+                            _ => {
+                                self.start_attribute();
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::AttributeNameState;
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_tag());
+                    }
+                }
+            }
+            LexerState::BeforeAttributeValueState => {
+                match self.next_character() {
+                    Some('\u{0009}' | '\u{000A}' | '\u{000C}' | ' ') => {
+                        //Ignored
+                    }
+                    Some('\"') => {
+                        self.state = LexerState::AttributeValueDoubleQuotedState;
+                    }
+                    Some('\'') => {
+                        self.state = LexerState::AttributeValueSingleQuotedState;
+                    }
+                    Some('>') => {
+                        self.error(ParseError::MissingAttributeValue);
+                        self.state = LexerState::DataState;
+                        return Some(self.emit_tag());
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::AttributeValueUnquotedState;
+                    }
+                }
+            }
+            LexerState::AttributeValueDoubleQuotedState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\"' => {
+                                self.state = LexerState::AfterAttributeValueQuotedState;
+                            }
+                            '&' => {
+                                self.return_state = Option::Some(LexerState::AttributeValueDoubleQuotedState);
+                                self.buffer = vec!['&'];
+                                self.state = LexerState::CharacterReferenceState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.attribute.1.push_from(self.input.source(), '\u{FFFD}');
+                            }
+                            _ => {
+                                self.attribute.1.push_from(self.input.source(), char);
+                                let span = self.input.consume_run(|char| matches!(char, '"' | '&' | '\0'));
+                                self.attribute.1.push_span(self.input.source().0, span);
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_tag());
+                    }
+                }
+            }
+            LexerState::AttributeValueSingleQuotedState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\'' => {
+                                self.state = LexerState::AfterAttributeValueQuotedState;
+                            }
+                            '&' => {
+                                self.return_state = Option::Some(LexerState::AttributeValueSingleQuotedState);
+                                self.buffer = vec!['&'];
+                                self.state = LexerState::CharacterReferenceState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.attribute.1.push_from(self.input.source(), '\u{FFFD}');
+                            }
+                            _ => {
+                                self.attribute.1.push_from(self.input.source(), char);
+                                let span = self.input.consume_run(|char| matches!(char, '\'' | '&' | '\0'));
+                                self.attribute.1.push_span(self.input.source().0, span);
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_tag());
+                    }
+                }
+            }
+            LexerState::AttributeValueUnquotedState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                self.state = LexerState::BeforeAttributeNameState;
+                            }
+                            '&' => {
+                                self.return_state = Option::Some(LexerState::AttributeValueUnquotedState);
+                                self.buffer = vec!['&'];
+                                self.state = LexerState::CharacterReferenceState;
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_tag());
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.attribute.1.push_from(self.input.source(), '\u{FFFD}');
+                            }
+                            '\"' | '\'' | '<' | '=' | '`' => {
+                                self.error(ParseError::UnexpectedCharacterInUnquotedAttributeValue);
+                                self.attribute.1.push_from(self.input.source(), char);
+                            }
+                            _ => {
+                                self.attribute.1.push_from(self.input.source(), char);
+                                let span = self.input.consume_run(|char| matches!(char, '\t' | '\n' | '\u{000C}' | ' ' | '&' | '>' | '\0' | '"' | '\'' | '<' | '=' | '`'));
+                                self.attribute.1.push_span(self.input.source().0, span);
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_tag());
+                    }
+                }
+            }
+            LexerState::AfterAttributeValueQuotedState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                self.state = LexerState::BeforeAttributeNameState;
+                            }
+                            '/' => {
+                                self.state = LexerState::SelfClosingStartTagState;
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_tag());
+                            }
+                            _ => {
+                                self.error(ParseError::MissingWhitespaceBetweenAttributes);
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::BeforeAttributeNameState;
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_tag());
+                    }
+                }
+            }
+            LexerState::SelfClosingStartTagState => {
+                match self.next_character() {
+                    Some('>') => {
+                        self.tag.self_closing = true;
+                        self.state = LexerState::DataState;
+                        return Some(self.emit_tag());
+                    }
+                    Some(char) => {
+                        self.error(ParseError::UnexpectedSolidusInTag);
+                        self.reconsume = Option::Some(char);
+                        self.state = LexerState::BeforeAttributeNameState;
+                    }
+                    None => {
+                        return Some(self.eof_in_tag());
+                    }
+                }
+            }
+            LexerState::BogusCommentState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_comment());
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.comment.push_from(self.input.source(), '\u{FFFD}');
+                            }
+                            _ => {
+                                self.comment.push_from(self.input.source(), char);
+                            }
+                        }
+                    }
+                    None => {
+                        self.state = LexerState::DataState;
+                        return Some(self.emit_comment());
+                    }
+                }
+            }
+            LexerState::MarkupDeclarationOpenState => {
+                if self.consume_if_next_is("--", false) {
+                    self.comment.clear();
+                    self.state = LexerState::CommentStartState;
+                } else if self.consume_if_next_is("DOCTYPE", true) {
+                    self.doctype = DoctypeData::new();
+                    self.state = LexerState::DOCTYPEState;
+                } else if self.consume_if_next_is("[CDATA[", false) {
+                    if self.in_foreign_content {
+                        self.state = LexerState::CDATASectionState;
+                    } else {
+                        self.error(ParseError::CdataInHtmlContent);
+                        self.comment = Text::from("[CDATA[");
+                        self.state = LexerState::BogusCommentState;
+                    }
+                } else {
+                    self.error(ParseError::IncorrectlyOpenedComment);
+                    self.comment.clear();
+                    self.state = LexerState::BogusCommentState;
+                }
+            }
+            LexerState::CommentStartState => {
+                match self.next_character() {
+                    Some('-') => {
+                        self.state = LexerState::CommentStartDashState;
+                    }
+                    Some('>') => {
+                        self.error(ParseError::AbruptClosingOfEmptyComment);
+                        self.state = LexerState::DataState;
+                        return Some(self.emit_comment());
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::CommentState;
+                    }
+                }
+            }
+            LexerState::CommentStartDashState => {
+                match self.next_character() {
+                    Some('-') => {
+                        self.state = LexerState::CommentEndState;
+                    }
+                    Some('>') => {
+                        self.error(ParseError::AbruptClosingOfEmptyComment);
+                        self.state = LexerState::DataState;
+                        return Some(self.emit_comment());
+                    }
+                    Some(char) => {
+                        self.comment.push_from(self.input.source(), '-');
+                        self.reconsume = Option::Some(char);
+                        self.state = LexerState::CommentState;
+                    }
+                    None => {
+                        return Some(self.eof_in_comment());
+                    }
+                }
+            }
+            LexerState::CommentState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '<' => {
+                                self.comment.push_from(self.input.source(), char);
+                                self.state = LexerState::CommentLessThanSignState;
+                            }
+                            '-' => {
+                                self.state = LexerState::CommentEndDashState;
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.comment.push_from(self.input.source(), '\u{FFFD}');
+                            }
+                            _ => {
+                                self.comment.push_from(self.input.source(), char);
+                                let span = self.input.consume_run(|char| matches!(char, '<' | '-' | '\0'));
+                                self.comment.push_span(self.input.source().0, span);
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_comment());
+                    }
+                }
+            }
+            LexerState::CommentLessThanSignState => {
+                match self.next_character() {
+                    Some('!') => {
+                        self.comment.push_from(self.input.source(), '!');
+                        self.state = LexerState::CommentLessThanSignBangState;
+                    }
+                    Some('<') => {
+                        self.comment.push_from(self.input.source(), '<');
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::CommentState;
+                    }
+                }
+            }
+            LexerState::CommentLessThanSignBangState => {
+                match self.next_character() {
+                    Some('-') => {
+                        self.state = LexerState::CommentLessThanSignBangDashState;
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::CommentState;
+                    }
+                }
+            }
+            LexerState::CommentLessThanSignBangDashState => {
+                match self.next_character() {
+                    Some('-') => {
+                        self.state = LexerState::CommentLessThanSignBangDashDashState;
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::CommentEndDashState;
+                    }
+                }
+            }
+            LexerState::CommentLessThanSignBangDashDashState => {
+                let char = self.next_character();
+                if !matches!(char, Some('>') | None) {
+                    self.error(ParseError::NestedComment);
+                }
+                self.reconsume = char;
+                self.state = LexerState::CommentEndState;
+            }
+            LexerState::CommentEndDashState => {
+                match self.next_character() {
+                    Some('-') => {
+                        self.state = LexerState::CommentEndState;
+                    }
+                    Some(char) => {
+                        self.comment.push_from(self.input.source(), '-');
+                        self.reconsume = Option::Some(char);
+                        self.state = LexerState::CommentState;
+                    }
+                    None => {
+                        return Some(self.eof_in_comment());
+                    }
+                }
+            }
+            LexerState::CommentEndState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_comment());
+                            }
+                            '!' => {
+                                self.state = LexerState::CommentEndBangState;
+                            }
+                            '-' => {
+                                self.comment.push_from(self.input.source(), '-');
+                            }
+                            _ => {
+                                self.comment.push_str_from(self.input.source(), "--");
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::CommentState;
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_comment());
+                    }
+                }
+            }
+            LexerState::CommentEndBangState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '-' => {
+                                self.comment.push_str_from(self.input.source(), "--!");
+                                self.state = LexerState::CommentEndDashState;
+                            }
+                            '>' => {
+                                self.error(ParseError::IncorrectlyClosedComment);
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_comment());
+                            }
+                            _ => {
+                                self.comment.push_str_from(self.input.source(), "--!");
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::CommentState;
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_comment());
+                    }
+                }
+            }
+            LexerState::DOCTYPEState => {
+                match self.next_character() {
+                    Some('\u{0009}' | '\u{000A}' | '\u{000C}' | ' ') => {
+                        self.state = LexerState::BeforeDOCTYPENameState;
+                    }
+                    Some('>') => {
+                        self.reconsume = Option::Some('>');
+                        self.state = LexerState::BeforeDOCTYPENameState;
+                    }
+                    Some(char) => {
+                        self.error(ParseError::MissingWhitespaceBeforeDoctypeName);
+                        self.reconsume = Option::Some(char);
+                        self.state = LexerState::BeforeDOCTYPENameState;
+                    }
+                    None => {
+                        return Some(self.eof_in_doctype());
+                    }
+                }
+            }
+            LexerState::BeforeDOCTYPENameState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                //Ignored
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.doctype.name = Option::Some(Text::from("\u{FFFD}"));
+                                self.state = LexerState::DOCTYPENameState;
+                            }
+                            '>' => {
+                                self.error(ParseError::MissingDoctypeName);
+                                self.doctype.force_quirks = true;
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_doctype());
+                            }
+                            _ => {
+                                let mut name = Text::new();
+                                name.push_from(self.input.source(), char.to_ascii_lowercase());
+                                self.doctype.name = Option::Some(name);
+                                self.state = LexerState::DOCTYPENameState;
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_doctype());
+                    }
+                }
+            }
+            LexerState::DOCTYPENameState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                self.state = LexerState::AfterDOCTYPENameState;
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_doctype());
+                            }
+                            '\u{0000}' => {
+                                self.error(ParseError::UnexpectedNullCharacter);
+                                self.doctype.name.get_or_insert_with(Text::new).push_from(self.input.source(), '\u{FFFD}');
+                            }
+                            _ => {
+                                self.doctype.name.get_or_insert_with(Text::new).push_from(self.input.source(), char.to_ascii_lowercase());
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_doctype());
+                    }
+                }
+            }
+            LexerState::AfterDOCTYPENameState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                //Ignored
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_doctype());
+                            }
+                            'P' | 'p' if self.consume_if_next_is("UBLIC", true) => {
+                                self.state = LexerState::AfterDOCTYPEPublicKeywordState;
+                            }
+                            'S' | 's' if self.consume_if_next_is("YSTEM", true) => {
+                                self.state = LexerState::AfterDOCTYPESystemKeywordState;
+                            }
+                            _ => {
+                                self.bogus_doctype(ParseError::InvalidCharacterSequenceAfterDoctypeName, char);
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_doctype());
+                    }
+                }
+            }
+            LexerState::AfterDOCTYPEPublicKeywordState => {
+                let char = self.next_character();
+                if let Some(token) = self.doctype_identifier_start(char, true, true) {
+                    return Some(token);
+                }
+            }
+            LexerState::BeforeDOCTYPEPublicIdentifierState => {
+                let char = self.next_character();
+                if let Some(token) = self.doctype_identifier_start(char, true, false) {
+                    return Some(token);
+                }
+            }
+            LexerState::DOCTYPEPublicIdentifierDoubleQuotedState => {
+                let char = self.next_character();
+                if let Some(token) = self.doctype_identifier(char, true, '"') {
+                    return Some(token);
+                }
+            }
+            LexerState::DOCTYPEPublicIdentifierSingleQuotedState => {
+                let char = self.next_character();
+                if let Some(token) = self.doctype_identifier(char, true, '\'') {
+                    return Some(token);
+                }
+            }
+            LexerState::AfterDOCTYPEPublicIdentifierState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                self.state = LexerState::BetweenDOCTYPEPublicAndSystemIdentifiersState;
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_doctype());
+                            }
+                            '"' | '\'' => {
+                                self.error(ParseError::MissingWhitespaceBetweenDoctypePublicAndSystemIdentifiers);
+                                self.start_doctype_identifier(false, char);
+                            }
+                            _ => {
+                                self.bogus_doctype(ParseError::MissingQuoteBeforeDoctypeSystemIdentifier, char);
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_doctype());
+                    }
+                }
+            }
+            LexerState::BetweenDOCTYPEPublicAndSystemIdentifiersState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                //Ignored
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_doctype());
+                            }
+                            '"' | '\'' => {
+                                self.start_doctype_identifier(false, char);
+                            }
+                            _ => {
+                                self.bogus_doctype(ParseError::MissingQuoteBeforeDoctypeSystemIdentifier, char);
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_doctype());
+                    }
+                }
+            }
+            LexerState::AfterDOCTYPESystemKeywordState => {
+                let char = self.next_character();
+                if let Some(token) = self.doctype_identifier_start(char, false, true) {
+                    return Some(token);
+                }
+            }
+            LexerState::BeforeDOCTYPESystemIdentifierState => {
+                let char = self.next_character();
+                if let Some(token) = self.doctype_identifier_start(char, false, false) {
+                    return Some(token);
+                }
+            }
+            LexerState::DOCTYPESystemIdentifierDoubleQuotedState => {
+                let char = self.next_character();
+                if let Some(token) = self.doctype_identifier(char, false, '"') {
+                    return Some(token);
+                }
+            }
+            LexerState::DOCTYPESystemIdentifierSingleQuotedState => {
+                let char = self.next_character();
+                if let Some(token) = self.doctype_identifier(char, false, '\'') {
+                    return Some(token);
+                }
+            }
+            LexerState::AfterDOCTYPESystemIdentifierState => {
+                match self.next_character() {
+                    Some(char) => {
+                        match char {
+                            '\u{0009}' | '\u{000A}' | '\u{000C}' | ' ' => {
+                                //Ignored
+                            }
+                            '>' => {
+                                self.state = LexerState::DataState;
+                                return Some(self.emit_doctype());
+                            }
+                            _ => {
+                                // Unlike the other DOCTYPE errors, this one doesn't set force-quirks
+                                self.error(ParseError::UnexpectedCharacterAfterDoctypeSystemIdentifier);
+                                self.reconsume = Option::Some(char);
+                                self.state = LexerState::BogusDOCTYPEState;
+                            }
+                        }
+                    }
+                    None => {
+                        return Some(self.eof_in_doctype());
+                    }
+                }
+            }
+            LexerState::BogusDOCTYPEState => {
+                match self.next_character() {
+                    Some('>') => {
+                        self.state = LexerState::DataState;
+                        return Some(self.emit_doctype());
+                    }
+                    Some('\u{0000}') => {
+                        self.error(ParseError::UnexpectedNullCharacter);
+                    }
+                    Some(_) => {
+                        //Ignored
+                    }
+                    None => {
+                        self.state = LexerState::DataState;
+                        return Some(self.emit_doctype());
+                    }
+                }
+            }
+            LexerState::CDATASectionState => {
+                match self.next_character() {
+                    Some(']') => {
+                        self.state = LexerState::CDATASectionBracketState;
+                    }
+                    Some(char) => {
+                        self.emit_character(char);
+                    }
+                    None => {
+                        self.error(ParseError::EofInCdata);
+                        return Some(Token::EndOfFile());
+                    }
+                }
+            }
+            LexerState::CDATASectionBracketState => {
+                match self.next_character() {
+                    Some(']') => {
+                        self.state = LexerState::CDATASectionEndState;
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::CDATASectionState;
+                        self.emit_character(']');
+                    }
+                }
+            }
+            LexerState::CDATASectionEndState => {
+                match self.next_character() {
+                    Some(']') => {
+                        self.emit_character(']');
+                    }
+                    Some('>') => {
+                        self.state = LexerState::DataState;
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::CDATASectionState;
+                        self.emit_character(']');
+                        self.emit_character(']');
+                    }
+                }
+            }
+            LexerState::CharacterReferenceState => {
+                match self.next_character() {
+                    Some(char) if char.is_ascii_alphanumeric() => {
+                        self.reconsume = Option::Some(char);
+                        self.state = LexerState::NamedCharacterReferenceState;
+                    }
+                    Some('#') => {
+                        self.buffer.push('#');
+                        self.state = LexerState::NumericCharacterReferenceState;
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.flush_character_reference();
+                        self.return_from_character_reference();
+                    }
+                }
+            }
+            LexerState::NamedCharacterReferenceState => {
+                let lookahead = self.reconsume.into_iter().chain(self.input.lookahead());
+                match entities::longest_match(lookahead) {
+                    Some((length, value)) => {
+                        for _ in 0..length {
+                            if let Some(char) = self.next_character() {
+                                self.buffer.push(char);
+                            }
+                        }
+                        let terminated = self.buffer.last() == Some(&';');
+                        let next = self.peek_character();
+                        // For historical reasons, `&amp=` or `&ampx` in an attribute value are left as is
+                        if !terminated && self.in_attribute_value()
+                            && next.is_some_and(|next| next == '=' || next.is_ascii_alphanumeric()) {
+                            self.flush_character_reference();
+                        } else {
+                            if !terminated {
+                                self.error(ParseError::MissingSemicolonAfterCharacterReference);
+                            }
+                            self.buffer = value.chars().collect();
+                            self.flush_character_reference();
+                        }
+                        self.return_from_character_reference();
+                    }
+                    None => {
+                        self.flush_character_reference();
+                        self.state = LexerState::AmbiguousAmpersandState;
+                    }
+                }
+            }
+            LexerState::AmbiguousAmpersandState => {
+                match self.next_character() {
+                    Some(char) if char.is_ascii_alphanumeric() => {
+                        if self.in_attribute_value() {
+                            self.attribute.1.push_from(self.input.source(), char);
+                        } else {
+                            self.emit_character(char);
+                        }
+                    }
+                    char => {
+                        if char == Some(';') {
+                            self.error(ParseError::UnknownNamedCharacterReference);
+                        }
+                        self.reconsume = char;
+                        self.return_from_character_reference();
+                    }
+                }
+            }
+            LexerState::NumericCharacterReferenceState => {
+                self.character_reference_code = 0;
+                match self.next_character() {
+                    Some(char @ ('x' | 'X')) => {
+                        self.buffer.push(char);
+                        self.state = LexerState::HexadecimalCharacterReferenceStartState;
+                    }
+                    char => {
+                        self.reconsume = char;
+                        self.state = LexerState::DecimalCharacterReferenceStartState;
+                    }
+                }
+            }
+            LexerState::HexadecimalCharacterReferenceStartState => {
+                let char = self.next_character();
+                self.reconsume = char;
+                if char.is_some_and(|char| char.is_ascii_hexdigit()) {
+                    self.state = LexerState::HexadecimalCharacterReferenceState;
+                } else {
+                    self.error(ParseError::AbsenceOfDigitsInNumericCharacterReference);
+                    self.flush_character_reference();
+                    self.return_from_character_reference();
+                }
+            }
+            LexerState::DecimalCharacterReferenceStartState => {
+                let char = self.next_character();
+                self.reconsume = char;
+                if char.is_some_and(|char| char.is_ascii_digit()) {
+                    self.state = LexerState::DecimalCharacterReferenceState;
+                } else {
+                    self.error(ParseError::AbsenceOfDigitsInNumericCharacterReference);
+                    self.flush_character_reference();
+                    self.return_from_character_reference();
+                }
+            }
+            LexerState::HexadecimalCharacterReferenceState => {
+                match self.next_character() {
+                    Some(char) if char.is_ascii_hexdigit() => {
+                        // Saturates instead of overflowing, anything past 0x10FFFF is out of range anyway
+                        self.character_reference_code = self.character_reference_code
+                            .saturating_mul(16)
+                            .saturating_add(char.to_digit(16).unwrap_or(0));
+                    }
+                    Some(';') => {
+                        self.state = LexerState::NumericCharacterReferenceEndState;
+                    }
+                    char => {
+                        self.error(ParseError::MissingSemicolonAfterCharacterReference);
+                        self.reconsume = char;
+                        self.state = LexerState::NumericCharacterReferenceEndState;
+                    }
+                }
+            }
+            LexerState::DecimalCharacterReferenceState => {
+                match self.next_character() {
+                    Some(char) if char.is_ascii_digit() => {
+                        // Saturates instead of overflowing, anything past 0x10FFFF is out of range anyway
+                        self.character_reference_code = self.character_reference_code
+                            .saturating_mul(10)
+                            .saturating_add(char.to_digit(10).unwrap_or(0));
+                    }
+                    Some(';') => {
+                        self.state = LexerState::NumericCharacterReferenceEndState;
+                    }
+                    char => {
+                        self.error(ParseError::MissingSemicolonAfterCharacterReference);
+                        self.reconsume = char;
+                        self.state = LexerState::NumericCharacterReferenceEndState;
+                    }
+                }
+            }
+            LexerState::NumericCharacterReferenceEndState => {
+                let char = self.numeric_character_reference_end();
+                self.buffer = vec![char];
+                self.flush_character_reference();
+                self.return_from_character_reference();
+            }
+        }
+        None
     }
 }
-
-pub struct BogusCommentState {}
-
-impl LexerState for BogusCommentState {
-    fn next_token(self, mut self: Lexer) -> Option<Token> {
-        Token::EndOfFile()
-    }
-}
\ No newline at end of file
@@ -1,5 +1,17 @@
+pub mod dom;
 pub mod node;
 pub mod parser;
 
+pub mod atoms;
 pub mod tokens;
-pub mod lexer;
\ No newline at end of file
+pub mod lexer;
+pub mod entities;
+pub mod errors;
+pub mod input;
+pub mod encoding;
+pub mod encoding_indexes;
+pub mod sniff;
+pub mod quirks;
+pub mod text;
+pub mod namespace;
+pub mod script;
@@ -0,0 +1,162 @@
+//! Namespaces of elements and attributes, and the adjustments the tree builder makes to
+//! names in SVG and MathML, whose tokens are lowercased like any other.
+
+use crate::html::atoms::{self, Atom};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Namespace {
+    Html,
+    MathMl,
+    Svg,
+    XLink,
+    Xml,
+    Xmlns,
+}
+
+impl Namespace {
+    pub fn url(self) -> &'static str {
+        match self {
+            Namespace::Html => "http://www.w3.org/1999/xhtml",
+            Namespace::MathMl => "http://www.w3.org/1998/Math/MathML",
+            Namespace::Svg => "http://www.w3.org/2000/svg",
+            Namespace::XLink => "http://www.w3.org/1999/xlink",
+            Namespace::Xml => "http://www.w3.org/XML/1998/namespace",
+            Namespace::Xmlns => "http://www.w3.org/2000/xmlns/",
+        }
+    }
+}
+
+/// Restores the case of SVG element names like `foreignObject`.
+pub fn adjust_svg_tag_name(name: Atom) -> Atom {
+    match name {
+        atoms::ALTGLYPH => atoms::ALT_GLYPH,
+        atoms::ALTGLYPHDEF => atoms::ALT_GLYPH_DEF,
+        atoms::ALTGLYPHITEM => atoms::ALT_GLYPH_ITEM,
+        atoms::ANIMATECOLOR => atoms::ANIMATE_COLOR,
+        atoms::ANIMATEMOTION => atoms::ANIMATE_MOTION,
+        atoms::ANIMATETRANSFORM => atoms::ANIMATE_TRANSFORM,
+        atoms::CLIPPATH => atoms::CLIPPATH_ADJUSTED,
+        atoms::FEBLEND => atoms::FE_BLEND,
+        atoms::FECOLORMATRIX => atoms::FE_COLOR_MATRIX,
+        atoms::FECOMPONENTTRANSFER => atoms::FE_COMPONENT_TRANSFER,
+        atoms::FECOMPOSITE => atoms::FE_COMPOSITE,
+        atoms::FECONVOLVEMATRIX => atoms::FE_CONVOLVE_MATRIX,
+        atoms::FEDIFFUSELIGHTING => atoms::FE_DIFFUSE_LIGHTING,
+        atoms::FEDISPLACEMENTMAP => atoms::FE_DISPLACEMENT_MAP,
+        atoms::FEDISTANTLIGHT => atoms::FE_DISTANT_LIGHT,
+        atoms::FEDROPSHADOW => atoms::FE_DROP_SHADOW,
+        atoms::FEFLOOD => atoms::FE_FLOOD,
+        atoms::FEFUNCA => atoms::FE_FUNC_A,
+        atoms::FEFUNCB => atoms::FE_FUNC_B,
+        atoms::FEFUNCG => atoms::FE_FUNC_G,
+        atoms::FEFUNCR => atoms::FE_FUNC_R,
+        atoms::FEGAUSSIANBLUR => atoms::FE_GAUSSIAN_BLUR,
+        atoms::FEIMAGE => atoms::FE_IMAGE,
+        atoms::FEMERGE => atoms::FE_MERGE,
+        atoms::FEMERGENODE => atoms::FE_MERGE_NODE,
+        atoms::FEMORPHOLOGY => atoms::FE_MORPHOLOGY,
+        atoms::FEOFFSET => atoms::FE_OFFSET,
+        atoms::FEPOINTLIGHT => atoms::FE_POINT_LIGHT,
+        atoms::FESPECULARLIGHTING => atoms::FE_SPECULAR_LIGHTING,
+        atoms::FESPOTLIGHT => atoms::FE_SPOT_LIGHT,
+        atoms::FETILE => atoms::FE_TILE,
+        atoms::FETURBULENCE => atoms::FE_TURBULENCE,
+        atoms::FOREIGNOBJECT => atoms::FOREIGN_OBJECT,
+        atoms::GLYPHREF => atoms::GLYPH_REF,
+        atoms::LINEARGRADIENT => atoms::LINEAR_GRADIENT,
+        atoms::RADIALGRADIENT => atoms::RADIAL_GRADIENT,
+        atoms::TEXTPATH => atoms::TEXT_PATH,
+        _ => name
+    }
+}
+
+/// Restores the case of SVG attribute names like `viewBox`.
+pub fn adjust_svg_attribute(name: Atom) -> Atom {
+    match name {
+        atoms::ATTRIBUTENAME => atoms::ATTRIBUTE_NAME,
+        atoms::ATTRIBUTETYPE => atoms::ATTRIBUTE_TYPE,
+        atoms::BASEFREQUENCY => atoms::BASE_FREQUENCY,
+        atoms::BASEPROFILE => atoms::BASE_PROFILE,
+        atoms::CALCMODE => atoms::CALC_MODE,
+        atoms::CLIPPATHUNITS => atoms::CLIP_PATH_UNITS,
+        atoms::DIFFUSECONSTANT => atoms::DIFFUSE_CONSTANT,
+        atoms::EDGEMODE => atoms::EDGE_MODE,
+        atoms::FILTERUNITS => atoms::FILTER_UNITS,
+        atoms::GRADIENTTRANSFORM => atoms::GRADIENT_TRANSFORM,
+        atoms::GRADIENTUNITS => atoms::GRADIENT_UNITS,
+        atoms::KERNELMATRIX => atoms::KERNEL_MATRIX,
+        atoms::KERNELUNITLENGTH => atoms::KERNEL_UNIT_LENGTH,
+        atoms::KEYPOINTS => atoms::KEY_POINTS,
+        atoms::KEYSPLINES => atoms::KEY_SPLINES,
+        atoms::KEYTIMES => atoms::KEY_TIMES,
+        atoms::LENGTHADJUST => atoms::LENGTH_ADJUST,
+        atoms::LIMITINGCONEANGLE => atoms::LIMITING_CONE_ANGLE,
+        atoms::MARKERHEIGHT => atoms::MARKER_HEIGHT,
+        atoms::MARKERUNITS => atoms::MARKER_UNITS,
+        atoms::MARKERWIDTH => atoms::MARKER_WIDTH,
+        atoms::MASKCONTENTUNITS => atoms::MASK_CONTENT_UNITS,
+        atoms::MASKUNITS => atoms::MASK_UNITS,
+        atoms::NUMOCTAVES => atoms::NUM_OCTAVES,
+        atoms::PATHLENGTH => atoms::PATH_LENGTH,
+        atoms::PATTERNCONTENTUNITS => atoms::PATTERN_CONTENT_UNITS,
+        atoms::PATTERNTRANSFORM => atoms::PATTERN_TRANSFORM,
+        atoms::PATTERNUNITS => atoms::PATTERN_UNITS,
+        atoms::POINTSATX => atoms::POINTS_AT_X,
+        atoms::POINTSATY => atoms::POINTS_AT_Y,
+        atoms::POINTSATZ => atoms::POINTS_AT_Z,
+        atoms::PRESERVEALPHA => atoms::PRESERVE_ALPHA,
+        atoms::PRESERVEASPECTRATIO => atoms::PRESERVE_ASPECT_RATIO,
+        atoms::PRIMITIVEUNITS => atoms::PRIMITIVE_UNITS,
+        atoms::REFX => atoms::REF_X,
+        atoms::REFY => atoms::REF_Y,
+        atoms::REPEATCOUNT => atoms::REPEAT_COUNT,
+        atoms::REPEATDUR => atoms::REPEAT_DUR,
+        atoms::REQUIREDEXTENSIONS => atoms::REQUIRED_EXTENSIONS,
+        atoms::REQUIREDFEATURES => atoms::REQUIRED_FEATURES,
+        atoms::SPECULARCONSTANT => atoms::SPECULAR_CONSTANT,
+        atoms::SPECULAREXPONENT => atoms::SPECULAR_EXPONENT,
+        atoms::SPREADMETHOD => atoms::SPREAD_METHOD,
+        atoms::STARTOFFSET => atoms::START_OFFSET,
+        atoms::STDDEVIATION => atoms::STD_DEVIATION,
+        atoms::STITCHTILES => atoms::STITCH_TILES,
+        atoms::SURFACESCALE => atoms::SURFACE_SCALE,
+        atoms::SYSTEMLANGUAGE => atoms::SYSTEM_LANGUAGE,
+        atoms::TABLEVALUES => atoms::TABLE_VALUES,
+        atoms::TARGETX => atoms::TARGET_X,
+        atoms::TARGETY => atoms::TARGET_Y,
+        atoms::TEXTLENGTH => atoms::TEXT_LENGTH,
+        atoms::VIEWBOX => atoms::VIEW_BOX,
+        atoms::VIEWTARGET => atoms::VIEW_TARGET,
+        atoms::XCHANNELSELECTOR => atoms::X_CHANNEL_SELECTOR,
+        atoms::YCHANNELSELECTOR => atoms::Y_CHANNEL_SELECTOR,
+        atoms::ZOOMANDPAN => atoms::ZOOM_AND_PAN,
+        _ => name
+    }
+}
+
+pub fn adjust_mathml_attribute(name: Atom) -> Atom {
+    match name {
+        atoms::DEFINITIONURL => atoms::DEFINITION_URL,
+        _ => name
+    }
+}
+
+/// The prefix, local name and namespace of the namespaced attributes foreign elements can
+/// have, like `xlink:href`. Other attributes stay in no namespace.
+pub fn adjust_foreign_attribute(name: &Atom) -> Option<(Option<&'static str>, &'static str, Namespace)> {
+    let adjusted = match *name {
+        atoms::XLINK_ACTUATE => (Some("xlink"), "actuate", Namespace::XLink),
+        atoms::XLINK_ARCROLE => (Some("xlink"), "arcrole", Namespace::XLink),
+        atoms::XLINK_HREF => (Some("xlink"), "href", Namespace::XLink),
+        atoms::XLINK_ROLE => (Some("xlink"), "role", Namespace::XLink),
+        atoms::XLINK_SHOW => (Some("xlink"), "show", Namespace::XLink),
+        atoms::XLINK_TITLE => (Some("xlink"), "title", Namespace::XLink),
+        atoms::XLINK_TYPE => (Some("xlink"), "type", Namespace::XLink),
+        atoms::XML_LANG => (Some("xml"), "lang", Namespace::Xml),
+        atoms::XML_SPACE => (Some("xml"), "space", Namespace::Xml),
+        atoms::XMLNS => (None, "xmlns", Namespace::Xmlns),
+        atoms::XMLNS_XLINK => (Some("xmlns"), "xlink", Namespace::Xmlns),
+        _ => return None
+    };
+    Some(adjusted)
+}
@@ -1,59 +1,419 @@
-pub struct Node {
-    children: Vec<Element>,
-    node_type: NodeType,
+use crate::html::atoms::{self, Atom};
+use crate::html::dom::NodeId;
+use crate::html::errors::DomException;
+use crate::html::namespace::Namespace;
+use crate::html::quirks::QuirksMode;
+
+#[derive(Clone)]
+pub struct Element {
+    pub tag: Atom,
+    pub namespace: Namespace,
+    attributes: Vec<Attribute>,
+    /// The document fragment holding the contents of a `template` element, which aren't its
+    /// children.
+    pub template_contents: Option<NodeId>,
+    /// The value of the `id` attribute unless it's empty, kept up to date with it.
+    id: Option<Atom>,
+    /// The tokens of the `class` attribute without duplicates, kept up to date with it.
+    classes: Vec<Atom>,
 }
 
-impl Node {
-    pub fn add_child(&mut self, child: Element) {
-        self.children.push(child);
+impl Element {
+    pub fn new(tag: Atom, namespace: Namespace, attributes: Vec<Attribute>) -> Element {
+        let mut element = Element { tag, namespace, attributes, template_contents: None, id: None, classes: Vec::new() };
+        element.attributes_changed();
+        element
+    }
+
+    pub fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+
+    /// The value of the first attribute with this qualified name, like `href` or
+    /// `xlink:href`. Names are matched in lowercase on HTML elements.
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        self.attribute_position(name).map(|index| self.attributes[index].value.as_str())
+    }
+
+    pub fn get_attribute_ns(&self, namespace: Option<Namespace>, local_name: &str) -> Option<&str> {
+        self.attribute_position_ns(namespace, local_name).map(|index| self.attributes[index].value.as_str())
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attribute_position(name).is_some()
+    }
+
+    pub fn has_attribute_ns(&self, namespace: Option<Namespace>, local_name: &str) -> bool {
+        self.attribute_position_ns(namespace, local_name).is_some()
+    }
+
+    /// Changes the value of the attribute with this qualified name, or adds one in no
+    /// namespace if there's none.
+    pub fn set_attribute(&mut self, name: &str, value: String) -> Result<(), DomException> {
+        if !is_valid_attribute_name(name) {
+            return Err(DomException::InvalidCharacterError);
+        }
+        match self.attribute_position(name) {
+            Some(index) => self.attributes[index].value = value,
+            None => {
+                let name = if self.namespace == Namespace::Html { name.to_ascii_lowercase() } else { name.to_string() };
+                self.attributes.push(Attribute::new(Atom::new(&name), value));
+            }
+        }
+        self.attributes_changed();
+        Ok(())
+    }
+
+    /// Changes the value of the attribute with this namespace and the local name of
+    /// `qualified_name`, or adds one with its prefix. A prefix needs a namespace, and the
+    /// `xml` and `xmlns` prefixes need their own.
+    pub fn set_attribute_ns(&mut self, namespace: Option<Namespace>, qualified_name: &str, value: String) -> Result<(), DomException> {
+        let (prefix, local_name) = match qualified_name.split_once(':') {
+            Some((prefix, local_name)) => (Some(prefix), local_name),
+            None => (None, qualified_name)
+        };
+        if prefix.is_some_and(|prefix| !is_valid_attribute_name(prefix))
+            || !is_valid_attribute_name(local_name) || local_name.contains(':') {
+            return Err(DomException::InvalidCharacterError);
+        }
+        let xmlns = prefix == Some("xmlns") || qualified_name == "xmlns";
+        if (prefix.is_some() && namespace.is_none())
+            || (prefix == Some("xml") && namespace != Some(Namespace::Xml))
+            || (xmlns != (namespace == Some(Namespace::Xmlns))) {
+            return Err(DomException::NamespaceError);
+        }
+        match self.attribute_position_ns(namespace, local_name) {
+            Some(index) => self.attributes[index].value = value,
+            None => self.attributes.push(Attribute {
+                name: Atom::new(local_name),
+                namespace,
+                prefix: prefix.map(Atom::new),
+                value,
+            })
+        }
+        self.attributes_changed();
+        Ok(())
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) -> Option<Attribute> {
+        let index = self.attribute_position(name)?;
+        let removed = self.attributes.remove(index);
+        self.attributes_changed();
+        Some(removed)
+    }
+
+    pub fn remove_attribute_ns(&mut self, namespace: Option<Namespace>, local_name: &str) -> Option<Attribute> {
+        let index = self.attribute_position_ns(namespace, local_name)?;
+        let removed = self.attributes.remove(index);
+        self.attributes_changed();
+        Some(removed)
+    }
+
+    /// Adds an attribute as the tree builder found it, without checking its name.
+    pub(crate) fn push_attribute(&mut self, attribute: Attribute) {
+        self.attributes.push(attribute);
+        self.attributes_changed();
+    }
+
+    pub fn id(&self) -> Option<&Atom> {
+        self.id.as_ref()
+    }
+
+    /// The classes of the element, in the order they first appear in the `class` attribute.
+    pub fn class_list(&self) -> &[Atom] {
+        &self.classes
+    }
+
+    /// Changes the classes of the element like `DOMTokenList` does, writing them back to the
+    /// `class` attribute.
+    pub fn class_list_mut(&mut self) -> ClassList<'_> {
+        ClassList { element: self }
+    }
+
+    /// The `data-*` attributes of the element, by the camel-cased names scripts use for them.
+    pub fn dataset(&self) -> Dataset<'_> {
+        Dataset { element: self }
+    }
+
+    pub fn dataset_mut(&mut self) -> DatasetMut<'_> {
+        DatasetMut { element: self }
+    }
+
+    fn attribute_position(&self, name: &str) -> Option<usize> {
+        let name = if self.namespace == Namespace::Html { name.to_ascii_lowercase() } else { name.to_string() };
+        self.attributes.iter().position(|attribute| attribute.has_qualified_name(&name))
+    }
+
+    fn attribute_position_ns(&self, namespace: Option<Namespace>, local_name: &str) -> Option<usize> {
+        self.attributes.iter().position(|attribute| attribute.namespace == namespace && attribute.name == *local_name)
+    }
+
+    /// Updates the cached `id` and classes after any change to the attributes.
+    fn attributes_changed(&mut self) {
+        let value = |name: Atom| self.attributes.iter()
+            .find(|attribute| attribute.namespace.is_none() && attribute.name == name)
+            .map(|attribute| attribute.value.as_str());
+        self.id = value(atoms::ID).filter(|id| !id.is_empty()).map(Atom::new);
+        self.classes = value(atoms::CLASS).map(parse_tokens).unwrap_or_default();
     }
 }
 
-pub struct Element {
-    tag: String,
-    attributes: Vec<Attribute>,
+/// The classes of an element with the methods of `DOMTokenList`, which keep the `class`
+/// attribute in sync.
+pub struct ClassList<'a> {
+    element: &'a mut Element,
 }
 
-pub struct DocumentType {
-    internel_subset: String,
-    name: String,
-    notations: Vec<Attribute>,
-    public_id: String,
-    system_id: String,
-    quirks: bool
+impl ClassList<'_> {
+    pub fn contains(&self, token: &str) -> bool {
+        self.element.classes.iter().any(|class| *class == *token)
+    }
+
+    /// Adds the tokens that aren't there yet at the end.
+    pub fn add(&mut self, tokens: &[&str]) -> Result<(), DomException> {
+        tokens.iter().try_for_each(|token| check_token(token))?;
+        let mut classes = self.element.classes.clone();
+        for &token in tokens {
+            let token = Atom::new(token);
+            if !classes.contains(&token) {
+                classes.push(token);
+            }
+        }
+        self.update(classes);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, tokens: &[&str]) -> Result<(), DomException> {
+        tokens.iter().try_for_each(|token| check_token(token))?;
+        let mut classes = self.element.classes.clone();
+        classes.retain(|class| !tokens.contains(&class.as_str()));
+        self.update(classes);
+        Ok(())
+    }
+
+    /// Removes the token if it's there and adds it otherwise, or only one of the two if
+    /// `force` is set. Returns whether the token is there afterwards.
+    pub fn toggle(&mut self, token: &str, force: Option<bool>) -> Result<bool, DomException> {
+        check_token(token)?;
+        let mut classes = self.element.classes.clone();
+        match classes.iter().position(|class| *class == *token) {
+            Some(_) if force == Some(true) => Ok(true),
+            Some(index) => {
+                classes.remove(index);
+                self.update(classes);
+                Ok(false)
+            }
+            None if force == Some(false) => Ok(false),
+            None => {
+                classes.push(Atom::new(token));
+                self.update(classes);
+                Ok(true)
+            }
+        }
+    }
+
+    /// Puts `new_token` in the place of `token`, returns whether `token` was there.
+    pub fn replace(&mut self, token: &str, new_token: &str) -> Result<bool, DomException> {
+        if token.is_empty() || new_token.is_empty() {
+            return Err(DomException::SyntaxError);
+        }
+        check_token(token)?;
+        check_token(new_token)?;
+        if !self.contains(token) {
+            return Ok(false);
+        }
+        let new_token = Atom::new(new_token);
+        let mut classes = self.element.classes.clone();
+        let index = classes.iter().position(|class| *class == *token || *class == new_token)
+            .expect("the token is in the list");
+        classes[index] = new_token.clone();
+        // Replacing a token with itself only drops its duplicates
+        let mut seen = false;
+        classes.retain(|class| {
+            let keep = !(*class == new_token && seen) && (*class != *token || new_token == *token);
+            seen |= *class == new_token;
+            keep
+        });
+        self.update(classes);
+        Ok(true)
+    }
+
+    /// Writes the classes back to the attribute, which isn't added just to hold nothing.
+    fn update(&mut self, classes: Vec<Atom>) {
+        if classes.is_empty() && !self.element.has_attribute_ns(None, "class") {
+            return;
+        }
+        let value = classes.iter().map(|class| class.as_str()).collect::<Vec<_>>().join(" ");
+        match self.element.attribute_position_ns(None, "class") {
+            Some(index) => self.element.attributes[index].value = value,
+            None => self.element.attributes.push(Attribute::new(atoms::CLASS, value))
+        }
+        self.element.attributes_changed();
+    }
 }
 
-pub struct Document {
+/// Read access to the `data-*` attributes of an element, the `DOMStringMap` of `dataset`.
+pub struct Dataset<'a> {
+    element: &'a Element,
+}
+
+impl<'a> Dataset<'a> {
+    /// The value of the attribute a name like `userId` stands for, `data-user-id`.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.iter().find(|(key, _)| key == name).map(|(_, value)| value)
+    }
 
+    /// The names and values of the `data-*` attributes, in the order of the attributes.
+    pub fn iter(&self) -> impl Iterator<Item = (String, &'a str)> {
+        self.element.attributes.iter().filter_map(|attribute| {
+            let name = attribute.name.as_str().strip_prefix("data-")?;
+            if attribute.namespace.is_some() || name.contains(|char: char| char.is_ascii_uppercase()) {
+                return None;
+            }
+            Some((camel_case(name), attribute.value.as_str()))
+        })
+    }
 }
 
-pub struct DocumentFragment {
+/// Changes the `data-*` attributes of an element through the names of `dataset`.
+pub struct DatasetMut<'a> {
+    element: &'a mut Element,
+}
 
+impl DatasetMut<'_> {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.element.dataset().get(name)
+    }
+
+    /// Sets the attribute `name` stands for, which can't have a dash followed by a lowercase
+    /// letter since that wouldn't map back to the same name.
+    pub fn set(&mut self, name: &str, value: String) -> Result<(), DomException> {
+        let mut chars = name.chars().peekable();
+        while let Some(char) = chars.next() {
+            if char == '-' && chars.peek().is_some_and(char::is_ascii_lowercase) {
+                return Err(DomException::SyntaxError);
+            }
+        }
+        self.element.set_attribute(&attribute_name(name), value)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Attribute> {
+        self.element.remove_attribute(&attribute_name(name))
+    }
 }
 
-type Attribute = (String, String);
+/// `data-` followed by the name with each uppercase letter turned into a dash and the
+/// lowercase letter.
+fn attribute_name(name: &str) -> String {
+    let mut attribute = String::from("data-");
+    for char in name.chars() {
+        if char.is_ascii_uppercase() {
+            attribute.push('-');
+        }
+        attribute.push(char.to_ascii_lowercase());
+    }
+    attribute
+}
 
-pub fn text(text: String) -> Node {
-    Node { children: Vec::new(), node_type: NodeType::Text(text) }
+/// The `dataset` name of an attribute name without its `data-`, with each dash followed by
+/// a lowercase letter turned into the uppercase letter.
+fn camel_case(name: &str) -> String {
+    let mut camel_case = String::with_capacity(name.len());
+    let mut chars = name.chars().peekable();
+    while let Some(char) = chars.next() {
+        match chars.peek() {
+            Some(&next) if char == '-' && next.is_ascii_lowercase() => {
+                camel_case.push(next.to_ascii_uppercase());
+                chars.next();
+            }
+            _ => camel_case.push(char)
+        }
+    }
+    camel_case
 }
 
-pub fn element(children: Vec<Element>, attributes: Vec<Attribute>, tag: String) -> Node {
-    Node {
-        children,
-        node_type: NodeType::Element(Element { attributes, tag }),
+/// Splits a `class` attribute into its tokens, dropping duplicates.
+fn parse_tokens(value: &str) -> Vec<Atom> {
+    let mut tokens = Vec::new();
+    for token in value.split(is_ascii_whitespace).filter(|token| !token.is_empty()) {
+        let token = Atom::new(token);
+        if !tokens.contains(&token) {
+            tokens.push(token);
+        }
     }
+    tokens
 }
 
-pub fn document(children: Vec<Element>) -> Node {
-    Node {
-        children,
-        node_type: NodeType::Document(Document {}),
+/// The tokens `DOMTokenList` methods accept, which can't be empty or hold whitespace.
+fn check_token(token: &str) -> Result<(), DomException> {
+    if token.is_empty() {
+        Err(DomException::SyntaxError)
+    } else if token.contains(is_ascii_whitespace) {
+        Err(DomException::InvalidCharacterError)
+    } else {
+        Ok(())
     }
 }
 
+fn is_ascii_whitespace(char: char) -> bool {
+    matches!(char, '\t' | '\n' | '\u{000C}' | '\r' | ' ')
+}
+
+/// Attribute names can't be empty or hold whitespace, NULL, `/`, `=` or `>`.
+fn is_valid_attribute_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(|char| is_ascii_whitespace(char) || matches!(char, '\0' | '/' | '=' | '>'))
+}
+
+#[derive(Clone)]
+pub struct DocumentType {
+    pub internel_subset: String,
+    pub name: String,
+    pub notations: Vec<Attribute>,
+    pub public_id: String,
+    pub system_id: String,
+    pub quirks: bool
+}
+
+#[derive(Clone)]
+pub struct Document {
+    pub quirks_mode: QuirksMode,
+}
+
+#[derive(Clone)]
+pub struct DocumentFragment {
+
+}
+
+#[derive(Clone)]
+pub struct Attribute {
+    /// The local name, without the prefix.
+    pub name: Atom,
+    /// Set for namespaced attributes, like the `xlink:href` of SVG elements.
+    pub namespace: Option<Namespace>,
+    pub prefix: Option<Atom>,
+    pub value: String,
+}
+
+impl Attribute {
+    /// An attribute in no namespace.
+    pub fn new(name: Atom, value: String) -> Attribute {
+        Attribute { name, namespace: None, prefix: None, value }
+    }
+
+    /// Whether the name is `name`, with the prefix if there's one.
+    fn has_qualified_name(&self, name: &str) -> bool {
+        match &self.prefix {
+            Some(prefix) => name.strip_prefix(prefix.as_str())
+                .and_then(|rest| rest.strip_prefix(':'))
+                .is_some_and(|local_name| self.name == *local_name),
+            None => self.name == *name
+        }
+    }
+}
+
+#[derive(Clone)]
 pub enum NodeType {
     Text(String),
-    Attribute(Attribute),
     Element(Element),
     CDataSection(String),
     ProcessingInstruction(String),
@@ -61,4 +421,4 @@ pub enum NodeType {
     Document(Document),
     DocumentType(DocumentType),
     DocumentFragment(DocumentFragment)
-}
\ No newline at end of file
+}
@@ -1,22 +1,2485 @@
-use crate::html::node::{document, Document, Node};
-use crate::html::lexer::Lexer;
+use std::{mem, slice};
 
-pub struct Parser<'a> {
-    stack: Vec<Node>,
-    lexer: Lexer<'a>
+use crate::html::atoms::{self, Atom};
+use crate::html::encoding::Encoding;
+use crate::html::errors::{ParseError, ParseErrorReport};
+use crate::html::lexer::{Lexer, LexerState};
+use crate::html::namespace::{self, Namespace};
+use crate::html::dom::{Dom, NodeId};
+use crate::html::node::{Attribute, DocumentFragment, DocumentType, Element, NodeType};
+use crate::html::quirks::{self, QuirksMode};
+use crate::html::script::{DocumentWriter, Script, ScriptHandler};
+use crate::html::sniff::{self, Confidence};
+use crate::html::text::Text;
+use crate::html::tokens::{self, DoctypeData, TagData, Token};
+
+/// The insertion modes of the tree construction stage, which decide how each token is handled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InsertionMode {
+    Initial,
+    BeforeHtml,
+    BeforeHead,
+    InHead,
+    InHeadNoscript,
+    AfterHead,
+    InBody,
+    Text,
+    InTable,
+    InTableText,
+    InCaption,
+    InColumnGroup,
+    InTableBody,
+    InRow,
+    InCell,
+    InSelect,
+    InSelectInTable,
+    InTemplate,
+    AfterBody,
+    InFrameset,
+    AfterFrameset,
+    AfterAfterBody,
+    AfterAfterFrameset,
+}
+
+/// The kinds of scope the tree builder checks elements against, each is bounded by a
+/// different set of elements.
+#[derive(Clone, Copy, PartialEq)]
+enum Scope {
+    Default,
+    ListItem,
+    Button,
+    Table,
+    Select,
 }
 
-impl<'a> Parser<'a> {
-    pub fn new(input: String) -> Parser<'a> {
-        Parser { stack: Vec::new(), lexer: Lexer::new(input) }
+/// An entry in the list of active formatting elements. Elements keep the token they were
+/// created for, so they can be recreated when they have to be reopened.
+#[derive(Clone)]
+enum FormattingEntry {
+    /// Set when entering elements like `applet` and `object`, formatting elements from
+    /// outside them aren't reopened inside.
+    Marker,
+    Element(NodeId, TagData),
+}
+
+pub struct Parser {
+    /// The stack of open elements, the current node is the last one.
+    stack: Vec<NodeId>,
+    /// The list of active formatting elements, the last one is the most recently opened.
+    formatting: Vec<FormattingEntry>,
+    dom: Dom,
+    lexer: Lexer,
+    errors: ParseErrorReport,
+    mode: InsertionMode,
+    /// The mode to go back to after the text mode, or after in table text.
+    original_mode: InsertionMode,
+    /// The insertion modes of the open `template` elements, the current one is the last.
+    template_modes: Vec<InsertionMode>,
+    head: Option<NodeId>,
+    form: Option<NodeId>,
+    /// The element a fragment is parsed in, which isn't part of the tree.
+    context: Option<NodeId>,
+    frameset_ok: bool,
+    /// Whether scripting is enabled, which changes how `noscript` is parsed.
+    scripting: bool,
+    script_handler: Option<Box<dyn ScriptHandler>>,
+    /// Set after `pre`, `listing` and `textarea` start tags, whose first newline is dropped.
+    skip_newline: bool,
+    /// Whether the self-closing flag of the current start tag was acknowledged.
+    acknowledged: bool,
+    /// Set while content that isn't allowed in a table is moved in front of it.
+    foster_parenting: bool,
+    /// Text in a table, which is kept there or moved in front of it once it has ended.
+    pending_table_text: String,
+    /// The encoding the input was decoded with, UTF-8 when it was given as a string.
+    encoding: Encoding,
+    confidence: Confidence,
+    /// The document's bytes without the byte order mark, kept to decode them again if the
+    /// encoding was only a guess. Empty when it was given as a string.
+    bytes: Vec<u8>,
+    /// Set when a `<meta>` declares an encoding other than the one guessed, which stops
+    /// parsing so the document can be parsed again from the start.
+    new_encoding: Option<Encoding>,
+}
+
+impl Parser {
+    pub fn new(input: String) -> Parser {
+        Parser::with_lexer(Lexer::new(input), Encoding::Utf8, Confidence::Irrelevant)
+    }
+
+    /// A parser for a document given as bytes, decoded with the encoding the sniffing
+    /// algorithm picks. `transport_charset` is the charset of the `Content-Type` it was
+    /// served with, if any, which wins over a `<meta>` in the document.
+    pub fn from_bytes(bytes: &[u8], transport_charset: Option<&str>) -> Parser {
+        let (encoding, confidence) = sniff::sniff(bytes, transport_charset);
+        let bom = match sniff::bom(bytes) {
+            Some((_, length)) => length,
+            None => 0
+        };
+        Parser::decode(bytes[bom..].to_vec(), encoding, confidence)
+    }
+
+    fn decode(bytes: Vec<u8>, encoding: Encoding, confidence: Confidence) -> Parser {
+        let mut lexer = Lexer::incremental();
+        lexer.set_encoding(encoding);
+        lexer.push_bytes(&bytes);
+        lexer.finish();
+        let mut parser = Parser::with_lexer(lexer, encoding, confidence);
+        parser.bytes = bytes;
+        parser
+    }
+
+    fn with_lexer(lexer: Lexer, encoding: Encoding, confidence: Confidence) -> Parser {
+        Parser {
+            stack: Vec::new(),
+            formatting: Vec::new(),
+            dom: Dom::new(),
+            lexer,
+            errors: ParseErrorReport::new(),
+            mode: InsertionMode::Initial,
+            original_mode: InsertionMode::Initial,
+            template_modes: Vec::new(),
+            head: None,
+            form: None,
+            context: None,
+            frameset_ok: true,
+            scripting: false,
+            script_handler: None,
+            skip_newline: false,
+            acknowledged: false,
+            foster_parenting: false,
+            pending_table_text: String::new(),
+            encoding,
+            confidence,
+            bytes: Vec::new(),
+            new_encoding: None,
+        }
+    }
+
+    /// The encoding of the document and how sure the parser is about it.
+    pub fn encoding(&self) -> (Encoding, Confidence) {
+        (self.encoding, self.confidence)
+    }
+
+    /// Runs parser-inserted scripts with `handler` as their end tags are reached. This
+    /// enables scripting, so `noscript` contents are parsed as text like in a browser.
+    pub fn set_script_handler(&mut self, handler: impl ScriptHandler + 'static) {
+        self.script_handler = Some(Box::new(handler));
+        self.scripting = true;
+    }
+
+    /// The errors found by the tokenizer and tree builder, sorted by position.
+    pub fn errors(&self) -> ParseErrorReport {
+        let mut errors = self.errors.clone();
+        errors.merge(self.lexer.errors().clone());
+        errors
+    }
+
+    /// Runs the tokenizer and tree builder over the whole input and returns the tree, whose
+    /// `document()` is the parsed document.
+    pub fn parse(&mut self) -> Dom {
+        self.run();
+        if let Some(encoding) = self.new_encoding.take() {
+            self.restart(encoding);
+            self.run();
+        }
+        mem::take(&mut self.dom)
+    }
+
+    /// Throws away what was parsed so far and starts over, decoding the bytes with the
+    /// encoding a `<meta>` declared. That one is certain, so this only happens once.
+    fn restart(&mut self, encoding: Encoding) {
+        let mut parser = Parser::decode(mem::take(&mut self.bytes), encoding, Confidence::Certain);
+        parser.scripting = self.scripting;
+        parser.script_handler = self.script_handler.take();
+        *self = parser;
+    }
+
+    /// Parses the input as the contents of `context`, the way `innerHTML` is set, and returns
+    /// the tree with a document fragment holding the nodes it's made of. The context decides
+    /// the tokenizer state the input starts in and the insertion mode, so `<td>` contents may
+    /// hold a table row while `<title>` contents are only text.
+    ///
+    /// The context is taken on its own, without the document it's in: the form element
+    /// pointer is only set when the context is a `form` itself rather than from its nearest
+    /// form ancestor, and the fragment is parsed in no-quirks mode whatever the mode of the
+    /// context's document.
+    pub fn parse_fragment(&mut self, context: &Element) -> (Dom, NodeId) {
+        let state = match context.tag {
+            _ if context.namespace != Namespace::Html => None,
+            atoms::TITLE | atoms::TEXTAREA => Some(LexerState::RCDATAState),
+            atoms::STYLE | atoms::XMP | atoms::IFRAME | atoms::NOEMBED | atoms::NOFRAMES => Some(LexerState::RAWTEXTState),
+            atoms::NOSCRIPT if self.scripting => Some(LexerState::RAWTEXTState),
+            atoms::SCRIPT => Some(LexerState::ScriptDataState),
+            atoms::PLAINTEXT => Some(LexerState::PLAINTEXTState),
+            _ => None
+        };
+        if let Some(state) = state {
+            self.lexer.set_state(state);
+        }
+
+        let element = Element::new(context.tag.clone(), context.namespace, context.attributes().to_vec());
+        let context_node = self.create_node(NodeType::Element(element));
+        self.context = Some(context_node);
+        self.insert_html_element(&TagData::new());
+        let html = self.current();
+        if context.tag == atoms::TEMPLATE {
+            self.template_modes.push(InsertionMode::InTemplate);
+        }
+        if context.tag == atoms::FORM {
+            self.form = Some(context_node);
+        }
+        self.reset_insertion_mode();
+
+        // The context is the adjusted current node until the first token is processed
+        self.lexer.set_in_foreign_content(context.namespace != Namespace::Html);
+        self.run();
+        let fragment = self.create_node(NodeType::DocumentFragment(DocumentFragment {}));
+        while let Some(child) = self.dom[html].first_child() {
+            self.detach(child);
+            self.append(fragment, child);
+        }
+        (mem::take(&mut self.dom), fragment)
+    }
+
+    fn run(&mut self) {
+        while self.new_encoding.is_none() {
+            let Some(token) = self.lexer.next_token() else {
+                break;
+            };
+            let end_of_file = token == Token::EndOfFile();
+            self.process_token(token);
+            if end_of_file {
+                break;
+            }
+        }
+    }
+
+    fn process_token(&mut self, mut token: Token) {
+        if mem::take(&mut self.skip_newline) {
+            if let Token::Characters(text) = &token {
+                if let Some(rest) = text.strip_prefix('\n') {
+                    if rest.is_empty() {
+                        return;
+                    }
+                    token = Token::Characters(Text::from(rest));
+                }
+            }
+        }
+        let self_closing = matches!(&token, Token::Tag(tag) if !tag.end_tag && tag.self_closing);
+        self.acknowledged = false;
+        if self.in_html_content(&token) {
+            self.process(token);
+        } else {
+            self.in_foreign_content(token);
+        }
+        if self_closing && !self.acknowledged {
+            self.error(ParseError::NonVoidHtmlElementStartTagWithTrailingSolidus);
+        }
+        let foreign = self.adjusted_current_node().is_some_and(|node| self.namespace(node) != Namespace::Html);
+        self.lexer.set_in_foreign_content(foreign);
+    }
+
+    /// Whether the token is handled by the rules of the insertion mode rather than as foreign
+    /// content. Foreign content lasts until an integration point lets HTML back in.
+    fn in_html_content(&self, token: &Token) -> bool {
+        let node = match self.adjusted_current_node() {
+            Some(node) => node,
+            None => return true
+        };
+        if self.namespace(node) == Namespace::Html {
+            return true;
+        }
+        let start_tag = match token {
+            Token::Tag(tag) if !tag.end_tag => Some(&tag.name),
+            _ => None
+        };
+        let characters = matches!(token, Token::Characters(_));
+        if self.is_mathml_text_integration_point(node)
+            && (characters || start_tag.is_some_and(|name| !matches!(*name, atoms::MGLYPH | atoms::MALIGNMARK))) {
+            return true;
+        }
+        if self.namespace(node) == Namespace::MathMl && self.local_name(node) == atoms::ANNOTATION_XML
+            && start_tag == Some(&atoms::SVG) {
+            return true;
+        }
+        if self.is_html_integration_point(node) && (characters || start_tag.is_some()) {
+            return true;
+        }
+        matches!(token, Token::EndOfFile())
+    }
+
+    fn in_foreign_content(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                for _ in text.matches('\0') {
+                    self.error(ParseError::UnexpectedNullCharacter);
+                }
+                let text = if text.contains('\0') { Text::from(text.replace('\0', "\u{FFFD}")) } else { text };
+                if !split_whitespace(&text).1.is_empty() {
+                    self.frameset_ok = false;
+                }
+                self.insert_text(&text);
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if breaks_out_of_foreign_content(&tag) => {
+                if tag.end_tag {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                } else {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                }
+                while !self.is_mathml_text_integration_point(self.current())
+                    && !self.is_html_integration_point(self.current())
+                    && self.namespace(self.current()) != Namespace::Html {
+                    self.stack.pop();
+                }
+                self.process(Token::Tag(tag));
+            }
+            Token::Tag(tag) if !tag.end_tag => {
+                let node = self.adjusted_current_node().expect("foreign content has an element to be in");
+                let namespace = self.namespace(node);
+                let self_closing_script = tag.self_closing && tag.name == atoms::SCRIPT && namespace == Namespace::Svg;
+                let element = self.insert_foreign_element(tag, namespace);
+                if self_closing_script {
+                    self.run_script(element);
+                }
+            }
+            Token::Tag(tag) if tag.name == atoms::SCRIPT && self.namespace(self.current()) == Namespace::Svg
+                && self.local_name(self.current()) == atoms::SCRIPT => {
+                let script = self.current();
+                self.stack.pop();
+                self.run_script(script);
+            }
+            Token::Tag(tag) => {
+                let matches = |parser: &Parser, index: usize| {
+                    parser.local_name(parser.stack[index]).as_str().eq_ignore_ascii_case(tag.name.as_str())
+                };
+                let mut index = self.stack.len() - 1;
+                if !matches(self, index) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                }
+                while index > 0 {
+                    if matches(self, index) {
+                        self.stack.truncate(index);
+                        return;
+                    }
+                    index -= 1;
+                    if self.namespace(self.stack[index]) == Namespace::Html {
+                        self.process(Token::Tag(tag));
+                        return;
+                    }
+                }
+            }
+            Token::EndOfFile() => self.process(Token::EndOfFile())
+        }
+    }
+
+    fn process(&mut self, token: Token) {
+        self.process_using(self.mode, token);
+    }
+
+    /// Handles a token using the rules of `mode`, which isn't necessarily the current one.
+    fn process_using(&mut self, mode: InsertionMode, token: Token) {
+        match mode {
+            InsertionMode::Initial => self.initial(token),
+            InsertionMode::BeforeHtml => self.before_html(token),
+            InsertionMode::BeforeHead => self.before_head(token),
+            InsertionMode::InHead => self.in_head(token),
+            InsertionMode::InHeadNoscript => self.in_head_noscript(token),
+            InsertionMode::AfterHead => self.after_head(token),
+            InsertionMode::InBody => self.in_body(token),
+            InsertionMode::Text => self.text(token),
+            InsertionMode::InTable => self.in_table(token),
+            InsertionMode::InTableText => self.in_table_text(token),
+            InsertionMode::InCaption => self.in_caption(token),
+            InsertionMode::InColumnGroup => self.in_column_group(token),
+            InsertionMode::InTableBody => self.in_table_body(token),
+            InsertionMode::InRow => self.in_row(token),
+            InsertionMode::InCell => self.in_cell(token),
+            InsertionMode::InSelect => self.in_select(token),
+            InsertionMode::InSelectInTable => self.in_select_in_table(token),
+            InsertionMode::InTemplate => self.in_template(token),
+            InsertionMode::AfterBody => self.after_body(token),
+            InsertionMode::InFrameset => self.in_frameset(token),
+            InsertionMode::AfterFrameset => self.after_frameset(token),
+            InsertionMode::AfterAfterBody => self.after_after_body(token),
+            InsertionMode::AfterAfterFrameset => self.after_after_frameset(token),
+        }
+    }
+
+    fn error(&mut self, error: ParseError) {
+        self.errors.report(error, self.lexer.position());
+    }
+
+    fn initial(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                let (_, rest) = split_whitespace(&text);
+                if !rest.is_empty() {
+                    self.missing_doctype();
+                    self.process(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Comment(text) => self.insert_comment_in(text, self.dom.document()),
+            Token::Doctype(doctype) => {
+                let name = doctype.name.as_deref();
+                let system_id = doctype.system_id.as_deref();
+                if name != Some("html") || doctype.public_id.is_some()
+                    || system_id.is_some_and(|id| id != "about:legacy-compat") {
+                    self.error(ParseError::NonConformingDoctype);
+                }
+                let quirks_mode = quirks::quirks_mode(&doctype);
+                let node = self.create_node(NodeType::DocumentType(document_type(doctype)));
+                self.append(self.dom.document(), node);
+                self.set_quirks_mode(quirks_mode);
+                self.mode = InsertionMode::BeforeHtml;
+            }
+            token => {
+                self.missing_doctype();
+                self.process(token);
+            }
+        }
+    }
+
+    fn missing_doctype(&mut self) {
+        self.error(ParseError::MissingDoctype);
+        self.set_quirks_mode(QuirksMode::Quirks);
+        self.mode = InsertionMode::BeforeHtml;
+    }
+
+    fn before_html(&mut self, token: Token) {
+        match token {
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Comment(text) => self.insert_comment_in(text, self.dom.document()),
+            Token::Characters(text) => {
+                let (_, rest) = split_whitespace(&text);
+                if !rest.is_empty() {
+                    self.insert_html_element(&TagData::new());
+                    self.mode = InsertionMode::BeforeHead;
+                    self.process(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::HTML => {
+                self.insert_html_element(&tag);
+                self.mode = InsertionMode::BeforeHead;
+            }
+            Token::Tag(tag) if tag.end_tag && !matches!(tag.name, atoms::HEAD | atoms::BODY | atoms::HTML | atoms::BR) => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            token => {
+                self.insert_html_element(&TagData::new());
+                self.mode = InsertionMode::BeforeHead;
+                self.process(token);
+            }
+        }
+    }
+
+    fn insert_html_element(&mut self, tag: &TagData) {
+        let html = self.create_element(atoms::HTML, Namespace::Html, tag);
+        self.append(self.dom.document(), html);
+        self.stack.push(html);
+    }
+
+    fn before_head(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                let (_, rest) = split_whitespace(&text);
+                if !rest.is_empty() {
+                    self.insert_head(TagData::new());
+                    self.process(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::HTML => {
+                self.process_using(InsertionMode::InBody, Token::Tag(tag));
+            }
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::HEAD => self.insert_head(tag),
+            Token::Tag(tag) if tag.end_tag && !matches!(tag.name, atoms::HEAD | atoms::BODY | atoms::HTML | atoms::BR) => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            token => {
+                self.insert_head(TagData::new());
+                self.process(token);
+            }
+        }
+    }
+
+    fn insert_head(&mut self, mut tag: TagData) {
+        tag.name = atoms::HEAD;
+        self.head = Some(self.insert_element(tag));
+        self.mode = InsertionMode::InHead;
+    }
+
+    fn in_head(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                let (whitespace, rest) = split_whitespace(&text);
+                self.insert_text(whitespace);
+                if !rest.is_empty() {
+                    self.leave_head();
+                    self.process(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag => match tag.name {
+                atoms::HTML => self.process_using(InsertionMode::InBody, Token::Tag(tag)),
+                atoms::BASE | atoms::BASEFONT | atoms::BGSOUND | atoms::LINK => self.insert_void_element(tag),
+                atoms::META => {
+                    if self.confidence == Confidence::Tentative {
+                        self.change_encoding(&tag);
+                    }
+                    self.insert_void_element(tag);
+                }
+                atoms::TITLE => self.parse_raw_text(tag, LexerState::RCDATAState),
+                atoms::NOSCRIPT if self.scripting => self.parse_raw_text(tag, LexerState::RAWTEXTState),
+                atoms::NOFRAMES | atoms::STYLE => self.parse_raw_text(tag, LexerState::RAWTEXTState),
+                atoms::NOSCRIPT => {
+                    self.insert_element(tag);
+                    self.mode = InsertionMode::InHeadNoscript;
+                }
+                atoms::SCRIPT => self.parse_raw_text(tag, LexerState::ScriptDataState),
+                atoms::TEMPLATE => {
+                    self.insert_element(tag);
+                    self.formatting.push(FormattingEntry::Marker);
+                    self.frameset_ok = false;
+                    self.mode = InsertionMode::InTemplate;
+                    self.template_modes.push(InsertionMode::InTemplate);
+                }
+                atoms::HEAD => self.error(ParseError::UnexpectedStartTag(tag.name.to_string())),
+                _ => {
+                    self.leave_head();
+                    self.process(Token::Tag(tag));
+                }
+            },
+            Token::Tag(tag) => match tag.name {
+                atoms::HEAD => {
+                    self.stack.pop();
+                    self.mode = InsertionMode::AfterHead;
+                }
+                atoms::BODY | atoms::HTML | atoms::BR => {
+                    self.leave_head();
+                    self.process(Token::Tag(tag));
+                }
+                atoms::TEMPLATE => {
+                    if !self.has_template() {
+                        self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                        return;
+                    }
+                    self.generate_all_implied_end_tags();
+                    if self.current_name() != atoms::TEMPLATE {
+                        self.error(ParseError::EndTagTooEarly(tag.name.to_string()));
+                    }
+                    self.close_template();
+                }
+                _ => self.error(ParseError::UnexpectedEndTag(tag.name.to_string()))
+            },
+            Token::EndOfFile() => {
+                self.leave_head();
+                self.process(Token::EndOfFile());
+            }
+        }
+    }
+
+    fn leave_head(&mut self) {
+        self.stack.pop();
+        self.mode = InsertionMode::AfterHead;
+    }
+
+    fn in_head_noscript(&mut self, token: Token) {
+        match token {
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::HTML => {
+                self.process_using(InsertionMode::InBody, Token::Tag(tag));
+            }
+            Token::Tag(tag) if tag.end_tag && tag.name == atoms::NOSCRIPT => {
+                self.stack.pop();
+                self.mode = InsertionMode::InHead;
+            }
+            Token::Characters(text) => {
+                let (whitespace, rest) = split_whitespace(&text);
+                self.insert_text(whitespace);
+                if !rest.is_empty() {
+                    self.error(ParseError::UnexpectedCharacter);
+                    self.leave_noscript(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Tag(tag) if !tag.end_tag && matches!(tag.name, atoms::BASEFONT | atoms::BGSOUND | atoms::LINK
+                | atoms::META | atoms::NOFRAMES | atoms::STYLE) => {
+                self.process_using(InsertionMode::InHead, Token::Tag(tag));
+            }
+            Token::Tag(tag) if !tag.end_tag && matches!(tag.name, atoms::HEAD | atoms::NOSCRIPT) => {
+                self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+            }
+            Token::Tag(tag) if tag.end_tag && tag.name != atoms::BR => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            Token::Tag(tag) => {
+                if tag.end_tag {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                } else {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                }
+                self.leave_noscript(Token::Tag(tag));
+            }
+            Token::EndOfFile() => {
+                self.error(ParseError::EofWithOpenElements);
+                self.leave_noscript(Token::EndOfFile());
+            }
+        }
+    }
+
+    fn leave_noscript(&mut self, token: Token) {
+        self.stack.pop();
+        self.mode = InsertionMode::InHead;
+        self.process(token);
+    }
+
+    fn after_head(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                let (whitespace, rest) = split_whitespace(&text);
+                self.insert_text(whitespace);
+                if !rest.is_empty() {
+                    self.insert_body();
+                    self.process(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag => match tag.name {
+                atoms::HTML => self.process_using(InsertionMode::InBody, Token::Tag(tag)),
+                atoms::BODY => {
+                    self.insert_element(tag);
+                    self.frameset_ok = false;
+                    self.mode = InsertionMode::InBody;
+                }
+                atoms::FRAMESET => {
+                    self.insert_element(tag);
+                    self.mode = InsertionMode::InFrameset;
+                }
+                atoms::BASE | atoms::BASEFONT | atoms::BGSOUND | atoms::LINK | atoms::META | atoms::NOFRAMES
+                | atoms::SCRIPT | atoms::STYLE | atoms::TEMPLATE | atoms::TITLE => {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    // Handled as if it was still in the head
+                    let head = self.head.expect("the head element is created before leaving it");
+                    self.stack.push(head);
+                    self.process_using(InsertionMode::InHead, Token::Tag(tag));
+                    self.remove_from_stack(head);
+                }
+                atoms::HEAD => self.error(ParseError::UnexpectedStartTag(tag.name.to_string())),
+                _ => {
+                    self.insert_body();
+                    self.process(Token::Tag(tag));
+                }
+            },
+            Token::Tag(tag) if tag.name == atoms::TEMPLATE => {
+                self.process_using(InsertionMode::InHead, Token::Tag(tag));
+            }
+            Token::Tag(tag) if !matches!(tag.name, atoms::BODY | atoms::HTML | atoms::BR) => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            token => {
+                self.insert_body();
+                self.process(token);
+            }
+        }
+    }
+
+    fn insert_body(&mut self) {
+        let mut body = TagData::new();
+        body.name = atoms::BODY;
+        self.insert_element(body);
+        self.mode = InsertionMode::InBody;
+    }
+
+    fn in_body(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                for _ in text.matches('\0') {
+                    self.error(ParseError::UnexpectedNullCharacter);
+                }
+                let text = if text.contains('\0') { Text::from(text.replace('\0', "")) } else { text };
+                if text.is_empty() {
+                    return;
+                }
+                self.reconstruct_formatting_elements();
+                if !split_whitespace(&text).1.is_empty() {
+                    self.frameset_ok = false;
+                }
+                self.insert_text(&text);
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag => self.in_body_start_tag(tag),
+            Token::Tag(tag) => self.in_body_end_tag(tag),
+            Token::EndOfFile() => {
+                if self.template_modes.is_empty() {
+                    self.check_open_elements();
+                } else {
+                    self.process_using(InsertionMode::InTemplate, Token::EndOfFile());
+                }
+            }
+        }
+    }
+
+    /// Reports elements that are still open at the end of the body or input, other than the
+    /// ones whose end tags can be left out.
+    fn check_open_elements(&mut self) {
+        let unclosed = self.stack.iter().any(|&node| !matches!(self.name(node), atoms::DD | atoms::DT | atoms::LI
+            | atoms::OPTGROUP | atoms::OPTION | atoms::P | atoms::RB | atoms::RP | atoms::RT | atoms::RTC
+            | atoms::TBODY | atoms::TD | atoms::TFOOT | atoms::TH | atoms::THEAD | atoms::TR | atoms::BODY
+            | atoms::HTML));
+        if unclosed {
+            self.error(ParseError::EofWithOpenElements);
+        }
+    }
+
+    fn in_body_start_tag(&mut self, mut tag: TagData) {
+        match tag.name {
+            atoms::HTML => {
+                self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                if !self.has_template() {
+                    let html = self.stack[0];
+                    self.add_missing_attributes(html, &tag);
+                }
+            }
+            atoms::BASE | atoms::BASEFONT | atoms::BGSOUND | atoms::LINK | atoms::META | atoms::NOFRAMES
+            | atoms::SCRIPT | atoms::STYLE | atoms::TEMPLATE | atoms::TITLE => {
+                self.process_using(InsertionMode::InHead, Token::Tag(tag));
+            }
+            atoms::BODY => {
+                self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                if self.stack.len() > 1 && self.name(self.stack[1]) == atoms::BODY && !self.has_template() {
+                    self.frameset_ok = false;
+                    let body = self.stack[1];
+                    self.add_missing_attributes(body, &tag);
+                }
+            }
+            atoms::FRAMESET => {
+                self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                if self.stack.len() > 1 && self.name(self.stack[1]) == atoms::BODY && self.frameset_ok {
+                    let body = self.stack[1];
+                    self.detach(body);
+                    self.stack.truncate(1);
+                    self.insert_element(tag);
+                    self.mode = InsertionMode::InFrameset;
+                }
+            }
+            atoms::ADDRESS | atoms::ARTICLE | atoms::ASIDE | atoms::BLOCKQUOTE | atoms::CENTER | atoms::DETAILS
+            | atoms::DIALOG | atoms::DIR | atoms::DIV | atoms::DL | atoms::FIELDSET | atoms::FIGCAPTION
+            | atoms::FIGURE | atoms::FOOTER | atoms::HEADER | atoms::HGROUP | atoms::MAIN | atoms::MENU
+            | atoms::NAV | atoms::OL | atoms::P | atoms::SEARCH | atoms::SECTION | atoms::SUMMARY | atoms::UL => {
+                self.close_p_in_button_scope();
+                self.insert_element(tag);
+            }
+            atoms::H1 | atoms::H2 | atoms::H3 | atoms::H4 | atoms::H5 | atoms::H6 => {
+                self.close_p_in_button_scope();
+                if is_heading(&self.current_name()) {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    self.stack.pop();
+                }
+                self.insert_element(tag);
+            }
+            atoms::PRE | atoms::LISTING => {
+                self.close_p_in_button_scope();
+                self.insert_element(tag);
+                self.skip_newline = true;
+                self.frameset_ok = false;
+            }
+            atoms::FORM => {
+                let in_template = self.has_template();
+                if self.form.is_some() && !in_template {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                } else {
+                    self.close_p_in_button_scope();
+                    let form = self.insert_element(tag);
+                    if !in_template {
+                        self.form = Some(form);
+                    }
+                }
+            }
+            atoms::LI | atoms::DD | atoms::DT => {
+                self.frameset_ok = false;
+                for index in (0..self.stack.len()).rev() {
+                    let node = self.stack[index];
+                    let name = self.name(node);
+                    let closes = match tag.name {
+                        atoms::LI => name == atoms::LI,
+                        _ => matches!(name, atoms::DD | atoms::DT)
+                    };
+                    if closes {
+                        self.generate_implied_end_tags(Some(&name));
+                        if self.current_name() != name {
+                            self.error(ParseError::EndTagTooEarly(name.to_string()));
+                        }
+                        self.pop_until(&[name]);
+                        break;
+                    }
+                    if self.is_special_element(node) && !matches!(name, atoms::ADDRESS | atoms::DIV | atoms::P) {
+                        break;
+                    }
+                }
+                self.close_p_in_button_scope();
+                self.insert_element(tag);
+            }
+            atoms::PLAINTEXT => {
+                self.close_p_in_button_scope();
+                self.insert_element(tag);
+                self.lexer.set_state(LexerState::PLAINTEXTState);
+            }
+            atoms::BUTTON => {
+                if self.in_scope(&[atoms::BUTTON], Scope::Default) {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    self.generate_implied_end_tags(None);
+                    self.pop_until(&[atoms::BUTTON]);
+                }
+                self.reconstruct_formatting_elements();
+                self.insert_element(tag);
+                self.frameset_ok = false;
+            }
+            atoms::A => {
+                if let Some(a) = self.formatting_element(&atoms::A) {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    self.adoption_agency(&atoms::A);
+                    self.remove_formatting_element(a);
+                    self.remove_from_stack(a);
+                }
+                self.reconstruct_formatting_elements();
+                self.insert_formatting_element(tag);
+            }
+            atoms::NOBR => {
+                self.reconstruct_formatting_elements();
+                if self.in_scope(&[atoms::NOBR], Scope::Default) {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    self.adoption_agency(&atoms::NOBR);
+                    self.reconstruct_formatting_elements();
+                }
+                self.insert_formatting_element(tag);
+            }
+            atoms::B | atoms::BIG | atoms::CODE | atoms::EM | atoms::FONT | atoms::I | atoms::S | atoms::SMALL
+            | atoms::STRIKE | atoms::STRONG | atoms::TT | atoms::U => {
+                self.reconstruct_formatting_elements();
+                self.insert_formatting_element(tag);
+            }
+            atoms::APPLET | atoms::MARQUEE | atoms::OBJECT => {
+                self.reconstruct_formatting_elements();
+                self.insert_element(tag);
+                self.formatting.push(FormattingEntry::Marker);
+                self.frameset_ok = false;
+            }
+            atoms::AREA | atoms::BR | atoms::EMBED | atoms::IMG | atoms::KEYGEN | atoms::WBR => {
+                self.reconstruct_formatting_elements();
+                self.insert_void_element(tag);
+                self.frameset_ok = false;
+            }
+            atoms::INPUT => {
+                self.reconstruct_formatting_elements();
+                let hidden = tag.attribute(atoms::TYPE).is_some_and(|kind| kind.eq_ignore_ascii_case("hidden"));
+                self.insert_void_element(tag);
+                if !hidden {
+                    self.frameset_ok = false;
+                }
+            }
+            atoms::PARAM | atoms::SOURCE | atoms::TRACK => self.insert_void_element(tag),
+            atoms::HR => {
+                self.close_p_in_button_scope();
+                self.insert_void_element(tag);
+                self.frameset_ok = false;
+            }
+            atoms::IMAGE => {
+                self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                tag.name = atoms::IMG;
+                self.process(Token::Tag(tag));
+            }
+            atoms::TEXTAREA => {
+                self.insert_element(tag);
+                self.skip_newline = true;
+                self.lexer.set_state(LexerState::RCDATAState);
+                self.original_mode = self.mode;
+                self.frameset_ok = false;
+                self.mode = InsertionMode::Text;
+            }
+            atoms::XMP => {
+                self.close_p_in_button_scope();
+                self.reconstruct_formatting_elements();
+                self.frameset_ok = false;
+                self.parse_raw_text(tag, LexerState::RAWTEXTState);
+            }
+            atoms::IFRAME => {
+                self.frameset_ok = false;
+                self.parse_raw_text(tag, LexerState::RAWTEXTState);
+            }
+            atoms::NOEMBED => self.parse_raw_text(tag, LexerState::RAWTEXTState),
+            atoms::NOSCRIPT if self.scripting => self.parse_raw_text(tag, LexerState::RAWTEXTState),
+            atoms::TABLE => {
+                if self.quirks_mode() != QuirksMode::Quirks {
+                    self.close_p_in_button_scope();
+                }
+                self.insert_element(tag);
+                self.frameset_ok = false;
+                self.mode = InsertionMode::InTable;
+            }
+            atoms::SELECT => {
+                self.reconstruct_formatting_elements();
+                self.insert_element(tag);
+                self.frameset_ok = false;
+                self.mode = match self.mode {
+                    InsertionMode::InTable | InsertionMode::InCaption | InsertionMode::InTableBody
+                    | InsertionMode::InRow | InsertionMode::InCell => InsertionMode::InSelectInTable,
+                    _ => InsertionMode::InSelect
+                };
+            }
+            atoms::OPTGROUP | atoms::OPTION => {
+                if self.current_name() == atoms::OPTION {
+                    self.stack.pop();
+                }
+                self.reconstruct_formatting_elements();
+                self.insert_element(tag);
+            }
+            atoms::RB | atoms::RTC => {
+                if self.in_scope(&[atoms::RUBY], Scope::Default) {
+                    self.generate_implied_end_tags(None);
+                    if self.current_name() != atoms::RUBY {
+                        self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    }
+                }
+                self.insert_element(tag);
+            }
+            atoms::RP | atoms::RT => {
+                if self.in_scope(&[atoms::RUBY], Scope::Default) {
+                    self.generate_implied_end_tags(Some(&atoms::RTC));
+                    if !matches!(self.current_name(), atoms::RUBY | atoms::RTC) {
+                        self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    }
+                }
+                self.insert_element(tag);
+            }
+            atoms::MATH => {
+                self.reconstruct_formatting_elements();
+                self.insert_foreign_element(tag, Namespace::MathMl);
+            }
+            atoms::SVG => {
+                self.reconstruct_formatting_elements();
+                self.insert_foreign_element(tag, Namespace::Svg);
+            }
+            atoms::CAPTION | atoms::COL | atoms::COLGROUP | atoms::FRAME | atoms::HEAD | atoms::TBODY | atoms::TD
+            | atoms::TFOOT | atoms::TH | atoms::THEAD | atoms::TR => {
+                self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+            }
+            _ => {
+                self.reconstruct_formatting_elements();
+                self.insert_element(tag);
+            }
+        }
+    }
+
+    fn in_body_end_tag(&mut self, tag: TagData) {
+        match tag.name {
+            atoms::BODY | atoms::HTML => {
+                if !self.in_scope(&[atoms::BODY], Scope::Default) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    return;
+                }
+                self.check_open_elements();
+                self.mode = InsertionMode::AfterBody;
+                if tag.name == atoms::HTML {
+                    self.process(Token::Tag(tag));
+                }
+            }
+            atoms::ADDRESS | atoms::ARTICLE | atoms::ASIDE | atoms::BLOCKQUOTE | atoms::BUTTON | atoms::CENTER
+            | atoms::DETAILS | atoms::DIALOG | atoms::DIR | atoms::DIV | atoms::DL | atoms::FIELDSET
+            | atoms::FIGCAPTION | atoms::FIGURE | atoms::FOOTER | atoms::HEADER | atoms::HGROUP | atoms::LISTING
+            | atoms::MAIN | atoms::MENU | atoms::NAV | atoms::OL | atoms::PRE | atoms::SEARCH | atoms::SECTION
+            | atoms::SUMMARY | atoms::UL => {
+                if !self.in_scope(slice::from_ref(&tag.name), Scope::Default) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    return;
+                }
+                self.generate_implied_end_tags(None);
+                self.close_element(&tag.name);
+            }
+            atoms::APPLET | atoms::MARQUEE | atoms::OBJECT => {
+                if !self.in_scope(slice::from_ref(&tag.name), Scope::Default) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    return;
+                }
+                self.generate_implied_end_tags(None);
+                self.close_element(&tag.name);
+                self.clear_formatting_to_marker();
+            }
+            atoms::A | atoms::B | atoms::BIG | atoms::CODE | atoms::EM | atoms::FONT | atoms::I | atoms::NOBR
+            | atoms::S | atoms::SMALL | atoms::STRIKE | atoms::STRONG | atoms::TT | atoms::U => {
+                if !self.adoption_agency(&tag.name) {
+                    self.any_other_end_tag(&tag.name);
+                }
+            }
+            atoms::TEMPLATE => self.process_using(InsertionMode::InHead, Token::Tag(tag)),
+            atoms::FORM if self.has_template() => {
+                if !self.in_scope(&[atoms::FORM], Scope::Default) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    return;
+                }
+                self.generate_implied_end_tags(None);
+                self.close_element(&atoms::FORM);
+            }
+            atoms::FORM => {
+                let form = self.form.take();
+                match form {
+                    Some(form) if self.node_in_scope(form, Scope::Default) => {
+                        self.generate_implied_end_tags(None);
+                        if self.current() != form {
+                            self.error(ParseError::EndTagTooEarly(tag.name.to_string()));
+                        }
+                        self.remove_from_stack(form);
+                    }
+                    _ => self.error(ParseError::UnexpectedEndTag(tag.name.to_string()))
+                }
+            }
+            atoms::P => {
+                if !self.in_scope(&[atoms::P], Scope::Button) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    let mut p = TagData::new();
+                    p.name = atoms::P;
+                    self.insert_element(p);
+                }
+                self.close_p();
+            }
+            atoms::LI | atoms::DD | atoms::DT => {
+                let scope = if tag.name == atoms::LI { Scope::ListItem } else { Scope::Default };
+                if !self.in_scope(slice::from_ref(&tag.name), scope) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    return;
+                }
+                self.generate_implied_end_tags(Some(&tag.name));
+                self.close_element(&tag.name);
+            }
+            atoms::H1 | atoms::H2 | atoms::H3 | atoms::H4 | atoms::H5 | atoms::H6 => {
+                const HEADINGS: [Atom; 6] = [atoms::H1, atoms::H2, atoms::H3, atoms::H4, atoms::H5, atoms::H6];
+                if !self.in_scope(&HEADINGS, Scope::Default) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    return;
+                }
+                self.generate_implied_end_tags(None);
+                if self.current_name() != tag.name {
+                    self.error(ParseError::EndTagTooEarly(tag.name.to_string()));
+                }
+                self.pop_until(&HEADINGS);
+            }
+            atoms::BR => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                let mut br = TagData::new();
+                br.name = atoms::BR;
+                self.process(Token::Tag(br));
+            }
+            _ => self.any_other_end_tag(&tag.name)
+        }
+    }
+
+    /// Closes the innermost open element with this name, unless a special element is in the way.
+    fn any_other_end_tag(&mut self, name: &Atom) {
+        for index in (0..self.stack.len()).rev() {
+            let node = self.stack[index];
+            if self.name(node) == *name {
+                self.generate_implied_end_tags(Some(name));
+                if self.current() != node {
+                    self.error(ParseError::EndTagTooEarly(name.to_string()));
+                }
+                self.stack.truncate(index);
+                return;
+            }
+            if self.is_special_element(node) {
+                self.error(ParseError::UnexpectedEndTag(name.to_string()));
+                return;
+            }
+        }
+    }
+
+    fn text(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => self.insert_text(&text),
+            Token::EndOfFile() => {
+                self.error(ParseError::EofWithOpenElements);
+                self.stack.pop();
+                self.mode = self.original_mode;
+                self.process(Token::EndOfFile());
+            }
+            Token::Tag(tag) if tag.end_tag => {
+                let element = self.current();
+                self.stack.pop();
+                self.mode = self.original_mode;
+                if tag.name == atoms::SCRIPT {
+                    self.run_script(element);
+                }
+            }
+            // The tokenizer only produces text and the end tag in the raw text states
+            _ => {}
+        }
+    }
+
+    fn in_select(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                for _ in text.matches('\0') {
+                    self.error(ParseError::UnexpectedNullCharacter);
+                }
+                self.insert_text(&text.replace('\0', ""));
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag => match tag.name {
+                atoms::HTML => self.process_using(InsertionMode::InBody, Token::Tag(tag)),
+                atoms::OPTION => {
+                    if self.current_name() == atoms::OPTION {
+                        self.stack.pop();
+                    }
+                    self.insert_element(tag);
+                }
+                atoms::OPTGROUP | atoms::HR => {
+                    if self.current_name() == atoms::OPTION {
+                        self.stack.pop();
+                    }
+                    if self.current_name() == atoms::OPTGROUP {
+                        self.stack.pop();
+                    }
+                    if tag.name == atoms::HR {
+                        self.insert_void_element(tag);
+                    } else {
+                        self.insert_element(tag);
+                    }
+                }
+                atoms::SELECT => {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    if self.in_scope(&[atoms::SELECT], Scope::Select) {
+                        self.pop_until(&[atoms::SELECT]);
+                        self.reset_insertion_mode();
+                    }
+                }
+                atoms::INPUT | atoms::KEYGEN | atoms::TEXTAREA => {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    if self.in_scope(&[atoms::SELECT], Scope::Select) {
+                        self.pop_until(&[atoms::SELECT]);
+                        self.reset_insertion_mode();
+                        self.process(Token::Tag(tag));
+                    }
+                }
+                atoms::SCRIPT | atoms::TEMPLATE => self.process_using(InsertionMode::InHead, Token::Tag(tag)),
+                _ => self.error(ParseError::UnexpectedStartTag(tag.name.to_string()))
+            },
+            Token::Tag(tag) => match tag.name {
+                atoms::TEMPLATE => self.process_using(InsertionMode::InHead, Token::Tag(tag)),
+                atoms::OPTGROUP => {
+                    let length = self.stack.len();
+                    if self.current_name() == atoms::OPTION && length > 1
+                        && self.name(self.stack[length - 2]) == atoms::OPTGROUP {
+                        self.stack.pop();
+                    }
+                    if self.current_name() == atoms::OPTGROUP {
+                        self.stack.pop();
+                    } else {
+                        self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    }
+                }
+                atoms::OPTION => {
+                    if self.current_name() == atoms::OPTION {
+                        self.stack.pop();
+                    } else {
+                        self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    }
+                }
+                atoms::SELECT => {
+                    if self.in_scope(&[atoms::SELECT], Scope::Select) {
+                        self.pop_until(&[atoms::SELECT]);
+                        self.reset_insertion_mode();
+                    } else {
+                        self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    }
+                }
+                _ => self.error(ParseError::UnexpectedEndTag(tag.name.to_string()))
+            },
+            Token::EndOfFile() => self.process_using(InsertionMode::InBody, Token::EndOfFile()),
+        }
+    }
+
+    fn in_table(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) if matches!(self.current_name(), atoms::TABLE | atoms::TBODY
+                | atoms::TEMPLATE | atoms::TFOOT | atoms::THEAD | atoms::TR) => {
+                self.pending_table_text.clear();
+                self.original_mode = self.mode;
+                self.mode = InsertionMode::InTableText;
+                self.process(Token::Characters(text));
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag => match tag.name {
+                atoms::CAPTION => {
+                    self.clear_stack_to_context(&[atoms::TABLE]);
+                    self.formatting.push(FormattingEntry::Marker);
+                    self.insert_element(tag);
+                    self.mode = InsertionMode::InCaption;
+                }
+                atoms::COLGROUP => {
+                    self.clear_stack_to_context(&[atoms::TABLE]);
+                    self.insert_element(tag);
+                    self.mode = InsertionMode::InColumnGroup;
+                }
+                atoms::COL => {
+                    self.clear_stack_to_context(&[atoms::TABLE]);
+                    self.insert_implied(atoms::COLGROUP);
+                    self.mode = InsertionMode::InColumnGroup;
+                    self.process(Token::Tag(tag));
+                }
+                atoms::TBODY | atoms::TFOOT | atoms::THEAD => {
+                    self.clear_stack_to_context(&[atoms::TABLE]);
+                    self.insert_element(tag);
+                    self.mode = InsertionMode::InTableBody;
+                }
+                atoms::TD | atoms::TH | atoms::TR => {
+                    self.clear_stack_to_context(&[atoms::TABLE]);
+                    self.insert_implied(atoms::TBODY);
+                    self.mode = InsertionMode::InTableBody;
+                    self.process(Token::Tag(tag));
+                }
+                atoms::TABLE => {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    if self.in_scope(&[atoms::TABLE], Scope::Table) {
+                        self.pop_until(&[atoms::TABLE]);
+                        self.reset_insertion_mode();
+                        self.process(Token::Tag(tag));
+                    }
+                }
+                atoms::STYLE | atoms::SCRIPT | atoms::TEMPLATE => {
+                    self.process_using(InsertionMode::InHead, Token::Tag(tag));
+                }
+                atoms::INPUT if tag.attribute(atoms::TYPE).is_some_and(|kind| kind.eq_ignore_ascii_case("hidden")) => {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    self.insert_void_element(tag);
+                }
+                atoms::FORM => {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    if self.form.is_none() && !self.has_template() {
+                        self.form = Some(self.insert_element(tag));
+                        self.stack.pop();
+                    }
+                }
+                _ => self.foster_parent(Token::Tag(tag))
+            },
+            Token::Tag(tag) => match tag.name {
+                atoms::TABLE => {
+                    if self.in_scope(&[atoms::TABLE], Scope::Table) {
+                        self.pop_until(&[atoms::TABLE]);
+                        self.reset_insertion_mode();
+                    } else {
+                        self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    }
+                }
+                atoms::BODY | atoms::CAPTION | atoms::COL | atoms::COLGROUP | atoms::HTML | atoms::TBODY
+                | atoms::TD | atoms::TFOOT | atoms::TH | atoms::THEAD | atoms::TR => {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                }
+                atoms::TEMPLATE => self.process_using(InsertionMode::InHead, Token::Tag(tag)),
+                _ => self.foster_parent(Token::Tag(tag))
+            },
+            Token::EndOfFile() => self.process_using(InsertionMode::InBody, Token::EndOfFile()),
+            token => self.foster_parent(token)
+        }
+    }
+
+    /// Content that isn't allowed in a table is handled as in the body, but inserted in front
+    /// of the table.
+    fn foster_parent(&mut self, token: Token) {
+        match &token {
+            Token::Tag(tag) if tag.end_tag => self.error(ParseError::UnexpectedEndTag(tag.name.to_string())),
+            Token::Tag(tag) => self.error(ParseError::UnexpectedStartTag(tag.name.to_string())),
+            _ => {}
+        }
+        self.foster_parenting = true;
+        self.process_using(InsertionMode::InBody, token);
+        self.foster_parenting = false;
+    }
+
+    /// Collects the text in a table, which only stays there if it's all whitespace.
+    fn in_table_text(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                for _ in text.matches('\0') {
+                    self.error(ParseError::UnexpectedNullCharacter);
+                }
+                self.pending_table_text.push_str(&text.replace('\0', ""));
+            }
+            token => {
+                let text = mem::take(&mut self.pending_table_text);
+                if text.chars().all(is_whitespace) {
+                    self.insert_text(&text);
+                } else {
+                    self.error(ParseError::UnexpectedCharacterInTable);
+                    self.foster_parenting = true;
+                    self.process_using(InsertionMode::InBody, Token::Characters(Text::from(text)));
+                    self.foster_parenting = false;
+                }
+                self.mode = self.original_mode;
+                self.process(token);
+            }
+        }
+    }
+
+    fn in_caption(&mut self, token: Token) {
+        match token {
+            Token::Tag(tag) if tag.end_tag && tag.name == atoms::CAPTION => {
+                if self.close_caption() {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                }
+            }
+            Token::Tag(tag) if (!tag.end_tag && matches!(tag.name, atoms::CAPTION | atoms::COL | atoms::COLGROUP
+                | atoms::TBODY | atoms::TD | atoms::TFOOT | atoms::TH | atoms::THEAD | atoms::TR))
+                || (tag.end_tag && tag.name == atoms::TABLE) => {
+                if self.close_caption() {
+                    if tag.end_tag {
+                        self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    } else {
+                        self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                    }
+                } else {
+                    self.process(Token::Tag(tag));
+                }
+            }
+            Token::Tag(tag) if tag.end_tag && matches!(tag.name, atoms::BODY | atoms::COL | atoms::COLGROUP
+                | atoms::HTML | atoms::TBODY | atoms::TD | atoms::TFOOT | atoms::TH | atoms::THEAD | atoms::TR) => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            token => self.process_using(InsertionMode::InBody, token)
+        }
+    }
+
+    /// Closes the caption and goes back to the table. Returns true if there was no caption
+    /// to close, the token that would have closed it is then ignored.
+    fn close_caption(&mut self) -> bool {
+        if !self.in_scope(&[atoms::CAPTION], Scope::Table) {
+            return true;
+        }
+        self.generate_implied_end_tags(None);
+        self.close_element(&atoms::CAPTION);
+        self.clear_formatting_to_marker();
+        self.mode = InsertionMode::InTable;
+        false
+    }
+
+    fn in_column_group(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                let (whitespace, rest) = split_whitespace(&text);
+                self.insert_text(whitespace);
+                if !rest.is_empty() {
+                    self.leave_column_group(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::HTML => {
+                self.process_using(InsertionMode::InBody, Token::Tag(tag));
+            }
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::COL => self.insert_void_element(tag),
+            Token::Tag(tag) if tag.name == atoms::TEMPLATE => {
+                self.process_using(InsertionMode::InHead, Token::Tag(tag));
+            }
+            Token::Tag(tag) if tag.end_tag && tag.name == atoms::COLGROUP => {
+                if self.current_name() == atoms::COLGROUP {
+                    self.stack.pop();
+                    self.mode = InsertionMode::InTable;
+                } else {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                }
+            }
+            Token::Tag(tag) if tag.end_tag && tag.name == atoms::COL => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            Token::EndOfFile() => self.process_using(InsertionMode::InBody, Token::EndOfFile()),
+            token => self.leave_column_group(token)
+        }
+    }
+
+    fn leave_column_group(&mut self, token: Token) {
+        if self.current_name() != atoms::COLGROUP {
+            self.error(match token {
+                Token::Tag(tag) if tag.end_tag => ParseError::UnexpectedEndTag(tag.name.to_string()),
+                Token::Tag(tag) => ParseError::UnexpectedStartTag(tag.name.to_string()),
+                _ => ParseError::UnexpectedCharacter
+            });
+            return;
+        }
+        self.stack.pop();
+        self.mode = InsertionMode::InTable;
+        self.process(token);
+    }
+
+    fn in_table_body(&mut self, token: Token) {
+        const SECTIONS: [Atom; 3] = [atoms::TBODY, atoms::TFOOT, atoms::THEAD];
+        match token {
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::TR => {
+                self.clear_stack_to_context(&SECTIONS);
+                self.insert_element(tag);
+                self.mode = InsertionMode::InRow;
+            }
+            Token::Tag(tag) if !tag.end_tag && matches!(tag.name, atoms::TD | atoms::TH) => {
+                self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                self.clear_stack_to_context(&SECTIONS);
+                self.insert_implied(atoms::TR);
+                self.mode = InsertionMode::InRow;
+                self.process(Token::Tag(tag));
+            }
+            Token::Tag(tag) if tag.end_tag && SECTIONS.contains(&tag.name) => {
+                if self.in_scope(slice::from_ref(&tag.name), Scope::Table) {
+                    self.clear_stack_to_context(&SECTIONS);
+                    self.stack.pop();
+                    self.mode = InsertionMode::InTable;
+                } else {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                }
+            }
+            Token::Tag(tag) if (!tag.end_tag && matches!(tag.name, atoms::CAPTION | atoms::COL | atoms::COLGROUP
+                | atoms::TBODY | atoms::TFOOT | atoms::THEAD)) || (tag.end_tag && tag.name == atoms::TABLE) => {
+                if self.in_scope(&SECTIONS, Scope::Table) {
+                    self.clear_stack_to_context(&SECTIONS);
+                    self.stack.pop();
+                    self.mode = InsertionMode::InTable;
+                    self.process(Token::Tag(tag));
+                } else if tag.end_tag {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                } else {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                }
+            }
+            Token::Tag(tag) if tag.end_tag && matches!(tag.name, atoms::BODY | atoms::CAPTION | atoms::COL
+                | atoms::COLGROUP | atoms::HTML | atoms::TD | atoms::TH | atoms::TR) => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            token => self.process_using(InsertionMode::InTable, token)
+        }
+    }
+
+    fn in_row(&mut self, token: Token) {
+        match token {
+            Token::Tag(tag) if !tag.end_tag && matches!(tag.name, atoms::TD | atoms::TH) => {
+                self.clear_stack_to_context(&[atoms::TR]);
+                self.insert_element(tag);
+                self.mode = InsertionMode::InCell;
+                self.formatting.push(FormattingEntry::Marker);
+            }
+            Token::Tag(tag) if tag.end_tag && tag.name == atoms::TR => {
+                if !self.close_row() {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                }
+            }
+            Token::Tag(tag) if (!tag.end_tag && matches!(tag.name, atoms::CAPTION | atoms::COL | atoms::COLGROUP
+                | atoms::TBODY | atoms::TFOOT | atoms::THEAD | atoms::TR)) || (tag.end_tag && tag.name == atoms::TABLE) => {
+                if self.close_row() {
+                    self.process(Token::Tag(tag));
+                } else if tag.end_tag {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                } else {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                }
+            }
+            Token::Tag(tag) if tag.end_tag && matches!(tag.name, atoms::TBODY | atoms::TFOOT | atoms::THEAD) => {
+                if !self.in_scope(slice::from_ref(&tag.name), Scope::Table) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                } else if self.close_row() {
+                    self.process(Token::Tag(tag));
+                }
+            }
+            Token::Tag(tag) if tag.end_tag && matches!(tag.name, atoms::BODY | atoms::CAPTION | atoms::COL
+                | atoms::COLGROUP | atoms::HTML | atoms::TD | atoms::TH) => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            token => self.process_using(InsertionMode::InTable, token)
+        }
+    }
+
+    /// Closes the current row and goes back to its table section, returns false if there
+    /// was no row to close.
+    fn close_row(&mut self) -> bool {
+        if !self.in_scope(&[atoms::TR], Scope::Table) {
+            return false;
+        }
+        self.clear_stack_to_context(&[atoms::TR]);
+        self.stack.pop();
+        self.mode = InsertionMode::InTableBody;
+        true
+    }
+
+    fn in_cell(&mut self, token: Token) {
+        match token {
+            Token::Tag(tag) if tag.end_tag && matches!(tag.name, atoms::TD | atoms::TH) => {
+                if !self.in_scope(slice::from_ref(&tag.name), Scope::Table) {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    return;
+                }
+                self.generate_implied_end_tags(None);
+                self.close_element(&tag.name);
+                self.clear_formatting_to_marker();
+                self.mode = InsertionMode::InRow;
+            }
+            Token::Tag(tag) if !tag.end_tag && matches!(tag.name, atoms::CAPTION | atoms::COL | atoms::COLGROUP
+                | atoms::TBODY | atoms::TD | atoms::TFOOT | atoms::TH | atoms::THEAD | atoms::TR) => {
+                if self.in_scope(&[atoms::TD, atoms::TH], Scope::Table) {
+                    self.close_cell();
+                    self.process(Token::Tag(tag));
+                } else {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                }
+            }
+            Token::Tag(tag) if tag.end_tag && matches!(tag.name, atoms::BODY | atoms::CAPTION | atoms::COL
+                | atoms::COLGROUP | atoms::HTML) => {
+                self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+            }
+            Token::Tag(tag) if tag.end_tag && matches!(tag.name, atoms::TABLE | atoms::TBODY | atoms::TFOOT
+                | atoms::THEAD | atoms::TR) => {
+                if self.in_scope(slice::from_ref(&tag.name), Scope::Table) {
+                    self.close_cell();
+                    self.process(Token::Tag(tag));
+                } else {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                }
+            }
+            token => self.process_using(InsertionMode::InBody, token)
+        }
+    }
+
+    fn close_cell(&mut self) {
+        self.generate_implied_end_tags(None);
+        if !matches!(self.current_name(), atoms::TD | atoms::TH) {
+            self.error(ParseError::EndTagTooEarly(self.current_name().to_string()));
+        }
+        self.pop_until(&[atoms::TD, atoms::TH]);
+        self.clear_formatting_to_marker();
+        self.mode = InsertionMode::InRow;
+    }
+
+    fn in_select_in_table(&mut self, token: Token) {
+        match token {
+            Token::Tag(tag) if matches!(tag.name, atoms::CAPTION | atoms::TABLE | atoms::TBODY | atoms::TFOOT
+                | atoms::THEAD | atoms::TR | atoms::TD | atoms::TH) => {
+                if tag.end_tag {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    if !self.in_scope(slice::from_ref(&tag.name), Scope::Table) {
+                        return;
+                    }
+                } else {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                }
+                self.pop_until(&[atoms::SELECT]);
+                self.reset_insertion_mode();
+                self.process(Token::Tag(tag));
+            }
+            token => self.process_using(InsertionMode::InSelect, token)
+        }
+    }
+
+    /// Pops elements until the current node is one of `names`, or `html` or `template`.
+    fn clear_stack_to_context(&mut self, names: &[Atom]) {
+        while !names.contains(&self.current_name()) && !matches!(self.current_name(), atoms::HTML | atoms::TEMPLATE) {
+            self.stack.pop();
+        }
+    }
+
+    /// Inserts an element whose start tag was left out, like `tbody`.
+    fn insert_implied(&mut self, name: Atom) {
+        let mut tag = TagData::new();
+        tag.name = name;
+        self.insert_element(tag);
+    }
+
+    fn in_template(&mut self, token: Token) {
+        match token {
+            Token::Characters(_) | Token::Comment(_) | Token::Doctype(_) => {
+                self.process_using(InsertionMode::InBody, token);
+            }
+            Token::Tag(tag) if !tag.end_tag => match tag.name {
+                atoms::BASE | atoms::BASEFONT | atoms::BGSOUND | atoms::LINK | atoms::META | atoms::NOFRAMES
+                | atoms::SCRIPT | atoms::STYLE | atoms::TEMPLATE | atoms::TITLE => {
+                    self.process_using(InsertionMode::InHead, Token::Tag(tag));
+                }
+                atoms::CAPTION | atoms::COLGROUP | atoms::TBODY | atoms::TFOOT | atoms::THEAD => {
+                    self.switch_template_mode(InsertionMode::InTable, Token::Tag(tag));
+                }
+                atoms::COL => self.switch_template_mode(InsertionMode::InColumnGroup, Token::Tag(tag)),
+                atoms::TR => self.switch_template_mode(InsertionMode::InTableBody, Token::Tag(tag)),
+                atoms::TD | atoms::TH => self.switch_template_mode(InsertionMode::InRow, Token::Tag(tag)),
+                _ => self.switch_template_mode(InsertionMode::InBody, Token::Tag(tag))
+            },
+            Token::Tag(tag) if tag.name == atoms::TEMPLATE => {
+                self.process_using(InsertionMode::InHead, Token::Tag(tag));
+            }
+            Token::Tag(tag) => self.error(ParseError::UnexpectedEndTag(tag.name.to_string())),
+            Token::EndOfFile() => {
+                if !self.has_template() {
+                    return;
+                }
+                self.error(ParseError::EofWithOpenElements);
+                self.close_template();
+                self.process(Token::EndOfFile());
+            }
+        }
+    }
+
+    /// Replaces the current template insertion mode once the first element in the template
+    /// tells what kind of content it holds.
+    fn switch_template_mode(&mut self, mode: InsertionMode, token: Token) {
+        self.template_modes.pop();
+        self.template_modes.push(mode);
+        self.mode = mode;
+        self.process(token);
+    }
+
+    /// Pops the elements up to the innermost `template` and leaves its insertion mode.
+    fn close_template(&mut self) {
+        self.pop_until(&[atoms::TEMPLATE]);
+        self.clear_formatting_to_marker();
+        self.template_modes.pop();
+        self.reset_insertion_mode();
+    }
+
+    fn after_body(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => {
+                let (whitespace, rest) = split_whitespace(&text);
+                if !whitespace.is_empty() {
+                    self.process_using(InsertionMode::InBody, Token::Characters(Text::from(whitespace)));
+                }
+                if !rest.is_empty() {
+                    self.error(ParseError::UnexpectedCharacter);
+                    self.mode = InsertionMode::InBody;
+                    self.process(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Comment(text) => {
+                let html = self.stack[0];
+                self.insert_comment_in(text, html);
+            }
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::HTML => {
+                self.process_using(InsertionMode::InBody, Token::Tag(tag));
+            }
+            Token::Tag(tag) if tag.end_tag && tag.name == atoms::HTML => {
+                self.mode = InsertionMode::AfterAfterBody;
+            }
+            Token::EndOfFile() => {}
+            Token::Tag(tag) => {
+                if tag.end_tag {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                } else {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                }
+                self.mode = InsertionMode::InBody;
+                self.process(Token::Tag(tag));
+            }
+        }
+    }
+
+    fn in_frameset(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => self.frameset_whitespace(&text),
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag => match tag.name {
+                atoms::HTML => self.process_using(InsertionMode::InBody, Token::Tag(tag)),
+                atoms::FRAMESET => {
+                    self.insert_element(tag);
+                }
+                atoms::FRAME => self.insert_void_element(tag),
+                atoms::NOFRAMES => self.process_using(InsertionMode::InHead, Token::Tag(tag)),
+                _ => self.error(ParseError::UnexpectedStartTag(tag.name.to_string()))
+            },
+            Token::Tag(tag) if tag.name == atoms::FRAMESET => {
+                if self.current_name() == atoms::HTML {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                    return;
+                }
+                self.stack.pop();
+                if self.context.is_none() && self.current_name() != atoms::FRAMESET {
+                    self.mode = InsertionMode::AfterFrameset;
+                }
+            }
+            Token::Tag(tag) => self.error(ParseError::UnexpectedEndTag(tag.name.to_string())),
+            Token::EndOfFile() => {
+                if self.current_name() != atoms::HTML {
+                    self.error(ParseError::EofWithOpenElements);
+                }
+            }
+        }
+    }
+
+    /// Framesets only take whitespace text, anything else is dropped.
+    fn frameset_whitespace(&mut self, text: &str) {
+        let whitespace: String = text.chars().filter(|&char| is_whitespace(char)).collect();
+        for _ in text.chars().filter(|&char| !is_whitespace(char)) {
+            self.error(ParseError::UnexpectedCharacter);
+        }
+        self.insert_text(&whitespace);
+    }
+
+    fn after_frameset(&mut self, token: Token) {
+        match token {
+            Token::Characters(text) => self.frameset_whitespace(&text),
+            Token::Comment(text) => self.insert_comment(text),
+            Token::Doctype(_) => self.error(ParseError::UnexpectedDoctype),
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::HTML => {
+                self.process_using(InsertionMode::InBody, Token::Tag(tag));
+            }
+            Token::Tag(tag) if tag.end_tag && tag.name == atoms::HTML => {
+                self.mode = InsertionMode::AfterAfterFrameset;
+            }
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::NOFRAMES => {
+                self.process_using(InsertionMode::InHead, Token::Tag(tag));
+            }
+            Token::Tag(tag) if tag.end_tag => self.error(ParseError::UnexpectedEndTag(tag.name.to_string())),
+            Token::Tag(tag) => self.error(ParseError::UnexpectedStartTag(tag.name.to_string())),
+            Token::EndOfFile() => {}
+        }
+    }
+
+    fn after_after_body(&mut self, token: Token) {
+        match token {
+            Token::Comment(text) => self.insert_comment_in(text, self.dom.document()),
+            Token::Characters(text) => {
+                let (whitespace, rest) = split_whitespace(&text);
+                if !whitespace.is_empty() {
+                    self.process_using(InsertionMode::InBody, Token::Characters(Text::from(whitespace)));
+                }
+                if !rest.is_empty() {
+                    self.error(ParseError::UnexpectedCharacter);
+                    self.mode = InsertionMode::InBody;
+                    self.process(Token::Characters(Text::from(rest)));
+                }
+            }
+            Token::Doctype(_) => self.process_using(InsertionMode::InBody, token),
+            Token::Tag(tag) if !tag.end_tag && tag.name == atoms::HTML => {
+                self.process_using(InsertionMode::InBody, Token::Tag(tag));
+            }
+            Token::EndOfFile() => {}
+            Token::Tag(tag) => {
+                if tag.end_tag {
+                    self.error(ParseError::UnexpectedEndTag(tag.name.to_string()));
+                } else {
+                    self.error(ParseError::UnexpectedStartTag(tag.name.to_string()));
+                }
+                self.mode = InsertionMode::InBody;
+                self.process(Token::Tag(tag));
+            }
+        }
+    }
+
+    fn after_after_frameset(&mut self, token: Token) {
+        match token {
+            Token::Comment(text) => self.insert_comment_in(text, self.dom.document()),
+            Token::Characters(text) => {
+                let (whitespace, rest) = split_whitespace(&text);
+                if !whitespace.is_empty() {
+                    self.process_using(InsertionMode::InBody, Token::Characters(Text::from(whitespace)));
+                }
+                for _ in rest.chars().filter(|&char| !is_whitespace(char)) {
+                    self.error(ParseError::UnexpectedCharacter);
+                }
+            }
+            Token::Doctype(_) => self.process_using(InsertionMode::InBody, token),
+            Token::Tag(tag) if !tag.end_tag && matches!(tag.name, atoms::HTML | atoms::NOFRAMES) => {
+                let mode = if tag.name == atoms::HTML { InsertionMode::InBody } else { InsertionMode::InHead };
+                self.process_using(mode, Token::Tag(tag));
+            }
+            Token::EndOfFile() => {}
+            Token::Tag(tag) if tag.end_tag => self.error(ParseError::UnexpectedEndTag(tag.name.to_string())),
+            Token::Tag(tag) => self.error(ParseError::UnexpectedStartTag(tag.name.to_string())),
+        }
+    }
+
+    /// The generic raw text and RCDATA element parsing algorithms, the element's contents are
+    /// tokenized as text until its end tag.
+    fn parse_raw_text(&mut self, tag: TagData, state: LexerState) {
+        self.insert_element(tag);
+        self.lexer.set_state(state);
+        self.original_mode = self.mode;
+        self.mode = InsertionMode::Text;
+    }
+
+    /// Picks the insertion mode from the elements on the stack, after it was changed in a way
+    /// the current mode can't account for.
+    fn reset_insertion_mode(&mut self) {
+        for index in (0..self.stack.len()).rev() {
+            let last = index == 0;
+            let node = match self.context {
+                Some(context) if last => context,
+                _ => self.stack[index]
+            };
+            self.mode = match self.name(node) {
+                atoms::SELECT => {
+                    let in_table = self.stack[..index].iter().rev()
+                        .map(|&node| self.name(node))
+                        .take_while(|name| *name != atoms::TEMPLATE)
+                        .any(|name| name == atoms::TABLE);
+                    if in_table && !last { InsertionMode::InSelectInTable } else { InsertionMode::InSelect }
+                }
+                atoms::TD | atoms::TH if !last => InsertionMode::InCell,
+                atoms::TR => InsertionMode::InRow,
+                atoms::TBODY | atoms::THEAD | atoms::TFOOT => InsertionMode::InTableBody,
+                atoms::CAPTION => InsertionMode::InCaption,
+                atoms::COLGROUP => InsertionMode::InColumnGroup,
+                atoms::TABLE => InsertionMode::InTable,
+                atoms::TEMPLATE => *self.template_modes.last().expect("open templates have an insertion mode"),
+                atoms::HEAD if !last => InsertionMode::InHead,
+                atoms::BODY => InsertionMode::InBody,
+                atoms::FRAMESET => InsertionMode::InFrameset,
+                atoms::HTML if self.head.is_none() => InsertionMode::BeforeHead,
+                atoms::HTML => InsertionMode::AfterHead,
+                _ if last => InsertionMode::InBody,
+                _ => continue
+            };
+            return;
+        }
+    }
+
+    /// Inserts a formatting element and adds it to the list of active formatting elements.
+    /// Per the Noah's Ark clause, only the last three identical ones since the last marker are kept.
+    fn insert_formatting_element(&mut self, tag: TagData) {
+        let mut identical = Vec::new();
+        for (index, entry) in self.formatting.iter().enumerate().rev() {
+            match entry {
+                FormattingEntry::Marker => break,
+                FormattingEntry::Element(_, other) if other.name == tag.name && same_attributes(other, &tag) => {
+                    identical.push(index);
+                }
+                FormattingEntry::Element(..) => {}
+            }
+        }
+        if identical.len() >= 3 {
+            self.formatting.remove(identical[identical.len() - 1]);
+        }
+        let element = self.insert_element(tag.clone());
+        self.formatting.push(FormattingEntry::Element(element, tag));
+    }
+
+    /// The last formatting element with this name after the last marker.
+    fn formatting_element(&self, name: &Atom) -> Option<NodeId> {
+        for entry in self.formatting.iter().rev() {
+            match entry {
+                FormattingEntry::Marker => return None,
+                FormattingEntry::Element(node, tag) if tag.name == *name => return Some(*node),
+                FormattingEntry::Element(..) => {}
+            }
+        }
+        None
+    }
+
+    fn formatting_position(&self, node: NodeId) -> Option<usize> {
+        self.formatting.iter().position(|entry| matches!(entry, FormattingEntry::Element(element, _) if *element == node))
+    }
+
+    fn remove_formatting_element(&mut self, node: NodeId) {
+        if let Some(index) = self.formatting_position(node) {
+            self.formatting.remove(index);
+        }
+    }
+
+    fn clear_formatting_to_marker(&mut self) {
+        while let Some(entry) = self.formatting.pop() {
+            if matches!(entry, FormattingEntry::Marker) {
+                return;
+            }
+        }
+    }
+
+    /// Reopens the formatting elements that were closed implicitly, like the `b` in
+    /// `<p><b>x<p>y`, so the text after them is still formatted.
+    fn reconstruct_formatting_elements(&mut self) {
+        let is_open = |parser: &Parser, entry: &FormattingEntry| match entry {
+            FormattingEntry::Marker => true,
+            FormattingEntry::Element(node, _) => parser.stack.contains(node)
+        };
+        let mut index = match self.formatting.last() {
+            Some(entry) if !is_open(self, entry) => self.formatting.len() - 1,
+            _ => return
+        };
+        while index > 0 && !is_open(self, &self.formatting[index - 1]) {
+            index -= 1;
+        }
+        for index in index..self.formatting.len() {
+            if let FormattingEntry::Element(_, tag) = &self.formatting[index] {
+                let tag = tag.clone();
+                let element = self.insert_element(tag.clone());
+                self.formatting[index] = FormattingEntry::Element(element, tag);
+            }
+        }
+    }
+
+    /// The adoption agency algorithm, which handles end tags of formatting elements that
+    /// aren't properly nested by moving nodes around until they are. Returns false if there
+    /// was no formatting element to close, the end tag is then handled like any other.
+    fn adoption_agency(&mut self, subject: &Atom) -> bool {
+        let current = self.current();
+        if self.name(current) == *subject && self.formatting_position(current).is_none() {
+            self.stack.pop();
+            return true;
+        }
+        for _ in 0..8 {
+            let formatting_element = match self.formatting_element(subject) {
+                Some(node) => node,
+                None => return false
+            };
+            let tag = match self.formatting_position(formatting_element).map(|index| &self.formatting[index]) {
+                Some(FormattingEntry::Element(_, tag)) => tag.clone(),
+                _ => unreachable!("the formatting element is in the list")
+            };
+            let stack_index = match self.stack.iter().position(|&node| node == formatting_element) {
+                Some(index) => index,
+                None => {
+                    self.error(ParseError::UnexpectedEndTag(subject.to_string()));
+                    self.remove_formatting_element(formatting_element);
+                    return true;
+                }
+            };
+            if !self.node_in_scope(formatting_element, Scope::Default) {
+                self.error(ParseError::UnexpectedEndTag(subject.to_string()));
+                return true;
+            }
+            if formatting_element != self.current() {
+                self.error(ParseError::MisnestedFormattingElement(subject.to_string()));
+            }
+
+            let furthest_block = self.stack[stack_index + 1..].iter()
+                .copied()
+                .find(|&node| self.is_special_element(node));
+            let furthest_block = match furthest_block {
+                Some(node) => node,
+                None => {
+                    self.stack.truncate(stack_index);
+                    self.remove_formatting_element(formatting_element);
+                    return true;
+                }
+            };
+            let common_ancestor = self.stack[stack_index - 1];
+            let mut bookmark = self.formatting_position(formatting_element).expect("the formatting element is in the list");
+
+            let mut node_index = self.stack.iter().position(|&node| node == furthest_block).expect("the furthest block is open");
+            let mut last_node = furthest_block;
+            let mut inner_loop_counter = 0;
+            loop {
+                inner_loop_counter += 1;
+                node_index -= 1;
+                let node = self.stack[node_index];
+                if node == formatting_element {
+                    break;
+                }
+                let mut position = self.formatting_position(node);
+                if let (true, Some(index)) = (inner_loop_counter > 3, position) {
+                    self.formatting.remove(index);
+                    if index < bookmark {
+                        bookmark -= 1;
+                    }
+                    position = None;
+                }
+                let position = match position {
+                    Some(index) => index,
+                    None => {
+                        self.stack.remove(node_index);
+                        continue;
+                    }
+                };
+                let node_tag = match &self.formatting[position] {
+                    FormattingEntry::Element(_, tag) => tag.clone(),
+                    FormattingEntry::Marker => unreachable!("markers aren't on the stack")
+                };
+                let element = self.create_element(node_tag.name.clone(), Namespace::Html, &node_tag);
+                self.formatting[position] = FormattingEntry::Element(element, node_tag);
+                self.stack[node_index] = element;
+                if last_node == furthest_block {
+                    bookmark = position + 1;
+                }
+                self.detach(last_node);
+                self.append(element, last_node);
+                last_node = element;
+            }
+
+            self.detach(last_node);
+            let place = self.insertion_place(common_ancestor);
+            self.insert_at(place, last_node);
+
+            let element = self.create_element(tag.name.clone(), Namespace::Html, &tag);
+            while let Some(child) = self.dom[furthest_block].first_child() {
+                self.detach(child);
+                self.append(element, child);
+            }
+            self.append(furthest_block, element);
+
+            let old_position = self.formatting_position(formatting_element).expect("the formatting element is in the list");
+            self.formatting.remove(old_position);
+            if old_position < bookmark {
+                bookmark -= 1;
+            }
+            self.formatting.insert(bookmark, FormattingEntry::Element(element, tag));
+
+            self.remove_from_stack(formatting_element);
+            let furthest_index = self.stack.iter().position(|&node| node == furthest_block).expect("the furthest block is open");
+            self.stack.insert(furthest_index + 1, element);
+        }
+        true
+    }
+
+    fn close_p_in_button_scope(&mut self) {
+        if self.in_scope(&[atoms::P], Scope::Button) {
+            self.close_p();
+        }
+    }
+
+    fn close_p(&mut self) {
+        self.generate_implied_end_tags(Some(&atoms::P));
+        self.close_element(&atoms::P);
+    }
+
+    /// Pops elements until one named `name` was popped, reporting it if it wasn't the current node.
+    fn close_element(&mut self, name: &Atom) {
+        if self.current_name() != *name {
+            self.error(ParseError::EndTagTooEarly(name.to_string()));
+        }
+        self.pop_until(slice::from_ref(name));
+    }
+
+    /// Pops the elements whose end tags can be left out, other than `except`.
+    fn generate_implied_end_tags(&mut self, except: Option<&Atom>) {
+        while let Some(&node) = self.stack.last() {
+            let name = self.name(node);
+            if Some(&name) == except || !matches!(name, atoms::DD | atoms::DT | atoms::LI | atoms::OPTGROUP
+                | atoms::OPTION | atoms::P | atoms::RB | atoms::RP | atoms::RT | atoms::RTC) {
+                return;
+            }
+            self.stack.pop();
+        }
+    }
+
+    /// Pops all elements whose end tags can be left out, including table parts, before a
+    /// `template` is closed.
+    fn generate_all_implied_end_tags(&mut self) {
+        while matches!(self.current_name(), atoms::CAPTION | atoms::COLGROUP | atoms::DD | atoms::DT | atoms::LI
+            | atoms::OPTGROUP | atoms::OPTION | atoms::P | atoms::RB | atoms::RP | atoms::RT | atoms::RTC
+            | atoms::TBODY | atoms::TD | atoms::TFOOT | atoms::TH | atoms::THEAD | atoms::TR) {
+            self.stack.pop();
+        }
+    }
+
+    fn has_template(&self) -> bool {
+        self.stack.iter().any(|&node| self.name(node) == atoms::TEMPLATE)
+    }
+
+    /// Pops elements until one with any of the names was popped.
+    fn pop_until(&mut self, names: &[Atom]) {
+        while let Some(node) = self.stack.pop() {
+            if names.contains(&self.name(node)) {
+                return;
+            }
+        }
     }
 
-    pub fn parse(&mut self, text: String) -> Node {
-        self.stack.push(document(Vec::new()));
+    fn remove_from_stack(&mut self, node: NodeId) {
+        if let Some(index) = self.stack.iter().rposition(|&open| open == node) {
+            self.stack.remove(index);
+        }
+    }
+
+    /// Whether an element with any of the names is in the given scope.
+    fn in_scope(&self, names: &[Atom], scope: Scope) -> bool {
+        self.scope_search(scope, |node| names.contains(&self.name(node)))
+    }
+
+    fn node_in_scope(&self, target: NodeId, scope: Scope) -> bool {
+        self.scope_search(scope, |node| node == target)
+    }
 
-        match self.stack.pop() {
-            None => { document(Vec::new()) }
-            Some(value) => { value }
+    fn scope_search(&self, scope: Scope, is_target: impl Fn(NodeId) -> bool) -> bool {
+        for &node in self.stack.iter().rev() {
+            if is_target(node) {
+                return true;
+            }
+            let name = self.name(node);
+            let boundary = match scope {
+                Scope::Default => is_scope_boundary(&name) || self.is_foreign_boundary(node),
+                Scope::ListItem => is_scope_boundary(&name) || self.is_foreign_boundary(node)
+                    || matches!(name, atoms::OL | atoms::UL),
+                Scope::Button => is_scope_boundary(&name) || self.is_foreign_boundary(node) || name == atoms::BUTTON,
+                Scope::Table => matches!(name, atoms::HTML | atoms::TABLE | atoms::TEMPLATE),
+                Scope::Select => !matches!(name, atoms::OPTGROUP | atoms::OPTION)
+            };
+            if boundary {
+                return false;
+            }
         }
+        false
     }
-}
\ No newline at end of file
+
+    fn current(&self) -> NodeId {
+        *self.stack.last().expect("the html element stays on the stack")
+    }
+
+    fn current_name(&self) -> Atom {
+        self.stack.last().map_or(atoms::EMPTY, |&node| self.name(node))
+    }
+
+    /// The tag name of an HTML element. SVG and MathML elements don't match any of the names
+    /// the tree builder looks for, even those spelled the same like `title` and `a`.
+    fn name(&self, node: NodeId) -> Atom {
+        match &self.dom[node].node_type {
+            NodeType::Element(element) if element.namespace == Namespace::Html => element.tag.clone(),
+            _ => atoms::EMPTY
+        }
+    }
+
+    /// The tag name of an element in any namespace.
+    fn local_name(&self, node: NodeId) -> Atom {
+        match &self.dom[node].node_type {
+            NodeType::Element(element) => element.tag.clone(),
+            _ => atoms::EMPTY
+        }
+    }
+
+    fn namespace(&self, node: NodeId) -> Namespace {
+        match &self.dom[node].node_type {
+            NodeType::Element(element) => element.namespace,
+            _ => Namespace::Html
+        }
+    }
+
+    /// The context element while only the root `html` element of a fragment is open,
+    /// otherwise the current node.
+    fn adjusted_current_node(&self) -> Option<NodeId> {
+        match self.context {
+            Some(context) if self.stack.len() == 1 => Some(context),
+            _ => self.stack.last().copied()
+        }
+    }
+
+    fn is_mathml_text_integration_point(&self, node: NodeId) -> bool {
+        self.namespace(node) == Namespace::MathMl
+            && matches!(self.local_name(node), atoms::MI | atoms::MO | atoms::MN | atoms::MS | atoms::MTEXT)
+    }
+
+    /// Foreign elements whose contents are parsed as HTML: `annotation-xml` holding HTML and
+    /// the SVG elements that may contain text.
+    fn is_html_integration_point(&self, node: NodeId) -> bool {
+        match &self.dom[node].node_type {
+            NodeType::Element(element) if element.namespace == Namespace::MathMl => {
+                element.tag == atoms::ANNOTATION_XML && element.attributes().iter().any(|attribute| {
+                    attribute.name == atoms::ENCODING && (attribute.value.eq_ignore_ascii_case("text/html")
+                        || attribute.value.eq_ignore_ascii_case("application/xhtml+xml"))
+                })
+            }
+            NodeType::Element(element) if element.namespace == Namespace::Svg => {
+                matches!(element.tag, atoms::FOREIGN_OBJECT | atoms::DESC | atoms::TITLE)
+            }
+            _ => false
+        }
+    }
+
+    /// The SVG and MathML elements that bound the default scope and are special.
+    fn is_foreign_boundary(&self, node: NodeId) -> bool {
+        match self.namespace(node) {
+            Namespace::MathMl => matches!(self.local_name(node), atoms::MI | atoms::MO | atoms::MN | atoms::MS
+                | atoms::MTEXT | atoms::ANNOTATION_XML),
+            Namespace::Svg => matches!(self.local_name(node), atoms::FOREIGN_OBJECT | atoms::DESC | atoms::TITLE),
+            _ => false
+        }
+    }
+
+    fn is_special_element(&self, node: NodeId) -> bool {
+        is_special(&self.name(node)) || self.is_foreign_boundary(node)
+    }
+
+    fn quirks_mode(&self) -> QuirksMode {
+        match &self.dom[self.dom.document()].node_type {
+            NodeType::Document(document) => document.quirks_mode,
+            _ => QuirksMode::NoQuirks
+        }
+    }
+
+    fn set_quirks_mode(&mut self, quirks_mode: QuirksMode) {
+        let document = self.dom.document();
+        if let NodeType::Document(document) = &mut self.dom[document].node_type {
+            document.quirks_mode = quirks_mode;
+        }
+    }
+
+    fn create_node(&mut self, node_type: NodeType) -> NodeId {
+        self.dom.create_node(self.dom.document(), node_type)
+    }
+
+    /// Creates an element for a start tag, `name` overrides the tag's for implied elements.
+    /// The case of SVG and MathML names is restored.
+    fn create_element(&mut self, name: Atom, namespace: Namespace, tag: &TagData) -> NodeId {
+        let name = if namespace == Namespace::Svg { namespace::adjust_svg_tag_name(name) } else { name };
+        let attributes = tag.attributes.iter()
+            .map(|attribute| element_attribute(namespace, attribute))
+            .collect();
+        let is_template = name == atoms::TEMPLATE;
+        let mut element = Element::new(name, namespace, attributes);
+        if is_template {
+            element.template_contents = Some(self.create_node(NodeType::DocumentFragment(DocumentFragment {})));
+        }
+        self.create_node(NodeType::Element(element))
+    }
+
+    /// The appropriate place for inserting a node into `target`, as a parent and the child to
+    /// insert in front of. With foster parenting, nodes that would end up in a table are
+    /// moved in front of it instead. Nodes inserted into a `template` go into its contents.
+    fn insertion_place(&self, target: NodeId) -> (NodeId, Option<NodeId>) {
+        let (parent, before) = if self.foster_parenting && matches!(self.name(target), atoms::TABLE
+            | atoms::TBODY | atoms::TFOOT | atoms::THEAD | atoms::TR) {
+            self.foster_parent_place()
+        } else {
+            (target, None)
+        };
+        match &self.dom[parent].node_type {
+            NodeType::Element(Element { template_contents: Some(contents), .. }) => (*contents, None),
+            _ => (parent, before)
+        }
+    }
+
+    /// In front of the last table, unless a template was opened inside of it.
+    fn foster_parent_place(&self) -> (NodeId, Option<NodeId>) {
+        let template = self.stack.iter().rposition(|&node| self.name(node) == atoms::TEMPLATE);
+        let table = self.stack.iter().rposition(|&node| self.name(node) == atoms::TABLE);
+        match (table, template) {
+            (Some(table), Some(template)) if template > table => (self.stack[template], None),
+            (Some(index), _) => {
+                let table = self.stack[index];
+                match self.dom[table].parent() {
+                    Some(parent) => (parent, Some(table)),
+                    None => (self.stack[index - 1], None)
+                }
+            }
+            (None, Some(template)) => (self.stack[template], None),
+            (None, None) => (self.stack[0], None)
+        }
+    }
+
+    fn insert_at(&mut self, (parent, before): (NodeId, Option<NodeId>), child: NodeId) {
+        self.dom.insert(parent, child, before);
+    }
+
+    fn append(&mut self, parent: NodeId, child: NodeId) {
+        self.dom.append(parent, child);
+    }
+
+    fn detach(&mut self, node: NodeId) {
+        self.dom.detach(node);
+    }
+
+    /// Inserts an element for the tag at the current node and pushes it onto the stack.
+    fn insert_element(&mut self, tag: TagData) -> NodeId {
+        let element = self.create_element(tag.name.clone(), Namespace::Html, &tag);
+        let place = self.insertion_place(self.current());
+        self.insert_at(place, element);
+        self.stack.push(element);
+        element
+    }
+
+    /// Inserts an SVG or MathML element, which is closed right away if its tag is self-closing.
+    fn insert_foreign_element(&mut self, tag: TagData, namespace: Namespace) -> NodeId {
+        let element = self.create_element(tag.name.clone(), namespace, &tag);
+        let place = self.insertion_place(self.current());
+        self.insert_at(place, element);
+        self.stack.push(element);
+        if tag.self_closing {
+            self.stack.pop();
+            self.acknowledged = true;
+        }
+        element
+    }
+
+    /// Pauses parsing to run a script that was just closed, then parses what it wrote ahead
+    /// of the rest of the input. Scripts in fragments are never run.
+    fn run_script(&mut self, element: NodeId) {
+        if self.context.is_some() || self.script_handler.is_none() {
+            return;
+        }
+        let (attributes, namespace) = match &self.dom[element].node_type {
+            NodeType::Element(element) => (element.attributes().to_vec(), element.namespace),
+            _ => return
+        };
+        let text = self.dom.children(element)
+            .filter_map(|child| match &self.dom[child].node_type {
+                NodeType::Text(text) => Some(text.as_str()),
+                _ => None
+            })
+            .collect();
+        let script = Script { text, attributes, namespace };
+        let mut writer = DocumentWriter::default();
+        if let Some(handler) = &mut self.script_handler {
+            handler.run_script(&script, &mut writer);
+        }
+        self.lexer.insert_str(&writer.into_text());
+    }
+
+    /// Switches to the encoding a `<meta>` declares with its `charset`, or with the `content`
+    /// of an `http-equiv="content-type"`, if it isn't the one the document is decoded with.
+    fn change_encoding(&mut self, tag: &TagData) {
+        let declared = tag.attribute(atoms::CHARSET)
+            .and_then(|charset| Encoding::for_label(charset))
+            .or_else(|| {
+                let http_equiv = tag.attribute(atoms::HTTP_EQUIV)?;
+                if !http_equiv.eq_ignore_ascii_case("content-type") {
+                    return None;
+                }
+                Encoding::for_label(&sniff::extract_charset(tag.attribute(atoms::CONTENT)?)?)
+            });
+        let encoding = match declared {
+            Some(encoding) => sniff::meta_encoding(encoding),
+            None => return
+        };
+        if encoding == self.encoding {
+            self.confidence = Confidence::Certain;
+        } else {
+            self.new_encoding = Some(encoding);
+        }
+    }
+
+    /// Inserts an element that can't have children, like `br`, and acknowledges its
+    /// self-closing flag.
+    fn insert_void_element(&mut self, tag: TagData) {
+        self.insert_element(tag);
+        self.stack.pop();
+        self.acknowledged = true;
+    }
+
+    /// Inserts text at the current node, merging it into a text node that's already there.
+    fn insert_text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        let (parent, before) = self.insertion_place(self.current());
+        let previous = match before {
+            Some(before) => self.dom[before].previous_sibling(),
+            None => self.dom[parent].last_child()
+        };
+        if let Some(previous) = previous {
+            if let NodeType::Text(existing) = &mut self.dom[previous].node_type {
+                existing.push_str(text);
+                return;
+            }
+        }
+        let node = self.create_node(NodeType::Text(text.to_string()));
+        self.insert_at((parent, before), node);
+    }
+
+    fn insert_comment(&mut self, text: Text) {
+        let place = self.insertion_place(self.current());
+        let node = self.create_node(NodeType::Comment(text.to_string()));
+        self.insert_at(place, node);
+    }
+
+    fn insert_comment_in(&mut self, text: Text, parent: NodeId) {
+        let node = self.create_node(NodeType::Comment(text.to_string()));
+        self.append(parent, node);
+    }
+
+    /// Adds the tag's attributes that the element doesn't have yet, for repeated `html` and
+    /// `body` start tags.
+    fn add_missing_attributes(&mut self, node: NodeId, tag: &TagData) {
+        if let NodeType::Element(element) = &mut self.dom[node].node_type {
+            for attribute in &tag.attributes {
+                if !element.attributes().iter().any(|existing| existing.name == attribute.name) {
+                    element.push_attribute(Attribute::new(attribute.name.clone(), attribute.value.to_string()));
+                }
+            }
+        }
+    }
+}
+
+fn document_type(doctype: DoctypeData) -> DocumentType {
+    DocumentType {
+        internel_subset: String::new(),
+        name: doctype.name.map(|name| name.to_string()).unwrap_or_default(),
+        notations: Vec::new(),
+        public_id: doctype.public_id.map(|id| id.to_string()).unwrap_or_default(),
+        system_id: doctype.system_id.map(|id| id.to_string()).unwrap_or_default(),
+        quirks: doctype.force_quirks,
+    }
+}
+
+/// Converts a token's attribute for an element in `namespace`, restoring the case of SVG and
+/// MathML attribute names and the namespace of attributes like `xlink:href`.
+fn element_attribute(namespace: Namespace, attribute: &tokens::Attribute) -> Attribute {
+    let name = match namespace {
+        Namespace::Svg => namespace::adjust_svg_attribute(attribute.name.clone()),
+        Namespace::MathMl => namespace::adjust_mathml_attribute(attribute.name.clone()),
+        _ => attribute.name.clone()
+    };
+    let mut adjusted = Attribute::new(name, attribute.value.to_string());
+    if namespace != Namespace::Html {
+        if let Some((prefix, local_name, namespace)) = namespace::adjust_foreign_attribute(&attribute.name) {
+            adjusted.name = Atom::new(local_name);
+            adjusted.prefix = prefix.map(Atom::new);
+            adjusted.namespace = Some(namespace);
+        }
+    }
+    adjusted
+}
+
+/// Tags that leave SVG and MathML content for HTML, unless they're inside an integration point.
+fn breaks_out_of_foreign_content(tag: &TagData) -> bool {
+    if tag.end_tag {
+        return matches!(tag.name, atoms::BR | atoms::P);
+    }
+    match tag.name {
+        atoms::FONT => tag.has_attribute(atoms::COLOR) || tag.has_attribute(atoms::FACE) || tag.has_attribute(atoms::SIZE),
+        atoms::B | atoms::BIG | atoms::BLOCKQUOTE | atoms::BODY | atoms::BR | atoms::CENTER | atoms::CODE | atoms::DD
+        | atoms::DIV | atoms::DL | atoms::DT | atoms::EM | atoms::EMBED | atoms::H1 | atoms::H2 | atoms::H3
+        | atoms::H4 | atoms::H5 | atoms::H6 | atoms::HEAD | atoms::HR | atoms::I | atoms::IMG | atoms::LI
+        | atoms::LISTING | atoms::MENU | atoms::META | atoms::NOBR | atoms::OL | atoms::P | atoms::PRE | atoms::RUBY
+        | atoms::S | atoms::SMALL | atoms::SPAN | atoms::STRONG | atoms::STRIKE | atoms::SUB | atoms::SUP
+        | atoms::TABLE | atoms::TT | atoms::U | atoms::UL | atoms::VAR => true,
+        _ => false
+    }
+}
+
+fn is_whitespace(char: char) -> bool {
+    matches!(char, '\t' | '\n' | '\u{000C}' | '\r' | ' ')
+}
+
+/// Splits text into its leading whitespace and the rest.
+fn split_whitespace(text: &str) -> (&str, &str) {
+    let end = text.find(|char| !is_whitespace(char)).unwrap_or(text.len());
+    text.split_at(end)
+}
+
+fn is_heading(name: &Atom) -> bool {
+    matches!(*name, atoms::H1 | atoms::H2 | atoms::H3 | atoms::H4 | atoms::H5 | atoms::H6)
+}
+
+/// The elements that bound the default scope.
+fn is_scope_boundary(name: &Atom) -> bool {
+    matches!(*name, atoms::APPLET | atoms::CAPTION | atoms::HTML | atoms::TABLE | atoms::TD | atoms::TH
+        | atoms::MARQUEE | atoms::OBJECT | atoms::TEMPLATE)
+}
+
+/// The special category, elements that end tags of other elements don't close implicitly.
+fn is_special(name: &Atom) -> bool {
+    matches!(*name, atoms::ADDRESS | atoms::APPLET | atoms::AREA | atoms::ARTICLE | atoms::ASIDE | atoms::BASE
+        | atoms::BASEFONT | atoms::BGSOUND | atoms::BLOCKQUOTE | atoms::BODY | atoms::BR | atoms::BUTTON
+        | atoms::CAPTION | atoms::CENTER | atoms::COL | atoms::COLGROUP | atoms::DD | atoms::DETAILS | atoms::DIR
+        | atoms::DIV | atoms::DL | atoms::DT | atoms::EMBED | atoms::FIELDSET | atoms::FIGCAPTION | atoms::FIGURE
+        | atoms::FOOTER | atoms::FORM | atoms::FRAME | atoms::FRAMESET | atoms::H1 | atoms::H2 | atoms::H3
+        | atoms::H4 | atoms::H5 | atoms::H6 | atoms::HEAD | atoms::HEADER | atoms::HGROUP | atoms::HR | atoms::HTML
+        | atoms::IFRAME | atoms::IMG | atoms::INPUT | atoms::KEYGEN | atoms::LI | atoms::LINK | atoms::LISTING
+        | atoms::MAIN | atoms::MARQUEE | atoms::MENU | atoms::META | atoms::NAV | atoms::NOEMBED | atoms::NOFRAMES
+        | atoms::NOSCRIPT | atoms::OBJECT | atoms::OL | atoms::P | atoms::PARAM | atoms::PLAINTEXT | atoms::PRE
+        | atoms::SCRIPT | atoms::SEARCH | atoms::SECTION | atoms::SELECT | atoms::SOURCE | atoms::STYLE
+        | atoms::SUMMARY | atoms::TABLE | atoms::TBODY | atoms::TD | atoms::TEMPLATE | atoms::TEXTAREA
+        | atoms::TFOOT | atoms::TH | atoms::THEAD | atoms::TITLE | atoms::TR | atoms::TRACK | atoms::UL
+        | atoms::WBR | atoms::XMP)
+}
+
+/// Whether two tags have the same attributes, in any order.
+fn same_attributes(first: &TagData, second: &TagData) -> bool {
+    first.attributes.len() == second.attributes.len()
+        && first.attributes.iter().all(|attribute| second.attribute(attribute.name.clone()) == Some(&attribute.value))
+}
@@ -0,0 +1,54 @@
+//! Hooks for running the scripts of a document while it's being parsed. Parsing pauses at
+//! the end tag of each script the parser inserts, so that the script sees the document as
+//! it was parsed up to that point, and whatever it writes is parsed next.
+
+use crate::html::atoms::Atom;
+use crate::html::namespace::Namespace;
+use crate::html::node::Attribute;
+
+/// A `script` element the parser just closed, HTML or SVG.
+pub struct Script {
+    /// The text inside the element, the script to run unless it has a `src` attribute.
+    pub text: String,
+    pub attributes: Vec<Attribute>,
+    pub namespace: Namespace,
+}
+
+impl Script {
+    pub fn attribute(&self, name: Atom) -> Option<&str> {
+        self.attributes.iter()
+            .find(|attribute| attribute.name == name && attribute.namespace.is_none())
+            .map(|attribute| attribute.value.as_str())
+    }
+}
+
+/// Collects the markup a script writes into the document with `document.write`, which is
+/// inserted in the input right after the script's end tag.
+#[derive(Default)]
+pub struct DocumentWriter {
+    text: String,
+}
+
+impl DocumentWriter {
+    pub fn write(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    pub fn writeln(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.text.push('\n');
+    }
+
+    /// Everything written so far.
+    pub fn into_text(self) -> String {
+        self.text
+    }
+}
+
+/// Runs the scripts the parser inserts, like a script engine would.
+pub trait ScriptHandler {
+    /// Called when the end tag of a parser-inserted script was reached, parsing continues
+    /// once it returns. Markup written to `writer` is parsed before the rest of the input,
+    /// including any scripts it contains.
+    fn run_script(&mut self, script: &Script, writer: &mut DocumentWriter);
+}
@@ -0,0 +1,242 @@
+//! The encoding sniffing algorithm, which picks the encoding to decode a document's bytes
+//! with before any of it is parsed: a byte order mark, then the charset the document was
+//! served with, then a `<meta>` declaring one near the start of the document.
+
+use crate::html::encoding::Encoding;
+
+/// How sure the parser is that it's decoding the document with the right encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Confidence {
+    /// A guess, which a `<meta>` declaring another encoding later in the document overrides.
+    Tentative,
+    Certain,
+    /// The document was given as characters, so there was nothing to decode.
+    Irrelevant,
+}
+
+/// How far into the document the prescan looks for a `<meta>`.
+const PRESCAN_LENGTH: usize = 1024;
+
+/// The encoding of a document given as bytes, and the `Content-Type` charset it was served
+/// with if any.
+pub fn sniff(bytes: &[u8], transport_charset: Option<&str>) -> (Encoding, Confidence) {
+    if let Some((encoding, _)) = bom(bytes) {
+        return (encoding, Confidence::Certain);
+    }
+    if let Some(encoding) = transport_charset.and_then(Encoding::for_label) {
+        return (encoding, Confidence::Certain);
+    }
+    if let Some(encoding) = prescan(&bytes[..bytes.len().min(PRESCAN_LENGTH)]) {
+        return (encoding, Confidence::Tentative);
+    }
+    // Text that happens to be valid UTF-8 is very unlikely to be in any other encoding
+    if !bytes.is_ascii() && std::str::from_utf8(bytes).is_ok() {
+        return (Encoding::Utf8, Confidence::Tentative);
+    }
+    (Encoding::Windows1252, Confidence::Tentative)
+}
+
+/// The encoding a byte order mark at the start of the bytes stands for, and its length.
+pub fn bom(bytes: &[u8]) -> Option<(Encoding, usize)> {
+    match bytes {
+        [0xEF, 0xBB, 0xBF, ..] => Some((Encoding::Utf8, 3)),
+        [0xFE, 0xFF, ..] => Some((Encoding::Utf16Be, 2)),
+        [0xFF, 0xFE, ..] => Some((Encoding::Utf16Le, 2)),
+        _ => None
+    }
+}
+
+/// The encoding a `<meta>` tag declares, where a UTF-16 declaration can't be right since the
+/// tag could be read as ASCII.
+pub fn meta_encoding(encoding: Encoding) -> Encoding {
+    match encoding {
+        Encoding::Utf16Be | Encoding::Utf16Le => Encoding::Utf8,
+        Encoding::XUserDefined => Encoding::Windows1252,
+        encoding => encoding
+    }
+}
+
+/// Looks for a `<meta>` declaring the encoding, skipping over comments and the other tags so
+/// that their attributes aren't mistaken for one.
+pub fn prescan(bytes: &[u8]) -> Option<Encoding> {
+    let mut prescanner = Prescanner { bytes, position: 0 };
+    prescanner.run()
+}
+
+struct Prescanner<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
+
+impl Prescanner<'_> {
+    fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.bytes[self.position..].len() >= prefix.len()
+            && self.bytes[self.position..self.position + prefix.len()].eq_ignore_ascii_case(prefix)
+    }
+
+    fn byte(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn run(&mut self) -> Option<Encoding> {
+        while self.position < self.bytes.len() {
+            if self.starts_with(b"<!--") {
+                // The dashes of the end can overlap with the ones of the start, as in `<!-->`
+                let after = self.position + 2;
+                let end = self.bytes[after..].windows(3).position(|window| window == b"-->")?;
+                self.position = after + end + 2;
+            } else if self.starts_with(b"<meta")
+                && self.bytes.get(self.position + 5).is_some_and(|&byte| is_whitespace(byte) || byte == b'/')
+            {
+                self.position += 5;
+                if let Some(encoding) = self.meta()? {
+                    return Some(encoding);
+                }
+            } else if self.byte() == Some(b'<')
+                && (self.bytes.get(self.position + 1).is_some_and(u8::is_ascii_alphabetic)
+                    || (self.bytes.get(self.position + 1) == Some(&b'/')
+                        && self.bytes.get(self.position + 2).is_some_and(u8::is_ascii_alphabetic)))
+            {
+                let name_end = self.bytes[self.position..].iter()
+                    .position(|&byte| is_whitespace(byte) || byte == b'>')?;
+                self.position += name_end;
+                while self.attribute()?.is_some() {}
+            } else if self.starts_with(b"<!") || self.starts_with(b"</") || self.starts_with(b"<?") {
+                let end = self.bytes[self.position..].iter().position(|&byte| byte == b'>')?;
+                self.position += end;
+            }
+            self.position += 1;
+        }
+        None
+    }
+
+    /// The attributes of a `<meta>`, read up to the end of the tag. Returns the encoding it
+    /// declares, or `None` for the outer option if the input ended first.
+    fn meta(&mut self) -> Option<Option<Encoding>> {
+        let mut names = Vec::new();
+        let mut got_pragma = false;
+        let mut need_pragma = None;
+        let mut charset = None;
+        while let Some((name, value)) = self.attribute()? {
+            if names.contains(&name) {
+                continue;
+            }
+            match name.as_str() {
+                "http-equiv" => got_pragma |= value == "content-type",
+                "content" if charset.is_none() => {
+                    if let Some(label) = extract_charset(&value) {
+                        charset = Encoding::for_label(&label);
+                        need_pragma = Some(true);
+                    }
+                }
+                "charset" => {
+                    charset = Encoding::for_label(&value);
+                    need_pragma = Some(false);
+                }
+                _ => {}
+            }
+            names.push(name);
+        }
+        match need_pragma {
+            None => Some(None),
+            Some(true) if !got_pragma => Some(None),
+            _ => Some(charset.map(meta_encoding))
+        }
+    }
+
+    /// The next attribute of a tag with its name and value lowercased, or `None` at the end
+    /// of the tag. Returns `None` for the outer option if the input ended first.
+    fn attribute(&mut self) -> Option<Option<(String, String)>> {
+        while is_whitespace(self.byte()?) || self.byte()? == b'/' {
+            self.position += 1;
+        }
+        if self.byte()? == b'>' {
+            return Some(None);
+        }
+
+        let mut name = String::new();
+        loop {
+            match self.byte()? {
+                b'=' if !name.is_empty() => break,
+                byte if is_whitespace(byte) => {
+                    while is_whitespace(self.byte()?) {
+                        self.position += 1;
+                    }
+                    if self.byte()? != b'=' {
+                        return Some(Some((name, String::new())));
+                    }
+                    break;
+                }
+                b'/' | b'>' => return Some(Some((name, String::new()))),
+                byte => name.push(byte.to_ascii_lowercase() as char)
+            }
+            self.position += 1;
+        }
+        // Past the `=`
+        self.position += 1;
+        while is_whitespace(self.byte()?) {
+            self.position += 1;
+        }
+
+        let mut value = String::new();
+        match self.byte()? {
+            quote @ (b'"' | b'\'') => loop {
+                self.position += 1;
+                match self.byte()? {
+                    byte if byte == quote => {
+                        self.position += 1;
+                        return Some(Some((name, value)));
+                    }
+                    byte => value.push(byte.to_ascii_lowercase() as char)
+                }
+            },
+            b'>' => return Some(Some((name, value))),
+            _ => {}
+        }
+        loop {
+            match self.byte()? {
+                byte if is_whitespace(byte) || byte == b'>' => return Some(Some((name, value))),
+                byte => value.push(byte.to_ascii_lowercase() as char)
+            }
+            self.position += 1;
+        }
+    }
+}
+
+/// The encoding label in the `content` attribute of a `<meta http-equiv="content-type">`,
+/// like the `utf-8` of `text/html; charset=utf-8`.
+pub fn extract_charset(content: &str) -> Option<String> {
+    let content = content.as_bytes();
+    let mut position = 0;
+    loop {
+        let found = content[position..].windows(7).position(|window| window.eq_ignore_ascii_case(b"charset"))?;
+        position += found + 7;
+        while content.get(position).is_some_and(|&byte| is_whitespace(byte)) {
+            position += 1;
+        }
+        if content.get(position) == Some(&b'=') {
+            break;
+        }
+    }
+    position += 1;
+    while content.get(position).is_some_and(|&byte| is_whitespace(byte)) {
+        position += 1;
+    }
+
+    let rest = &content[position..];
+    let label = match rest.first()? {
+        quote @ (b'"' | b'\'') => {
+            let end = rest[1..].iter().position(|byte| byte == quote)?;
+            &rest[1..end + 1]
+        }
+        _ => {
+            let end = rest.iter().position(|&byte| is_whitespace(byte) || byte == b';').unwrap_or(rest.len());
+            &rest[..end]
+        }
+    };
+    Some(String::from_utf8_lossy(label).into_owned())
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, Range};
+use std::rc::Rc;
+
+/// A piece of token text. As long as it is identical to a run of the input it shares the
+/// input's buffer, it only gets its own allocation once something like a character reference,
+/// NULL replacement or newline normalization makes it differ from the source.
+#[derive(Clone)]
+pub enum Text {
+    Shared(Rc<str>, Range<usize>),
+    Owned(String),
+}
+
+impl Text {
+    pub fn new() -> Text {
+        Text::Owned(String::new())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Text::Shared(_, range) => range.len(),
+            Text::Owned(string) => string.len()
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Text::Shared(buffer, range) => &buffer[range.clone()],
+            Text::Owned(string) => string
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Text::new();
+    }
+
+    /// Appends a character that came from `source`, where `offset` is the position it was
+    /// read from. The text keeps sharing the buffer as long as what it holds matches it.
+    pub fn push_from(&mut self, (source, offset): (&Rc<str>, usize), char: char) {
+        match self {
+            Text::Shared(buffer, range) => {
+                if Rc::ptr_eq(buffer, source) && buffer[range.end..].starts_with(char) {
+                    range.end += char.len_utf8();
+                    return;
+                }
+            }
+            Text::Owned(string) => {
+                if string.is_empty() && source[offset..].starts_with(char) {
+                    *self = Text::Shared(source.clone(), offset..offset + char.len_utf8());
+                    return;
+                }
+            }
+        }
+        self.push(char);
+    }
+
+    /// Appends the given range of `source`, sharing it if it directly follows this text.
+    pub fn push_span(&mut self, source: &Rc<str>, span: Range<usize>) {
+        match self {
+            Text::Shared(buffer, range) if Rc::ptr_eq(buffer, source) && range.end == span.start => {
+                range.end = span.end;
+            }
+            Text::Owned(string) if string.is_empty() => {
+                *self = Text::Shared(source.clone(), span);
+            }
+            _ => self.push_str(&source[span])
+        }
+    }
+
+    pub fn push_str_from(&mut self, source: (&Rc<str>, usize), text: &str) {
+        for char in text.chars() {
+            self.push_from(source, char);
+        }
+    }
+
+    /// Appends a character, copying the text out of the input buffer if it was shared.
+    pub fn push(&mut self, char: char) {
+        if let Text::Shared(buffer, range) = self {
+            let mut owned = String::with_capacity(range.len() + char.len_utf8());
+            owned.push_str(&buffer[range.clone()]);
+            *self = Text::Owned(owned);
+        }
+        if let Text::Owned(string) = self {
+            string.push(char);
+        }
+    }
+
+    pub fn push_str(&mut self, text: &str) {
+        for char in text.chars() {
+            self.push(char);
+        }
+    }
+}
+
+impl Default for Text {
+    fn default() -> Text {
+        Text::new()
+    }
+}
+
+impl Deref for Text {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for Text {
+    fn from(text: &str) -> Text {
+        Text::Owned(text.to_string())
+    }
+}
+
+impl From<String> for Text {
+    fn from(text: String) -> Text {
+        Text::Owned(text)
+    }
+}
+
+impl PartialEq for Text {
+    fn eq(&self, other: &Text) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Text {}
+
+impl PartialEq<str> for Text {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Text {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Hash for Text {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Debug for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
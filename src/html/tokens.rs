@@ -1,26 +1,55 @@
-use std::collections::HashMap;
+use crate::html::atoms::{self, Atom};
+use crate::html::text::Text;
 
+#[derive(Clone, Debug, PartialEq)]
 pub enum Token {
-    Character(char),
-    CharacterReference(char),
-    StartTag(),
-    EndTag(),
-    StartComment(),
-    Comment(Vec<char>),
-    StartAttribute(),
-    Attribute { name: Vec<char>, value: Vec<char> },
+    Characters(Text),
+    Comment(Text),
     Tag(TagData),
+    Doctype(DoctypeData),
     EndOfFile()
 }
 
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct TagData {
-    pub tag_name: Vec<char>,
+    pub name: Atom,
+    pub end_tag: bool,
     pub self_closing: bool,
-    pub attributes: HashMap<Vec<char>, Vec<char>>
+    /// In source order, without duplicates.
+    pub attributes: Vec<Attribute>
 }
 
 impl TagData {
     pub fn new() -> TagData {
-        TagData { tag_name: Vec::new(), self_closing: false, attributes: HashMap::new() }
+        TagData { name: atoms::EMPTY, end_tag: false, self_closing: false, attributes: Vec::new() }
     }
-}
\ No newline at end of file
+
+    pub fn attribute(&self, name: Atom) -> Option<&Text> {
+        self.attributes.iter().find(|attribute| attribute.name == name).map(|attribute| &attribute.value)
+    }
+
+    pub fn has_attribute(&self, name: Atom) -> bool {
+        self.attribute(name).is_some()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    pub name: Atom,
+    pub value: Text
+}
+
+/// A DOCTYPE token, where a missing name or identifier is distinct from an empty one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DoctypeData {
+    pub name: Option<Text>,
+    pub public_id: Option<Text>,
+    pub system_id: Option<Text>,
+    pub force_quirks: bool
+}
+
+impl DoctypeData {
+    pub fn new() -> DoctypeData {
+        DoctypeData { name: None, public_id: None, system_id: None, force_quirks: false }
+    }
+}
@@ -1,5 +1,26 @@
-mod html;
+use std::process::ExitCode;
 
-fn main() {
-    println!("Hello, world!");
+use toy_browser::html::parser::Parser;
+
+/// Parses each file given on the command line and prints its parse errors,
+/// failing if any of them has errors.
+fn main() -> ExitCode {
+    let mut failed = false;
+    for path in std::env::args().skip(1) {
+        let input = match std::fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{}: {}", path, error);
+                failed = true;
+                continue;
+            }
+        };
+        let mut parser = Parser::new(input);
+        parser.parse();
+        for error in parser.errors().iter() {
+            println!("{}:{}", path, error);
+            failed = true;
+        }
+    }
+    if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS }
 }
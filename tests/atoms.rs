@@ -0,0 +1,29 @@
+use toy_browser::html::atoms::{self, Atom};
+
+#[test]
+fn static_names() {
+    assert_eq!(Atom::new("div"), atoms::DIV);
+    assert!(atoms::DIV.is_static());
+    assert_eq!(atoms::DIV.as_str(), "div");
+    assert_eq!(Atom::default(), atoms::EMPTY);
+    for name in ["onclick", "aria-label", "part", "shadowrootmode", "foreignObject", "xlink:href"] {
+        assert!(Atom::new(name).is_static(), "{}", name);
+    }
+}
+
+#[test]
+fn dynamic_names() {
+    let atom = Atom::new("data-user-id");
+    assert!(!atom.is_static());
+    assert_eq!(atom.as_str(), "data-user-id");
+    assert_eq!(atom, Atom::new("data-user-id"));
+    assert_ne!(atom, Atom::new("data-user"));
+    assert_ne!(Atom::new("Div"), atoms::DIV);
+}
+
+#[test]
+fn atoms_match_constants() {
+    let name = Atom::new("template");
+    assert!(matches!(name, atoms::TEMPLATE));
+    assert!(!matches!(Atom::new("my-template"), atoms::TEMPLATE));
+}
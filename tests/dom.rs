@@ -0,0 +1,173 @@
+use toy_browser::html::atoms::{self, Atom};
+use toy_browser::html::dom::{Dom, NodeId};
+use toy_browser::html::errors::DomException;
+use toy_browser::html::namespace::Namespace;
+use toy_browser::html::node::{DocumentFragment, Element, NodeType};
+use toy_browser::html::parser::Parser;
+
+/// The first element with the given tag name in tree order.
+fn find(dom: &Dom, tag: &str) -> NodeId {
+    dom.descendants(dom.document())
+        .find(|&node| matches!(&dom[node].node_type, NodeType::Element(element) if element.tag.as_str() == tag))
+        .unwrap_or_else(|| panic!("no {} element", tag))
+}
+
+fn create_element(dom: &mut Dom, tag: Atom) -> NodeId {
+    dom.create_node(dom.document(), NodeType::Element(Element::new(tag, Namespace::Html, Vec::new())))
+}
+
+fn names(dom: &Dom, nodes: impl Iterator<Item = NodeId>) -> Vec<String> {
+    nodes.map(|node| match &dom[node].node_type {
+        NodeType::Element(element) => element.tag.to_string(),
+        NodeType::Text(text) => format!("{:?}", text),
+        NodeType::Document(_) => "#document".to_string(),
+        _ => "?".to_string()
+    }).collect()
+}
+
+#[test]
+fn sibling_and_parent_links() {
+    let dom = Parser::new("<p>a<b>b</b>c</p>".to_string()).parse();
+    let p = find(&dom, "p");
+    let b = find(&dom, "b");
+    assert_eq!(names(&dom, dom.children(p)), ["\"a\"", "b", "\"c\""]);
+    assert_eq!(dom[b].parent(), Some(p));
+    assert_eq!(dom[dom[b].previous_sibling().unwrap()].next_sibling(), Some(b));
+    assert_eq!(dom[dom[b].next_sibling().unwrap()].previous_sibling(), Some(b));
+    assert_eq!(dom[p].first_child().and_then(|first| dom[first].previous_sibling()), None);
+    assert_eq!(dom[p].last_child().and_then(|last| dom[last].next_sibling()), None);
+    assert_eq!(names(&dom, dom.ancestors(b)), ["p", "body", "html", "#document"]);
+}
+
+#[test]
+fn descendants_in_tree_order() {
+    let dom = Parser::new("<p>a<b>b</b></p><template><i>x</i></template><p>c".to_string()).parse();
+    let body = find(&dom, "body");
+    assert_eq!(names(&dom, dom.descendants(body)), ["p", "\"a\"", "b", "\"b\"", "template", "p", "\"c\""]);
+}
+
+#[test]
+fn owner_document() {
+    let dom = Parser::new("<p>x".to_string()).parse();
+    let document = dom.document();
+    assert_eq!(dom.owner_document(document), None);
+    assert!(dom.descendants(document).all(|node| dom.owner_document(node) == Some(document)));
+}
+
+#[test]
+fn document_refuses_text() {
+    let mut dom = Dom::new();
+    let document = dom.document();
+    let text = dom.create_node(document, NodeType::Text("x".to_string()));
+    let cdata = dom.create_node(document, NodeType::CDataSection("x".to_string()));
+    assert_eq!(dom.insert_before(document, text, None), Err(DomException::HierarchyRequestError));
+    assert_eq!(dom.insert_before(document, cdata, None), Err(DomException::HierarchyRequestError));
+}
+
+#[test]
+fn document_refuses_fragment_with_text() {
+    let mut dom = Dom::new();
+    let document = dom.document();
+    for text in [NodeType::Text("x".to_string()), NodeType::CDataSection("x".to_string())] {
+        let fragment = dom.create_node(document, NodeType::DocumentFragment(DocumentFragment {}));
+        let element = create_element(&mut dom, atoms::HTML);
+        let text = dom.create_node(document, text);
+        dom.append_child(fragment, element).unwrap();
+        dom.append_child(fragment, text).unwrap();
+        assert_eq!(dom.append_child(document, fragment), Err(DomException::HierarchyRequestError));
+    }
+}
+
+#[test]
+fn document_takes_one_element() {
+    let mut dom = Dom::new();
+    let document = dom.document();
+    let html = create_element(&mut dom, atoms::HTML);
+    let other = create_element(&mut dom, atoms::HTML);
+    assert_eq!(dom.append_child(document, html), Ok(html));
+    assert_eq!(dom.append_child(document, other), Err(DomException::HierarchyRequestError));
+    assert_eq!(dom.replace_child(document, other, html), Ok(html));
+    assert_eq!(names(&dom, dom.children(document)), ["html"]);
+    assert_eq!(dom[html].parent(), None);
+}
+
+#[test]
+fn element_takes_text() {
+    let mut dom = Dom::new();
+    let document = dom.document();
+    let element = create_element(&mut dom, atoms::HTML);
+    let cdata = dom.create_node(document, NodeType::CDataSection("x".to_string()));
+    dom.append_child(document, element).unwrap();
+    assert_eq!(dom.append_child(element, cdata), Ok(cdata));
+    assert_eq!(dom[cdata].parent(), Some(element));
+}
+
+#[test]
+fn node_cannot_go_inside_itself() {
+    let mut dom = Dom::new();
+    let outer = create_element(&mut dom, atoms::DIV);
+    let inner = create_element(&mut dom, atoms::P);
+    dom.append_child(outer, inner).unwrap();
+    assert_eq!(dom.append_child(inner, outer), Err(DomException::HierarchyRequestError));
+    assert_eq!(dom.append_child(inner, inner), Err(DomException::HierarchyRequestError));
+}
+
+#[test]
+fn reference_child_must_be_a_child() {
+    let mut dom = Dom::new();
+    let parent = create_element(&mut dom, atoms::DIV);
+    let node = create_element(&mut dom, atoms::P);
+    let stranger = create_element(&mut dom, atoms::B);
+    assert_eq!(dom.insert_before(parent, node, Some(stranger)), Err(DomException::NotFoundError));
+    assert_eq!(dom.remove_child(parent, stranger), Err(DomException::NotFoundError));
+}
+
+#[test]
+fn adopt_moves_into_another_document() {
+    let mut dom = Parser::new("<p>x".to_string()).parse();
+    let p = find(&dom, "p");
+    let other = dom.create_document();
+    assert_eq!(dom.adopt(p, other), Ok(p));
+    assert_eq!(dom[p].parent(), None);
+    assert!(dom.descendants(p).all(|node| dom.owner_document(node) == Some(other)));
+    assert_eq!(dom.owner_document(p), Some(other));
+}
+
+#[test]
+fn adopt_needs_a_document_target() {
+    let mut dom = Parser::new("<p>x<b>y".to_string()).parse();
+    let (p, b) = (find(&dom, "p"), find(&dom, "b"));
+    let document = dom.document();
+    let other = dom.create_document();
+    assert_eq!(dom.adopt(document, other), Err(DomException::NotSupportedError));
+    assert_eq!(dom.adopt(b, p), Err(DomException::HierarchyRequestError));
+    assert_eq!(dom[b].parent(), Some(p));
+    assert_eq!(dom.owner_document(b), Some(document));
+}
+
+#[test]
+fn deep_clone_copies_template_contents() {
+    let mut dom = Parser::new("<template><b>x</b></template>".to_string()).parse();
+    let template = find(&dom, "template");
+    let copy = dom.clone_node(template, true);
+    let contents = |dom: &Dom, node: NodeId| match &dom[node].node_type {
+        NodeType::Element(element) => element.template_contents.unwrap(),
+        _ => panic!("expected an element")
+    };
+    assert_ne!(contents(&dom, copy), contents(&dom, template));
+    assert_eq!(names(&dom, dom.descendants(contents(&dom, copy))), ["b", "\"x\""]);
+    assert_eq!(dom[copy].parent(), None);
+}
+
+#[test]
+fn normalize_merges_text() {
+    let mut dom = Dom::new();
+    let document = dom.document();
+    let p = create_element(&mut dom, atoms::P);
+    for data in ["a", "", "b"] {
+        let text = dom.create_node(document, NodeType::Text(data.to_string()));
+        dom.append_child(p, text).unwrap();
+    }
+    dom.normalize(p);
+    assert_eq!(names(&dom, dom.children(p)), ["\"ab\""]);
+}
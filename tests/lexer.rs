@@ -0,0 +1,80 @@
+use toy_browser::html::lexer::Lexer;
+use toy_browser::html::text::Text;
+use toy_browser::html::tokens::Token;
+
+/// The tokens up to the end of file, one line each, with adjacent runs of text joined.
+fn tokens(lexer: &mut Lexer) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut text = String::new();
+    while let Some(token) = lexer.next_token() {
+        if let Token::Characters(characters) = &token {
+            text.push_str(characters);
+            continue;
+        }
+        if !text.is_empty() {
+            tokens.push(format!("{:?}", text));
+            text.clear();
+        }
+        let eof = token == Token::EndOfFile();
+        tokens.push(match token {
+            Token::Characters(_) => unreachable!(),
+            Token::Comment(text) => format!("<!--{}-->", text),
+            Token::Tag(tag) => {
+                let attributes: String = tag.attributes.iter()
+                    .map(|attribute| format!(" {}={:?}", attribute.name, attribute.value))
+                    .collect();
+                format!("<{}{}{}>", if tag.end_tag { "/" } else { "" }, tag.name, attributes)
+            }
+            Token::Doctype(doctype) => format!("<!DOCTYPE {:?}>", doctype.name),
+            Token::EndOfFile() => "EOF".to_string()
+        });
+        if eof {
+            break;
+        }
+    }
+    tokens
+}
+
+#[test]
+fn character_references_in_text() {
+    let mut lexer = Lexer::new("<p class=a>one two &amp; three</p>".to_string());
+    assert_eq!(tokens(&mut lexer), ["<p class=\"a\">", "\"one two & three\"", "</p>", "EOF"]);
+}
+
+#[test]
+fn text_differing_from_the_input() {
+    let mut lexer = Lexer::new("<A HREF='x&lt;y'>a\r\nb</A><!--c\0-->".to_string());
+    assert_eq!(tokens(&mut lexer), ["<a href=\"x<y\">", "\"a\\nb\"", "</a>", "<!--c\u{FFFD}-->", "EOF"]);
+}
+
+#[test]
+fn attributes_in_source_order() {
+    let mut lexer = Lexer::new("<img src=a alt=b id=c src=d ALT=e>".to_string());
+    assert_eq!(tokens(&mut lexer), ["<img src=\"a\" alt=\"b\" id=\"c\">", "EOF"]);
+    let errors: Vec<String> = lexer.errors().iter().map(|error| error.to_string()).collect();
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors.iter().all(|error| error.ends_with("duplicate-attribute")), "{:?}", errors);
+}
+
+#[test]
+fn plain_text_shares_the_input() {
+    let mut lexer = Lexer::new("<p title=hello>some text<!-- note -->".to_string());
+    let Some(Token::Tag(tag)) = lexer.next_token() else { panic!("expected a tag") };
+    assert!(tag.attributes.iter().all(|attribute| matches!(attribute.value, Text::Shared(..))));
+    assert!(matches!(lexer.next_token(), Some(Token::Characters(Text::Shared(..)))));
+    assert!(matches!(lexer.next_token(), Some(Token::Comment(Text::Shared(..)))));
+}
+
+#[test]
+fn chunked_input_gives_the_same_text() {
+    let input = "<div id=x>hello &copy; world<!-- note --></div>";
+    let expected = tokens(&mut Lexer::new(input.to_string()));
+    for size in 1..input.len() {
+        let mut lexer = Lexer::incremental();
+        for chunk in input.as_bytes().chunks(size) {
+            lexer.push_bytes(chunk);
+        }
+        lexer.finish();
+        assert_eq!(tokens(&mut lexer), expected, "chunks of {}", size);
+    }
+}
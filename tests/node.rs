@@ -0,0 +1,75 @@
+use toy_browser::html::atoms::{self, Atom};
+use toy_browser::html::errors::DomException;
+use toy_browser::html::namespace::Namespace;
+use toy_browser::html::node::{Attribute, Element};
+
+fn element_with_class(class: &str) -> Element {
+    let mut element = Element::new(atoms::DIV, Namespace::Html, Vec::new());
+    element.set_attribute("class", class.to_string()).unwrap();
+    element
+}
+
+#[test]
+fn class_list_replace() {
+    let mut element = element_with_class("x y z");
+    assert_eq!(element.class_list_mut().replace("y", "z"), Ok(true));
+    assert_eq!(element.get_attribute("class"), Some("x z"));
+    assert_eq!(element.class_list_mut().replace("y", "w"), Ok(false));
+    assert_eq!(element.get_attribute("class"), Some("x z"));
+}
+
+#[test]
+fn class_list_replace_with_itself() {
+    let mut element = element_with_class("x y");
+    assert_eq!(element.class_list_mut().replace("x", "x"), Ok(true));
+    assert_eq!(element.get_attribute("class"), Some("x y"));
+    assert_eq!(element.class_list(), [Atom::new("x"), Atom::new("y")]);
+}
+
+#[test]
+fn set_attribute_ns_checks_names() {
+    let mut element = Element::new(atoms::DIV, Namespace::Html, Vec::new());
+    assert_eq!(element.set_attribute_ns(Some(Namespace::XLink), "xlink:href", "#a".to_string()), Ok(()));
+    assert_eq!(element.get_attribute("xlink:href"), Some("#a"));
+    assert_eq!(element.get_attribute_ns(Some(Namespace::XLink), "href"), Some("#a"));
+    assert_eq!(element.set_attribute_ns(Some(Namespace::XLink), "a:b:c", String::new()),
+        Err(DomException::InvalidCharacterError));
+    assert_eq!(element.set_attribute_ns(None, "a:b", String::new()), Err(DomException::NamespaceError));
+}
+
+#[test]
+fn id_follows_the_attribute() {
+    let mut element = Element::new(atoms::DIV, Namespace::Html, vec![Attribute::new(atoms::ID, "a".to_string())]);
+    assert_eq!(element.id(), Some(&Atom::new("a")));
+    element.set_attribute("ID", "b".to_string()).unwrap();
+    assert_eq!(element.id(), Some(&Atom::new("b")));
+    element.remove_attribute("id");
+    assert_eq!(element.id(), None);
+}
+
+#[test]
+fn class_list_edits_the_attribute() {
+    let mut element = element_with_class("  x\ty x ");
+    assert_eq!(element.class_list(), [Atom::new("x"), Atom::new("y")]);
+    element.class_list_mut().add(&["z", "x"]).unwrap();
+    assert_eq!(element.get_attribute("class"), Some("x y z"));
+    element.class_list_mut().remove(&["x"]).unwrap();
+    assert_eq!(element.class_list_mut().toggle("y", None), Ok(false));
+    assert_eq!(element.class_list_mut().toggle("w", Some(true)), Ok(true));
+    assert_eq!(element.get_attribute("class"), Some("z w"));
+    assert!(element.class_list_mut().contains("w"));
+    assert_eq!(element.class_list_mut().add(&[""]), Err(DomException::SyntaxError));
+    assert_eq!(element.class_list_mut().add(&["a b"]), Err(DomException::InvalidCharacterError));
+}
+
+#[test]
+fn dataset_maps_data_attributes() {
+    let mut element = Element::new(atoms::DIV, Namespace::Html, Vec::new());
+    element.dataset_mut().set("userId", "7".to_string()).unwrap();
+    assert_eq!(element.get_attribute("data-user-id"), Some("7"));
+    assert_eq!(element.dataset().get("userId"), Some("7"));
+    assert_eq!(element.dataset().iter().collect::<Vec<_>>(), [("userId".to_string(), "7")]);
+    assert_eq!(element.dataset_mut().set("a-b", String::new()), Err(DomException::SyntaxError));
+    assert!(element.dataset_mut().remove("userId").is_some());
+    assert!(!element.has_attribute("data-user-id"));
+}
@@ -0,0 +1,399 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use toy_browser::html::atoms::Atom;
+use toy_browser::html::namespace::Namespace;
+use toy_browser::html::dom::{Dom, NodeId};
+use toy_browser::html::node::{Element, NodeType};
+use toy_browser::html::parser::Parser;
+use toy_browser::html::script::{DocumentWriter, Script, ScriptHandler};
+use toy_browser::html::quirks::QuirksMode;
+
+/// The tree in the format of the html5lib tests, one line per node or attribute, with text
+/// escaped so that it fits on one line.
+fn prefix(namespace: Option<Namespace>) -> &'static str {
+    match namespace {
+        Some(Namespace::Svg) => "svg ",
+        Some(Namespace::MathMl) => "math ",
+        Some(Namespace::XLink) => "xlink ",
+        Some(Namespace::Xml) => "xml ",
+        Some(Namespace::Xmlns) => "xmlns ",
+        Some(Namespace::Html) | None => ""
+    }
+}
+
+fn dump(dom: &Dom, node: NodeId, depth: usize, lines: &mut Vec<String>) {
+    for child in dom.children(node) {
+        let indent = format!("|{}", " ".repeat(depth * 2 + 1));
+        match &dom[child].node_type {
+            NodeType::DocumentType(doctype) => lines.push(format!("{}<!DOCTYPE {}>", indent, doctype.name)),
+            NodeType::Text(text) => lines.push(format!("{}{:?}", indent, text)),
+            NodeType::Comment(comment) => lines.push(format!("{}<!-- {} -->", indent, comment)),
+            NodeType::Element(element) => {
+                lines.push(format!("{}<{}{}>", indent, prefix(Some(element.namespace)), element.tag));
+                let mut attributes: Vec<String> = element.attributes().iter()
+                    .map(|attribute| format!("{}  {}{}={:?}", indent, prefix(attribute.namespace),
+                        attribute.name, attribute.value))
+                    .collect();
+                attributes.sort();
+                lines.extend(attributes);
+                if let Some(contents) = element.template_contents {
+                    lines.push(format!("{}  content", indent));
+                    dump(dom, contents, depth + 2, lines);
+                }
+            }
+            _ => {}
+        }
+        dump(dom, child, depth + 1, lines);
+    }
+}
+
+fn parse(input: &str) -> Vec<String> {
+    let dom = Parser::new(input.to_string()).parse();
+    let mut lines = Vec::new();
+    dump(&dom, dom.document(), 0, &mut lines);
+    lines
+}
+
+/// Parses `input` in a context element given like `td` or `svg title`.
+fn parse_fragment(context: &str, input: &str) -> Vec<String> {
+    let (namespace, tag) = match context.split_once(' ') {
+        Some(("svg", tag)) => (Namespace::Svg, tag),
+        Some(("math", tag)) => (Namespace::MathMl, tag),
+        _ => (Namespace::Html, context)
+    };
+    let context = Element::new(Atom::new(tag), namespace, Vec::new());
+    let (dom, fragment) = Parser::new(input.to_string()).parse_fragment(&context);
+    let mut lines = Vec::new();
+    dump(&dom, fragment, 0, &mut lines);
+    lines
+}
+
+fn quirks_mode(input: &str) -> QuirksMode {
+    let dom = Parser::new(input.to_string()).parse();
+    match &dom[dom.document()].node_type {
+        NodeType::Document(document) => document.quirks_mode,
+        _ => panic!("expected a document")
+    }
+}
+
+#[test]
+fn implied_elements() {
+    assert_eq!(parse("<!DOCTYPE html><title>x &amp; y</title><p class=a id=b>one<p>two"), [
+        "| <!DOCTYPE html>",
+        "| <html>",
+        "|   <head>",
+        "|     <title>",
+        "|       \"x & y\"",
+        "|   <body>",
+        "|     <p>",
+        "|       class=\"a\"",
+        "|       id=\"b\"",
+        "|       \"one\"",
+        "|     <p>",
+        "|       \"two\"",
+    ]);
+}
+
+#[test]
+fn implied_end_tags() {
+    assert_eq!(parse("<ul><li>a<li>b</ul><dl><dt>c<dd>d</dl><p>e<div>f</div>"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <ul>",
+        "|       <li>",
+        "|         \"a\"",
+        "|       <li>",
+        "|         \"b\"",
+        "|     <dl>",
+        "|       <dt>",
+        "|         \"c\"",
+        "|       <dd>",
+        "|         \"d\"",
+        "|     <p>",
+        "|       \"e\"",
+        "|     <div>",
+        "|       \"f\"",
+    ]);
+}
+
+#[test]
+fn comments_outside_the_body() {
+    assert_eq!(parse("<!--a--><html><!--b--><body></body></html><!--c-->"), [
+        "| <!-- a -->",
+        "| <html>",
+        "|   <!-- b -->",
+        "|   <head>",
+        "|   <body>",
+        "| <!-- c -->",
+    ]);
+}
+
+#[test]
+fn text_only_elements() {
+    assert_eq!(parse("<textarea>\n<b>x</b></textarea><pre>\n\ny</pre>"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <textarea>",
+        "|       \"<b>x</b>\"",
+        "|     <pre>",
+        "|       \"\\ny\"",
+    ]);
+}
+
+#[test]
+fn document_mode() {
+    assert_eq!(quirks_mode("<!DOCTYPE html><p>"), QuirksMode::NoQuirks);
+    assert_eq!(quirks_mode("<p>"), QuirksMode::Quirks);
+    assert_eq!(quirks_mode("<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01 Transitional//EN\" \"x\">"),
+        QuirksMode::LimitedQuirks);
+}
+
+#[test]
+fn adoption_agency() {
+    assert_eq!(parse("<b>1<p>2</b>3</p>"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <b>",
+        "|       \"1\"",
+        "|     <p>",
+        "|       <b>",
+        "|         \"2\"",
+        "|       \"3\"",
+    ]);
+    assert_eq!(parse("<a href=x>1<p>2<a>3</a></p>"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <a>",
+        "|       href=\"x\"",
+        "|       \"1\"",
+        "|     <p>",
+        "|       <a>",
+        "|         href=\"x\"",
+        "|         \"2\"",
+        "|       <a>",
+        "|         \"3\"",
+    ]);
+}
+
+#[test]
+fn misnested_formatting_elements() {
+    assert_eq!(parse("<b><i>x</b>y</i>"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <b>",
+        "|       <i>",
+        "|         \"x\"",
+        "|     <i>",
+        "|       \"y\"",
+    ]);
+}
+
+#[test]
+fn noahs_ark_clause() {
+    assert_eq!(parse("<p><b><b><b><b>x</p>y"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <p>",
+        "|       <b>",
+        "|         <b>",
+        "|           <b>",
+        "|             <b>",
+        "|               \"x\"",
+        "|     <b>",
+        "|       <b>",
+        "|         <b>",
+        "|           \"y\"",
+    ]);
+}
+
+#[test]
+fn foster_parenting() {
+    assert_eq!(parse("<table>a<tr><td>b</td></tr><b>c</table>"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     \"a\"",
+        "|     <b>",
+        "|       \"c\"",
+        "|     <table>",
+        "|       <tbody>",
+        "|         <tr>",
+        "|           <td>",
+        "|             \"b\"",
+    ]);
+}
+
+#[test]
+fn implied_table_sections() {
+    assert_eq!(parse("<table><colgroup><col></colgroup><tr><td>x</table>"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <table>",
+        "|       <colgroup>",
+        "|         <col>",
+        "|       <tbody>",
+        "|         <tr>",
+        "|           <td>",
+        "|             \"x\"",
+    ]);
+}
+
+#[test]
+fn template_contents() {
+    assert_eq!(parse("<template><tr><td>a</td></tr></template><table><template><b>b</b></template></table>"), [
+        "| <html>",
+        "|   <head>",
+        "|     <template>",
+        "|       content",
+        "|         <tr>",
+        "|           <td>",
+        "|             \"a\"",
+        "|   <body>",
+        "|     <table>",
+        "|       <template>",
+        "|         content",
+        "|           <b>",
+        "|             \"b\"",
+    ]);
+}
+
+#[test]
+fn fragment_contexts() {
+    assert_eq!(parse_fragment("tr", "<td>a<td>b"), [
+        "| <td>",
+        "|   \"a\"",
+        "| <td>",
+        "|   \"b\"",
+    ]);
+    assert_eq!(parse_fragment("title", "<b>x</b>"), ["| \"<b>x</b>\""]);
+}
+
+#[test]
+fn form_fragment_ignores_nested_forms() {
+    assert_eq!(parse_fragment("form", "<form><input></form>x"), [
+        "| <input>",
+        "| \"x\"",
+    ]);
+}
+
+#[test]
+fn frameset_fragment_stays_in_frameset() {
+    assert_eq!(parse_fragment("frameset", "<frameset></frameset><frame>"), [
+        "| <frameset>",
+        "| <frame>",
+    ]);
+}
+
+#[test]
+fn foreign_content() {
+    let input = concat!("<svg viewBox=\"0 0 1 1\"><foreignObject><p>a</p></foreignObject><a xlink:href=\"x\">b</a></svg>",
+        "<math><mi>c</mi><annotation-xml encoding=\"text/html\"><div>d</div></annotation-xml></math>");
+    assert_eq!(parse(input), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <svg svg>",
+        "|       viewBox=\"0 0 1 1\"",
+        "|       <svg foreignObject>",
+        "|         <p>",
+        "|           \"a\"",
+        "|       <svg a>",
+        "|         xlink href=\"x\"",
+        "|         \"b\"",
+        "|     <math math>",
+        "|       <math mi>",
+        "|         \"c\"",
+        "|       <math annotation-xml>",
+        "|         encoding=\"text/html\"",
+        "|         <div>",
+        "|           \"d\"",
+    ]);
+}
+
+#[test]
+fn html_breaks_out_of_foreign_content() {
+    assert_eq!(parse("<svg><p>x"), [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <svg svg>",
+        "|     <p>",
+        "|       \"x\"",
+    ]);
+}
+
+#[test]
+fn foreign_fragment_contexts() {
+    assert_eq!(parse_fragment("svg svg", "<![CDATA[x<y]]>"), ["| \"x<y\""]);
+    assert_eq!(parse_fragment("svg title", "<b>x</b><path/>"), [
+        "| <b>",
+        "|   \"x\"",
+        "| <path>",
+    ]);
+    assert_eq!(parse_fragment("svg script", "<b>x</b>"), [
+        "| <b>",
+        "|   \"x\"",
+    ]);
+}
+
+/// Writes the markup given for each script text, and records the scripts it ran.
+struct Writer {
+    writes: Vec<(&'static str, &'static str)>,
+    ran: Rc<RefCell<Vec<String>>>,
+}
+
+impl ScriptHandler for Writer {
+    fn run_script(&mut self, script: &Script, writer: &mut DocumentWriter) {
+        self.ran.borrow_mut().push(script.text.clone());
+        if let Some((_, markup)) = self.writes.iter().find(|(text, _)| *text == script.text) {
+            writer.write(markup);
+        }
+    }
+}
+
+#[test]
+fn scripts_written_by_scripts_run_in_turn() {
+    let ran = Rc::new(RefCell::new(Vec::new()));
+    let mut parser = Parser::new("<body><script>a</script><p>end".to_string());
+    parser.set_script_handler(Writer {
+        writes: vec![("a", "<script>b</script><i>"), ("b", "<b>x</b>")],
+        ran: ran.clone(),
+    });
+    let dom = parser.parse();
+    let mut lines = Vec::new();
+    dump(&dom, dom.document(), 0, &mut lines);
+    assert_eq!(lines, [
+        "| <html>",
+        "|   <head>",
+        "|   <body>",
+        "|     <script>",
+        "|       \"a\"",
+        "|     <script>",
+        "|       \"b\"",
+        "|     <b>",
+        "|       \"x\"",
+        "|     <i>",
+        "|       <p>",
+        "|         \"end\"",
+    ]);
+    assert_eq!(*ran.borrow(), ["a", "b"]);
+}
+
+#[test]
+fn scripts_in_fragments_are_not_run() {
+    let ran = Rc::new(RefCell::new(Vec::new()));
+    let mut parser = Parser::new("<script>a</script>".to_string());
+    parser.set_script_handler(Writer { writes: vec![("a", "<b>")], ran: ran.clone() });
+    let context = Element::new(Atom::new("div"), Namespace::Html, Vec::new());
+    let (dom, fragment) = parser.parse_fragment(&context);
+    assert_eq!(dom.children(fragment).count(), 1);
+    assert!(ran.borrow().is_empty());
+}
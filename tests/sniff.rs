@@ -0,0 +1,75 @@
+use toy_browser::html::encoding::Encoding;
+use toy_browser::html::dom::Dom;
+use toy_browser::html::node::NodeType;
+use toy_browser::html::parser::Parser;
+use toy_browser::html::sniff::{self, Confidence};
+
+/// All the text in the document, in tree order.
+fn text(dom: &Dom) -> String {
+    dom.descendants(dom.document())
+        .filter_map(|node| match &dom[node].node_type {
+            NodeType::Text(text) => Some(text.as_str()),
+            _ => None
+        })
+        .collect()
+}
+
+#[test]
+fn byte_order_mark_wins() {
+    let bytes = b"\xFE\xFF\0<\0p\0>";
+    assert_eq!(sniff::bom(bytes), Some((Encoding::Utf16Be, 2)));
+    assert_eq!(sniff::sniff(bytes, Some("iso-8859-2")), (Encoding::Utf16Be, Confidence::Certain));
+    assert_eq!(sniff::sniff(b"\xEF\xBB\xBF<meta charset=latin2>", None), (Encoding::Utf8, Confidence::Certain));
+}
+
+#[test]
+fn transport_charset_wins_over_meta() {
+    assert_eq!(sniff::sniff(b"<meta charset=utf-8>", Some(" Shift_JIS ")), (Encoding::ShiftJis, Confidence::Certain));
+    assert_eq!(sniff::sniff(b"<meta charset=utf-8>", Some("bogus")), (Encoding::Utf8, Confidence::Tentative));
+}
+
+#[test]
+fn prescan_finds_meta() {
+    assert_eq!(sniff::prescan(b"<!DOCTYPE html><meta charset=\"koi8-r\">"), Some(Encoding::Koi8R));
+    assert_eq!(sniff::prescan(b"<meta http-equiv=Content-Type content='text/html; charset=euc-kr'>"),
+        Some(Encoding::EucKr));
+    assert_eq!(sniff::prescan(b"<meta content='text/html; charset=euc-kr'>"), None);
+    assert_eq!(sniff::prescan(b"<!-- <meta charset=koi8-r> --><title>x</title>"), None);
+    assert_eq!(sniff::prescan(b"<p title='<meta charset=koi8-r>'>"), None);
+    assert_eq!(sniff::prescan(b"<meta charset=utf-16le>"), Some(Encoding::Utf8));
+}
+
+#[test]
+fn fallback_encodings() {
+    assert_eq!(sniff::sniff("<p>caf\u{E9}".as_bytes(), None), (Encoding::Utf8, Confidence::Tentative));
+    assert_eq!(sniff::sniff(b"<p>caf\xE9", None), (Encoding::Windows1252, Confidence::Tentative));
+}
+
+#[test]
+fn bytes_are_decoded_with_the_sniffed_encoding() {
+    let mut parser = Parser::from_bytes(b"<meta charset=iso-8859-7><p>\xE1\xE2\xE3", None);
+    assert_eq!(parser.encoding(), (Encoding::Iso8859_7, Confidence::Tentative));
+    assert_eq!(text(&parser.parse()), "\u{3B1}\u{3B2}\u{3B3}");
+
+    let mut parser = Parser::from_bytes(b"\xFF\xFEx\0y\0", None);
+    assert_eq!(text(&parser.parse()), "xy");
+}
+
+#[test]
+fn late_meta_restarts_with_its_encoding() {
+    let mut bytes = format!("<!--{}-->", " ".repeat(1024)).into_bytes();
+    bytes.extend_from_slice(b"<meta charset=iso-8859-7><p>\xE1\xE2\xE3");
+    let mut parser = Parser::from_bytes(&bytes, None);
+    assert_eq!(parser.encoding(), (Encoding::Windows1252, Confidence::Tentative));
+    assert_eq!(text(&parser.parse()), "\u{3B1}\u{3B2}\u{3B3}");
+    assert_eq!(parser.encoding(), (Encoding::Iso8859_7, Confidence::Certain));
+}
+
+#[test]
+fn meta_confirming_the_guess_makes_it_certain() {
+    let mut bytes = format!("<!--{}-->", " ".repeat(1024)).into_bytes();
+    bytes.extend_from_slice(b"<meta charset=windows-1252><p>caf\xE9");
+    let mut parser = Parser::from_bytes(&bytes, None);
+    assert_eq!(text(&parser.parse()), "caf\u{E9}");
+    assert_eq!(parser.encoding(), (Encoding::Windows1252, Confidence::Certain));
+}